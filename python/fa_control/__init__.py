@@ -25,14 +25,96 @@ from fa_control._internal import (
     set_app_volume,
     is_app_muted,
     set_app_mute,
+    set_app_output_device,
     get_active_audio_apps,
+    get_clients,
+    get_streams,
+    kill_client,
+    kill_stream,
+    get_process_tree_streams,
+    get_process_tree_volume,
+    set_process_tree_volume,
+    is_process_tree_muted,
+    set_process_tree_mute,
+    set_process_tree_output_device,
+    get_app_streams_by_id,
+    get_app_volume_by_id,
+    set_app_volume_by_id,
+    is_app_muted_by_id,
+    set_app_mute_by_id,
+    set_app_output_device_by_id,
+    get_modules,
+    load_module,
+    unload_module,
+    get_restore_entries,
+    write_restore_entry,
+    delete_restore_entries,
+    app_restore_key,
+    watch_preferred_devices,
+    get_routing_rules,
+    set_routing_rule,
+    remove_routing_rule,
+    watch_routing_rules,
     get_microphone_volume,
     set_microphone_volume,
     is_microphone_muted,
     toggle_microphone_mute,
     set_microphone_mute,
+    get_cards,
+    set_card_profile,
+    get_output_devices,
+    get_input_devices,
+    set_device_port,
+    set_default_output_device,
+    set_default_input_device,
+    suspend_device,
+    create_null_sink,
+    create_remapped_source,
+    create_combined_output,
+    create_loopback,
+    create_echo_cancel,
+    start_recording,
+    start_app_recording,
+    start_app_recording_by_selector,
+    start_replay_buffer,
+    play_audio,
+    get_device_channels,
+    play_test_tone,
+    upload_sample,
+    play_sample,
+    get_samples,
+    remove_sample,
+    create_soundboard,
+    create_equalizer,
+    get_equalizer_presets,
+    create_noise_suppressor,
+    get_server_info,
     get_platform,
     AppInfo,
+    CardInfo,
+    CardProfile,
+    DeviceInfo,
+    PortInfo,
+    ChannelInfo,
+    VirtualDevice,
+    Loopback,
+    EchoCancel,
+    Recording,
+    ReplayBuffer,
+    Playback,
+    CachedSample,
+    Soundboard,
+    EqBand,
+    Equalizer,
+    NoiseSuppressor,
+    ServerInfo,
+    ClientInfo,
+    StreamInfo,
+    ModuleInfo,
+    RestoreEntry,
+    PreferredDevices,
+    RoutingRule,
+    RoutingWatcher,
 )
 
 __all__ = [
@@ -45,14 +127,96 @@ __all__ = [
     "set_app_volume",
     "is_app_muted",
     "set_app_mute",
+    "set_app_output_device",
     "get_active_audio_apps",
+    "get_clients",
+    "get_streams",
+    "kill_client",
+    "kill_stream",
+    "get_process_tree_streams",
+    "get_process_tree_volume",
+    "set_process_tree_volume",
+    "is_process_tree_muted",
+    "set_process_tree_mute",
+    "set_process_tree_output_device",
+    "get_app_streams_by_id",
+    "get_app_volume_by_id",
+    "set_app_volume_by_id",
+    "is_app_muted_by_id",
+    "set_app_mute_by_id",
+    "set_app_output_device_by_id",
+    "get_modules",
+    "load_module",
+    "unload_module",
+    "get_restore_entries",
+    "write_restore_entry",
+    "delete_restore_entries",
+    "app_restore_key",
+    "watch_preferred_devices",
+    "get_routing_rules",
+    "set_routing_rule",
+    "remove_routing_rule",
+    "watch_routing_rules",
     "get_microphone_volume",
     "set_microphone_volume",
     "is_microphone_muted",
     "toggle_microphone_mute",
     "set_microphone_mute",
+    "get_cards",
+    "set_card_profile",
+    "get_output_devices",
+    "get_input_devices",
+    "set_device_port",
+    "set_default_output_device",
+    "set_default_input_device",
+    "suspend_device",
+    "create_null_sink",
+    "create_remapped_source",
+    "create_combined_output",
+    "create_loopback",
+    "create_echo_cancel",
+    "start_recording",
+    "start_app_recording",
+    "start_app_recording_by_selector",
+    "start_replay_buffer",
+    "play_audio",
+    "get_device_channels",
+    "play_test_tone",
+    "upload_sample",
+    "play_sample",
+    "get_samples",
+    "remove_sample",
+    "create_soundboard",
+    "create_equalizer",
+    "get_equalizer_presets",
+    "create_noise_suppressor",
+    "get_server_info",
     "get_platform",
     "AppInfo",
+    "CardInfo",
+    "CardProfile",
+    "DeviceInfo",
+    "PortInfo",
+    "ChannelInfo",
+    "VirtualDevice",
+    "Loopback",
+    "EchoCancel",
+    "Recording",
+    "ReplayBuffer",
+    "Playback",
+    "CachedSample",
+    "Soundboard",
+    "EqBand",
+    "Equalizer",
+    "NoiseSuppressor",
+    "ServerInfo",
+    "ClientInfo",
+    "StreamInfo",
+    "ModuleInfo",
+    "RestoreEntry",
+    "PreferredDevices",
+    "RoutingRule",
+    "RoutingWatcher",
 ]
 
 __version__ = "0.1.3"
@@ -0,0 +1,284 @@
+"""
+Command line interface for fa-control
+
+Usage:
+    fa-control replay [--seconds N] [--device NAME | --pid PID] [--output-dir DIR]
+    fa-control speaker-test [--device NAME] [--channel NAME] [--signal sine|pink] ...
+    fa-control clients | streams
+    fa-control kill-client INDEX
+    fa-control kill-stream INDEX [--kind sink_input|source_output]
+    fa-control modules | unload-module INDEX
+    fa-control prefer-devices [--output NAME ...] [--input NAME ...] [--no-move-streams]
+    fa-control routes | route SELECTOR DEVICE | unroute SELECTOR | watch-routes
+    python -m fa_control replay ...
+"""
+
+import argparse
+import os
+import signal
+import sys
+import time
+
+import fa_control
+
+
+def _replay(args):
+    buffer = fa_control.start_replay_buffer(
+        seconds=args.seconds,
+        device=args.device,
+        pid=args.pid,
+        rate=args.rate,
+        channels=args.channels,
+    )
+
+    def save():
+        name = time.strftime("replay-%Y%m%d-%H%M%S.wav")
+        path = os.path.join(args.output_dir, name)
+        written = buffer.save(path)
+        print(f"Saved {written:.1f}s to {path}", flush=True)
+
+    if hasattr(signal, "SIGUSR1"):
+        signal.signal(signal.SIGUSR1, lambda signum, frame: save())
+
+    print(
+        f"Buffering the last {args.seconds:g}s (pid {os.getpid()}). "
+        "Press Enter or send SIGUSR1 to save, Ctrl+C to quit.",
+        flush=True,
+    )
+    with buffer:
+        try:
+            for _ in sys.stdin:
+                save()
+            # stdin closed (e.g. running in the background): wait for signals
+            while True:
+                time.sleep(1)
+        except KeyboardInterrupt:
+            pass
+    return 0
+
+
+def _speaker_test(args):
+    labels = {c.name: c.label for c in fa_control.get_device_channels(args.device)}
+    channels = [args.channel] if args.channel else list(labels)
+
+    try:
+        for channel in channels:
+            print(labels.get(channel, channel), flush=True)
+            fa_control.play_test_tone(
+                device=args.device,
+                channel=channel,
+                signal=args.signal,
+                frequency=args.frequency,
+                level=args.level,
+                duration=args.duration,
+                wait=True,
+            )
+    except KeyboardInterrupt:
+        pass
+    return 0
+
+
+def _clients(args):
+    for client in fa_control.get_clients():
+        pid = client.pid if client.pid is not None else "-"
+        print(f"{client.index:>5}  {pid:>7}  {client.driver:<20}  {client.name}")
+    return 0
+
+
+def _streams(args):
+    for stream in fa_control.get_streams():
+        pid = stream.pid if stream.pid is not None else "-"
+        client = stream.client if stream.client is not None else "-"
+        print(f"{stream.index:>5}  {stream.kind:<13}  {client:>6}  {pid:>7}  {stream.name}")
+    return 0
+
+
+def _kill_client(args):
+    fa_control.kill_client(args.index)
+    return 0
+
+
+def _kill_stream(args):
+    fa_control.kill_stream(args.index, kind=args.kind)
+    return 0
+
+
+def _modules(args):
+    for module in fa_control.get_modules():
+        usage = module.usage if module.usage is not None else "-"
+        print(f"{module.index:>5}  {usage:>5}  {module.name:<28}  {module.argument}")
+    return 0
+
+
+def _unload_module(args):
+    fa_control.unload_module(args.index)
+    return 0
+
+
+def _prefer_devices(args):
+    if not args.output and not args.input:
+        raise ValueError("Give at least one --output or --input device")
+
+    watcher = fa_control.watch_preferred_devices(
+        outputs=args.output, inputs=args.input, move_streams=args.move_streams
+    )
+    print("Keeping the preferred devices as defaults. Press Ctrl+C to quit.", flush=True)
+    with watcher:
+        try:
+            while watcher.running:
+                time.sleep(1)
+        except KeyboardInterrupt:
+            return 0
+    raise RuntimeError("Lost connection to the sound server")
+
+
+def _routes(args):
+    for rule in fa_control.get_routing_rules(args.rules):
+        print(f"{rule.selector}  ->  {rule.device}")
+    return 0
+
+
+def _route(args):
+    fa_control.set_routing_rule(args.selector, args.device, args.rules)
+    return 0
+
+
+def _unroute(args):
+    if not fa_control.remove_routing_rule(args.selector, args.rules):
+        raise ValueError(f"No routing rule for '{args.selector}'")
+    return 0
+
+
+def _watch_routes(args):
+    watcher = fa_control.watch_routing_rules(args.rules)
+    print(f"Applying routing rules from {watcher.path}. Press Ctrl+C to quit.", flush=True)
+    with watcher:
+        try:
+            while watcher.running:
+                time.sleep(1)
+        except KeyboardInterrupt:
+            return 0
+    raise RuntimeError("Lost connection to the sound server")
+
+
+def build_parser():
+    parser = argparse.ArgumentParser(
+        prog="fa-control", description="Control audio devices and applications"
+    )
+    commands = parser.add_subparsers(dest="command", required=True)
+
+    replay = commands.add_parser(
+        "replay", help="keep the last N seconds of audio in memory and save them on demand"
+    )
+    replay.add_argument("--seconds", type=float, default=30.0, help="seconds of audio to keep")
+    source = replay.add_mutually_exclusive_group()
+    source.add_argument("--device", help="device to capture (default: default input)")
+    source.add_argument("--pid", type=int, help="capture only this application's audio")
+    replay.add_argument("--rate", type=int, default=48000)
+    replay.add_argument("--channels", type=int, default=2)
+    replay.add_argument("--output-dir", default=".", help="directory to save replays to")
+    replay.set_defaults(func=_replay)
+
+    speaker_test = commands.add_parser(
+        "speaker-test", help="play a test signal on each speaker of a device in turn"
+    )
+    speaker_test.add_argument("--device", help="output device (default: default output)")
+    speaker_test.add_argument(
+        "--channel", help="only test this channel, e.g. front-left (default: every channel)"
+    )
+    speaker_test.add_argument("--signal", choices=["sine", "pink"], default="sine")
+    speaker_test.add_argument("--frequency", type=float, default=440.0, help="sine frequency in Hz")
+    speaker_test.add_argument("--level", type=float, default=0.5, help="peak level, 0.0 to 1.0")
+    speaker_test.add_argument(
+        "--duration", type=float, default=1.0, help="seconds to play on each channel"
+    )
+    speaker_test.set_defaults(func=_speaker_test)
+
+    clients = commands.add_parser(
+        "clients", help="list connected clients: index, PID, driver and name"
+    )
+    clients.set_defaults(func=_clients)
+
+    streams = commands.add_parser(
+        "streams", help="list playback and recording streams: index, kind, client, PID and name"
+    )
+    streams.set_defaults(func=_streams)
+
+    kill_client = commands.add_parser(
+        "kill-client", help="disconnect a client, closing all of its streams"
+    )
+    kill_client.add_argument("index", type=int, help="client index, from `fa-control clients`")
+    kill_client.set_defaults(func=_kill_client)
+
+    kill_stream = commands.add_parser("kill-stream", help="close a single stream")
+    kill_stream.add_argument("index", type=int, help="stream index, from `fa-control streams`")
+    kill_stream.add_argument(
+        "--kind", choices=["sink_input", "source_output"], default="sink_input"
+    )
+    kill_stream.set_defaults(func=_kill_stream)
+
+    modules = commands.add_parser(
+        "modules", help="list loaded server modules: index, usage count, name and arguments"
+    )
+    modules.set_defaults(func=_modules)
+
+    unload_module = commands.add_parser("unload-module", help="unload a server module")
+    unload_module.add_argument("index", type=int, help="module index, from `fa-control modules`")
+    unload_module.set_defaults(func=_unload_module)
+
+    prefer_devices = commands.add_parser(
+        "prefer-devices",
+        help="keep the default devices on the first available device of each list",
+    )
+    prefer_devices.add_argument(
+        "--output", action="append", default=[], help="output device, highest priority first"
+    )
+    prefer_devices.add_argument(
+        "--input", action="append", default=[], help="input device, highest priority first"
+    )
+    prefer_devices.add_argument(
+        "--no-move-streams",
+        dest="move_streams",
+        action="store_false",
+        help="leave existing streams on the previous default",
+    )
+    prefer_devices.set_defaults(func=_prefer_devices)
+
+    rules = argparse.ArgumentParser(add_help=False)
+    rules.add_argument("--rules", help="routing rules file (default: ~/.config/fa-control/routing.rules)")
+
+    routes = commands.add_parser("routes", parents=[rules], help="list per-app routing rules")
+    routes.set_defaults(func=_routes)
+
+    route = commands.add_parser(
+        "route", parents=[rules], help="always play matching applications on a device"
+    )
+    route.add_argument(
+        "selector", help="application name or binary, or a stream property as key=value"
+    )
+    route.add_argument("device", help="output device name")
+    route.set_defaults(func=_route)
+
+    unroute = commands.add_parser("unroute", parents=[rules], help="remove a routing rule")
+    unroute.add_argument("selector")
+    unroute.set_defaults(func=_unroute)
+
+    watch_routes = commands.add_parser(
+        "watch-routes", parents=[rules], help="keep applying the routing rules until Ctrl+C"
+    )
+    watch_routes.set_defaults(func=_watch_routes)
+
+    return parser
+
+
+def main(argv=None):
+    args = build_parser().parse_args(argv)
+    try:
+        return args.func(args)
+    except (RuntimeError, ValueError) as e:
+        print(f"fa-control: {e}", file=sys.stderr)
+        return 1
+
+
+if __name__ == "__main__":
+    sys.exit(main())
@@ -1,3 +1,5 @@
+import time
+
 import pytest
 import fa_control
 
@@ -154,6 +156,843 @@ class TestAppVolume:
         fa_control.set_app_mute(first_app.pid, original)
 
 
+@pytest.mark.skipif(fa_control.get_platform() != "linux", reason="Linux only")
+class TestCards:
+    """Test card profile listing and switching"""
+    def test_get_cards(self):
+        cards = fa_control.get_cards()
+        assert isinstance(cards, list)
+
+        for card in cards:
+            assert isinstance(card, fa_control.CardInfo)
+            assert isinstance(card.name, str)
+            for profile in card.profiles:
+                assert isinstance(profile, fa_control.CardProfile)
+                assert isinstance(profile.available, bool)
+
+    def test_set_card_profile(self):
+        cards = [card for card in fa_control.get_cards() if card.active_profile]
+        if not cards:
+            pytest.skip("No cards with an active profile")
+
+        card = cards[0]
+        fa_control.set_card_profile(card.name, card.active_profile)
+        current = [c for c in fa_control.get_cards() if c.name == card.name][0]
+        assert current.active_profile == card.active_profile
+
+    def test_set_card_profile_invalid(self):
+        with pytest.raises(RuntimeError):
+            fa_control.set_card_profile("no-such-card", "off")
+
+
+@pytest.mark.skipif(fa_control.get_platform() != "linux", reason="Linux only")
+class TestDevices:
+    """Test device and port listing"""
+    def test_get_output_devices(self):
+        devices = fa_control.get_output_devices()
+        assert isinstance(devices, list)
+
+        for device in devices:
+            assert isinstance(device, fa_control.DeviceInfo)
+            assert isinstance(device.name, str)
+            assert sum(port.active for port in device.ports) <= 1
+            for port in device.ports:
+                assert isinstance(port, fa_control.PortInfo)
+                assert port.available in (True, False, None)
+
+    def test_get_input_devices(self):
+        devices = fa_control.get_input_devices()
+        assert isinstance(devices, list)
+
+        for device in devices:
+            assert isinstance(device, fa_control.DeviceInfo)
+            assert device.state in ("running", "idle", "suspended", "unknown")
+
+    def test_suspend_device(self):
+        with fa_control.create_null_sink("fa_control_test_suspend") as sink:
+            fa_control.suspend_device(sink.name)
+            states = {d.name: d.state for d in fa_control.get_output_devices()}
+            assert states[sink.name] == "suspended"
+
+            fa_control.suspend_device(sink.name, False)
+            states = {d.name: d.state for d in fa_control.get_output_devices()}
+            assert states[sink.name] != "suspended"
+
+    def test_suspend_device_invalid(self):
+        with pytest.raises(RuntimeError):
+            fa_control.suspend_device("no-such-device")
+
+    def test_set_device_port(self):
+        devices = [d for d in fa_control.get_output_devices() if d.active_port]
+        if not devices:
+            pytest.skip("No output devices with ports")
+
+        device = devices[0]
+        fa_control.set_device_port(device.name, device.active_port)
+
+    def test_set_device_port_invalid(self):
+        with pytest.raises(RuntimeError):
+            fa_control.set_device_port("no-such-device", "headphones")
+
+
+@pytest.mark.skipif(fa_control.get_platform() != "linux", reason="Linux only")
+class TestVirtualDevices:
+    """Test virtual null sinks and remapped sources"""
+    def test_create_null_sink(self):
+        sink = fa_control.create_null_sink("fa_control_test_sink", "fa-control Test Sink")
+        try:
+            names = [d.name for d in fa_control.get_output_devices()]
+            assert "fa_control_test_sink" in names
+        finally:
+            sink.destroy()
+
+        assert not sink.active
+        names = [d.name for d in fa_control.get_output_devices()]
+        assert "fa_control_test_sink" not in names
+
+    def test_create_remapped_source(self):
+        with fa_control.create_null_sink("fa_control_test_sink") as sink:
+            with fa_control.create_remapped_source(
+                "fa_control_test_mic", f"{sink.name}.monitor", "fa-control Test Mic"
+            ):
+                names = [d.name for d in fa_control.get_input_devices()]
+                assert "fa_control_test_mic" in names
+
+    def test_create_combined_output(self):
+        with fa_control.create_null_sink("fa_control_test_a") as a, \
+                fa_control.create_null_sink("fa_control_test_b") as b:
+            with fa_control.create_combined_output(
+                [a.name, b.name], "fa_control_test_combined", "fa-control Combined"
+            ) as combined:
+                names = [d.name for d in fa_control.get_output_devices()]
+                assert combined.name in names
+
+    def test_create_combined_output_needs_two_devices(self):
+        with pytest.raises(RuntimeError):
+            fa_control.create_combined_output(["only_one"], "fa_control_test_combined")
+
+    def test_create_null_sink_invalid_name(self):
+        with pytest.raises(RuntimeError):
+            fa_control.create_null_sink("bad name")
+
+
+@pytest.mark.skipif(fa_control.get_platform() != "linux", reason="Linux only")
+class TestLoopback:
+    """Test loopback routing"""
+    def test_create_loopback(self):
+        with fa_control.create_null_sink("fa_control_test_sink") as sink:
+            with fa_control.create_loopback(f"{sink.name}.monitor", sink.name, 50) as loopback:
+                assert loopback.active
+
+                loopback.set_volume(0.3)
+                assert loopback.get_volume() == pytest.approx(0.3, rel=1e-2)
+
+                loopback.set_mute(True)
+                assert loopback.is_muted() is True
+
+            assert not loopback.active
+
+    def test_set_loopback_volume_invalid(self):
+        with fa_control.create_null_sink("fa_control_test_sink") as sink:
+            with fa_control.create_loopback(f"{sink.name}.monitor", sink.name) as loopback:
+                with pytest.raises(ValueError, match="Volume must be between 0.0 and 1.0"):
+                    loopback.set_volume(1.5)
+
+
+@pytest.mark.skipif(fa_control.get_platform() != "linux", reason="Linux only")
+class TestEchoCancel:
+    """Test echo-cancelled source/sink pairs"""
+    def test_create_echo_cancel(self):
+        with fa_control.create_echo_cancel(
+            source_name="fa_control_test_ec_source",
+            sink_name="fa_control_test_ec_sink",
+        ) as echo_cancel:
+            inputs = [d.name for d in fa_control.get_input_devices()]
+            outputs = [d.name for d in fa_control.get_output_devices()]
+            assert echo_cancel.source_name in inputs
+            assert echo_cancel.sink_name in outputs
+
+        assert not echo_cancel.active
+
+    def test_create_echo_cancel_invalid_name(self):
+        with pytest.raises(RuntimeError):
+            fa_control.create_echo_cancel(source_name="bad name")
+
+
+@pytest.mark.skipif(fa_control.get_platform() != "linux", reason="Linux only")
+class TestRecording:
+    """Test recording to WAV files and callbacks"""
+    def test_record_to_wav(self, tmp_path):
+        path = tmp_path / "test.wav"
+        with fa_control.create_null_sink("fa_control_test_record") as sink:
+            recording = fa_control.start_recording(
+                str(path), device=sink.name, max_duration=0.2
+            )
+            recording.wait()
+
+        header = path.read_bytes()[:12]
+        assert header[:4] == b"RIFF"
+        assert header[8:12] == b"WAVE"
+
+    def test_record_to_callback(self):
+        chunks = []
+        with fa_control.create_null_sink("fa_control_test_record") as sink:
+            with fa_control.start_recording(
+                callback=chunks.append, device=sink.name, channels=1, max_duration=0.2
+            ) as recording:
+                recording.wait()
+
+        assert all(isinstance(chunk, bytes) for chunk in chunks)
+
+    def test_record_requires_one_output(self, tmp_path):
+        with pytest.raises(ValueError):
+            fa_control.start_recording()
+        with pytest.raises(ValueError):
+            fa_control.start_recording(str(tmp_path / "a.wav"), callback=print)
+
+    def test_record_invalid_format(self, tmp_path):
+        with pytest.raises(ValueError):
+            fa_control.start_recording(str(tmp_path / "a.wav"), format="mp3")
+
+    def test_record_app_invalid_pid(self, tmp_path):
+        with pytest.raises(RuntimeError):
+            fa_control.start_app_recording(999999, str(tmp_path / "a.wav"))
+
+    def test_record_app(self, tmp_path):
+        apps = fa_control.get_active_audio_apps()
+        if not apps:
+            pytest.skip("No audio applications running")
+
+        path = tmp_path / "app.wav"
+        with fa_control.start_app_recording(apps[0].pid, str(path)):
+            time.sleep(0.2)
+        assert path.read_bytes()[:4] == b"RIFF"
+
+    def test_record_app_by_selector(self, tmp_path):
+        name = "fa-control selector recording"
+        playback = fa_control.play_audio(data=bytes(4 * 48000 * 2), name=name)
+        try:
+            path = tmp_path / "selector.wav"
+            with fa_control.start_app_recording_by_selector(f"media.name={name}", str(path)):
+                time.sleep(0.2)
+            assert path.read_bytes()[:4] == b"RIFF"
+        finally:
+            playback.stop()
+
+        with pytest.raises(RuntimeError):
+            fa_control.start_app_recording_by_selector(
+                "application.name=fa-control missing", str(tmp_path / "missing.wav")
+            )
+
+
+@pytest.mark.skipif(fa_control.get_platform() != "linux", reason="Linux only")
+class TestReplayBuffer:
+    """Test instant replay buffers"""
+    def test_replay_save(self, tmp_path):
+        path = tmp_path / "replay.wav"
+        with fa_control.create_null_sink("fa_control_test_replay") as sink:
+            with fa_control.start_replay_buffer(seconds=1, device=sink.name) as replay:
+                assert replay.running
+                assert replay.capacity_seconds == pytest.approx(1.0, rel=1e-2)
+                time.sleep(0.3)
+                written = replay.save(str(path), seconds=0.1)
+
+        assert not replay.running
+        assert written <= 0.1 + 1e-3
+        assert path.read_bytes()[:4] == b"RIFF"
+
+    def test_replay_invalid_arguments(self):
+        with pytest.raises(ValueError):
+            fa_control.start_replay_buffer(seconds=0)
+        with pytest.raises(RuntimeError):
+            fa_control.start_replay_buffer(seconds=1, device="a", pid=1)
+        with pytest.raises(RuntimeError):
+            fa_control.start_replay_buffer(seconds=100000)
+
+
+@pytest.mark.skipif(fa_control.get_platform() != "linux", reason="Linux only")
+class TestPlayback:
+    """Test playing WAV files and PCM buffers"""
+    def test_play_pcm_listed_as_app(self):
+        silence = bytes(4 * 48000)  # 1 second of 16 bit stereo
+        with fa_control.create_null_sink("fa_control_test_playback") as sink:
+            with fa_control.play_audio(
+                data=silence, device=sink.name, name="fa-control test tone", role="event"
+            ) as playback:
+                assert not playback.finished
+                names = [app.name for app in fa_control.get_active_audio_apps()]
+                assert "fa-control test tone" in names
+
+            assert playback.finished
+
+    def test_play_wav_blocking(self, tmp_path):
+        path = tmp_path / "clip.wav"
+        with fa_control.create_null_sink("fa_control_test_playback") as sink:
+            fa_control.start_recording(
+                str(path), device=sink.name, max_duration=0.2
+            ).wait()
+            playback = fa_control.play_audio(str(path), device=sink.name, wait=True)
+
+        assert playback.finished
+
+    def test_play_invalid_arguments(self, tmp_path):
+        with pytest.raises(ValueError):
+            fa_control.play_audio()
+        with pytest.raises(RuntimeError):
+            fa_control.play_audio(data=b"\x00\x00\x00")
+        with pytest.raises(RuntimeError):
+            fa_control.play_audio(str(tmp_path / "missing.wav"))
+
+
+@pytest.mark.skipif(fa_control.get_platform() != "linux", reason="Linux only")
+class TestSpeakerTest:
+    """Test test tone generation"""
+    def test_device_channels(self):
+        with fa_control.create_null_sink("fa_control_test_speakers") as sink:
+            channels = fa_control.get_device_channels(sink.name)
+            assert [c.name for c in channels] == ["front-left", "front-right"]
+            assert channels[0].label == "Front Left"
+
+            device = next(d for d in fa_control.get_output_devices() if d.name == sink.name)
+            assert [c.name for c in device.channels] == ["front-left", "front-right"]
+
+    def test_play_test_tone_on_channel(self):
+        with fa_control.create_null_sink("fa_control_test_speakers") as sink:
+            playback = fa_control.play_test_tone(
+                device=sink.name, channel="front-right", duration=0.1, wait=True
+            )
+            assert playback.finished
+
+            fa_control.play_test_tone(
+                device=sink.name, signal="pink", level=0.1, duration=0.1, wait=True
+            )
+
+    def test_play_test_tone_invalid_arguments(self):
+        with pytest.raises(ValueError):
+            fa_control.play_test_tone(signal="square")
+        with pytest.raises(ValueError):
+            fa_control.play_test_tone(level=2.0)
+        with fa_control.create_null_sink("fa_control_test_speakers") as sink:
+            with pytest.raises(RuntimeError):
+                fa_control.play_test_tone(device=sink.name, channel="rear-center")
+            with pytest.raises(RuntimeError):
+                fa_control.play_test_tone(device=sink.name, frequency=30000)
+
+
+@pytest.mark.skipif(fa_control.get_platform() != "linux", reason="Linux only")
+class TestSampleCache:
+    """Test the sample cache"""
+    def test_upload_play_remove(self):
+        beep = bytes(2 * 4800)  # 0.1 seconds of 16 bit mono
+        fa_control.upload_sample("fa_control_test_beep", data=beep, channels=1)
+        try:
+            samples = {s.name: s for s in fa_control.get_samples()}
+            sample = samples["fa_control_test_beep"]
+            assert sample.channels == 1
+            assert sample.rate == 48000
+            assert sample.duration == pytest.approx(0.1, abs=0.01)
+
+            with fa_control.create_null_sink("fa_control_test_samples") as sink:
+                fa_control.play_sample("fa_control_test_beep", device=sink.name, volume=0.5)
+        finally:
+            fa_control.remove_sample("fa_control_test_beep")
+
+        assert "fa_control_test_beep" not in [s.name for s in fa_control.get_samples()]
+
+    def test_play_missing_sample(self):
+        with pytest.raises(RuntimeError):
+            fa_control.play_sample("fa_control_test_missing")
+
+    def test_invalid_arguments(self):
+        with pytest.raises(ValueError):
+            fa_control.upload_sample("fa_control_test_beep")
+        with pytest.raises(ValueError):
+            fa_control.play_sample("fa_control_test_beep", volume=1.5)
+
+
+@pytest.mark.skipif(fa_control.get_platform() != "linux", reason="Linux only")
+class TestSoundboard:
+    """Test soundboard virtual microphones"""
+    def test_soundboard_clips(self):
+        with fa_control.create_null_sink("fa_control_test_board_src") as mic:
+            with fa_control.create_soundboard(
+                "fa_control_test_board", microphone=f"{mic.name}.monitor"
+            ) as board:
+                inputs = [d.name for d in fa_control.get_input_devices()]
+                assert board.source_name == "fa_control_test_board_mic"
+                assert board.source_name in inputs
+
+                board.add_clip("beep", data=bytes(4 * 4800), volume=0.5)
+                assert board.clips == ["beep"]
+                assert board.get_clip_volume("beep") == pytest.approx(0.5)
+                board.set_clip_volume("beep", 0.8)
+                board.play_clip("beep")
+                board.play_clip("beep")
+                board.stop_clips()
+
+                board.set_microphone_mute(True)
+                assert board.is_microphone_muted()
+
+                board.remove_clip("beep")
+                with pytest.raises(RuntimeError):
+                    board.play_clip("beep")
+
+            assert not board.active
+            inputs = [d.name for d in fa_control.get_input_devices()]
+            assert "fa_control_test_board_mic" not in inputs
+
+    def test_soundboard_invalid_name(self):
+        with pytest.raises(RuntimeError):
+            fa_control.create_soundboard("bad name")
+
+
+@pytest.mark.skipif(fa_control.get_platform() != "linux", reason="Linux only")
+class TestEqualizer:
+    """Test equalizer virtual sinks"""
+    def test_equalizer_presets(self, tmp_path):
+        assert "flat" in fa_control.get_equalizer_presets()
+
+        with fa_control.create_equalizer("fa_control_test_eq", preset="bass_boost") as eq:
+            outputs = [d.name for d in fa_control.get_output_devices()]
+            assert eq.sink_name == "fa_control_test_eq"
+            assert eq.sink_name in outputs
+            assert eq.preset_name == "bass_boost"
+
+            eq.set_bands([
+                fa_control.EqBand("low_shelf", 100, gain_db=4.0),
+                fa_control.EqBand("peaking", 3000, gain_db=-2.0, q=1.5),
+            ])
+            eq.set_band(1, fa_control.EqBand("high_shelf", 8000, gain_db=2.0))
+            eq.set_preamp(-3.0)
+            assert [b.filter for b in eq.bands] == ["low_shelf", "high_shelf"]
+
+            path = tmp_path / "custom.preset"
+            eq.save_preset(str(path))
+            eq.load_preset("flat")
+            assert eq.bands == []
+            eq.load_preset(str(path))
+            assert eq.preset_name == "custom"
+            assert eq.preamp_db == pytest.approx(-3.0)
+            assert len(eq.bands) == 2
+
+        assert not eq.active
+        outputs = [d.name for d in fa_control.get_output_devices()]
+        assert "fa_control_test_eq" not in outputs
+
+    def test_invalid_bands(self):
+        with pytest.raises(ValueError):
+            fa_control.EqBand("notch", 1000)
+        with pytest.raises(ValueError):
+            fa_control.EqBand("peaking", -5)
+        with pytest.raises(ValueError):
+            fa_control.create_equalizer("fa_control_test_eq", preset="no_such_preset")
+
+
+@pytest.mark.skipif(fa_control.get_platform() != "linux", reason="Linux only")
+class TestNoiseSuppression:
+    """Test noise-suppressed virtual microphones"""
+    def test_noise_suppressor(self):
+        with fa_control.create_null_sink("fa_control_test_ns_mic") as mic:
+            with fa_control.create_noise_suppressor(
+                "fa_control_test_ns", microphone=f"{mic.name}.monitor", strength=0.5
+            ) as ns:
+                inputs = [d.name for d in fa_control.get_input_devices()]
+                assert ns.source_name == "fa_control_test_ns"
+                assert ns.source_name in inputs
+                assert ns.microphone == f"{mic.name}.monitor"
+
+                assert ns.enabled
+                ns.set_enabled(False)
+                assert not ns.enabled
+                ns.set_strength(0.9)
+                assert ns.strength == pytest.approx(0.9)
+
+                ns.set_volume(0.5)
+                assert ns.get_volume() == pytest.approx(0.5, abs=0.02)
+                ns.set_mute(True)
+                assert ns.is_muted()
+
+            assert not ns.active
+            inputs = [d.name for d in fa_control.get_input_devices()]
+            assert "fa_control_test_ns" not in inputs
+
+    def test_invalid_strength(self):
+        with pytest.raises(ValueError):
+            fa_control.create_noise_suppressor("fa_control_test_ns", strength=1.5)
+
+
+@pytest.mark.skipif(fa_control.get_platform() != "linux", reason="Linux only")
+class TestServerInfo:
+    """Test the server information query"""
+    def test_get_server_info(self):
+        info = fa_control.get_server_info()
+        assert isinstance(info, fa_control.ServerInfo)
+        assert info.name
+        assert info.version
+        assert info.sample_rate > 0
+        assert len(info.channel_map) == info.channels
+        assert info.is_pipewire == ("PipeWire" in info.name)
+
+    def test_default_sink_is_listed(self):
+        info = fa_control.get_server_info()
+        if info.default_sink is None:
+            pytest.skip("No default output device")
+        names = [d.name for d in fa_control.get_output_devices()]
+        assert info.default_sink in names
+
+
+@pytest.mark.skipif(fa_control.get_platform() != "linux", reason="Linux only")
+class TestClients:
+    """Test client and stream listing and killing"""
+    def test_get_clients(self):
+        clients = fa_control.get_clients()
+        assert clients
+        for client in clients:
+            assert isinstance(client, fa_control.ClientInfo)
+            assert isinstance(client.properties, dict)
+
+    def test_kill_stream(self):
+        playback = fa_control.play_audio(data=bytes(4 * 48000 * 5))
+        try:
+            streams = [s for s in fa_control.get_streams() if s.index == playback.sink_input_index]
+            assert len(streams) == 1
+            assert streams[0].kind == "sink_input"
+
+            fa_control.kill_stream(playback.sink_input_index)
+            indexes = [s.index for s in fa_control.get_streams() if s.kind == "sink_input"]
+            assert playback.sink_input_index not in indexes
+        finally:
+            playback.stop()
+
+    def test_kill_missing(self):
+        with pytest.raises(RuntimeError):
+            fa_control.kill_client(999999)
+        with pytest.raises(RuntimeError):
+            fa_control.kill_stream(999999, "source_output")
+        with pytest.raises(ValueError):
+            fa_control.kill_stream(1, "speaker")
+
+
+@pytest.mark.skipif(fa_control.get_platform() != "linux", reason="Linux only")
+class TestModules:
+    """Test module listing, loading and unloading"""
+    def test_load_and_unload(self):
+        index = fa_control.load_module(
+            "module-null-sink", "sink_name=fa_control_module_test"
+        )
+        try:
+            modules = {m.index: m for m in fa_control.get_modules()}
+            assert modules[index].name == "module-null-sink"
+            assert "fa_control_module_test" in modules[index].argument
+        finally:
+            fa_control.unload_module(index)
+
+        assert index not in [m.index for m in fa_control.get_modules()]
+
+    def test_invalid(self):
+        with pytest.raises(RuntimeError):
+            fa_control.load_module("module-null-sink sink_name=x")
+        with pytest.raises(RuntimeError):
+            fa_control.unload_module(999999)
+
+
+@pytest.mark.skipif(fa_control.get_platform() != "linux", reason="Linux only")
+class TestStreamRestore:
+    """Test the stream-restore database"""
+    def test_app_restore_key(self):
+        assert fa_control.app_restore_key("Firefox") == "sink-input-by-application-name:Firefox"
+
+    def test_write_and_delete(self):
+        key = fa_control.app_restore_key("fa-control restore test")
+        fa_control.write_restore_entry(key, volume=0.5, mute=True, apply=False)
+        try:
+            entries = {e.name: e for e in fa_control.get_restore_entries()}
+            assert abs(entries[key].volume - 0.5) < 0.01
+            assert entries[key].mute
+        finally:
+            fa_control.delete_restore_entries([key])
+
+        assert key not in [e.name for e in fa_control.get_restore_entries()]
+
+    def test_invalid_volume(self):
+        with pytest.raises(ValueError):
+            fa_control.write_restore_entry("sink-input-by-media-role:music", volume=1.5)
+
+    def test_nul_rejected(self):
+        with pytest.raises(RuntimeError):
+            fa_control.write_restore_entry("sink-input-by-media-role:music", device="bad\0device")
+        with pytest.raises(RuntimeError):
+            fa_control.write_restore_entry("sink-input-by-media-role:\0music")
+
+
+@pytest.mark.skipif(fa_control.get_platform() != "linux", reason="Linux only")
+class TestPreferredDevices:
+    """Test preferred-device lists"""
+    def test_switches_to_preferred_device(self):
+        original = fa_control.get_server_info().default_sink
+        sink = fa_control.create_null_sink("fa_control_preferred_test", "Preferred Test")
+        try:
+            with fa_control.watch_preferred_devices(outputs=[sink.name]) as watcher:
+                assert watcher.running
+                assert watcher.outputs == [sink.name]
+                assert fa_control.get_server_info().default_sink == sink.name
+
+                # Unknown devices are skipped, leaving the current default
+                watcher.set_outputs(["no_such_device", sink.name])
+                assert fa_control.get_server_info().default_sink == sink.name
+            assert not watcher.running
+        finally:
+            sink.destroy()
+            if original:
+                fa_control.set_default_output_device(original)
+
+    def test_stops_when_disconnected(self):
+        watcher = fa_control.watch_preferred_devices()
+        assert watcher.running
+        client = next(
+            c for c in fa_control.get_clients() if c.name == "fa-control-preferred-devices"
+        )
+        fa_control.kill_client(client.index)
+
+        deadline = time.time() + 2
+        while watcher.running and time.time() < deadline:
+            time.sleep(0.05)
+        assert not watcher.running
+        with pytest.raises(RuntimeError, match="Lost connection"):
+            watcher.stop()
+
+    def test_stopped_watcher(self):
+        watcher = fa_control.watch_preferred_devices()
+        watcher.stop()
+        with pytest.raises(RuntimeError):
+            watcher.set_outputs([])
+
+
+@pytest.mark.skipif(fa_control.get_platform() != "linux", reason="Linux only")
+class TestRouting:
+    """Test per-app routing rules"""
+    def test_set_and_remove_rules(self, tmp_path):
+        path = tmp_path / "routing.rules"
+        assert fa_control.get_routing_rules(path) == []
+
+        fa_control.set_routing_rule("spotify", "speakers", path)
+        fa_control.set_routing_rule("application.name=Discord", "headset", path)
+        fa_control.set_routing_rule("spotify", "headset", path)
+        rules = fa_control.get_routing_rules(path)
+        assert [(r.selector, r.device) for r in rules] == [
+            ("spotify", "headset"),
+            ("application.name=Discord", "headset"),
+        ]
+
+        assert fa_control.remove_routing_rule("spotify", path)
+        assert not fa_control.remove_routing_rule("spotify", path)
+        assert len(fa_control.get_routing_rules(path)) == 1
+
+    def test_routes_new_streams(self, tmp_path):
+        path = tmp_path / "routing.rules"
+        with fa_control.create_null_sink("fa_control_routing_test") as sink:
+            fa_control.set_routing_rule("media.name=fa-control routing test", sink.name, path)
+            with fa_control.watch_routing_rules(path) as watcher:
+                assert watcher.running
+                playback = fa_control.play_audio(
+                    data=bytes(4 * 48000 * 2), name="fa-control routing test"
+                )
+                try:
+                    time.sleep(0.5)
+                    stream = next(
+                        s for s in fa_control.get_streams() if s.index == playback.sink_input_index
+                    )
+                    devices = {d.name: d.index for d in fa_control.get_output_devices()}
+                    assert stream.device == devices[sink.name]
+                finally:
+                    playback.stop()
+
+    def test_applies_edited_file(self, tmp_path):
+        path = tmp_path / "routing.rules"
+        with fa_control.create_null_sink("fa_control_routing_edit_test") as sink:
+            playback = fa_control.play_audio(
+                data=bytes(4 * 48000 * 3), name="fa-control routing edit test"
+            )
+            try:
+                with fa_control.watch_routing_rules(path):
+                    # Written by hand, so only the file's change can apply it
+                    path.write_text(f"rule = {sink.name} media.name=fa-control routing edit test\n")
+                    time.sleep(0.5)
+                    stream = next(
+                        s for s in fa_control.get_streams() if s.index == playback.sink_input_index
+                    )
+                    devices = {d.name: d.index for d in fa_control.get_output_devices()}
+                    assert stream.device == devices[sink.name]
+            finally:
+                playback.stop()
+
+    def test_stops_when_disconnected(self, tmp_path):
+        watcher = fa_control.watch_routing_rules(tmp_path / "routing.rules")
+        assert watcher.running
+        client = next(c for c in fa_control.get_clients() if c.name == "fa-control-routing")
+        fa_control.kill_client(client.index)
+
+        deadline = time.time() + 2
+        while watcher.running and time.time() < deadline:
+            time.sleep(0.05)
+        assert not watcher.running
+        with pytest.raises(RuntimeError, match="Lost connection"):
+            watcher.stop()
+
+    def test_invalid_rule(self, tmp_path):
+        with pytest.raises(ValueError):
+            fa_control.set_routing_rule("spotify", "Dock Speakers", tmp_path / "routing.rules")
+
+
+@pytest.mark.skipif(fa_control.get_platform() != "linux", reason="Linux only")
+class TestProcessInfo:
+    """Test /proc details of audio applications"""
+    def test_own_playback(self):
+        import getpass
+        import os
+
+        playback = fa_control.play_audio(data=bytes(4 * 48000 * 2), name="fa-control process test")
+        try:
+            apps = [a for a in fa_control.get_active_audio_apps() if a.pid == os.getpid()]
+            assert apps
+            app = apps[0]
+            assert app.name == "fa-control process test"
+            assert app.binary
+            assert app.ppid == os.getppid()
+            assert app.user == getpass.getuser()
+            assert app.cmdline
+        finally:
+            playback.stop()
+
+    def test_constructed_app_info(self):
+        app = fa_control.AppInfo(1, "test", 0.5, False)
+        assert app.binary is None
+        assert app.cmdline == []
+        assert app.flatpak_id is None
+        assert app.systemd_unit is None
+
+
+@pytest.mark.skipif(fa_control.get_platform() != "linux", reason="Linux only")
+class TestProcessTree:
+    """Test control by process tree"""
+    def test_child_process_stream(self):
+        import os
+        import subprocess
+        import sys
+
+        child = subprocess.Popen([
+            sys.executable,
+            "-c",
+            "import fa_control; fa_control.play_audio(data=bytes(4 * 48000 * 5), wait=True)",
+        ])
+        try:
+            deadline = time.time() + 5
+            while not fa_control.get_process_tree_streams(child.pid):
+                assert time.time() < deadline
+                time.sleep(0.1)
+
+            # The test process is the child's parent, so its tree includes the stream
+            root = os.getpid()
+            assert fa_control.set_process_tree_volume(root, 0.25) >= 1
+            assert abs(fa_control.get_process_tree_volume(child.pid) - 0.25) < 0.01
+            fa_control.set_process_tree_mute(root, True)
+            assert fa_control.is_process_tree_muted(child.pid)
+        finally:
+            child.kill()
+            child.wait()
+
+    def test_no_streams(self):
+        assert fa_control.get_process_tree_streams(999999) == []
+        with pytest.raises(RuntimeError):
+            fa_control.set_process_tree_volume(999999, 0.5)
+        with pytest.raises(ValueError):
+            fa_control.set_process_tree_volume(1, 1.5)
+
+
+@pytest.mark.skipif(fa_control.get_platform() != "linux", reason="Linux only")
+class TestSandbox:
+    """Test control by Flatpak app ID and systemd unit"""
+    def test_own_unit(self):
+        playback = fa_control.play_audio(data=bytes(4 * 48000 * 2), name="fa-control sandbox test")
+        try:
+            import os
+
+            apps = [a for a in fa_control.get_active_audio_apps() if a.pid == os.getpid()]
+            assert apps
+            unit = apps[0].systemd_unit
+            if unit is None:
+                pytest.skip("Not running in a systemd unit")
+            assert fa_control.get_app_streams_by_id(unit)
+            assert fa_control.set_app_volume_by_id(unit, 0.25) >= 1
+            assert abs(fa_control.get_app_volume_by_id(unit) - 0.25) < 0.01
+        finally:
+            playback.stop()
+
+    def test_unknown_app(self):
+        assert fa_control.get_app_streams_by_id("org.example.Missing") == []
+        with pytest.raises(RuntimeError):
+            fa_control.set_app_mute_by_id("org.example.Missing", True)
+        with pytest.raises(ValueError):
+            fa_control.set_app_volume_by_id("org.example.Missing", 1.5)
+
+
+class TestCli:
+    """Test the command line parser"""
+    def test_replay_arguments(self):
+        from fa_control.__main__ import build_parser
+
+        args = build_parser().parse_args(["replay", "--seconds", "10", "--pid", "42"])
+        assert args.command == "replay"
+        assert args.seconds == 10
+        assert args.pid == 42
+        assert args.device is None
+
+    def test_speaker_test_arguments(self):
+        from fa_control.__main__ import build_parser
+
+        args = build_parser().parse_args(
+            ["speaker-test", "--channel", "front-left", "--signal", "pink", "--level", "0.2"]
+        )
+        assert args.channel == "front-left"
+        assert args.signal == "pink"
+        assert args.level == pytest.approx(0.2)
+
+    def test_replay_source_is_exclusive(self):
+        from fa_control.__main__ import build_parser
+
+        with pytest.raises(SystemExit):
+            build_parser().parse_args(["replay", "--device", "a", "--pid", "1"])
+
+    def test_kill_stream_arguments(self):
+        from fa_control.__main__ import build_parser
+
+        args = build_parser().parse_args(["kill-stream", "42", "--kind", "source_output"])
+        assert args.index == 42
+        assert args.kind == "source_output"
+        args = build_parser().parse_args(["kill-client", "7"])
+        assert args.index == 7
+
+    def test_prefer_devices_arguments(self):
+        from fa_control.__main__ import build_parser
+
+        args = build_parser().parse_args(
+            ["prefer-devices", "--output", "usb", "--output", "dock", "--no-move-streams"]
+        )
+        assert args.output == ["usb", "dock"]
+        assert args.input == []
+        assert not args.move_streams
+
+    def test_route_arguments(self):
+        from fa_control.__main__ import build_parser
+
+        args = build_parser().parse_args(["route", "application.name=Discord", "headset"])
+        assert args.selector == "application.name=Discord"
+        assert args.device == "headset"
+        assert args.rules is None
+        args = build_parser().parse_args(["routes", "--rules", "my.rules"])
+        assert args.rules == "my.rules"
+
+
 class TestAppInfo:
     """Test AppInfo class"""
     def test_appinfo_creation(self):
@@ -0,0 +1,446 @@
+//! Noise suppression for microphone audio, after RNNoise.
+//!
+//! The audio is split into overlapping 512-sample frames whose spectrum is
+//! summarized in 22 bands on a Bark-like scale. A small recurrent network
+//! (see `model`) turns cepstral features of those band energies into one
+//! gain per band, which is interpolated across the frequency bins. Because
+//! the network remembers past frames it can tell speech from both steady
+//! noise (fans, hum, hiss) and short transients such as keyboard clicks.
+//! The weights are trained on synthetic speech and noise by `train`.
+
+use std::collections::VecDeque;
+use std::f32::consts::PI;
+
+mod model;
+#[cfg(test)]
+mod train;
+mod weights;
+
+use model::GainModel;
+
+const FFT_SIZE: usize = 512;
+const HOP: usize = FFT_SIZE / 2;
+const BINS: usize = FFT_SIZE / 2 + 1;
+
+const NB_BANDS: usize = 22;
+/// Cepstral coefficients whose differences are features too
+const NB_DELTA_CEPS: usize = 6;
+/// Frames the spectral variability is measured over
+const CEPS_MEM: usize = 8;
+const NB_FEATURES: usize = NB_BANDS + 2 * NB_DELTA_CEPS + 1;
+
+/// Lower band edges in Hz, RNNoise's; each band ramps up from the previous
+/// edge and down to the next
+const BAND_EDGES_HZ: [f32; NB_BANDS] = [
+    0.0, 200.0, 400.0, 600.0, 800.0, 1000.0, 1200.0, 1400.0, 1600.0, 2000.0, 2400.0, 2800.0,
+    3200.0, 4000.0, 4800.0, 5600.0, 6800.0, 8000.0, 9600.0, 12000.0, 15600.0, 20000.0,
+];
+
+/// Features are computed on 16-bit sample values over a spectrum scaled by
+/// `1 / FFT_SIZE`, so that their offsets match RNNoise's
+const SPECTRUM_SCALE: f32 = 32768.0 / FFT_SIZE as f32;
+/// Frames with less energy than this are digital silence and left alone
+const SILENCE_ENERGY: f32 = 0.04;
+/// Deepest attenuation, reached at full strength
+const MAX_ATTENUATION_DB: f32 = 30.0;
+/// Fraction of the previous gain a band's gain may fall to per frame
+const GAIN_RELEASE: f32 = 0.6;
+
+/// Radix-2 complex FFT of a fixed size
+struct Fft {
+    /// `e^(-2πik/n)` for `k` in `0..n/2`
+    twiddles: Vec<(f32, f32)>,
+    bit_reversed: Vec<usize>,
+}
+
+impl Fft {
+    fn new(size: usize) -> Self {
+        debug_assert!(size.is_power_of_two());
+        let bits = size.trailing_zeros();
+        Fft {
+            twiddles: (0..size / 2)
+                .map(|k| {
+                    let angle = -2.0 * PI * k as f32 / size as f32;
+                    (angle.cos(), angle.sin())
+                })
+                .collect(),
+            bit_reversed: (0..size)
+                .map(|i| i.reverse_bits() >> (usize::BITS - bits))
+                .collect(),
+        }
+    }
+
+    fn forward(&self, re: &mut [f32], im: &mut [f32]) {
+        let size = re.len();
+        for (i, &j) in self.bit_reversed.iter().enumerate() {
+            if i < j {
+                re.swap(i, j);
+                im.swap(i, j);
+            }
+        }
+
+        let mut len = 2;
+        while len <= size {
+            let step = size / len;
+            for start in (0..size).step_by(len) {
+                for k in 0..len / 2 {
+                    let (wr, wi) = self.twiddles[k * step];
+                    let a = start + k;
+                    let b = a + len / 2;
+                    let tr = re[b] * wr - im[b] * wi;
+                    let ti = re[b] * wi + im[b] * wr;
+                    re[b] = re[a] - tr;
+                    im[b] = im[a] - ti;
+                    re[a] += tr;
+                    im[a] += ti;
+                }
+            }
+            len *= 2;
+        }
+    }
+
+    fn inverse(&self, re: &mut [f32], im: &mut [f32]) {
+        im.iter_mut().for_each(|x| *x = -*x);
+        self.forward(re, im);
+        let scale = 1.0 / re.len() as f32;
+        re.iter_mut().for_each(|x| *x *= scale);
+        im.iter_mut().for_each(|x| *x *= -scale);
+    }
+}
+
+/// Triangular bands over the bins of a spectrum
+struct Bands {
+    /// First bin of each band edge
+    edges: [usize; NB_BANDS],
+}
+
+impl Bands {
+    fn new(rate: u32) -> Self {
+        let mut edges = [0; NB_BANDS];
+        for (edge, hz) in edges.iter_mut().zip(BAND_EDGES_HZ) {
+            *edge = ((hz * FFT_SIZE as f32 / rate as f32).round() as usize).min(BINS - 1);
+        }
+        Bands { edges }
+    }
+
+    /// Energy of each band of a spectrum, scaled as `SPECTRUM_SCALE` says
+    fn energy(&self, re: &[f32], im: &[f32]) -> [f32; NB_BANDS] {
+        let mut energy = [0.0; NB_BANDS];
+        for band in 0..NB_BANDS - 1 {
+            let (start, end) = (self.edges[band], self.edges[band + 1]);
+            for bin in start..end {
+                let fraction = (bin - start) as f32 / (end - start) as f32;
+                let power = (re[bin].powi(2) + im[bin].powi(2)) * SPECTRUM_SCALE.powi(2);
+                energy[band] += (1.0 - fraction) * power;
+                energy[band + 1] += fraction * power;
+            }
+        }
+        // The outer bands only get half a triangle
+        energy[0] *= 2.0;
+        energy[NB_BANDS - 1] *= 2.0;
+        energy
+    }
+
+    /// Per-bin gains interpolated between the band gains
+    fn interpolate(&self, gains: &[f32; NB_BANDS], bin_gains: &mut [f32]) {
+        bin_gains.fill(gains[NB_BANDS - 1]);
+        for band in 0..NB_BANDS - 1 {
+            let (start, end) = (self.edges[band], self.edges[band + 1]);
+            for (bin, gain) in bin_gains.iter_mut().enumerate().take(end).skip(start) {
+                let fraction = (bin - start) as f32 / (end - start) as f32;
+                *gain = (1.0 - fraction) * gains[band] + fraction * gains[band + 1];
+            }
+        }
+    }
+}
+
+/// Turns band energies into the model's input: cepstral coefficients of the
+/// log energies, their first and second differences over three frames, and
+/// how much the spectrum varies over `CEPS_MEM` frames
+struct FeatureHistory {
+    cepstra: VecDeque<[f32; NB_BANDS]>,
+}
+
+impl FeatureHistory {
+    fn new() -> Self {
+        FeatureHistory {
+            cepstra: std::iter::repeat_n([0.0; NB_BANDS], CEPS_MEM).collect(),
+        }
+    }
+
+    /// Features of the next frame, or `None` if it is silent
+    fn next(&mut self, energy: &[f32; NB_BANDS]) -> Option<[f32; NB_FEATURES]> {
+        if energy.iter().sum::<f32>() < SILENCE_ENERGY {
+            return None;
+        }
+
+        // Limit how far a band can fall below its neighbours and the
+        // loudest band, so near-empty bands don't dominate the cepstrum
+        let mut log_energy = [0.0; NB_BANDS];
+        let (mut log_max, mut follow) = (-2.0f32, -2.0f32);
+        for (log, &energy) in log_energy.iter_mut().zip(energy) {
+            *log = (1e-2 + energy).log10().max(follow - 1.5).max(log_max - 7.0);
+            log_max = log_max.max(*log);
+            follow = (follow - 1.5).max(*log);
+        }
+
+        let mut cepstrum = dct(&log_energy);
+        cepstrum[0] -= 12.0;
+        cepstrum[1] -= 4.0;
+        self.cepstra.pop_back();
+        self.cepstra.push_front(cepstrum);
+
+        let mut features = [0.0; NB_FEATURES];
+        features[..NB_BANDS].copy_from_slice(&cepstrum);
+        let (current, previous, oldest) = (&self.cepstra[0], &self.cepstra[1], &self.cepstra[2]);
+        for i in 0..NB_DELTA_CEPS {
+            features[i] = current[i] + previous[i] + oldest[i];
+            features[NB_BANDS + i] = current[i] - oldest[i];
+            features[NB_BANDS + NB_DELTA_CEPS + i] = current[i] - 2.0 * previous[i] + oldest[i];
+        }
+
+        let mut variability = 0.0;
+        for (i, a) in self.cepstra.iter().enumerate() {
+            let nearest = self
+                .cepstra
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .map(|(_, b)| a.iter().zip(b).map(|(x, y)| (x - y).powi(2)).sum::<f32>())
+                .fold(f32::INFINITY, f32::min);
+            variability += nearest;
+        }
+        features[NB_FEATURES - 1] = variability / (CEPS_MEM * CEPS_MEM) as f32 - 2.1;
+
+        Some(features)
+    }
+}
+
+/// Orthonormal DCT-II
+fn dct(input: &[f32; NB_BANDS]) -> [f32; NB_BANDS] {
+    let mut output = [0.0; NB_BANDS];
+    for (k, out) in output.iter_mut().enumerate() {
+        let sum: f32 = input
+            .iter()
+            .enumerate()
+            .map(|(n, x)| x * (PI * (n as f32 + 0.5) * k as f32 / NB_BANDS as f32).cos())
+            .sum();
+        let scale = if k == 0 { 0.5f32.sqrt() } else { 1.0 };
+        *out = sum * scale * (2.0 / NB_BANDS as f32).sqrt();
+    }
+    output
+}
+
+/// Streaming noise suppressor for one channel
+pub(crate) struct Denoiser {
+    fft: Fft,
+    /// Square root of a periodic Hann window, applied before and after the
+    /// FFT so overlapping frames add back up to the input
+    window: Vec<f32>,
+    bands: Bands,
+    history: FeatureHistory,
+    model: GainModel,
+    strength: f32,
+    frame: Vec<f32>,
+    pending: Vec<f32>,
+    overlap: Vec<f32>,
+    output: VecDeque<f32>,
+    re: Vec<f32>,
+    im: Vec<f32>,
+    gains: [f32; NB_BANDS],
+    bin_gains: Vec<f32>,
+}
+
+impl Denoiser {
+    pub(crate) fn new(rate: u32, strength: f32) -> Self {
+        Denoiser {
+            fft: Fft::new(FFT_SIZE),
+            window: sqrt_hann(),
+            bands: Bands::new(rate),
+            history: FeatureHistory::new(),
+            model: GainModel::new(),
+            strength: strength.clamp(0.0, 1.0),
+            frame: vec![0.0; FFT_SIZE],
+            pending: Vec::with_capacity(HOP),
+            overlap: vec![0.0; FFT_SIZE],
+            output: std::iter::repeat_n(0.0, HOP).collect(),
+            re: vec![0.0; FFT_SIZE],
+            im: vec![0.0; FFT_SIZE],
+            gains: [1.0; NB_BANDS],
+            bin_gains: vec![1.0; BINS],
+        }
+    }
+
+    /// How much noise to remove, from 0.0 (none) to 1.0 (up to 30 dB)
+    pub(crate) fn set_strength(&mut self, strength: f32) {
+        self.strength = strength.clamp(0.0, 1.0);
+    }
+
+    /// Denoise samples in place; the output lags the input by `FFT_SIZE`
+    /// samples
+    pub(crate) fn process(&mut self, samples: &mut [f32]) {
+        for sample in samples {
+            self.pending.push(*sample);
+            if self.pending.len() == HOP {
+                self.process_hop();
+            }
+            *sample = self.output.pop_front().unwrap_or(0.0);
+        }
+    }
+
+    fn process_hop(&mut self) {
+        self.frame.copy_within(HOP.., 0);
+        self.frame[FFT_SIZE - HOP..].copy_from_slice(&self.pending);
+        self.pending.clear();
+
+        for i in 0..FFT_SIZE {
+            self.re[i] = self.frame[i] * self.window[i];
+            self.im[i] = 0.0;
+        }
+        self.fft.forward(&mut self.re, &mut self.im);
+
+        // The model runs even at zero strength so it keeps track of the
+        // noise for when the strength is raised
+        let energy = self.bands.energy(&self.re, &self.im);
+        if let Some(features) = self.history.next(&energy) {
+            let mut gains = [0.0; NB_BANDS];
+            self.model.gains(&features, &mut gains);
+
+            let floor = 10f32.powf(-MAX_ATTENUATION_DB * self.strength / 20.0);
+            for (gain, previous) in gains.iter_mut().zip(&mut self.gains) {
+                *previous = gain.max(GAIN_RELEASE * *previous);
+                *gain = previous.max(floor);
+            }
+            self.bands.interpolate(&gains, &mut self.bin_gains);
+
+            for bin in 0..BINS {
+                let gain = self.bin_gains[bin];
+                self.re[bin] *= gain;
+                self.im[bin] *= gain;
+                if bin > 0 && bin < FFT_SIZE / 2 {
+                    self.re[FFT_SIZE - bin] *= gain;
+                    self.im[FFT_SIZE - bin] *= gain;
+                }
+            }
+        }
+
+        self.fft.inverse(&mut self.re, &mut self.im);
+        for i in 0..FFT_SIZE {
+            self.overlap[i] += self.re[i] * self.window[i];
+        }
+        self.output.extend(&self.overlap[..HOP]);
+        self.overlap.copy_within(HOP.., 0);
+        self.overlap[FFT_SIZE - HOP..].fill(0.0);
+    }
+}
+
+fn sqrt_hann() -> Vec<f32> {
+    (0..FFT_SIZE)
+        .map(|i| (0.5 - 0.5 * (2.0 * PI * i as f32 / FFT_SIZE as f32).cos()).sqrt())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RATE: u32 = 48000;
+    const LATENCY: usize = FFT_SIZE;
+
+    /// Deterministic white noise in -amplitude..amplitude
+    fn noise(amplitude: f32, count: usize) -> Vec<f32> {
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        (0..count)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                ((state >> 40) as f32 / (1u64 << 24) as f32 * 2.0 - 1.0) * amplitude
+            })
+            .collect()
+    }
+
+    fn rms(samples: &[f32]) -> f32 {
+        (samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32).sqrt()
+    }
+
+    #[test]
+    fn test_fft_round_trip() {
+        let fft = Fft::new(16);
+        let input: Vec<f32> = (0..16).map(|i| (i as f32 * 0.7).sin()).collect();
+        let mut re = input.clone();
+        let mut im = vec![0.0; 16];
+
+        fft.forward(&mut re, &mut im);
+        // The DC bin is the sum of the input
+        assert!((re[0] - input.iter().sum::<f32>()).abs() < 1e-4);
+
+        fft.inverse(&mut re, &mut im);
+        for (a, b) in re.iter().zip(&input) {
+            assert!((a - b).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_zero_strength_is_transparent() {
+        let input = noise(0.5, RATE as usize / 4);
+        let mut output = input.clone();
+        Denoiser::new(RATE, 0.0).process(&mut output);
+
+        for (a, b) in output[LATENCY..].iter().zip(&input) {
+            assert!((a - b).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_removes_steady_noise() {
+        let input = noise(0.1, 3 * RATE as usize);
+        let mut output = input.clone();
+        let mut denoiser = Denoiser::new(RATE, 1.0);
+        // Uneven chunk sizes, like a record stream delivers
+        for chunk in output.chunks_mut(441) {
+            denoiser.process(chunk);
+        }
+
+        let settled = 2 * RATE as usize..;
+        assert!(rms(&output[settled.clone()]) < 0.2 * rms(&input[settled]));
+    }
+
+    #[test]
+    fn test_keeps_speech_over_noise() {
+        let count = 4 * RATE as usize;
+        let mut speech = train::speech(&mut train::Rng::new(7), count);
+        let peak = speech.iter().fold(0.0f32, |m, s| m.max(s.abs()));
+        speech.iter_mut().for_each(|s| *s *= 0.5 / peak);
+        let mut output: Vec<f32> = speech
+            .iter()
+            .zip(noise(0.02, count))
+            .map(|(s, n)| s + n)
+            .collect();
+        Denoiser::new(RATE, 1.0).process(&mut output);
+
+        // Compare the loudest stretches of speech, past the first second
+        let settled = RATE as usize..count - LATENCY;
+        let input = &speech[settled.clone()];
+        let delayed = &output[settled.start + LATENCY..settled.end + LATENCY];
+        let loud: Vec<usize> = (0..input.len()).filter(|&i| input[i].abs() > 0.1).collect();
+        let kept = loud.iter().map(|&i| delayed[i] * input[i]).sum::<f32>()
+            / loud.iter().map(|&i| input[i] * input[i]).sum::<f32>();
+        assert!(kept > 0.85 && kept < 1.1, "kept {}", kept);
+    }
+
+    #[test]
+    fn test_removes_keyboard_clicks() {
+        let count = 4 * RATE as usize;
+        let mut clicks = train::keyboard(&mut train::Rng::new(9), count);
+        let peak = clicks.iter().fold(0.0f32, |m, s| m.max(s.abs()));
+        clicks.iter_mut().for_each(|s| *s *= 0.3 / peak);
+        let mut output = clicks.clone();
+        Denoiser::new(RATE, 1.0).process(&mut output);
+
+        let settled = RATE as usize..count - LATENCY;
+        let delayed = settled.start + LATENCY..settled.end + LATENCY;
+        let removed = rms(&output[delayed]) / rms(&clicks[settled]);
+        assert!(removed < 0.1, "kept {}", removed);
+    }
+}
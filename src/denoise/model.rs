@@ -0,0 +1,137 @@
+//! The gain network: a dense layer feeding a GRU, read out by a dense layer
+//! with one sigmoid gain per band, like RNNoise's denoising branch.
+
+use super::weights;
+use super::{NB_BANDS, NB_FEATURES};
+
+pub(super) const DENSE_SIZE: usize = 32;
+pub(super) const GRU_SIZE: usize = 48;
+
+/// Layer weights, row-major with one row per output. The GRU's input and
+/// recurrent weights and its biases hold the update gate, reset gate and
+/// candidate state in that order.
+pub(super) struct Params<'a> {
+    pub dense_weights: &'a [f32],
+    pub dense_bias: &'a [f32],
+    pub gru_input_weights: &'a [f32],
+    pub gru_recurrent_weights: &'a [f32],
+    pub gru_bias: &'a [f32],
+    pub output_weights: &'a [f32],
+    pub output_bias: &'a [f32],
+}
+
+/// The trained weights
+pub(super) const TRAINED: Params<'static> = Params {
+    dense_weights: &weights::DENSE_WEIGHTS,
+    dense_bias: &weights::DENSE_BIAS,
+    gru_input_weights: &weights::GRU_INPUT_WEIGHTS,
+    gru_recurrent_weights: &weights::GRU_RECURRENT_WEIGHTS,
+    gru_bias: &weights::GRU_BIAS,
+    output_weights: &weights::OUTPUT_WEIGHTS,
+    output_bias: &weights::OUTPUT_BIAS,
+};
+
+pub(super) fn sigmoid(x: f32) -> f32 {
+    1.0 / (1.0 + (-x).exp())
+}
+
+/// `output[i] = bias[i] + weights[i] · input`, added to `output`'s contents
+/// if `accumulate`
+pub(super) fn affine(
+    weights: &[f32],
+    bias: Option<&[f32]>,
+    input: &[f32],
+    output: &mut [f32],
+    accumulate: bool,
+) {
+    for (i, out) in output.iter_mut().enumerate() {
+        let row = &weights[i * input.len()..(i + 1) * input.len()];
+        let sum = row.iter().zip(input).map(|(w, x)| w * x).sum::<f32>()
+            + bias.map_or(0.0, |bias| bias[i]);
+        *out = if accumulate { *out + sum } else { sum };
+    }
+}
+
+/// The network with its recurrent state, which carries what it has learned
+/// about the noise from one frame to the next
+pub(super) struct GainModel {
+    params: Params<'static>,
+    state: [f32; GRU_SIZE],
+}
+
+impl GainModel {
+    pub(super) fn new() -> Self {
+        GainModel {
+            params: TRAINED,
+            state: [0.0; GRU_SIZE],
+        }
+    }
+
+    #[cfg(test)]
+    pub(super) fn with_params(params: Params<'static>) -> Self {
+        GainModel {
+            params,
+            state: [0.0; GRU_SIZE],
+        }
+    }
+
+    /// Gains from 0.0 to 1.0 for each band of the frame `features` describe
+    pub(super) fn gains(&mut self, features: &[f32; NB_FEATURES], gains: &mut [f32; NB_BANDS]) {
+        let p = &self.params;
+
+        let mut dense = [0.0; DENSE_SIZE];
+        affine(
+            p.dense_weights,
+            Some(p.dense_bias),
+            features,
+            &mut dense,
+            false,
+        );
+        dense.iter_mut().for_each(|x| *x = x.tanh());
+
+        let mut gates = [0.0; 3 * GRU_SIZE];
+        affine(
+            p.gru_input_weights,
+            Some(p.gru_bias),
+            &dense,
+            &mut gates,
+            false,
+        );
+        let (update_reset, candidate) = gates.split_at_mut(2 * GRU_SIZE);
+
+        let recurrent = GRU_SIZE * GRU_SIZE;
+        affine(
+            &p.gru_recurrent_weights[..2 * recurrent],
+            None,
+            &self.state,
+            update_reset,
+            true,
+        );
+        update_reset.iter_mut().for_each(|x| *x = sigmoid(*x));
+        let (update, reset) = update_reset.split_at(GRU_SIZE);
+
+        let mut reset_state = [0.0; GRU_SIZE];
+        for i in 0..GRU_SIZE {
+            reset_state[i] = reset[i] * self.state[i];
+        }
+        affine(
+            &p.gru_recurrent_weights[2 * recurrent..],
+            None,
+            &reset_state,
+            candidate,
+            true,
+        );
+        for i in 0..GRU_SIZE {
+            self.state[i] = update[i] * self.state[i] + (1.0 - update[i]) * candidate[i].tanh();
+        }
+
+        affine(
+            p.output_weights,
+            Some(p.output_bias),
+            &self.state,
+            gains,
+            false,
+        );
+        gains.iter_mut().for_each(|x| *x = sigmoid(*x));
+    }
+}
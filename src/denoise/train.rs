@@ -0,0 +1,1076 @@
+//! Training for the gain model, on synthetic speech mixed with synthetic
+//! fan, hiss, hum, whine, keyboard and mouse noise. The target gain of each
+//! band is the square root of its share of speech energy, as in RNNoise.
+//! Regenerate `weights.rs` with
+//!
+//! ```text
+//! cargo test --release train_gain_model -- --ignored --nocapture
+//! ```
+
+use std::f32::consts::PI;
+use std::fmt::Write as _;
+
+use super::model::{affine, sigmoid, GainModel, Params, DENSE_SIZE, GRU_SIZE};
+use super::{sqrt_hann, Bands, FeatureHistory, Fft, FFT_SIZE, HOP, NB_BANDS, NB_FEATURES};
+
+const RATE: u32 = 48000;
+
+const DENSE_WEIGHTS: usize = DENSE_SIZE * NB_FEATURES;
+const GRU_INPUT_WEIGHTS: usize = 3 * GRU_SIZE * DENSE_SIZE;
+const GRU_RECURRENT_WEIGHTS: usize = 3 * GRU_SIZE * GRU_SIZE;
+const OUTPUT_WEIGHTS: usize = NB_BANDS * GRU_SIZE;
+const PARAM_COUNT: usize = DENSE_WEIGHTS
+    + DENSE_SIZE
+    + GRU_INPUT_WEIGHTS
+    + GRU_RECURRENT_WEIGHTS
+    + 3 * GRU_SIZE
+    + OUTPUT_WEIGHTS
+    + NB_BANDS;
+
+/// Frames per training sequence, about 2.7 seconds
+const SEQUENCE_FRAMES: usize = 500;
+const BATCH_SIZE: usize = 8;
+const STEPS: usize = 6000;
+const LEARNING_RATE: f32 = 2e-3;
+/// Largest gradient norm applied in one step
+const MAX_GRADIENT_NORM: f32 = 1.0;
+/// Targets of bands with less speech and noise energy than this are ignored
+const MASK_ENERGY: f32 = 5e-2;
+
+/// Xorshift generator, so the data is the same on every run
+pub(super) struct Rng(u64);
+
+impl Rng {
+    pub(super) fn new(seed: u64) -> Self {
+        Rng(seed.wrapping_mul(0x9e37_79b9_7f4a_7c15) | 1)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Uniform in `0.0..1.0`
+    fn uniform(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    fn range(&mut self, low: f32, high: f32) -> f32 {
+        low + (high - low) * self.uniform()
+    }
+
+    fn chance(&mut self, probability: f32) -> bool {
+        self.uniform() < probability
+    }
+
+    /// Uniform in `-1.0..1.0`
+    fn noise(&mut self) -> f32 {
+        2.0 * self.uniform() - 1.0
+    }
+
+    fn seconds(&mut self, low: f32, high: f32) -> usize {
+        (self.range(low, high) * RATE as f32) as usize
+    }
+}
+
+/// Klatt's two-pole resonator, with unity gain at DC
+struct Resonator {
+    a: f32,
+    b: f32,
+    c: f32,
+    y1: f32,
+    y2: f32,
+}
+
+impl Resonator {
+    fn new(frequency: f32, bandwidth: f32) -> Self {
+        let mut resonator = Resonator {
+            a: 0.0,
+            b: 0.0,
+            c: 0.0,
+            y1: 0.0,
+            y2: 0.0,
+        };
+        resonator.tune(frequency, bandwidth);
+        resonator
+    }
+
+    fn tune(&mut self, frequency: f32, bandwidth: f32) {
+        let radius = (-PI * bandwidth / RATE as f32).exp();
+        self.c = -radius * radius;
+        self.b = 2.0 * radius * (2.0 * PI * frequency / RATE as f32).cos();
+        self.a = 1.0 - self.b - self.c;
+    }
+
+    fn process(&mut self, x: f32) -> f32 {
+        let y = self.a * x + self.b * self.y1 + self.c * self.y2;
+        self.y2 = self.y1;
+        self.y1 = y;
+        y
+    }
+}
+
+/// One-pole lowpass
+struct Lowpass {
+    coefficient: f32,
+    y: f32,
+}
+
+impl Lowpass {
+    fn new(cutoff: f32) -> Self {
+        Lowpass {
+            coefficient: (-2.0 * PI * cutoff / RATE as f32).exp(),
+            y: 0.0,
+        }
+    }
+
+    fn process(&mut self, x: f32) -> f32 {
+        self.y = x + self.coefficient * (self.y - x);
+        self.y
+    }
+}
+
+fn rms(samples: &[f32]) -> f32 {
+    (samples.iter().map(|s| s * s).sum::<f32>() / samples.len().max(1) as f32).sqrt()
+}
+
+fn db_to_gain(db: f32) -> f32 {
+    10f32.powf(db / 20.0)
+}
+
+/// Scale `samples` so the RMS of their non-silent part is `level`
+fn set_level(samples: &mut [f32], level: f32) {
+    let active: Vec<f32> = samples.iter().copied().filter(|s| *s != 0.0).collect();
+    let current = rms(&active);
+    if current > 0.0 {
+        samples.iter_mut().for_each(|s| *s *= level / current);
+    }
+}
+
+/// Mix `segment` into `output` at `start` with a raised-cosine fade in and
+/// out, scaled so its RMS is `level`
+fn add_segment(output: &mut [f32], start: usize, mut segment: Vec<f32>, level: f32, fade: usize) {
+    set_level(&mut segment, level);
+    let len = segment.len();
+    let fade = fade.min(len / 2).max(1);
+    for (i, sample) in segment.into_iter().enumerate() {
+        let Some(out) = output.get_mut(start + i) else {
+            break;
+        };
+        let edge = i.min(len - 1 - i);
+        let envelope = if edge < fade {
+            0.5 - 0.5 * (PI * edge as f32 / fade as f32).cos()
+        } else {
+            1.0
+        };
+        *out += sample * envelope;
+    }
+}
+
+/// Vowel formant frequencies and bandwidths
+fn vowel(rng: &mut Rng, scale: f32) -> [(f32, f32); 5] {
+    [
+        (rng.range(250.0, 900.0) * scale, rng.range(50.0, 130.0)),
+        (rng.range(800.0, 2500.0) * scale, rng.range(60.0, 160.0)),
+        (rng.range(2200.0, 3200.0) * scale, rng.range(100.0, 250.0)),
+        (rng.range(3300.0, 4200.0) * scale, rng.range(150.0, 300.0)),
+        (rng.range(4300.0, 5500.0) * scale, rng.range(200.0, 400.0)),
+    ]
+}
+
+/// A voiced syllable: a glottal pulse train with jittered, gliding pitch
+/// through a cascade of formants moving between two vowels
+fn voiced(rng: &mut Rng, len: usize, pitch: f32, scale: f32) -> Vec<f32> {
+    let (from, to) = (vowel(rng, scale), vowel(rng, scale));
+    let mut formants: Vec<Resonator> = from.iter().map(|&(f, b)| Resonator::new(f, b)).collect();
+    let mut glottis = Lowpass::new(rng.range(150.0, 400.0));
+    let glide = rng.range(-0.25, 0.25);
+    let breath = rng.range(0.0, 0.1);
+
+    let mut phase = 0.0;
+    let mut output = Vec::with_capacity(len);
+    for i in 0..len {
+        let progress = i as f32 / len as f32;
+        if i % HOP == 0 {
+            for (resonator, (a, b)) in formants.iter_mut().zip(from.iter().zip(&to)) {
+                resonator.tune(a.0 + (b.0 - a.0) * progress, a.1 + (b.1 - a.1) * progress);
+            }
+        }
+        let f0 = pitch * (1.0 + glide * progress) * (1.0 + 0.01 * rng.noise());
+        phase += f0 / RATE as f32;
+        let mut x = breath * rng.noise();
+        if phase >= 1.0 {
+            phase -= 1.0;
+            x += 1.0;
+        }
+        // Glottal roll-off, then lip radiation
+        let mut y = glottis.process(x);
+        for resonator in &mut formants {
+            y = resonator.process(y);
+        }
+        output.push(y);
+    }
+    // Radiation from the lips differentiates the output
+    let mut previous = 0.0;
+    for sample in &mut output {
+        let current = *sample;
+        *sample -= previous;
+        previous = current;
+    }
+    output
+}
+
+/// Fricative or plosive noise shaped by one wide resonance
+fn unvoiced(rng: &mut Rng, len: usize, low: f32, high: f32) -> Vec<f32> {
+    let mut resonator = Resonator::new(rng.range(low, high), rng.range(800.0, 3000.0));
+    let mut output: Vec<f32> = (0..len).map(|_| resonator.process(rng.noise())).collect();
+    // The resonator's DC normalization leaves its output mostly at DC for
+    // high resonances, so only keep the change
+    let mut previous = 0.0;
+    for sample in &mut output {
+        let current = *sample;
+        *sample -= previous;
+        previous = current;
+    }
+    output
+}
+
+/// Synthetic speech: words of one to four syllables, each optionally led by
+/// a plosive or fricative, separated by pauses of varying length
+pub(super) fn speech(rng: &mut Rng, len: usize) -> Vec<f32> {
+    let mut output = vec![0.0; len];
+    let pitch = rng.range(80.0, 280.0);
+    let scale = rng.range(0.85, 1.2);
+    let mut at = rng.seconds(0.0, 0.5);
+    while at < len {
+        for _ in 0..1 + (rng.uniform() * 4.0) as usize {
+            let level = db_to_gain(rng.range(-8.0, 0.0));
+            if rng.chance(0.3) {
+                let burst = rng.seconds(0.005, 0.02);
+                add_segment(
+                    &mut output,
+                    at,
+                    unvoiced(rng, burst, 1000.0, 5000.0),
+                    level * db_to_gain(rng.range(-12.0, -3.0)),
+                    burst / 4,
+                );
+                at += burst + rng.seconds(0.01, 0.04);
+            } else if rng.chance(0.35) {
+                let fricative = rng.seconds(0.05, 0.18);
+                add_segment(
+                    &mut output,
+                    at,
+                    unvoiced(rng, fricative, 2500.0, 8000.0),
+                    level * db_to_gain(rng.range(-15.0, -4.0)),
+                    fricative / 4,
+                );
+                at += fricative;
+            }
+            let syllable = rng.seconds(0.08, 0.32);
+            let syllable_pitch = pitch * rng.range(0.85, 1.2);
+            let voice = voiced(rng, syllable, syllable_pitch, scale);
+            add_segment(&mut output, at, voice, level, RATE as usize / 50);
+            at += syllable + rng.seconds(0.0, 0.05);
+        }
+        at += if rng.chance(0.25) {
+            rng.seconds(0.4, 1.5)
+        } else {
+            rng.seconds(0.05, 0.3)
+        };
+    }
+    output
+}
+
+/// Fan noise: lowpassed noise with a resonance or two, blade tones and a
+/// slow wobble
+fn fan(rng: &mut Rng, len: usize) -> Vec<f32> {
+    let mut first = Lowpass::new(rng.range(200.0, 3000.0));
+    let mut second = Lowpass::new(rng.range(500.0, 6000.0));
+    let mut body = Resonator::new(rng.range(100.0, 1500.0), rng.range(50.0, 400.0));
+    let body_mix = rng.range(0.0, 0.5);
+    let blade = rng.range(30.0, 300.0);
+    let blade_level = rng.range(0.0, 0.2);
+    let wobble = rng.range(0.2, 2.0);
+    let depth = rng.range(0.0, 0.2);
+    (0..len)
+        .map(|i| {
+            let t = i as f32 / RATE as f32;
+            let noise = second.process(first.process(rng.noise()));
+            let tones: f32 = (1..4)
+                .map(|k| (2.0 * PI * blade * k as f32 * t).sin() / k as f32)
+                .sum();
+            (noise + body_mix * body.process(noise) + blade_level * 0.05 * tones)
+                * (1.0 + depth * (2.0 * PI * wobble * t).sin())
+        })
+        .collect()
+}
+
+/// White to pink-ish hiss
+fn hiss(rng: &mut Rng, len: usize) -> Vec<f32> {
+    let mut lowpass = Lowpass::new(rng.range(500.0, 4000.0));
+    let tilt = rng.uniform();
+    (0..len)
+        .map(|_| {
+            let white = rng.noise();
+            (1.0 - tilt) * white + tilt * 4.0 * lowpass.process(white)
+        })
+        .collect()
+}
+
+/// Mains hum and its harmonics
+fn hum(rng: &mut Rng, len: usize) -> Vec<f32> {
+    let base = if rng.chance(0.5) { 50.0 } else { 60.0 };
+    let harmonics: Vec<(f32, f32)> = (1..16)
+        .map(|k| (base * k as f32, rng.uniform().powi(2) / k as f32))
+        .collect();
+    (0..len)
+        .map(|i| {
+            let t = i as f32 / RATE as f32;
+            harmonics
+                .iter()
+                .map(|(f, a)| a * (2.0 * PI * f * t).sin())
+                .sum()
+        })
+        .collect()
+}
+
+/// A steady electronic or mechanical whine
+fn whine(rng: &mut Rng, len: usize) -> Vec<f32> {
+    let frequency = rng.range(400.0, 6000.0);
+    let drift = rng.range(0.1, 1.0);
+    let mut phase = 0.0;
+    (0..len)
+        .map(|i| {
+            let t = i as f32 / RATE as f32;
+            phase += frequency * (1.0 + 0.005 * (2.0 * PI * drift * t).sin()) / RATE as f32;
+            (2.0 * PI * phase).sin()
+        })
+        .collect()
+}
+
+/// A click: a decaying noise burst through a couple of resonances, with a
+/// low thump
+fn click(rng: &mut Rng, bright: (f32, f32), decay: f32, thump: f32) -> Vec<f32> {
+    let decay = decay * rng.range(0.7, 1.4);
+    let len = (6.0 * decay * RATE as f32) as usize;
+    let mut first = Resonator::new(bright.0 * rng.range(0.8, 1.25), rng.range(300.0, 2000.0));
+    let mut second = Resonator::new(bright.1 * rng.range(0.8, 1.25), rng.range(300.0, 2000.0));
+    let mut low = Resonator::new(rng.range(80.0, 300.0), rng.range(30.0, 120.0));
+    let mut previous = 0.0;
+    (0..len)
+        .map(|i| {
+            let envelope = (-(i as f32) / (decay * RATE as f32)).exp();
+            let x = rng.noise() * envelope;
+            let bright = first.process(x) + second.process(x);
+            let y = bright - previous;
+            previous = bright;
+            y + thump * low.process(x) + 0.3 * x
+        })
+        .collect()
+}
+
+/// Typing: bursts of key presses and releases separated by pauses
+pub(super) fn keyboard(rng: &mut Rng, len: usize) -> Vec<f32> {
+    let mut output = vec![0.0; len];
+    let bright = (rng.range(800.0, 3000.0), rng.range(2500.0, 7000.0));
+    let decay = rng.range(0.001, 0.006);
+    let thump = rng.range(0.0, 2.0);
+    let rate = rng.range(3.0, 10.0);
+    let mut at = rng.seconds(0.0, 0.3);
+    while at < len {
+        let burst_end = at + rng.seconds(0.8, 5.0);
+        while at < burst_end.min(len) {
+            let level = db_to_gain(rng.range(-6.0, 0.0));
+            for (offset, gain) in [(0, 1.0), (rng.seconds(0.06, 0.15), rng.range(0.3, 1.0))] {
+                let sound = click(rng, bright, decay, thump);
+                let peak = sound.iter().fold(0.0f32, |m, s| m.max(s.abs())).max(1e-9);
+                for (i, s) in sound.into_iter().enumerate() {
+                    if let Some(out) = output.get_mut(at + offset + i) {
+                        *out += s / peak * level * gain;
+                    }
+                }
+            }
+            at += (RATE as f32 / rate * rng.range(0.4, 1.6)) as usize;
+        }
+        at += rng.seconds(0.3, 3.0);
+    }
+    output
+}
+
+/// Sparse, sharp mouse clicks
+fn mouse(rng: &mut Rng, len: usize) -> Vec<f32> {
+    let mut output = vec![0.0; len];
+    let bright = (rng.range(2000.0, 5000.0), rng.range(4000.0, 9000.0));
+    let mut at = rng.seconds(0.0, 1.0);
+    while at < len {
+        let decay = rng.range(0.0005, 0.002);
+        let sound = click(rng, bright, decay, 0.2);
+        let peak = sound.iter().fold(0.0f32, |m, s| m.max(s.abs())).max(1e-9);
+        for (i, s) in sound.into_iter().enumerate() {
+            if let Some(out) = output.get_mut(at + i) {
+                *out += s / peak;
+            }
+        }
+        at += rng.seconds(0.1, 2.0);
+    }
+    output
+}
+
+/// Makes `len` samples of one kind of noise
+type Generator = fn(&mut Rng, usize) -> Vec<f32>;
+
+/// Speech and noise of one training example, at `RATE`
+struct Scene {
+    speech: Vec<f32>,
+    noise: Vec<f32>,
+}
+
+fn scene(rng: &mut Rng, len: usize) -> Scene {
+    let has_speech = rng.chance(0.85);
+    let speech_level = db_to_gain(rng.range(-42.0, -12.0));
+    let mut speech_signal = vec![0.0; len];
+    if has_speech {
+        speech_signal = speech(rng, len);
+        set_level(&mut speech_signal, speech_level);
+    }
+
+    let mut noise_signal = vec![0.0; len];
+    let generators: [(f32, Generator, f32, f32); 6] = [
+        (0.45, fan, -5.0, 30.0),
+        (0.3, hiss, 0.0, 35.0),
+        (0.15, hum, 5.0, 35.0),
+        (0.1, whine, 5.0, 35.0),
+        (0.5, keyboard, -10.0, 20.0),
+        (0.15, mouse, -5.0, 25.0),
+    ];
+    let mut any = false;
+    for (probability, generate, min_snr, max_snr) in generators {
+        if rng.chance(probability) {
+            let mut component = generate(rng, len);
+            set_level(
+                &mut component,
+                speech_level * db_to_gain(-rng.range(min_snr, max_snr)),
+            );
+            noise_signal
+                .iter_mut()
+                .zip(component)
+                .for_each(|(n, c)| *n += c);
+            any = true;
+        }
+    }
+    // Always a little microphone self-noise, unless the scene is clean
+    if any || rng.chance(0.5) {
+        let floor = speech_level * db_to_gain(-rng.range(30.0, 60.0));
+        noise_signal
+            .iter_mut()
+            .for_each(|n| *n += floor * rng.noise());
+    }
+
+    // Some microphones cut the highs
+    if rng.chance(0.3) {
+        let cutoff = rng.range(3500.0, 12000.0);
+        for signal in [&mut speech_signal, &mut noise_signal] {
+            let mut first = Lowpass::new(cutoff);
+            let mut second = Lowpass::new(cutoff);
+            signal
+                .iter_mut()
+                .for_each(|s| *s = second.process(first.process(*s)));
+        }
+    }
+
+    Scene {
+        speech: speech_signal,
+        noise: noise_signal,
+    }
+}
+
+/// The features of a scene's non-silent frames and their target gains;
+/// `None` targets are left out of the loss
+struct Sequence {
+    features: Vec<[f32; NB_FEATURES]>,
+    targets: Vec<[Option<f32>; NB_BANDS]>,
+}
+
+struct Analyzer {
+    fft: Fft,
+    window: Vec<f32>,
+    bands: Bands,
+}
+
+impl Analyzer {
+    fn new() -> Self {
+        Analyzer {
+            fft: Fft::new(FFT_SIZE),
+            window: sqrt_hann(),
+            bands: Bands::new(RATE),
+        }
+    }
+
+    fn energy(&self, frame: &[f32]) -> [f32; NB_BANDS] {
+        let mut re: Vec<f32> = frame.iter().zip(&self.window).map(|(x, w)| x * w).collect();
+        let mut im = vec![0.0; FFT_SIZE];
+        self.fft.forward(&mut re, &mut im);
+        self.bands.energy(&re, &im)
+    }
+
+    fn sequence(&self, scene: &Scene) -> Sequence {
+        let mut history = FeatureHistory::new();
+        let mut sequence = Sequence {
+            features: Vec::new(),
+            targets: Vec::new(),
+        };
+        let mix: Vec<f32> = scene
+            .speech
+            .iter()
+            .zip(&scene.noise)
+            .map(|(s, n)| s + n)
+            .collect();
+        for start in (0..mix.len().saturating_sub(FFT_SIZE)).step_by(HOP) {
+            let frame = start..start + FFT_SIZE;
+            let mix_energy = self.energy(&mix[frame.clone()]);
+            let Some(features) = history.next(&mix_energy) else {
+                continue;
+            };
+            let speech_energy = self.energy(&scene.speech[frame.clone()]);
+            let noise_energy = self.energy(&scene.noise[frame]);
+            let mut targets = [None; NB_BANDS];
+            for band in 0..NB_BANDS {
+                if speech_energy[band] + noise_energy[band] > MASK_ENERGY {
+                    targets[band] = Some(
+                        (speech_energy[band] / (1e-3 + mix_energy[band]))
+                            .sqrt()
+                            .min(1.0),
+                    );
+                }
+            }
+            sequence.features.push(features);
+            sequence.targets.push(targets);
+        }
+        sequence
+    }
+}
+
+/// Parameters in the layout `params` splits them into
+fn params(values: &[f32]) -> Params<'_> {
+    let (dense_weights, rest) = values.split_at(DENSE_WEIGHTS);
+    let (dense_bias, rest) = rest.split_at(DENSE_SIZE);
+    let (gru_input_weights, rest) = rest.split_at(GRU_INPUT_WEIGHTS);
+    let (gru_recurrent_weights, rest) = rest.split_at(GRU_RECURRENT_WEIGHTS);
+    let (gru_bias, rest) = rest.split_at(3 * GRU_SIZE);
+    let (output_weights, output_bias) = rest.split_at(OUTPUT_WEIGHTS);
+    Params {
+        dense_weights,
+        dense_bias,
+        gru_input_weights,
+        gru_recurrent_weights,
+        gru_bias,
+        output_weights,
+        output_bias,
+    }
+}
+
+/// Gradients in the same layout as `params`
+struct Gradients<'a> {
+    dense_weights: &'a mut [f32],
+    dense_bias: &'a mut [f32],
+    gru_input_weights: &'a mut [f32],
+    gru_recurrent_weights: &'a mut [f32],
+    gru_bias: &'a mut [f32],
+    output_weights: &'a mut [f32],
+    output_bias: &'a mut [f32],
+}
+
+fn gradients(values: &mut [f32]) -> Gradients<'_> {
+    let (dense_weights, rest) = values.split_at_mut(DENSE_WEIGHTS);
+    let (dense_bias, rest) = rest.split_at_mut(DENSE_SIZE);
+    let (gru_input_weights, rest) = rest.split_at_mut(GRU_INPUT_WEIGHTS);
+    let (gru_recurrent_weights, rest) = rest.split_at_mut(GRU_RECURRENT_WEIGHTS);
+    let (gru_bias, rest) = rest.split_at_mut(3 * GRU_SIZE);
+    let (output_weights, output_bias) = rest.split_at_mut(OUTPUT_WEIGHTS);
+    Gradients {
+        dense_weights,
+        dense_bias,
+        gru_input_weights,
+        gru_recurrent_weights,
+        gru_bias,
+        output_weights,
+        output_bias,
+    }
+}
+
+/// Activations of one frame, kept for the backward pass
+struct Step {
+    dense: [f32; DENSE_SIZE],
+    /// Update gate, reset gate and candidate state
+    gates: [f32; 3 * GRU_SIZE],
+    state: [f32; GRU_SIZE],
+    gains: [f32; NB_BANDS],
+}
+
+fn forward(p: &Params, features: &[[f32; NB_FEATURES]]) -> Vec<Step> {
+    let mut steps: Vec<Step> = Vec::with_capacity(features.len());
+    for input in features {
+        let previous = steps.last().map_or([0.0; GRU_SIZE], |step| step.state);
+        let mut step = Step {
+            dense: [0.0; DENSE_SIZE],
+            gates: [0.0; 3 * GRU_SIZE],
+            state: [0.0; GRU_SIZE],
+            gains: [0.0; NB_BANDS],
+        };
+        affine(
+            p.dense_weights,
+            Some(p.dense_bias),
+            input,
+            &mut step.dense,
+            false,
+        );
+        step.dense.iter_mut().for_each(|x| *x = x.tanh());
+
+        affine(
+            p.gru_input_weights,
+            Some(p.gru_bias),
+            &step.dense,
+            &mut step.gates,
+            false,
+        );
+        let recurrent = GRU_SIZE * GRU_SIZE;
+        affine(
+            &p.gru_recurrent_weights[..2 * recurrent],
+            None,
+            &previous,
+            &mut step.gates[..2 * GRU_SIZE],
+            true,
+        );
+        step.gates[..2 * GRU_SIZE]
+            .iter_mut()
+            .for_each(|x| *x = sigmoid(*x));
+        let mut reset_state = [0.0; GRU_SIZE];
+        for i in 0..GRU_SIZE {
+            reset_state[i] = step.gates[GRU_SIZE + i] * previous[i];
+        }
+        affine(
+            &p.gru_recurrent_weights[2 * recurrent..],
+            None,
+            &reset_state,
+            &mut step.gates[2 * GRU_SIZE..],
+            true,
+        );
+        let (update, candidate) = step.gates.split_at_mut(2 * GRU_SIZE);
+        for (i, candidate) in candidate.iter_mut().enumerate() {
+            *candidate = candidate.tanh();
+            step.state[i] = update[i] * previous[i] + (1.0 - update[i]) * *candidate;
+        }
+
+        affine(
+            p.output_weights,
+            Some(p.output_bias),
+            &step.state,
+            &mut step.gains,
+            false,
+        );
+        step.gains.iter_mut().for_each(|x| *x = sigmoid(*x));
+        steps.push(step);
+    }
+    steps
+}
+
+/// RNNoise's loss for one band: squared error of the square roots, which
+/// weighs errors in quiet bands up, plus the squared error
+fn band_loss(gain: f32, target: f32) -> f32 {
+    10.0 * (gain.sqrt() - target.sqrt()).powi(2) + (gain - target).powi(2)
+}
+
+/// Derivative of `band_loss` with respect to the output's pre-activation
+fn band_loss_gradient(gain: f32, target: f32) -> f32 {
+    let root = gain.sqrt();
+    10.0 * (root - target.sqrt()) * root * (1.0 - gain)
+        + 2.0 * (gain - target) * gain * (1.0 - gain)
+}
+
+/// `output[j] += Σ_i weights[i][j] * gradient[i]`
+fn add_transposed(weights: &[f32], gradient: &[f32], output: &mut [f32]) {
+    for (i, g) in gradient.iter().enumerate() {
+        let row = &weights[i * output.len()..(i + 1) * output.len()];
+        for (out, w) in output.iter_mut().zip(row) {
+            *out += w * g;
+        }
+    }
+}
+
+/// `weights[i][j] += gradient[i] * input[j]`
+fn add_outer(weights: &mut [f32], gradient: &[f32], input: &[f32]) {
+    for (i, g) in gradient.iter().enumerate() {
+        let row = &mut weights[i * input.len()..(i + 1) * input.len()];
+        for (w, x) in row.iter_mut().zip(input) {
+            *w += g * x;
+        }
+    }
+}
+
+/// Add the loss gradients of a sequence, scaled by `scale`, to `grad`;
+/// returns the summed loss and how many band targets it covers
+fn backward(
+    p: &Params,
+    sequence: &Sequence,
+    steps: &[Step],
+    scale: f32,
+    grad: &mut Gradients,
+) -> (f32, usize) {
+    let mut loss = 0.0;
+    let mut count = 0;
+    let mut state_gradient = [0.0; GRU_SIZE];
+    let recurrent = GRU_SIZE * GRU_SIZE;
+
+    for t in (0..steps.len()).rev() {
+        let step = &steps[t];
+        let previous = if t > 0 {
+            steps[t - 1].state
+        } else {
+            [0.0; GRU_SIZE]
+        };
+
+        let mut output_gradient = [0.0; NB_BANDS];
+        for (band, target) in sequence.targets[t].iter().enumerate() {
+            if let Some(target) = *target {
+                loss += band_loss(step.gains[band], target);
+                count += 1;
+                output_gradient[band] = scale * band_loss_gradient(step.gains[band], target);
+            }
+        }
+        add_outer(grad.output_weights, &output_gradient, &step.state);
+        for (b, g) in grad.output_bias.iter_mut().zip(&output_gradient) {
+            *b += g;
+        }
+        add_transposed(p.output_weights, &output_gradient, &mut state_gradient);
+
+        // Through the GRU: pre-activation gradients of the update gate,
+        // reset gate and candidate state
+        let (update, reset, candidate) = (
+            &step.gates[..GRU_SIZE],
+            &step.gates[GRU_SIZE..2 * GRU_SIZE],
+            &step.gates[2 * GRU_SIZE..],
+        );
+        let mut gate_gradient = [0.0; 3 * GRU_SIZE];
+        let mut previous_gradient = [0.0; GRU_SIZE];
+        for i in 0..GRU_SIZE {
+            let d = state_gradient[i];
+            gate_gradient[i] = d * (previous[i] - candidate[i]) * update[i] * (1.0 - update[i]);
+            gate_gradient[2 * GRU_SIZE + i] =
+                d * (1.0 - update[i]) * (1.0 - candidate[i] * candidate[i]);
+            previous_gradient[i] = d * update[i];
+        }
+        let mut reset_state = [0.0; GRU_SIZE];
+        for i in 0..GRU_SIZE {
+            reset_state[i] = reset[i] * previous[i];
+        }
+        let mut reset_state_gradient = [0.0; GRU_SIZE];
+        add_transposed(
+            &p.gru_recurrent_weights[2 * recurrent..],
+            &gate_gradient[2 * GRU_SIZE..],
+            &mut reset_state_gradient,
+        );
+        add_outer(
+            &mut grad.gru_recurrent_weights[2 * recurrent..],
+            &gate_gradient[2 * GRU_SIZE..],
+            &reset_state,
+        );
+        for i in 0..GRU_SIZE {
+            gate_gradient[GRU_SIZE + i] =
+                reset_state_gradient[i] * previous[i] * reset[i] * (1.0 - reset[i]);
+            previous_gradient[i] += reset_state_gradient[i] * reset[i];
+        }
+        add_transposed(
+            &p.gru_recurrent_weights[..2 * recurrent],
+            &gate_gradient[..2 * GRU_SIZE],
+            &mut previous_gradient,
+        );
+        add_outer(
+            &mut grad.gru_recurrent_weights[..2 * recurrent],
+            &gate_gradient[..2 * GRU_SIZE],
+            &previous,
+        );
+        add_outer(grad.gru_input_weights, &gate_gradient, &step.dense);
+        for (b, g) in grad.gru_bias.iter_mut().zip(&gate_gradient) {
+            *b += g;
+        }
+
+        let mut dense_gradient = [0.0; DENSE_SIZE];
+        add_transposed(p.gru_input_weights, &gate_gradient, &mut dense_gradient);
+        for (g, a) in dense_gradient.iter_mut().zip(&step.dense) {
+            *g *= 1.0 - a * a;
+        }
+        add_outer(grad.dense_weights, &dense_gradient, &sequence.features[t]);
+        for (b, g) in grad.dense_bias.iter_mut().zip(&dense_gradient) {
+            *b += g;
+        }
+
+        state_gradient = previous_gradient;
+    }
+
+    (loss, count)
+}
+
+/// Glorot-uniform weights and zero biases
+fn initial_params(rng: &mut Rng) -> Vec<f32> {
+    let mut values = vec![0.0; PARAM_COUNT];
+    let g = gradients(&mut values);
+    for (weights, inputs, outputs) in [
+        (g.dense_weights, NB_FEATURES, DENSE_SIZE),
+        (g.gru_input_weights, DENSE_SIZE, GRU_SIZE),
+        (g.gru_recurrent_weights, GRU_SIZE, GRU_SIZE),
+        (g.output_weights, GRU_SIZE, NB_BANDS),
+    ] {
+        let limit = (6.0 / (inputs + outputs) as f32).sqrt();
+        weights.iter_mut().for_each(|w| *w = limit * rng.noise());
+    }
+    values
+}
+
+struct Adam {
+    first: Vec<f32>,
+    second: Vec<f32>,
+    step: i32,
+}
+
+impl Adam {
+    fn new() -> Self {
+        Adam {
+            first: vec![0.0; PARAM_COUNT],
+            second: vec![0.0; PARAM_COUNT],
+            step: 0,
+        }
+    }
+
+    fn update(&mut self, values: &mut [f32], gradient: &[f32], learning_rate: f32) {
+        const BETA1: f32 = 0.9;
+        const BETA2: f32 = 0.999;
+        self.step += 1;
+        let correction1 = 1.0 - BETA1.powi(self.step);
+        let correction2 = 1.0 - BETA2.powi(self.step);
+        for i in 0..values.len() {
+            self.first[i] = BETA1 * self.first[i] + (1.0 - BETA1) * gradient[i];
+            self.second[i] = BETA2 * self.second[i] + (1.0 - BETA2) * gradient[i].powi(2);
+            let first = self.first[i] / correction1;
+            let second = self.second[i] / correction2;
+            values[i] -= learning_rate * first / (second.sqrt() + 1e-8);
+        }
+    }
+}
+
+/// Mean loss of the model over `sequences`
+fn evaluate(values: &[f32], sequences: &[Sequence]) -> f32 {
+    let p = params(values);
+    let (mut loss, mut count) = (0.0, 0);
+    for sequence in sequences {
+        for (step, targets) in forward(&p, &sequence.features)
+            .iter()
+            .zip(&sequence.targets)
+        {
+            for (gain, target) in step.gains.iter().zip(targets) {
+                if let Some(target) = target {
+                    loss += band_loss(*gain, *target);
+                    count += 1;
+                }
+            }
+        }
+    }
+    loss / count.max(1) as f32
+}
+
+fn write_array(source: &mut String, name: &str, size: &str, values: &[f32]) {
+    let _ = writeln!(
+        source,
+        "\n#[rustfmt::skip]\npub(super) static {}: [f32; {}] = [",
+        name, size
+    );
+    for row in values.chunks(8) {
+        let row: Vec<String> = row.iter().map(|v| format!("{:?},", v)).collect();
+        let _ = writeln!(source, "    {}", row.join(" "));
+    }
+    source.push_str("];\n");
+}
+
+fn write_weights(values: &[f32]) {
+    let p = params(values);
+    let mut source = String::from(
+        "//! Weights of the gain model, written by `train`; don't edit by hand.\n\
+         #![allow(clippy::approx_constant)]\n\n\
+         use super::model::{DENSE_SIZE, GRU_SIZE};\n\
+         use super::{NB_BANDS, NB_FEATURES};\n",
+    );
+    write_array(
+        &mut source,
+        "DENSE_WEIGHTS",
+        "DENSE_SIZE * NB_FEATURES",
+        p.dense_weights,
+    );
+    write_array(&mut source, "DENSE_BIAS", "DENSE_SIZE", p.dense_bias);
+    write_array(
+        &mut source,
+        "GRU_INPUT_WEIGHTS",
+        "3 * GRU_SIZE * DENSE_SIZE",
+        p.gru_input_weights,
+    );
+    write_array(
+        &mut source,
+        "GRU_RECURRENT_WEIGHTS",
+        "3 * GRU_SIZE * GRU_SIZE",
+        p.gru_recurrent_weights,
+    );
+    write_array(&mut source, "GRU_BIAS", "3 * GRU_SIZE", p.gru_bias);
+    write_array(
+        &mut source,
+        "OUTPUT_WEIGHTS",
+        "NB_BANDS * GRU_SIZE",
+        p.output_weights,
+    );
+    write_array(&mut source, "OUTPUT_BIAS", "NB_BANDS", p.output_bias);
+
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/src/denoise/weights.rs");
+    std::fs::write(path, source).unwrap();
+}
+
+fn random_sequence(analyzer: &Analyzer, rng: &mut Rng) -> Sequence {
+    let len = (SEQUENCE_FRAMES + 2) * HOP;
+    analyzer.sequence(&scene(rng, len))
+}
+
+#[test]
+#[ignore = "trains the model for a long time; run to regenerate weights.rs"]
+fn train_gain_model() {
+    let analyzer = Analyzer::new();
+    let mut rng = Rng::new(1);
+    let mut values = initial_params(&mut rng);
+    let mut adam = Adam::new();
+
+    let mut validation_rng = Rng::new(2);
+    let validation: Vec<Sequence> = (0..24)
+        .map(|_| random_sequence(&analyzer, &mut validation_rng))
+        .collect();
+
+    let mut gradient = vec![0.0; PARAM_COUNT];
+    let started = std::time::Instant::now();
+    let mut best = (f32::INFINITY, values.clone());
+    for step in 0..STEPS {
+        let batch: Vec<Sequence> = (0..BATCH_SIZE)
+            .map(|_| random_sequence(&analyzer, &mut rng))
+            .collect();
+        let targets: usize = batch
+            .iter()
+            .flat_map(|s| &s.targets)
+            .map(|t| t.iter().flatten().count())
+            .sum();
+        let scale = 1.0 / targets.max(1) as f32;
+
+        gradient.fill(0.0);
+        let p = params(&values);
+        let mut loss = 0.0;
+        for sequence in &batch {
+            let steps = forward(&p, &sequence.features);
+            loss += backward(&p, sequence, &steps, scale, &mut gradients(&mut gradient)).0;
+        }
+
+        let norm = gradient.iter().map(|g| g * g).sum::<f32>().sqrt();
+        if norm > MAX_GRADIENT_NORM {
+            gradient
+                .iter_mut()
+                .for_each(|g| *g *= MAX_GRADIENT_NORM / norm);
+        }
+        // Cosine decay to a tenth of the rate
+        let progress = step as f32 / STEPS as f32;
+        let learning_rate = LEARNING_RATE * (0.55 + 0.45 * (PI * progress).cos());
+        adam.update(&mut values, &gradient, learning_rate);
+
+        if step % 100 == 99 || step == STEPS - 1 {
+            let validation_loss = evaluate(&values, &validation);
+            if validation_loss < best.0 {
+                best = (validation_loss, values.clone());
+            }
+            println!(
+                "step {} loss {:.4} validation {:.4} ({:.0?})",
+                step + 1,
+                loss * scale,
+                validation_loss,
+                started.elapsed()
+            );
+            write_weights(&best.1);
+        }
+    }
+}
+
+#[test]
+fn test_gradients() {
+    let analyzer = Analyzer::new();
+    let mut rng = Rng::new(3);
+    let scene = scene(&mut rng, 12 * HOP + FFT_SIZE);
+    let sequence = analyzer.sequence(&Scene {
+        // Keep the targets away from the clamped and masked extremes
+        noise: scene.noise.iter().map(|n| n + 0.05 * rng.noise()).collect(),
+        speech: scene
+            .speech
+            .iter()
+            .map(|s| s + 0.05 * rng.noise())
+            .collect(),
+    });
+    assert!(sequence.targets.iter().flatten().flatten().count() > 100);
+    let values: Vec<f32> = initial_params(&mut rng);
+
+    let mut gradient = vec![0.0; PARAM_COUNT];
+    let p = params(&values);
+    let steps = forward(&p, &sequence.features);
+    backward(&p, &sequence, &steps, 1.0, &mut gradients(&mut gradient));
+
+    let loss = |values: &[f32]| {
+        let p = params(values);
+        let steps = forward(&p, &sequence.features);
+        let mut total = 0.0f64;
+        for (step, targets) in steps.iter().zip(&sequence.targets) {
+            for (gain, target) in step.gains.iter().zip(targets) {
+                if let Some(target) = target {
+                    total += band_loss(*gain, *target) as f64;
+                }
+            }
+        }
+        total
+    };
+
+    // Compare with central differences for a sample of every layer
+    for index in (0..PARAM_COUNT).step_by(97) {
+        let epsilon = 1e-2;
+        let mut shifted = values.clone();
+        shifted[index] += epsilon;
+        let up = loss(&shifted);
+        shifted[index] -= 2.0 * epsilon;
+        let down = loss(&shifted);
+        let numeric = ((up - down) / (2.0 * epsilon as f64)) as f32;
+        let error = (numeric - gradient[index]).abs();
+        assert!(
+            error < 2e-3 + 1e-2 * numeric.abs(),
+            "parameter {}: {} vs {}",
+            index,
+            gradient[index],
+            numeric
+        );
+    }
+}
+
+#[test]
+fn test_forward_matches_model() {
+    let analyzer = Analyzer::new();
+    let mut rng = Rng::new(4);
+    let sequence = analyzer.sequence(&scene(&mut rng, 200 * HOP));
+    assert!(!sequence.features.is_empty());
+    let values: &'static [f32] = Box::leak(initial_params(&mut rng).into_boxed_slice());
+
+    let mut model = GainModel::with_params(params(values));
+    let steps = forward(&params(values), &sequence.features);
+    for (features, step) in sequence.features.iter().zip(&steps) {
+        let mut gains = [0.0; NB_BANDS];
+        model.gains(features, &mut gains);
+        for (a, b) in gains.iter().zip(&step.gains) {
+            assert!((a - b).abs() < 1e-5);
+        }
+    }
+}
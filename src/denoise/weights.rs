@@ -0,0 +1,1770 @@
+//! Weights of the gain model, written by `train`; don't edit by hand.
+#![allow(clippy::approx_constant)]
+
+use super::model::{DENSE_SIZE, GRU_SIZE};
+use super::{NB_BANDS, NB_FEATURES};
+
+#[rustfmt::skip]
+pub(super) static DENSE_WEIGHTS: [f32; DENSE_SIZE * NB_FEATURES] = [
+    0.05550105, -0.049217016, -0.06040782, 0.0017190431, -0.100363, 0.109562874, -0.18327878, 0.26969004,
+    0.0018292717, -0.17007525, -0.09573479, -0.15766712, -0.050932504, -0.18107724, 0.1639557, 0.2731919,
+    -0.12073987, 0.022650898, 0.34976077, 0.10213533, -0.11650301, 0.1245382, -0.0055967546, -0.22712763,
+    -0.51402867, -0.17500044, 0.14391254, -0.03378266, -0.12627523, 0.0064350143, -0.11844487, 0.09746777,
+    0.03872626, 0.086155415, 0.32507777, -0.029392352, 0.032371428, 0.065424025, -0.042720966, 0.064551294,
+    -0.16664386, 0.05645716, -0.43928248, -0.17870913, -0.34887907, -0.1353468, 0.1127897, 0.23096476,
+    -0.020008791, 0.3061823, 0.07011823, -0.03429524, 0.30007538, -0.27071851, 0.1135066, 0.026538972,
+    -0.005538441, -0.24281575, 0.37451848, 0.12170594, -0.26429024, 0.32277128, -0.30944082, -0.2004013,
+    0.49480683, -0.13415343, -0.14666247, 0.42372593, -0.27186337, 0.041461173, -0.012542856, 0.08025323,
+    0.09052011, -0.06007189, -0.078215435, -0.2463777, -0.008934314, 0.10213731, -0.27559662, -0.36979517,
+    -0.6688942, -0.2097629, 0.28594625, -0.086498, -0.034681, 0.07958878, 0.21021387, 0.5234017,
+    0.24834129, -0.056077432, 0.13282049, 0.2934405, 0.3557095, 0.052124497, 0.089734256, 0.46372887,
+    -0.20578688, -0.2805585, 0.26953086, -0.043141153, 0.09737992, 0.39895022, -0.12593466, -0.015740383,
+    0.08834193, -0.018884556, -0.13087453, 0.039643686, 0.12262557, -0.2528102, 0.3714588, 0.0786556,
+    0.5188611, -0.5219264, -0.17432556, -0.13794196, -0.5654969, -0.30064854, -0.08735151, -0.20950246,
+    -0.14275034, 0.118874386, 0.053178877, 0.0941841, 0.025542378, 0.20366631, 0.100046225, -0.012936794,
+    0.04715141, 0.041823152, 0.47599262, -0.45138508, 0.12696853, 0.057208188, -0.1991972, 0.22527428,
+    0.29993463, -0.28680933, 0.112811945, -0.17541821, 0.015671685, 0.18802711, -0.05282827, -0.13624433,
+    0.23747686, -0.31332794, 0.27802116, 0.21072842, 0.11910058, -0.23364066, -0.1760375, 0.24483456,
+    -0.21674508, -0.13605279, 0.112281, 0.0026401123, -0.15677129, 0.2588287, 0.05669103, 0.069446586,
+    -0.035338175, 0.25438374, -0.31079653, 0.4020406, -0.40400264, -0.5155118, 0.34328854, -0.5995709,
+    -0.20154442, 0.003619589, -0.13572784, -0.3435746, 0.18464796, -0.18879817, -0.21813583, -0.0027481003,
+    0.07678782, 0.11162829, -0.15147635, -0.18677944, 0.050585646, 0.15360536, 0.018471805, -0.10317393,
+    0.11295735, -0.11068364, 0.33830982, 0.23270518, -0.32595256, 0.10280819, 0.13164933, 0.10850502,
+    -0.026503935, -0.18811254, 0.048704613, -0.06713963, -0.024495352, -0.46140155, 0.12537359, 0.23204641,
+    -0.2466279, -0.2326388, 0.038614012, -0.46067497, 0.14825034, 0.20947361, -0.065983616, -0.17296566,
+    0.05285247, -0.54068685, 0.0026863413, 0.041967582, 0.022370677, -0.11445177, 0.21133515, -0.21633534,
+    0.3400348, 0.19907944, -0.44194725, 0.4230641, 0.38077906, -0.5392118, 0.3503582, -0.055817574,
+    -0.5331609, 0.4575069, -0.24509862, -0.42094967, 0.71578664, 0.10701713, -0.45869958, 0.060446884,
+    0.02674958, 0.004381647, -0.049687747, 0.13768834, 0.28834754, -0.32862908, 0.09102244, -0.02309241,
+    -0.13889465, 0.13265574, 0.031041594, -0.05988589, 0.31238574, 0.0336327, -0.19327661, -0.037634883,
+    -0.057371266, 0.012871563, -0.10264173, 0.30758366, 0.13463354, -0.06126455, 0.22107658, 0.089833304,
+    0.13875185, -0.17027612, -0.058302235, -0.017159631, 0.16011791, -0.14106862, 0.23899418, 0.17893676,
+    0.06214862, -0.10878788, 0.087948054, -8.54306e-5, 0.0023370325, -0.030978791, 0.124194406, 0.04149896,
+    0.08253768, -0.07547432, -0.1459006, -0.017806256, 0.35265854, 0.26760864, 0.19264278, -0.10374352,
+    -0.072669186, 0.046272386, 0.17887706, 0.03674397, -0.036047958, 0.22199775, 0.28934783, 0.025079608,
+    0.24697872, 0.35496953, -0.24670278, 0.343681, 0.27477568, -0.28299075, -0.18693124, 0.16069865,
+    -0.021832855, 0.3386976, 0.017095571, 0.13628025, -0.37954122, 0.19930868, -0.55687326, -0.092848666,
+    0.31764093, 0.06589876, 0.1990351, 0.6086181, 0.18533815, -0.2159552, 0.15059423, 0.6033541,
+    -0.30169436, 0.22894469, 0.1315513, -0.03476201, 0.0065143085, 0.024990834, 0.011556814, -0.024156645,
+    0.070934206, -0.1376058, 0.22639409, 0.021613367, 0.036147878, -0.0052431375, 0.036007136, -0.13295725,
+    0.15562378, -0.06416691, 0.095143504, -0.08888775, 0.18218012, 0.1082524, 0.18474174, 0.07921243,
+    -0.15519597, -0.45861447, -0.2896964, 0.26540068, 0.40618303, -0.29681313, 0.3333346, -0.4197154,
+    -0.28136992, 0.24196595, 0.32148442, -0.28797266, 0.32010755, -0.25464076, -0.006988989, 0.0032636803,
+    0.08644432, 0.006024256, 0.13256845, 0.062663555, 0.40317848, 0.38701436, 0.39645207, 0.24463978,
+    0.09520013, 0.17928588, -0.019746251, -0.15611427, -0.10646474, -0.19263044, -0.21263441, -0.15455128,
+    -0.03799881, 0.017859725, -0.027573032, 0.11995878, 0.018103177, -0.042000562, 0.21101485, -0.05005742,
+    0.31010133, 0.12759054, 4.6785426e-5, 0.003881396, 0.07721461, 0.010250616, 0.035434976, 0.02253854,
+    -0.01263623, 0.008453325, 4.5754456e-5, 0.0720855, 0.18847659, -0.19550565, 0.11809052, 0.33028373,
+    0.28616214, 0.14904024, -0.078396745, -0.13253985, 0.19707856, 0.05986932, -0.32783365, -0.22056314,
+    0.05858873, 0.04330938, -0.092020415, 0.19949305, 0.032396313, -0.15474504, 0.17535649, 1.0302744,
+    -0.75415224, 0.09726492, 0.34689674, -0.123111345, -0.04255888, 0.77461773, -0.75666106, 0.2859775,
+    0.09104524, -0.037901394, -0.044992883, 0.053612214, 0.00021378684, 0.05870074, 0.18843524, 0.03332253,
+    0.055842813, 0.25962332, -0.1632499, -0.23393254, -0.31056574, -0.80445576, -0.798597, -0.36065042,
+    -0.45058888, -0.55468285, 0.080930606, 0.18533853, 0.117982045, 0.2918903, 0.14452736, 0.26318374,
+    -0.045158852, 0.27490893, 0.35992756, 0.14675698, 0.09419269, -0.22892556, 0.025593868, 0.4084189,
+    -0.31959873, 0.009278325, 0.16275918, -0.086907744, -0.017272694, 0.21548826, 0.20529933, 0.031192956,
+    0.013306993, 0.21171978, 0.2528699, -0.1573642, -0.23453863, 0.13466635, 0.08376345, -0.007564383,
+    -0.26995292, -0.47043172, -0.06255723, -0.15210363, -0.049607452, -0.10628753, -0.19909601, -0.14758672,
+    0.00984641, -0.28236446, -0.1575202, 0.07321558, 0.0037082022, 0.06063051, 0.00029974765, 0.32072783,
+    0.4111181, -0.24673977, -0.16535896, -0.03141632, 0.026444372, 0.14241818, 0.14729694, 0.0040575773,
+    -0.07460256, 0.24474587, -0.017088775, -0.008775763, 0.098203346, 0.201613, -0.09249055, -0.15256074,
+    0.16809572, 0.42930382, 0.091330215, 0.5513024, -0.0063808705, -0.038323987, 0.265013, -0.20303215,
+    0.02155573, 0.08976416, -0.12075105, 0.068630196, 0.18250749, 0.23982742, -0.26161566, -0.028373443,
+    -0.33295718, -0.21320705, -0.056001388, 0.7273397, 0.03368139, 0.03131558, 0.008064557, 0.13380313,
+    -0.10222367, -0.02149505, -0.12487716, -0.11574393, 0.21398903, 0.03658323, 0.14802653, 0.02293207,
+    -0.24115151, -0.05739548, -0.1691631, 0.029812405, -0.23594719, 0.05591531, 0.37084547, 0.15624695,
+    0.23611787, 0.34876415, 0.15304391, 0.2666317, 0.12807308, -0.06232472, 0.18834762, 0.15118006,
+    -0.17841008, 0.3406103, -0.29324114, 0.44493055, 0.42387047, 0.013720812, -0.0067375363, 0.2626681,
+    -0.21083353, -0.22781391, -0.23998973, 0.14236866, -0.08539551, 0.064593785, -0.056107394, -0.3008595,
+    -0.013688785, -0.022850255, -0.09053894, 0.02626342, 0.08421157, -0.123100884, -0.36178568, 0.48916447,
+    -0.29753652, -0.3187437, 0.30339575, 0.10792822, -0.25224292, -0.022844942, 0.36224863, -0.117647946,
+    -0.023233091, -0.10290536, -0.2335077, 0.075728886, 0.18385084, -0.22165963, -0.112006515, -0.013655114,
+    -0.421083, 0.07880732, -0.0843407, 0.028524796, -0.050030638, -0.067570515, -0.119798675, -0.04290204,
+    0.04402669, -0.039481606, -0.102911636, 0.037943754, 0.0992502, 0.024398735, 0.07341975, -0.023619387,
+    0.07745848, -0.22388093, -0.15146267, 0.31394356, 0.15490861, 0.1951901, 0.18056656, 0.15048932,
+    -0.053585723, 0.03455423, -0.15579736, 0.052533623, 0.04234985, 0.041193843, -0.043302894, -0.12940696,
+    0.30203378, -0.059681248, 0.15612079, -0.025737043, 0.012719108, 0.12267602, 0.17280163, 0.0845493,
+    0.12660967, 0.1603176, 0.05357068, 0.05730223, 0.1847348, 0.07934598, -0.012890072, -0.009204301,
+    -0.085201405, -0.22619952, 0.30032006, -0.10935988, -0.38297302, -0.0059550544, -0.06488392, 0.27989239,
+    -0.22107646, 0.10882976, -0.057601035, 0.13628882, 0.38317794, -0.20154537, 0.08530561, -0.020720715,
+    -0.20075685, 0.080115214, 0.08114919, -0.15220888, 0.5206716, -0.024895497, -0.2598602, -0.41473392,
+    0.32542267, -0.039830156, 0.2754734, -0.26271006, -0.081175126, -0.008255916, 0.071859635, 0.03835925,
+    -0.11929103, 0.01812538, -0.092138976, 0.03424887, -0.0729462, 0.20075499, -0.031425558, -0.38094184,
+    -0.06099997, 0.077764995, -0.06973252, 0.11176344, -0.22220042, 0.0855099, 0.09378864, -0.27872226,
+    -0.12635571, -0.22237408, -0.3186201, 0.030143337, 0.19218746, 0.2299331, -0.21324597, 0.27975693,
+    -0.074106805, -0.63036937, 0.47970116, -0.3129262, -0.12510848, -0.3665114, 0.37389013, -0.10055664,
+    -0.069246784, 0.15483294, -0.033394884, -0.1843868, -0.019977894, -0.031176928, 0.08471423, -0.18359591,
+    -0.15152015, -0.1860162, 0.18318118, -0.30571753, -0.34882364, -0.074242316, -0.5051115, 0.05798899,
+    -0.34485364, -0.17892717, -0.2561611, 0.44260892, -0.0047644875, -0.32455686, 0.088001914, 0.34269646,
+    0.17087519, -0.101613045, 0.3099808, 0.51994395, 0.40453503, -0.7753862, -0.12557249, -0.23240371,
+    0.17016958, 0.06520723, -0.06598113, -0.46500084, -0.15857334, 0.0013050771, 0.007293359, 0.00014171147,
+    -0.065538876, -0.016055329, 0.032540955, -0.012419881, 0.002168623, 0.41556168, 0.50603515, 0.21727711,
+    0.36293173, 0.10499625, 0.1625232, 0.17163001, -0.16364138, -0.10281125, -0.089913286, -0.43538228,
+    -0.3108139, 0.32635093, -0.17144538, 0.09125184, 0.08434207, -0.2015152, -0.38829687, 0.123126596,
+    0.43780488, -0.1629696, 0.4430881, -0.5316191, 0.11747925, 0.14865728, 0.0717719, 0.11080369,
+    -0.06379196, -0.04993032, -0.0071047046, -0.045198716, -0.053700984, -0.0256938, 0.040893998, 0.13972475,
+    0.09144213, 0.5626284, -0.16998546, 0.34340727, 0.2959564, 0.2907024, 0.3068158, -0.05998785,
+    0.2789358, -0.14192723, 0.20226474, -0.007492027, 0.02384851, -0.115681574, -0.06057828, -0.022457756,
+    0.05494591, 0.08737672, -0.23179868, 0.08457423, 0.38314578, 0.16672574, 0.24093816, 0.13961762,
+    -0.21830367, 0.030152757, 0.32115653, 0.057945497, 0.007624157, 0.0065511097, -0.022332942, 0.015991429,
+    -0.021684755, -0.054527074, 0.06910224, -0.25100943, 0.1875539, -0.25758067, -0.0010358852, 0.13303238,
+    0.062322386, 0.0022524379, 0.14577274, 0.07034701, -0.096827246, 0.08665435, -0.20397542, -0.037065763,
+    0.052222785, -0.0880027, 0.07489631, 0.6526334, -1.0389076, 0.48018417, 0.06783243, -0.3303848,
+    0.35841152, 0.59509695, -0.97452193, 0.4451611, 0.047455303, -0.197277, 0.19236508, 0.31636408,
+    -0.0010758638, -0.08378532, -0.08236068, 0.23114868, -0.18406568, -0.0074885804, -0.11152101, -0.42589384,
+    0.28623322, -0.12248872, -0.25333196, -0.007312086, 0.08706605, -0.20124374, 0.122820646, -0.009308763,
+    0.14178236, 0.42122528, -0.31245235, -0.0009070303, 0.23894669, -0.059545178, 0.43081638, -0.2783675,
+    0.09933865, -0.32883373, -0.046117116, -0.11167752, 0.62574255, -0.4277271, 0.18729137, -0.17212048,
+    -0.021821162, 0.034011096, 0.24303718, 0.012156764, 0.103655666, 0.033555754, -0.29635954, -0.09547498,
+    -0.20219031, 0.13020852, -0.073486835, 0.104464516, -0.23825455, 0.17428856, 0.2062272, 0.18028016,
+    -0.09998365, 0.12809026, -0.032927368, -0.21033254, -0.030794073, -0.18155608, -0.35557207, 0.11450044,
+    0.08477716, 0.07619389, 0.44185978, 0.36580643, -0.66755056, -0.14904559, -0.3285186, -0.030522069,
+    0.29629534, 0.098897256, -0.29033098, -0.073555134, -0.1629777, 0.09464538, 0.00039190342, 0.058335487,
+    -0.06452072, -0.30117953, 0.17643441, 0.1739636, -0.2149782, -0.2324686, 0.24115042, 0.24764024,
+    0.1728695, 0.3105506, -0.14085348, 0.22969489, 0.25125173, 0.019959679, -0.06917202, 0.011950941,
+    -0.0028237668, 0.005004567, 0.010384558, -0.23850518, 0.82691664, 0.54340845, -0.44460362, -0.09126055,
+    0.21581683, -0.007574931, -0.0787214, 0.2328933, -0.087263994, -0.023411252, 0.13519777, -0.03789839,
+    0.0052971942, -0.0017895842, 0.122180976, -0.050262854, -0.012722832, 0.0412731, -0.04977132, 0.31835052,
+    -0.30828902, 0.5256322, 0.09789713, -0.037273064, -0.042205863, 0.1500219, -0.36381027, 0.17854975,
+    -0.250864, 0.088042155, 0.22110464, -0.10341224, -0.15729763, 0.08265703, -0.21794079, -0.036559094,
+    0.13098566, -0.1330691, -0.08790369, 0.09452439, -0.12604617, -0.058779854, 0.017500939, -0.08614968,
+    0.028122898, 0.026606016, 0.13441677, 0.50254697, 0.008905903, 0.0326646, 0.21008117, 0.21973203,
+    0.09096396, -0.022070361, 0.06534534, 0.26022634, 0.037095793, 0.118950084, 0.23917696, 0.35953292,
+    -0.07779602, 0.34955513, -0.12455262, -0.12796126, -0.007761054, -0.12814099, -0.4165075, 0.14564478,
+    0.14060812, -0.2923158, 0.090831265, 0.024387967, 0.473517, 0.34320638, 0.26930502, -0.07427488,
+    0.062004037, -0.02632927, 0.16748455, 0.12784442, 0.18560803, 0.03157712, -0.5451119, 0.0037302582,
+    0.06900695, 0.181842, -0.1603976, 0.11711442, 0.11013621, 0.029788196, -0.09846561, -0.0636389,
+    -0.056261756, -0.092783555, 0.10699547, 0.00660162, -0.2747597, 0.11754629, -0.16022235, -0.27845255,
+    -0.41308293, -0.018173223, 0.31635308, 0.1755173, -0.1256343, 0.023983847, 0.46467623, 0.08464892,
+    -0.2507115, 0.24010578, 0.031654574, 0.026258033, 0.30034116, -0.2082129, -0.070213966, 0.13873722,
+    -0.11926385, -0.0049736565, 0.03523992, -0.022258982, -0.18598805, -0.010659491, 0.13393435, -0.09054621,
+    0.14554866, -0.15274076, 0.123949155, 0.18692814, -0.1382657, -0.04902919, -0.08630232, 0.1613377,
+    -0.055052422, -0.10155075, -0.24529314, 0.21891145, -0.069976285, -0.32054427, -0.017079365, 0.18253486,
+    0.16293074, 0.108754314, 0.48652047, 0.10422164, 0.20940138, 0.20225006, 0.015628759, -0.05170168,
+    0.053803924, -0.098445185, -0.13871527, 0.029866718, -0.43877226, -0.005012598, -0.008673224, -0.06392077,
+    0.22317457, -0.13303259, -0.11510955, 0.21462171, -0.01614045, 0.016214464, -0.058093566, 0.3016714,
+    -0.055320576, 0.076476425, -0.108894944, -0.15486248, 0.18662313, 0.04213162, -0.14804254, 0.2381061,
+    -0.009419356, -0.16655143, 0.09092866, 0.28900123, 0.60238093, -0.56741595, 0.055160396, 0.013113855,
+    -0.14512973, -0.33172557, 0.1005619, -0.03471273, 0.085186176, -0.028453525, -0.02804971, -0.26189095,
+];
+
+#[rustfmt::skip]
+pub(super) static DENSE_BIAS: [f32; DENSE_SIZE] = [
+    -0.24308845, -0.02129719, -0.15142864, 0.055641975, 0.07714595, 0.31551424, -0.21790752, 0.2648751,
+    -0.17183457, 0.08011191, 0.16672811, -0.23334627, -0.11362151, 0.027872218, -0.13382438, 0.250263,
+    0.07131616, -0.03384513, 0.008211906, 0.07387683, -0.13244, 0.16564074, 0.11863892, -0.21587202,
+    -0.14460266, -0.1422766, -0.16219173, -0.6614715, 0.53959864, -0.027968643, 0.21221754, -0.116231866,
+];
+
+#[rustfmt::skip]
+pub(super) static GRU_INPUT_WEIGHTS: [f32; 3 * GRU_SIZE * DENSE_SIZE] = [
+    0.23508658, 0.03561657, -0.15887296, 0.02352391, 0.20245102, -0.018617086, -0.009462671, 0.32900625,
+    0.08225187, 0.1906503, -0.21379519, -0.17552587, 0.24168241, 0.48647738, -0.024068244, -0.42013085,
+    -0.63010293, 0.084974565, -0.41771296, 0.24221167, -0.022281934, -0.050104413, 0.2705332, -0.39297804,
+    -0.011157299, -0.04560185, 0.46368444, 0.38104853, 0.23829012, -0.24740753, 0.27720672, 0.007118177,
+    -0.050829258, 0.34586662, -0.27002338, 0.042522326, 0.03628452, -0.1404808, -0.11198771, 0.11661082,
+    0.35622358, 0.091863744, 0.18802695, -0.2944016, 0.17174433, 0.05837838, -0.15766497, 0.14098495,
+    -0.17485212, 0.29834363, -0.14322521, -0.11218015, -0.022722261, 0.4484432, 0.13942866, -0.6436812,
+    -0.51476395, -0.18715733, 0.2687919, 0.33628586, -0.029995458, 0.019816024, -0.16837767, 0.24371116,
+    -0.19035533, -0.24331406, 0.18783191, 0.32838017, 0.037364155, -0.3020168, -0.54040843, 0.21883732,
+    -0.32352045, -0.10891017, 0.07891191, 0.5250964, 0.32267684, -0.06514913, -0.0520938, 0.7526196,
+    -0.16436833, -0.110301316, -0.032893557, -0.084872805, 0.2943322, -0.12772834, -0.46627736, 0.32702053,
+    0.3380501, -0.14760056, 0.11648165, -0.04686874, -0.08103958, -0.42931378, 0.037979655, -0.29004776,
+    0.057220742, -0.36652982, 0.12998448, -0.20559056, 0.08619416, -0.4861538, 0.27945748, 0.029388836,
+    -0.13609262, -0.09355027, 0.31460357, 0.16009457, 0.20203868, -0.00811023, -0.22238971, 0.08662613,
+    -0.079638965, -0.20778991, 0.0059506767, 0.07744342, 0.10614651, -0.11085492, 0.15141359, 0.21940881,
+    0.0040122108, -0.1264718, 0.37590384, 0.09967738, 0.3413914, -0.060919855, -0.117304265, 0.13137946,
+    0.011232799, 0.061789617, 0.1344484, 0.20638236, -0.33704248, 0.36808613, 0.121072255, 0.05917565,
+    0.14450707, 0.12715961, 0.07912782, -0.04369112, -0.023691762, -0.032373004, -0.15454291, -0.19154096,
+    -0.18125162, 0.1192608, 0.3133155, 0.19945748, -0.037886333, -0.43453553, 0.17320693, -0.22082472,
+    0.35113338, 0.121946625, 0.1225439, -0.023408497, 0.31047502, 0.30323482, 0.3339954, -0.041937478,
+    -0.14161181, 0.20516431, 0.09989565, 0.16518222, -0.033518028, -0.021933923, -0.005968049, 0.19696034,
+    0.02736227, 0.05166459, 0.20977794, 0.039855823, -0.40259358, 0.0125453435, 0.2505896, -0.23605168,
+    -0.1181412, 0.14675814, -0.18916011, -0.053173073, 0.084652886, 0.20998389, 0.3082652, -0.123491816,
+    0.4468406, -0.44594195, -0.5118169, -0.18815681, -0.0036178653, -0.39147565, -0.03693768, 0.21977228,
+    0.1642073, -0.4273779, 0.36828005, -0.29421255, -0.28346068, -0.45490772, 0.07105118, -0.07129534,
+    -0.12932774, -0.24833065, 0.54545206, 0.30277327, 0.17511985, 0.68598044, -0.20967552, -0.2809239,
+    -0.7825305, -0.3162457, 0.079630576, -0.4420663, 0.02613511, 0.12570731, -0.48640463, 0.019272264,
+    -0.13724199, -0.28855768, 0.37262973, -0.095248096, 0.45444706, -0.22890966, 0.15006086, -0.56129104,
+    -0.083158046, -0.043226797, 0.1199805, -0.1231928, 0.030228784, 0.20746526, 0.18803659, 0.0013308364,
+    -0.23657274, 0.0015051368, -0.34200054, -0.062145494, 0.08939379, 0.16518435, -0.22110152, -0.17370795,
+    0.06523085, 0.14235257, -0.23307845, 0.056667767, 0.07988948, -0.017076991, 0.03423503, -0.24056233,
+    -0.0068883277, -0.011406838, 0.307248, -0.33742464, 0.3666452, -0.03113412, 0.4067444, 0.055764664,
+    0.04127955, 0.11676548, -0.23158665, 0.21671844, -0.24706538, -0.064495064, 0.031845484, 0.24921381,
+    -0.053872377, -0.17060447, -0.24850102, -0.4206023, -0.44862804, 0.40117955, 0.09313572, -0.0049341656,
+    -0.48488086, 0.13539088, -0.22843678, 0.030375684, -0.3431456, -0.0223083, 0.06616239, -0.36130682,
+    0.21217054, -0.32607037, 0.09313431, 0.014511898, 0.15160514, 0.051576875, -0.09468615, 0.06693786,
+    0.26471308, 0.21035796, -0.04384818, 0.3691065, 0.10676154, -0.039457463, 0.26490584, 0.15212479,
+    0.17547187, 0.039642777, -0.21249084, 0.042328466, 0.042043794, 0.10805747, 0.012827237, -0.059729557,
+    -0.037096802, 0.35864636, -0.06338246, -0.14609028, -0.28262845, -0.19211955, -0.15365635, -0.36737242,
+    -0.0009626505, 0.07301527, 0.32919267, -0.06672118, -0.013002445, 0.12902544, -0.23192568, 0.25576416,
+    0.16531256, -0.6027859, 0.274248, -0.26474288, -0.08365463, -0.22311588, 0.25069028, -0.16618921,
+    -0.013463101, -0.59470063, 0.38910842, 0.3040657, 0.028169299, 0.3571701, -0.09749558, -0.223016,
+    -0.3537056, 0.01854989, 0.3032238, -0.040086728, 0.289426, -0.45371005, 0.32000834, 0.6666869,
+    -0.115385905, -0.08722024, 0.09777886, 0.47669086, 0.41455802, -0.04728791, 0.2471818, -0.2302959,
+    0.12443679, 0.527019, -0.20574442, 0.3331223, 0.31109774, 0.081963204, -0.26095375, -0.040012337,
+    0.23878312, 0.23546197, 0.08778322, 0.016280212, 0.15952222, -0.0071014757, 0.1278825, -0.4296376,
+    -0.3655368, -0.13100116, -0.75566286, -0.2937636, 0.037924428, 0.17665519, -0.0976234, -0.008947257,
+    -0.5358401, 0.02570455, -0.21248287, 0.17547646, 0.21464133, 0.359278, -0.376738, -0.030342743,
+    0.13229156, -0.094428435, 0.37325785, -0.13533959, -0.073909536, 0.21193936, 0.4198756, -0.19159444,
+    -0.22236596, 0.022817971, 0.2362967, -0.33555317, 0.06251008, 0.2617856, 0.08032822, 0.19434042,
+    0.019984612, -0.031375527, -0.31478807, 0.33179122, 0.061712645, -0.26008552, 0.15608867, -0.34753716,
+    -0.14495654, -0.27108622, 0.31314367, 0.10693546, 0.22066331, -0.17003708, -0.19770023, 0.28006542,
+    -0.38366938, 0.098884106, 0.043846942, -0.100019425, 0.37288597, 0.11307249, 0.050360657, -0.22294663,
+    0.1931036, 0.11037281, 0.39629015, 0.14575906, 0.2758392, -0.052375782, 0.08397162, 0.059326548,
+    -0.14348282, 0.1620339, -0.024139203, 0.24149974, 0.142048, 0.3863377, -0.13498802, 0.3999704,
+    -0.071315765, 0.09022607, 0.07975079, 0.19878319, -0.039986435, 0.1556185, -0.036720335, -0.111045696,
+    -0.1482747, 0.0087271435, -0.098051175, 0.04341426, 0.24914163, -0.039891187, -0.5783398, -0.06809221,
+    -0.0656926, 0.20015977, 0.10227316, -0.44622403, 0.13797124, -0.2243137, 0.005683084, 0.056329697,
+    -0.13509417, 0.070582114, -0.23132433, 0.2220129, -0.06274511, 0.35862458, -0.5336892, 0.09151651,
+    -0.25359842, 0.022265008, -0.26303095, 0.12464019, -0.2092958, -0.32170582, -0.009582342, -0.3952264,
+    0.18874107, -0.28726086, -0.0146864, -0.2972481, -0.095328964, 0.18611687, -0.51806223, -0.22887279,
+    -0.014540169, 0.16277967, 0.24058938, -0.12374019, 0.20528471, 0.2180841, -0.035774205, 0.051581677,
+    -0.21193162, -0.095074534, -0.36300415, -0.23162809, -0.27584112, 0.09978027, -0.67518127, 0.008565823,
+    -0.075338885, -0.1235875, -0.01808194, 0.32910633, -0.06642794, -0.18715091, -0.07232307, -0.098088674,
+    0.120395884, -0.11003799, -0.33001888, 0.12803754, -0.03555333, 0.29889423, 0.035482544, 0.12702465,
+    0.025001407, -0.06616073, 0.3794677, 0.37275076, -0.18053843, 0.20387512, -0.30982545, 0.16771853,
+    -0.18308176, -0.17579757, 0.13127026, -0.07972107, 0.23824024, -0.3322176, -0.032245, -0.2772121,
+    0.060932778, -0.22190559, -0.323715, -0.36405924, 0.10487051, -0.053122465, -0.3102778, -0.017390363,
+    -0.050664328, 0.17162825, -0.07055874, -0.062368553, 0.32128274, -0.021854406, -0.5611159, 0.50647354,
+    -0.14546724, -0.03944915, -0.18336111, -0.1667573, -0.09383384, 0.23215671, -0.06369504, 0.10343815,
+    -0.062160157, -0.32483202, -0.045436297, -0.11669905, -0.49287853, -0.034681365, -0.39677945, -0.14206633,
+    -0.27031314, 0.030517416, 0.112256035, -0.09797649, 0.23025943, -0.5012606, 0.16636615, 0.18979797,
+    0.2123066, -0.15228471, -0.20940249, -0.1257158, 0.007916091, -0.14451319, -0.15001336, -0.15280548,
+    -0.3377997, 0.034023512, -0.3976824, -0.11069421, -0.25510418, 0.3466599, -0.111929774, -0.05614735,
+    -0.12559949, 0.08536738, -0.40137175, 0.06883492, -0.2730961, 0.20902923, -0.11372935, -0.18670104,
+    -0.54166704, -0.04714811, 0.17329463, 0.4542813, 0.091208324, 0.28570837, -0.2709629, 0.146281,
+    0.15283845, 0.32996038, -0.006120701, -0.44754604, -0.2807047, 0.11149803, -0.055085804, -0.41523474,
+    -0.10879627, 0.07127658, -0.29341158, -0.30649287, 0.39186653, 0.0986837, -0.18769678, 0.12839101,
+    -0.14786668, 0.45887786, -0.49630597, 0.18782774, 0.20380859, -0.11689822, -0.5234902, -0.2942932,
+    -0.4902033, 0.39014027, -0.22234972, 0.31196213, -0.38169122, 0.43669638, -0.076636955, 0.07772119,
+    -0.042527616, 0.26004282, -0.14884478, -0.21046586, 0.23835869, -0.004445761, -0.29658982, 0.020768225,
+    -0.14853776, 0.18132597, 0.04676402, -0.110367455, 0.13032788, 0.07036399, -0.35846373, 0.42577907,
+    -0.039289366, 0.18073022, -0.16057481, 0.025793038, 0.24246885, 0.10661545, -0.05492453, -0.12418455,
+    0.066020474, 0.034285214, 0.17533037, -0.17025128, 0.16165304, 0.12274912, 0.10904629, 0.18275057,
+    -0.10971671, -0.3672469, 0.012018439, 0.08060415, 0.13074511, -0.3032449, -0.20936994, -0.1588342,
+    -0.15978909, -0.23474492, -0.19340946, 0.35651004, 0.11589635, 0.22805399, 0.082364984, 0.15718058,
+    0.19224517, 0.108801454, 0.23566024, 0.10733197, 0.098889574, 0.37930384, 0.14120655, 0.40386665,
+    -0.0827516, 0.04240375, 0.0026696078, -0.18022108, 0.07454492, -0.14499982, -0.04511923, 0.24934521,
+    0.63689625, 0.10113728, 0.036301035, 0.19622377, 0.22968854, -0.1653088, 0.06289429, -0.064739965,
+    0.2220856, -0.10471799, -0.20914693, -0.33755103, 0.16681702, 0.21246572, 0.3268681, -0.43344077,
+    0.11436767, 0.026431628, -0.397905, 0.1883953, -0.3632433, 0.05404632, -0.27657554, -0.36727837,
+    -0.016861865, 0.17932141, 0.10400277, 0.10522128, -0.13591401, 0.34523192, 0.036784504, 0.28146824,
+    0.092007674, -0.13817193, 0.101863645, 0.3330015, -0.1314696, 0.1713552, -0.2163169, 0.23277447,
+    0.12664132, 0.14543179, -0.22133915, -0.118860535, -0.32868937, -0.09486433, 0.1633642, 0.16704762,
+    -0.51386994, -0.071888775, -0.5485614, -0.06795454, -0.18958086, 0.23267978, 0.0078522125, 0.058783162,
+    -0.39455968, -0.056029964, -0.056313086, -0.111516036, 0.20633216, -0.41651854, 0.081845455, -0.23221533,
+    -0.1647065, -0.18837392, -0.08300976, -0.13793138, -0.2879073, 0.040841676, -0.36151773, -0.28721255,
+    -0.07762222, 0.17791918, -0.019106619, -0.25131533, -0.18906628, 0.027897716, -0.47211358, 0.21888253,
+    -0.18242861, 0.15019044, -0.19229436, 0.09025918, -0.07964177, 0.080006786, -0.026455825, -0.23178947,
+    -0.024430856, -0.21739863, -0.14018181, -0.24931894, 0.26952907, -0.12701729, 0.58363485, -0.020298494,
+    0.20477475, -0.0057161916, 0.09852084, -0.44168508, 0.34988496, -0.180641, 0.013089019, -0.44358262,
+    -0.08538152, 0.065043256, -0.91257495, -0.19597815, 0.08194443, -0.2969723, 0.066317506, 0.18457733,
+    0.49833182, -0.020423895, -0.2568669, 0.16354564, 0.08485315, -0.060372, -0.32456112, -0.18650663,
+    0.03996895, 0.21503082, -0.18625405, 0.6946901, -0.17174855, -0.040580183, -0.13359459, 0.12027772,
+    -0.14397664, 0.4932292, -0.20887244, 0.41632718, 0.09826669, -0.11446501, 0.13752453, 0.10197993,
+    -0.029424276, -0.025084753, 0.20519318, -0.23964742, 0.0072970726, -0.06020086, 0.015238749, -0.17313442,
+    -0.06454783, 0.21183373, 0.018845929, -0.03291346, 0.24361943, 0.092213936, 0.23033535, -0.032076705,
+    0.23037538, 0.011420891, -0.17782265, -0.14292514, -0.2898296, 0.2871401, -0.18781729, 0.2090489,
+    -0.028721353, 0.49879244, 0.12657654, -0.096204355, 0.14833255, 0.2183963, 0.045105193, -0.6430664,
+    0.54471725, 0.08518728, -0.13745427, -0.16282351, -0.16879004, -0.29665932, 0.21519487, 0.05171868,
+    0.3156899, 0.12140893, -0.24349192, -0.01607401, 0.05550489, 0.09854119, -0.2781436, -0.066311255,
+    -0.054535095, 0.2789624, 0.09320152, 0.8456856, -0.23130316, 0.008560432, -0.40721443, 0.1841259,
+    0.27553526, 0.17861055, -0.31109866, -0.06975765, -0.15593109, 0.0761069, 0.029042544, 0.19808555,
+    -0.2400128, 0.22582912, 0.10273596, -0.20143645, -0.055389475, -0.13447268, -0.19676408, -0.20573021,
+    -0.44464317, -0.08618927, -0.12777883, 0.058315676, -0.10186775, 0.43316394, -0.325061, -0.4662605,
+    -0.07495701, 0.2887686, -0.118628696, 0.33904195, 0.2729464, -0.1297264, 0.12710762, 0.014640687,
+    -0.39680773, -0.17048214, 0.3893566, -0.0036688198, -0.103951775, -0.0865422, 0.5903912, -0.7225727,
+    -0.03482894, -0.028510453, 0.42664054, -0.12556297, -0.14649329, -0.005432184, 0.26576984, -0.009217986,
+    0.0026874058, -0.01688302, 0.13901934, -0.21199754, -0.04572891, 0.0829558, -0.26885876, -0.002348683,
+    -0.053189334, 0.18276541, -0.09406504, 0.39956206, -0.0944418, -0.0052732676, -0.25088346, -0.15129104,
+    0.12242543, -0.20933107, 0.0125328135, -0.23309334, -0.15669842, 0.043976102, -0.002119117, -0.23797163,
+    0.13524026, -0.18389769, -0.04903112, 0.0029298416, 0.12605637, 0.28042886, -0.022095125, 0.22178449,
+    0.32976747, 0.055230074, 0.062425923, 0.28362903, 0.45099068, -0.39112598, 0.039937723, -0.3800735,
+    0.26852426, 0.40899587, 0.25254115, 0.1443457, 0.100139104, 0.21404427, -0.09488283, 0.011573991,
+    -0.11845269, 0.09998386, -0.09093801, 0.048506152, -0.12395037, 0.07279616, -0.24471186, 0.06140332,
+    0.16954307, -0.10713902, 0.025480106, -0.3244387, 0.31527814, -0.1417776, 0.018473092, -0.1909253,
+    -0.3788626, 0.09132539, -0.18655027, -0.20493111, 0.22472131, 0.028131079, -0.14153534, 0.19635154,
+    -0.14911611, 0.0034685996, 0.19731003, 0.003914189, 0.018377071, -0.11068228, 0.22603151, -0.2803542,
+    -0.098563075, -0.37731037, 0.02079935, -0.07031396, -0.4715673, 0.0005935803, 0.10594275, -0.014237787,
+    0.13179448, -0.077345036, 0.076510325, 0.012503255, -0.24964301, 0.07691894, 0.28182864, 0.05061659,
+    0.26428604, 0.12531158, -0.3660672, 0.36271596, -0.11559953, 0.19127841, -0.017714864, 0.42108575,
+    -0.05168266, -0.27891657, -0.05902027, 0.06354925, 0.08449755, 0.0680275, 0.032835122, -0.16811384,
+    -0.07316105, -0.0051711802, -0.082354456, -0.04621295, -0.27023113, 0.16929395, 0.14499448, -0.2836246,
+    -0.17309347, 0.020720182, 0.14880504, 0.0018045204, -0.04346092, 0.095922545, -0.050950512, 0.22157775,
+    -0.24159089, 0.06801712, 0.16409484, 0.21114615, 0.2510501, -0.13956296, 0.11614097, 0.20867255,
+    0.19128858, 0.004064699, 0.04693305, 0.11904744, -0.046639804, 0.3277588, 0.077146806, 0.10083031,
+    0.037864946, -0.5520298, 0.2435887, -0.22337835, -0.17043008, -0.5440443, -0.043567844, 0.01316742,
+    0.07035976, -0.6227851, 0.42422053, 0.21230048, 0.30511957, 0.35488155, -0.28890666, -0.16082962,
+    -0.82345694, -0.13371432, 0.24405812, -0.24316381, 0.3914969, -0.36557487, -0.14549896, 0.57913,
+    -0.022238797, -0.077710435, 0.30560425, -0.12582012, -0.03161062, 0.0016314803, 0.14193283, -0.46487677,
+    0.47855273, 0.0940261, -0.21518138, -0.087073535, 0.08874335, 0.06967492, 0.24731995, -0.054742478,
+    0.032180555, -0.08865579, -0.059713602, 0.044552654, 0.12985888, -0.2739451, 0.20417224, 0.16804655,
+    0.33089918, -0.3231102, -0.16022952, 0.38599956, 0.07838636, 0.0027739576, 0.1459368, 0.04448888,
+    0.010208436, -0.31444168, -0.060690757, 0.5789011, -0.17394541, 0.057959054, -0.60795945, 0.05264827,
+    -0.18752882, -0.30084357, 0.011882955, -0.30803883, 0.34155178, 0.11354733, -0.20296624, 0.010741623,
+    0.087450214, 0.67127776, -0.07700082, -0.24572994, 0.37486157, -0.17764747, -0.2028871, -0.15996107,
+    -0.0045373663, -0.36171606, -0.5549321, 0.4873393, 0.19706242, 0.32020152, -0.19100481, -0.38546,
+    -0.09793351, -0.03415189, -0.054713726, 0.3252204, 0.058648676, -0.09164461, -0.22400941, -0.26322505,
+    0.15941995, 0.14064033, 0.08571346, -0.19333607, 0.089665085, 0.15346825, -0.03080074, -0.19012944,
+    0.21235429, 0.03920368, 0.005322034, 0.0059511154, 0.18709072, 0.082955636, -0.039372083, 0.18440516,
+    -0.22303778, -0.05438388, -0.040475816, -0.052019447, -0.0487644, -0.17220022, -0.10509428, -0.15849485,
+    -0.116057426, 0.41014537, 0.4186095, -0.31556132, 0.051410414, 0.32199085, -0.18286979, 0.3449851,
+    -0.17116953, -0.30895633, 0.33639985, 0.100042254, -0.43128452, 0.37174293, 0.20245437, -0.18375807,
+    -0.14985186, 0.32449922, -0.14515726, 0.19072711, -0.091550946, 0.06667006, -0.22727703, 0.1202575,
+    -0.09716699, 0.2182326, -0.33819133, -0.07913047, 0.034688085, -0.019716889, -0.26206228, 0.15930648,
+    0.29434597, -0.4116597, -0.15639989, -0.3395048, 0.44248688, -0.04181909, 0.34272364, -0.091180995,
+    0.06719617, 0.02747811, -0.033078123, -0.35934663, 0.3295784, -0.35041258, -0.98808795, 0.0013514318,
+    -0.013608083, 0.25400218, -0.20229743, -0.04132441, 0.17694381, -0.019992687, -0.2503654, 0.083783425,
+    0.20113209, 0.019348724, 0.2522199, 0.33516377, -0.027149402, -0.16772497, 0.5053194, 0.096329555,
+    -0.21553408, 0.5161761, 0.3377815, -0.13762914, 0.20444699, -0.033213213, -0.1945583, -0.25969115,
+    0.09481817, 0.2212159, 0.15326667, -0.20680353, 0.073933326, 0.21497194, -0.3391316, 0.099121936,
+    -0.23077941, -0.04849924, 0.16946623, 0.080316484, -0.04783916, -0.15793516, 0.15147552, -0.26949033,
+    -0.0034004352, -0.1028785, 0.01570974, 0.036927283, -0.13320519, 0.02576163, -0.109364025, 0.23002586,
+    0.4451501, -0.025413807, -0.26083717, 0.055307407, -0.038406294, -0.20429182, -0.15354969, -0.261837,
+    0.6082281, 0.108137846, -0.28251708, 0.30240846, 0.13750102, 0.08102583, -0.15723139, 0.5289844,
+    -0.035130415, 0.63000786, 0.09001989, -0.108329765, -0.013409045, 0.49388558, 0.35855892, -0.3475825,
+    0.4260647, -0.2639266, -0.3669077, -0.13512672, -0.39494777, 0.3491706, 0.04221187, -0.45923007,
+    -0.07280739, -0.30913028, -0.11162429, -0.046250645, 0.12633057, -0.17340441, -0.06709568, 0.2760167,
+    0.20871298, -0.43180263, 0.37927425, -0.09706858, -0.20103788, -0.30019245, 0.32784364, 0.10282031,
+    -0.05568065, -0.15352716, 0.50704795, 0.31032902, 0.25840786, 0.61397207, -0.32328948, -0.40617397,
+    -0.9500526, -0.38329184, -0.021537846, -0.22367631, 0.13333468, -0.16534705, -0.026717413, 0.060129106,
+    -0.6974819, -0.64849275, 0.36791593, 0.40353397, 0.7329504, -0.44608116, 0.09336264, -0.48765168,
+    0.017708732, -0.26162285, -0.39581546, 0.115306966, 0.081331514, -0.3534845, -0.11267375, 0.23313461,
+    -0.31025228, -0.0069544613, -0.15116078, 0.25904197, 0.036683146, 0.29119894, 0.03712982, -0.18727477,
+    -0.11820059, -0.16008914, -0.35371318, 0.16504207, -0.35420728, 0.46356112, 0.13448098, -0.016792446,
+    0.24397099, -0.07778275, -0.26713428, -0.18073012, 0.4154567, -0.08320922, 0.065722555, 0.56946695,
+    -0.114698134, -0.051044974, -0.06995067, -0.14157975, -0.25629553, 0.16449665, -0.7929726, -0.2205544,
+    -0.0338328, 0.35627866, -0.10755237, -0.07180103, 0.024522532, -0.0607568, -0.14080524, -0.11643014,
+    0.023962783, -0.07319884, -0.3742325, 0.19205332, 0.38635704, 0.3829081, -0.31711844, 0.16453429,
+    -0.03982377, 0.04505462, -0.4645108, -0.043293558, 0.28165033, -0.20818524, 0.18356274, -0.41216123,
+    -0.10164159, -0.18870167, 0.11804026, -0.28004524, 0.27176744, 0.07042238, -0.22430575, 0.10923483,
+    -0.18759261, 0.09534099, -0.09144524, -0.22270198, -0.047745816, 0.0047831675, -0.20992209, 0.11858242,
+    0.014862496, -0.011144789, -0.20361002, 0.2854974, 0.14356552, -0.06751563, 0.0010478427, 0.046874832,
+    -0.1780691, 0.018830622, 0.15176198, 0.13550487, 0.40389493, -0.24454333, 0.27537188, 0.0326497,
+    -0.26361117, -0.053757716, 0.11173944, 0.09794126, 0.2634979, 0.24372064, 0.19729844, -0.34086934,
+    -0.16463424, 0.0003069973, 0.036272332, -0.20185365, -0.42129478, 0.31705093, -0.0924413, -0.3721095,
+    0.0020238184, -0.12808153, 0.08978196, 0.087362334, 0.19044067, -0.09306828, 0.1028701, 0.031904247,
+    0.3447355, -0.26222113, -0.17280683, -0.13965568, 0.4246931, -0.03950457, 0.29501557, 0.06507321,
+    0.1258095, -0.09617687, -0.26996532, -0.082532495, 0.117630504, 0.1051049, -0.3778838, -0.29747656,
+    0.073131844, 0.06724366, -0.24354863, -0.38789052, 0.06664184, 0.08721995, 0.14054877, -0.035227124,
+    0.1911239, -0.16505405, 0.2410473, -0.0967257, -0.023789363, 0.07896527, -0.13055275, -0.1742587,
+    -0.0084959, -0.22195585, 0.027504351, 0.3378505, 0.09681847, -0.60527956, 0.09110566, -0.08613079,
+    0.044028908, 0.39495644, -0.35765153, 0.12283783, 0.13137552, 0.52667445, -0.03929982, 0.2040023,
+    -0.20305468, 0.7081283, 0.06838069, -0.5532585, 0.02790345, 0.13379386, -0.057518885, -0.03745942,
+    -0.5261745, -0.43974847, -0.1561893, 0.18898395, -0.0042355354, 0.3429617, 0.12024157, -0.2608764,
+    0.05475902, -0.40032032, -0.1465026, -0.074023955, 0.14602785, -0.18158697, -0.02562522, -0.09455987,
+    0.31381497, 0.34616685, -0.13368778, 0.10440568, -0.071616404, 0.42619422, -0.1390498, 0.040352654,
+    -0.28824672, 0.37224504, 0.31301904, -0.6442458, 0.14726436, 0.22562614, 0.18909903, -0.14265232,
+    -0.33047915, -0.15582001, -0.067972496, 0.239163, -0.26035073, 0.40104708, -0.13226509, -0.4702511,
+    -0.4316792, -0.12760532, 0.17134416, -0.037485633, 0.22472456, -0.22976352, 0.1474753, 0.12872243,
+    -0.1344942, -0.2972918, -0.059028134, -0.10925937, -0.44742706, 0.38739938, -0.49115345, 0.20521188,
+    -0.17988694, 0.16247025, -0.14773104, 0.19735442, 0.060605306, 0.060878705, -0.28273416, 0.10167133,
+    -0.28091988, -0.21018592, -0.17629904, 0.42238277, -0.3980926, -0.04435845, 0.2501439, 0.6035569,
+    -0.016185168, -0.4013508, -0.00035844636, -0.3651392, -0.11185482, 0.011720106, -0.080625765, 0.05828785,
+    -0.106433466, 0.009186549, 0.17565244, 0.18284339, 0.29707262, 0.36698362, 0.105755836, 0.09937,
+    0.30605394, 0.1687581, 0.10365264, 0.033822343, -0.07068322, -0.11062265, -0.038272273, 0.045132317,
+    -0.13630955, -0.17260228, -0.29408273, -0.079067454, 0.17700374, -0.06212464, -0.018227478, -0.11779493,
+    -0.3818776, 0.029999197, -0.090343356, -0.023206329, 0.10110344, 0.18112947, 0.071991, -0.28548416,
+    -0.21091332, -0.07315162, 0.45251223, -0.36378762, 0.4547295, -0.30260623, 0.15771692, 0.2742046,
+    0.19733097, -0.2395506, -0.2913321, -0.024793081, 0.38127, 0.24134499, 0.2576257, 0.12103731,
+    0.0032287026, 0.28760582, -0.44891128, 0.003198243, 0.0003574214, 0.054871183, -0.23769958, -0.10086798,
+    -0.31927305, 0.2567216, 0.054161973, 0.13467097, -0.041771963, -0.12436654, -0.22307332, 0.0038816475,
+    -0.23645414, 0.4408984, -0.14694823, 0.31337452, 0.05958084, 0.41529086, 0.13060223, -0.12287406,
+    0.16331989, 0.17485747, 0.23555414, -0.05382328, 0.059262075, -0.122034706, 0.1821505, 0.0028871025,
+    0.191626, 0.024160612, -0.13284926, -0.18104173, 0.0017736747, 0.24021079, 0.2809944, -0.4195244,
+    0.21439661, -0.1951207, -0.1112646, -0.008603427, -0.24670093, -0.14961058, -0.13107248, 0.025244998,
+    -0.26616985, 0.58098155, 0.025104579, -0.21319811, 0.092124574, 0.60181046, -0.15698725, -0.024887897,
+    0.042146996, 0.17066391, 0.025307577, 0.053392984, -0.052190747, 0.2758154, -0.2788317, 0.1887697,
+    -0.39592895, -0.12924953, -0.0670351, 0.07519685, -0.1073532, 0.1392103, -0.09167363, -0.4623643,
+    -0.16099149, 0.449869, 0.096955754, -0.24451505, 0.19339, 0.26712015, -0.06772493, 0.17278455,
+    -0.09016105, -0.017338002, -0.33525398, -0.02127853, 0.37227207, 0.8141174, 0.020948915, 0.23260437,
+    -0.30546182, 0.974238, 0.12923248, -0.3026534, 0.10520542, 0.2374463, -0.27555498, 0.048685264,
+    -0.34735617, 0.4011588, -0.10112967, -0.080026425, 0.020418404, 0.51565903, -0.57392627, -0.55756056,
+    -0.33484003, -0.48750934, -0.41861197, 0.35006323, 0.11840397, -0.1873331, 0.0316252, -0.06953995,
+    0.11683877, 0.027584726, 0.015917433, -0.033508617, -0.044225905, 0.045858026, 0.2601742, -0.48870677,
+    0.39920637, 0.17181145, 0.04752273, -0.0342414, 0.009863782, -0.14183317, -0.22318748, 0.091223545,
+    0.21131474, -0.1629558, -0.32367817, -0.2782134, 0.25659922, 0.031158088, -0.03540766, -0.18147443,
+    0.22533362, -0.2543668, 0.07037883, 0.30169895, -0.3038509, 0.15331003, 0.015657876, 0.18046267,
+    -0.003029083, 0.056011513, 0.18512447, -0.011965839, 0.039058793, 0.4924687, 0.20491442, -0.16057779,
+    0.15329118, 0.35616, 0.25591442, -0.13053595, 0.18285392, 0.40090448, 0.08518587, -0.061091863,
+    -0.26690057, -0.3173615, -0.5473045, -0.19548233, -0.2654626, 0.37840748, -0.16336781, 0.03039114,
+    -0.36124033, -0.48831826, -0.4985469, -0.20889796, -0.08546015, 0.051107585, -0.025296463, -0.35187846,
+    0.22135608, -0.11566414, -0.25450116, 0.017822316, 0.31611964, 0.4696902, 0.14779861, 0.19062108,
+    -0.07501316, 0.28129902, -0.13442346, -0.14447595, 0.09057906, -0.032732513, -0.45867422, -0.1846473,
+    -0.10110034, 0.2416198, -0.27488872, 0.11010927, 0.045144137, 0.24501295, -0.56969184, -0.15939365,
+    -0.4998262, 0.050433077, 0.21994004, 0.08289115, 0.129539, 0.18553486, -0.16733529, -0.20128743,
+    -0.068986356, -0.46694717, 0.07196249, -0.17797929, 0.16516441, 0.20583013, 0.2675662, -0.08326665,
+    0.18830173, 0.49406162, 0.4982245, 0.39527023, 0.09667903, 0.11709356, 0.17033717, -0.2210272,
+    -0.19771531, -0.39438683, -0.22642311, -0.16714688, 0.027099127, 0.12936606, 0.31189892, 0.5397293,
+    0.09065578, -0.49373057, -0.3246901, -0.22261071, 0.25910646, -0.06689963, 0.11569739, -0.0892676,
+    -0.09423303, 0.4059517, 0.013443194, 0.21810672, -0.08365267, 0.018593146, 0.060712337, 0.18721536,
+    0.03885985, 0.17669094, 0.19896573, -0.30222827, 0.06301141, -0.21536508, 0.1543885, -0.2169225,
+    -0.004653782, -0.13872771, 0.4124839, -0.030387381, -0.22997989, -0.07986632, -0.030818027, -0.15659283,
+    0.08952375, -0.28825015, -0.08049429, 0.19247319, -0.25470358, 0.04267709, 0.010190772, -0.17928286,
+    0.016437382, -0.033613984, 0.08492513, -0.1571144, 0.23409455, 0.27843133, -0.40986955, 0.0006786871,
+    -0.16823187, 0.4035066, -0.35543767, -0.11466867, -0.29230443, 0.15188567, -0.17862499, 0.34726736,
+    0.0833005, 0.1992614, -0.51719016, -0.08237848, -0.09606197, 0.047939, -0.2005852, 0.08162383,
+    0.04512925, 0.026947306, 0.14613938, -0.18425694, -0.036058303, -0.11820382, 0.31546748, 0.24002811,
+    0.25034326, -0.48010513, 0.31523713, 0.22977598, 0.07458825, -0.3637106, -0.0016152638, -0.020874612,
+    0.043914866, -0.15344842, 0.4524966, 0.4111501, 0.1908116, 0.10618644, 0.49517962, -0.048793625,
+    -0.32642484, 0.15687348, -0.44304642, -0.22861636, -0.02413718, 0.2442986, 0.0008414255, 0.28902128,
+    0.01782357, -0.18278494, -0.38124973, -0.16396615, 0.067712635, -0.1909414, -0.13995, -0.0877654,
+    0.06369379, -0.39230818, -0.0052247546, -0.09350373, 0.21168406, 0.69292533, -0.11329496, -0.4445583,
+    -0.03422849, -0.092925474, 0.18619788, -0.3793774, -0.038203973, 0.20440324, -0.41495478, 0.41483197,
+    -0.048135947, 0.5151456, 0.20675582, -0.026881369, -0.0518768, -0.116598785, 0.0020434405, -0.044359226,
+    -0.31473657, -0.22661375, 0.22829123, -0.07907559, 0.3604555, -0.10263634, 0.13636485, -0.11169909,
+    -0.1175541, 0.11530417, -0.23750132, 0.086268604, 0.10757403, -0.17984445, -0.102015495, 0.22494115,
+    0.101270154, 0.16016321, 0.39626843, 0.3050792, -0.30372593, -0.14261933, 0.32006192, -0.23161475,
+    0.16102715, 0.24798751, 0.31320336, -0.2728174, -0.043450523, -0.06550415, -0.027775498, -0.32379046,
+    0.06255245, -0.120759785, 0.034212064, 0.33544877, 0.10007445, -0.16377498, 0.24098888, -0.14950386,
+    0.23657516, -0.5426892, -0.11367479, 0.15343758, -0.1117872, -0.37076443, -0.14698415, -0.08939924,
+    0.008880648, -0.43941566, 0.009824966, 0.12193117, -0.26846144, 0.091130264, 0.0007761274, 0.119175,
+    -0.11860142, -0.073857196, 0.36116984, -0.06327945, 0.046643086, -0.28494343, 0.018077353, 0.017592963,
+    0.16513352, 0.3052988, 0.2554609, -0.17424577, 0.10191776, -0.2789206, 0.4715095, 0.25042996,
+    0.045735322, 0.21232387, -0.35915622, -0.09784122, -0.0735283, 0.5164736, -0.4432253, -0.10721019,
+    -0.051197823, 0.41981187, 0.1990899, -0.7059749, 0.10934976, -0.035216518, -0.03679147, 0.0538798,
+    -0.47031778, -0.19734134, -0.14510047, 0.41247007, 0.0486279, 0.14719035, 0.27059305, -0.9738511,
+    -0.21693583, -0.16100521, -0.24986625, -0.06519423, 0.106635146, -0.05663565, -0.029912854, 0.3569513,
+    0.046829395, 0.3865496, 0.33413497, 0.064836465, -0.37820044, 0.08719914, 0.06904293, -0.28664607,
+    -0.20140101, -0.15713577, -0.018991554, -0.12826137, 0.56811965, -0.018936744, 0.104811676, -0.27733845,
+    -0.35264823, -0.067070775, 0.10961318, 0.023994466, 0.2088573, 0.09732806, 0.24790631, -0.32401392,
+    0.032151327, 0.17929932, -0.04264023, 0.24922241, 0.025024567, 0.016446823, -0.5053494, -0.057046987,
+    -0.029759424, 0.3249225, 0.017463654, 0.31898743, 0.08791634, -0.12196292, 0.0067363167, 0.18660982,
+    0.06757184, 0.05708957, 0.11639225, -0.29058215, 0.19743651, 0.25445428, -0.09904933, 0.2239086,
+    -0.31660843, 0.3003036, -0.31843147, -0.19905339, -0.42785522, -0.04314521, 0.20235531, -0.2951445,
+    0.017368747, 0.28304482, -0.28627822, -0.12367599, 0.33578366, 0.045989547, -0.18058418, -0.0019469985,
+    0.014708753, -0.3522064, -0.123503745, -0.007144359, -0.52389646, -0.35101628, -0.15322007, -0.0092542935,
+    0.008767712, -0.2760045, -0.026269551, 0.8042352, -0.07334406, 0.12285887, 0.24670634, 0.10061516,
+    0.29476714, -0.2767713, 0.18844311, -0.052495953, -0.22588673, 0.050245084, 0.044194855, 0.9079276,
+    0.46502584, 0.22183742, 0.064558215, -0.58260065, 0.07973574, 0.016828062, 0.122279204, 0.04826032,
+    0.017560396, 0.23031244, -0.17230628, -0.138785, 0.28051463, 0.6962673, 0.3585003, -0.0018164491,
+    0.21510352, 0.28157857, -0.27234375, -0.40907076, -0.035584033, 0.042488135, -0.12822722, -0.09171816,
+    0.24837898, 0.049435362, -0.11261758, -0.16260597, -0.10385656, 0.3020739, -0.17336951, -0.28363973,
+    -0.094069354, -0.017139126, -0.19929312, 0.11941451, -0.24694452, -0.23971418, -0.37784854, -0.12453896,
+    0.27632833, -0.11895106, 0.07172563, -0.13375263, -0.100243434, 0.25591743, 0.12989186, -0.119680054,
+    0.23196696, 0.09277696, 0.14306922, -0.08090731, 0.75566334, 0.32737592, -0.050449252, -0.20756741,
+    -0.3248164, 0.19899896, 0.2660874, -0.29822686, 0.17000401, -0.39252502, -0.41468257, 0.23536547,
+    -0.1350504, -0.3882861, 0.077562355, 0.25932986, -0.05588726, 0.14835005, -0.15153366, 0.03390204,
+    0.055935554, 0.23425607, -0.4355635, 0.21840943, 0.08209044, 0.7035254, -0.09793196, 0.066199005,
+    0.0032717194, 0.5360687, -0.020349901, -0.41602862, 0.2939405, 0.28410962, 0.05672787, -0.06555281,
+    0.23366801, 0.08622907, -0.46523306, 0.039030332, 0.055981286, 0.42519554, -0.1776112, -0.4058107,
+    -0.4094507, 0.0008089457, -0.29185635, 0.06971464, -0.047737963, -0.30611405, 0.07950495, -0.0006352014,
+    0.064624205, -0.07793799, -0.018151725, -0.11858726, 0.034462903, 0.37121105, 0.062491126, -0.11090992,
+    0.21798718, 0.46126443, 0.098247856, -0.25717565, -0.19445485, 0.24389634, -0.1614561, 0.025688658,
+    -0.054646637, -0.20577899, 0.07700502, -0.11712096, 0.097890116, -0.050821904, -0.45480797, -0.35276732,
+    -0.27512977, -0.043582812, -0.078208126, 0.27933007, -0.18169273, -0.09599038, -0.2640791, -0.09050214,
+    -0.19216363, 0.26839897, 0.025028056, -0.21422045, 0.017394908, 0.0017232264, 0.32491967, 0.23552427,
+    -0.22850978, -0.12744659, -0.18129973, -0.3869309, 0.18837544, -0.1831766, -0.052771784, -0.24171907,
+    0.116786964, 0.24905488, -0.14343184, -0.13887222, 0.14973849, 0.0055758455, -0.32755017, -0.28228086,
+    -0.26658818, -0.06675358, -0.13624452, 0.25586182, 0.19251266, -0.2741692, -0.047584277, 0.28127894,
+    0.2570323, -0.07474941, -0.027905945, -0.3456908, 0.09071781, 0.009574153, -0.059255645, -0.2189686,
+    -0.13208035, -0.1910185, -0.028933374, 0.36487904, 0.31979364, 0.21055482, -0.29205325, 0.32504722,
+    -0.34321004, -0.10613844, 0.1567891, -0.10675481, -0.08838044, 0.08912798, -0.11184223, -0.22797495,
+    -0.4027583, -0.08831204, 0.36036623, 0.14740269, 0.20601648, 0.20090683, 0.32149777, 0.2104001,
+    0.053902835, -0.08325288, -0.024667494, -0.028249282, -0.32773954, -0.29477847, -0.32991612, 0.12151702,
+    -0.009203238, -0.07422529, -0.37392297, -0.06044747, -0.15279053, 0.12206622, 0.07764636, -0.25880355,
+    -0.25133973, -0.24363762, -0.27704072, 0.09446591, -0.19640908, -0.08134766, -0.20505294, 0.32687423,
+    -0.03162705, -0.17940389, 0.032889932, 0.12881014, -0.043805026, 0.17115752, 0.19146888, 0.06746424,
+    0.12815039, 0.16572009, -0.05498016, 0.22319357, -0.14311075, 0.4477555, -0.14127664, 0.020282416,
+    0.40738815, 0.39996764, -0.34551108, -0.32421458, -0.16845188, 0.031649444, 0.12168397, 0.117155604,
+    0.18017739, -0.032385625, -0.20202945, -0.17096162, 0.07686255, 0.39800212, -0.19099791, -0.2041206,
+    0.29153728, -0.011186834, -0.616518, -0.108236164, -0.36761117, -0.1592995, -0.39869672, -0.17955509,
+    0.023202252, 0.17351353, -0.50808483, 0.0927664, 0.059755906, 0.5535632, 0.26255083, 0.1047276,
+    -0.2547621, 0.316814, 0.200217, -0.4127484, 0.19405074, 0.19089179, 0.08341523, 0.14510013,
+    -0.18768549, 0.19003686, 0.21060458, 0.07029707, 0.01743232, 0.14146279, -0.5836257, 0.04160178,
+    -0.0612741, -0.4399133, -0.533535, 0.06715728, 0.2554597, -0.46022043, -0.124911755, 0.08652847,
+    -0.19450143, 0.088017814, 0.09936732, -0.31894302, 0.43263614, 0.37232646, 0.20381446, -0.05323823,
+    0.07319026, 0.16974324, 0.357495, -0.27731818, -0.0039181393, 0.087199144, -0.06360175, -0.15551773,
+    0.008295427, 0.25753707, -0.114354245, -0.35191336, 0.059046093, 0.25382254, -0.08735591, -0.16550739,
+    -0.3605635, -0.32671762, 0.053707495, -0.15077746, 0.4193014, -0.1714972, 0.20198207, 0.14496908,
+    -0.03427292, 0.3382082, -0.16805722, -0.08160431, 0.33918294, 0.61614627, 0.008321474, -0.09321829,
+    0.10503989, 0.3905644, -0.43969724, -0.633828, -0.112256475, 0.08714128, -0.27389637, 0.0992268,
+    -0.13535888, 0.34721738, -0.11012282, -0.06981841, 0.18101385, -0.01825035, -0.6358761, 0.2536671,
+    -0.33316422, 0.19660577, -0.07452846, -0.03558123, 0.18214439, 0.08597423, -0.03148854, -0.12847994,
+    -0.24464001, -0.13246667, -0.084626615, 0.10733997, 0.12046478, -0.032274153, 0.35684332, 0.10058827,
+    0.081805214, -0.098200634, 0.5280625, 0.8948093, -0.004749436, 0.17734443, -0.080363154, -0.00268585,
+    -0.60381144, 0.13489056, -0.38419867, -0.4318233, -0.008603798, -0.60383654, -0.26135796, 0.44149908,
+    -0.1508875, -0.16092826, 0.2969614, 0.041737437, 0.43706506, 0.3692291, 0.20515133, -0.13198715,
+    -0.22489247, -0.03707966, -0.33684766, 0.020469036, 0.17804828, -0.052859742, 0.05392768, 0.10430165,
+    -0.01851038, 0.7337973, 0.3632817, 0.08591987, -0.013255692, 0.21264349, -0.18438159, -0.18312442,
+    0.016396644, -0.10319133, -0.013383738, 0.17813924, 0.10051164, 0.08049396, 0.069285445, 0.29454708,
+    -0.15314129, -0.2480023, -0.41375443, 0.2555815, -0.030352572, -0.24933279, -0.2703603, -0.07784606,
+    0.38202053, -0.25911245, -0.33243492, -0.20159662, 0.031229509, -0.17063405, 0.012631954, -0.15864839,
+    0.01086152, 0.17938219, 0.11052064, -0.1804239, 0.20068774, -0.1105533, 0.14266358, -0.17833723,
+    0.10433541, 0.008029648, -0.005783018, -0.14131187, -0.11733366, 0.42665142, -0.35180712, -0.3202073,
+    -0.12101112, -0.14583588, -0.042910244, 0.124460615, -0.16484419, -0.1978773, -0.17198347, 0.3709414,
+    -0.2857312, 0.16360185, 0.18314566, 0.2507058, -0.10869525, -0.5420188, 0.18230088, 0.31643635,
+    -0.24686985, 0.10047804, 0.25189492, 0.010509262, -0.056153163, -0.18300857, -0.14916088, -0.101076975,
+    0.09692896, 0.15496854, 0.042313922, 0.02392068, 0.08898107, -0.063930504, -0.03316389, -0.23545206,
+    0.038952217, 0.14120363, 0.013719768, 0.026588937, 0.23732665, -0.052549563, 0.34736773, -0.49275714,
+    -0.26107118, -0.13232145, 0.10146564, -0.20855851, -0.253232, -0.07562214, -0.2245153, -0.27918947,
+    -0.14828594, 0.18789664, 0.11617327, -0.0012604431, 0.12282513, 0.035328478, 0.19127485, 0.35252807,
+    0.09746636, -0.24442315, 0.21589981, 0.15271778, 0.049711585, -0.086522296, 0.3138791, -0.34419098,
+    -0.04134054, 0.1915506, 0.30715764, -0.25105262, -0.11256217, 0.04265623, -0.044453245, -0.0050820364,
+    -0.2126244, -0.15302442, 0.191273, -0.13381189, 0.18474206, 0.28099653, 0.22060113, 0.23907271,
+    -0.025743686, 0.4838814, 0.5784458, 0.37684765, 0.26763454, 0.13044904, -0.16249603, 0.19409011,
+    -0.45097518, 0.15922937, -0.20166911, -0.26063347, 0.1130055, -0.22388896, -0.20139708, 0.27305168,
+    -0.31297004, -0.09712232, 0.013823188, -0.035741862, 0.1019045, -0.22034827, -0.019240787, -0.2679607,
+    0.20244458, -0.49281323, -0.06616161, 0.30213907, -0.27513832, 0.10302832, -0.35746673, -0.045172006,
+    0.122331, -0.050273806, -0.08104831, 0.36652118, -0.36179563, -0.002670311, 0.31712177, 0.1057863,
+    0.13806376, -0.3181257, -0.08007802, -0.0734615, -0.23511541, -0.07355606, -0.11637916, 0.8128875,
+    0.31155586, 0.09463826, -0.36229762, -0.14892195, -0.024352366, -0.23122814, 0.04609766, -0.18578112,
+    -0.37162474, 0.16761197, -0.18389611, 0.0881141, -0.061044086, 0.2679047, 0.031622842, -0.13185877,
+    0.37953013, 0.28280574, 0.06216453, -0.064933814, -0.0999558, -0.106214665, -0.10335755, -0.028768864,
+    -0.25220272, 0.23735507, -0.42271563, -0.30319858, 0.16406363, -0.10054657, -0.10666321, -0.60945904,
+    -0.07633789, 0.12902108, -0.14278367, 0.26868904, -0.20621657, 0.2521531, -0.03871342, 0.12487538,
+    0.4328646, -0.3689372, -0.10364983, 0.19389473, -0.25606123, 0.07924347, -0.34866893, 0.2532123,
+    0.04441295, 0.28952107, -0.48480442, 0.2736614, -0.02420455, 0.0070653083, -0.14108576, 0.15461624,
+    0.15904878, -0.20971467, -0.27261272, 0.12832218, -0.12641475, 0.2041281, -0.10735847, 0.64794564,
+    0.12120245, 0.08411052, -0.243119, 0.06792515, 0.13126981, 0.017396355, 0.21254568, 0.28955096,
+    -0.032001134, -0.039457, -0.0468482, 0.050224397, 0.4418645, 0.32800114, -0.3661186, 0.010909362,
+    -0.06126225, 0.19727567, -0.14141694, -0.50275046, 0.0273878, -0.07180312, -0.041546047, 0.020203894,
+    0.23547785, 0.16789845, -0.11318683, -0.13283794, -0.2670362, 0.3379512, -0.35198212, -0.33910608,
+    -0.49572426, -0.11738652, 0.45020244, 0.23471767, -0.051231075, -0.27808648, -0.030603323, 0.30515462,
+    -0.12314796, -0.33463636, -0.06421349, 0.005507603, 0.14163433, -0.15728441, -0.24058852, 2.7568107e-5,
+    0.20390037, 0.08856426, 0.32220805, 0.010240728, -0.23192899, -0.06931349, 0.3618498, 0.23542437,
+    -0.25700375, 0.15938833, 0.07349901, -0.28778613, 0.13888377, 0.0026853834, 1.0211825e-5, -0.2284405,
+    0.08819398, -0.103421845, 0.027990624, 0.122292936, 0.32155624, 0.2113125, 0.1768608, -0.21926379,
+    -0.080929406, -0.12625171, -0.27520308, 0.07158822, 0.0495103, 0.32023162, -0.1324987, -0.12382215,
+    0.04074797, -0.026838582, -0.012829396, -0.08156077, 0.08404316, 0.005769127, -0.14538513, 0.4227933,
+    -0.047921028, 0.27073818, -0.16541907, -0.2919028, -0.28163496, 0.3145088, -0.5627045, -0.18462253,
+    -0.44742525, -0.30531406, -0.1574339, -0.12976234, 0.26265094, -0.42576626, -0.010703142, 0.1058587,
+    -0.2890174, -0.0020386274, 0.3930522, -0.11436254, -0.009351561, 0.021437295, -0.10593557, -0.29132167,
+    -0.03690144, -0.13095391, -0.09952792, 0.27868378, 0.23960063, 0.42208105, -0.16950396, 0.116225205,
+    -0.298951, -0.13236369, -0.2861754, 0.17739457, -0.073821664, -0.19654912, -0.2264924, 0.20205395,
+    -0.19228011, -0.26250634, 0.30318955, -0.04509935, -0.1343218, 0.021693867, -0.10048139, -0.20479281,
+    -0.026244638, 0.1635541, 0.06680692, -0.123318665, 0.04057074, -0.38035354, 0.040372327, 0.14249158,
+    0.24214981, -0.11452269, 0.0062006144, -0.3022547, 0.33102897, -0.18523751, 0.109087184, -0.09592873,
+    -0.19562091, 0.17779343, -0.06428728, -0.13677952, -0.042450625, 0.24749847, 0.045364548, -0.80640787,
+    -0.062125288, 0.1707183, 0.15092981, -0.087151505, 0.06932272, 0.41030568, -0.25786147, 0.36287126,
+    0.23374249, 0.25942743, -0.062756166, 0.07667809, 0.29296604, 0.4750473, -0.06586631, -0.1044684,
+    0.223492, 0.46498913, -0.2184129, -0.6192224, 0.20967713, -0.20087247, -0.068008564, -0.3588823,
+    -0.0055029993, -0.10417736, 0.023576241, 0.026464965, -0.15412143, 0.2648215, -0.31148762, -0.44639334,
+    -0.10017591, -0.06553728, -0.18862598, 0.073014915, 0.005041705, 0.29403841, -8.160074e-5, 0.14796163,
+    0.13430156, -0.15267769, -0.092032835, -0.039634384, 0.1313713, -0.30204418, -0.13901344, -0.22602905,
+    -0.16674027, 0.22887364, -0.20952679, 0.37272823, 0.31935224, -0.021305496, 0.0295215, -0.010855096,
+    -0.09070516, -0.052895136, -0.3547404, 0.22597614, -0.16874161, 0.10994156, -0.160202, 0.13940178,
+    0.14250638, 0.086122006, -0.10148529, 0.059729543, 0.06451269, 0.18066284, -0.041662086, -0.20816275,
+    0.115507096, 0.05208126, -0.061362047, -0.031328958, 0.21224695, -0.3262656, 0.36378318, 0.06687347,
+    -0.18887436, 0.098975256, -0.23644371, -0.0067446907, -0.050074093, 0.087501615, 0.111129306, 0.14185674,
+    0.57572997, -2.6683976e-5, 0.07570913, -0.14134279, -0.061928216, 0.32898572, 0.13358238, -0.21113813,
+    0.043658804, 0.065451935, -0.10601159, 0.21482947, -0.031678602, 0.063783534, -0.23483734, 0.16913721,
+    0.07095135, 0.24222657, -0.0031698558, -0.31623766, 0.052932274, 0.11677875, 0.18805848, 0.0050564343,
+    -0.0448315, -0.34260088, -0.13119812, -0.076041006, 0.23015246, -0.1668176, 0.015447316, 0.1282189,
+    -0.16375649, -0.07051585, 0.14472574, -0.12984152, -0.15395291, -0.21630242, -0.16181855, -0.36827597,
+    -0.1873941, 0.14283788, 0.27370954, 0.3543223, 0.23085271, 0.32805395, 0.14089888, 0.2300715,
+    -0.06073503, 0.05569154, 0.0677736, 0.04962462, 0.19052428, -0.25212288, 0.122297354, 0.13087012,
+    -0.1963284, -0.27052316, -0.24533316, 0.26720148, 0.17593378, -0.17923212, -0.21294233, 0.1341819,
+    -0.121635444, 0.23952514, -0.02937546, -0.14701658, 0.095563106, 0.11758143, 0.018647965, 0.1433944,
+    -0.11230657, 0.28054118, 0.42985937, 0.089942895, 0.3337502, 0.21138354, -0.04424205, 0.30504557,
+    -0.07113026, 0.2701805, -0.09854932, 0.0029252723, 0.18500824, -0.037414476, -0.2618926, 0.27201805,
+    -0.21489719, -0.054770436, -0.0048148013, -0.20531756, 0.044188634, 0.19493946, 0.0024191139, 0.28648397,
+    -0.18147989, -0.09353726, -0.07606519, 0.107895255, 0.022134325, 0.006696058, -0.04273304, -0.13520974,
+    -0.21154696, 0.09090843, 0.071152814, -0.43916124, 0.29948267, -0.24951051, -0.006095661, -0.15039557,
+    -0.37810817, 0.116885416, -0.056092564, 0.21510682, 0.09203733, 0.15300351, 0.20409296, -0.19735363,
+    0.21379757, -0.10165184, 0.2040165, -0.07173722, -0.07883011, 0.05937743, -0.1255616, 0.012820875,
+    0.17431022, -0.32126042, 0.020342715, 0.04805402, -0.053195715, -0.075978085, -0.007940659, 0.11247188,
+    0.18959, 0.020686159, 0.17301936, -0.13599616, 0.0062892195, 0.08216911, -0.12647973, 0.24335214,
+    -0.18538839, -0.2765953, 0.08111745, -0.14780524, -0.1995351, -0.36353824, 0.083645575, -0.13906795,
+    -0.19251435, -0.041711856, -0.039593045, 0.36825222, 0.18319252, 0.2524226, -0.17473172, 0.0025305927,
+    -0.19707178, -0.28457156, 0.2241269, -0.024953814, -0.06880312, 0.07803088, -0.27365065, 0.7680034,
+    0.27080014, -0.13600644, 0.09915641, 0.2836185, 0.29090035, -0.2573014, 0.06252146, -0.15763633,
+    0.118500516, -0.06137839, 0.23873189, -0.070200995, -0.08651709, -0.1367294, -0.11067088, -0.08887209,
+    -0.13723627, -0.18798831, -0.07180978, 0.2622819, 0.2656148, 0.15139107, -0.14001904, 0.32404274,
+    0.13201651, 0.08226021, 0.27703878, 0.09585803, 0.09947185, -0.065958485, 0.21633576, 0.2908558,
+    -0.074963, 0.28777736, 0.12004173, -0.08437735, 0.07581723, -0.23921117, -0.1266223, 0.35164708,
+    -0.13676311, 0.13945124, -0.3815332, 0.29226786, 0.11548121, 0.6203004, -0.2491317, 0.017713487,
+    -0.3085872, 0.63709354, -0.18340224, -0.048214424, -0.12049002, -0.16643173, -0.2086218, -0.18730657,
+    -0.30156472, 0.11985262, -0.005838013, -0.100787774, 0.10782343, 0.14533441, -0.29192555, -0.047400936,
+    0.0007911048, -0.18245871, -0.30296782, 0.0798273, 0.07118089, 0.16260168, -0.104311325, 0.1793859,
+    0.08426002, -0.3066561, -0.14269276, -0.15612584, 0.0072915633, -0.045379642, 0.1779534, -0.21365087,
+    0.15221633, 0.05781253, 0.054285306, 0.37201685, 0.096666925, -0.07249793, 0.09586751, 0.05975415,
+    0.17310892, -0.24919173, 0.2944348, 0.12046457, -0.11863129, -0.11389857, 0.24606165, 0.23498799,
+    -0.08931358, 0.06807037, -0.16328593, -0.12879646, 0.017266443, -0.2901572, 0.036392, 0.038419135,
+    0.05906436, 0.14685917, -0.29155415, 0.26603636, -0.033535622, 0.29783198, 0.16501503, -0.030898001,
+    -0.027613197, 0.25395235, 0.37449682, 0.12026096, 0.110729426, -0.102836914, -0.15492085, 0.1723485,
+    0.17422676, -0.27224645, -0.07948207, -0.043726563, -0.06653955, 0.24290279, -0.27412048, -0.30190673,
+    -0.1361976, -0.30068395, -0.20176983, 0.18265264, 0.18519369, 0.18239267, -0.023055488, 0.11270468,
+    -0.15181427, -0.09324492, -0.087415494, -0.3284795, -0.21586657, -0.26037702, 0.03143628, -0.088155754,
+    0.0074553923, -0.18153362, -0.026981601, 0.14450887, 0.004861168, 0.10688512, -0.07016929, 0.14059684,
+    -0.26307896, -0.1528372, 0.5089929, 0.1608838, 0.023669854, -0.12775417, -0.12795772, -0.21898739,
+    -0.022742042, -0.20002815, 0.33326718, -0.19583593, 0.016234515, -0.19751705, 0.33604792, -0.1498082,
+    -0.2809389, -0.0710399, -0.2354789, 0.16266164, 0.024525998, 0.5444316, 0.014197598, 0.0057097743,
+    0.15932336, 0.07600307, 0.10802403, -0.2721527, -0.29682314, -0.0594802, 0.19079769, -0.12670363,
+    0.016735513, -0.0612109, -0.27454567, 0.03405384, 0.076240994, 0.2715216, -0.11963559, 0.1944758,
+    0.033842918, -0.1489884, -0.29519123, 0.11978602, 0.15420742, -0.11023621, -0.08573845, -0.23102747,
+    0.11661374, -0.3216733, -0.048642345, 0.33715984, 0.058574937, -0.014118186, -0.098478206, 0.104828715,
+    -0.010489232, 0.24012329, 0.37980315, 0.19057688, -0.18029371, 0.13889313, 0.05373125, -0.036441725,
+    0.16931209, -0.18755731, -0.14927615, -0.014654134, 0.0056039756, 0.24949881, 0.16644253, 0.15649882,
+    -0.033415817, -0.2785142, -0.1773853, -0.01187172, -0.09349597, -0.15629095, -0.09815258, -0.0780906,
+    -0.15856431, 0.11256356, -0.15581869, 0.021763429, -0.102881655, -0.466791, -0.22532842, 0.12736923,
+    0.05611285, -0.09932849, -0.54903847, 0.10864454, 0.08972293, 0.019357039, 0.23337111, 0.18705325,
+    0.24367955, 0.24984263, -0.015713856, 0.1837431, -0.14460005, -0.06815987, -0.17583343, -0.16841443,
+    0.20042714, 0.43641648, 0.018521845, 0.083332755, -0.3374499, 0.30848277, -0.29320127, -0.15417114,
+    0.12860389, 0.20076162, -0.18892263, 0.026757035, 0.2842553, -0.20352814, 0.188243, -0.013796139,
+    -0.12953876, 0.058574464, -0.2284687, -0.13365136, 0.24004509, -0.016149307, -0.1427366, -0.097918995,
+    0.12959978, -0.043647014, -0.026686898, 0.13275787, -0.26303634, -0.10819304, -0.042814877, -0.16640675,
+    -0.25295064, 0.28789002, 0.32212335, 0.08974955, -0.048323486, -0.11516873, 0.011461653, -0.10893645,
+    0.11466969, 0.09563, 0.22525461, 0.16101564, -0.11650053, 0.08651096, 0.12394939, -0.09706012,
+    0.006452111, -0.029881196, -0.03422371, 0.061992336, -0.019107088, -0.18455946, 0.1799483, -0.20578918,
+    -0.04594273, 0.05141424, -0.034296915, -0.04656216, -0.092275605, -0.090557545, -0.050678127, 0.08848223,
+    -0.03258698, 0.15296873, 0.3585114, -0.2550626, -0.12868567, 0.11475533, -0.11862057, 0.2086545,
+    -0.075648926, 0.12410867, -0.13519704, 0.16018757, 0.051661327, -0.031724177, 0.06614252, -0.12757985,
+    0.19441535, -0.15545571, 0.21955387, 0.10951085, 0.06754339, -0.09517716, 0.109916076, -0.26392135,
+    0.22219443, -0.0635688, -0.061682828, 0.1312661, -0.12109053, 0.25760996, -0.15236726, -0.090969026,
+    0.177171, -0.2533481, -0.022540879, 0.18510638, -0.13469368, 0.0032987674, -0.31304118, 0.008905378,
+    -0.09603085, 0.14624138, 0.03611046, 0.21801472, 0.3176497, 0.016071932, 0.10930209, 0.19132818,
+    0.00845712, -0.1117344, 0.2809872, 0.112008564, 0.22405225, 0.095829, 0.053563155, 0.06377112,
+    -0.13295344, -0.36350617, 0.22227912, -0.19836937, 0.16931142, -0.31277505, -0.066159315, -0.51663,
+    -0.27338308, -0.19916256, 0.40803763, -0.23880556, 0.1900459, -0.16075988, -0.27517223, 0.10441679,
+    0.13853227, 0.13632198, -0.13927719, 0.2547573, 0.24179447, -0.018498903, -0.07871873, -0.05836378,
+    -0.121781275, -0.025681915, 0.4160676, -0.026492959, 0.040938575, -0.008662112, -0.2685677, 0.047355924,
+    -0.19049165, -0.19105023, 0.27054825, -0.04691858, 0.077639095, -0.09608969, 0.15729061, -0.1514078,
+    0.022325356, -0.12149476, 0.0775982, -0.4651398, 0.35301512, 0.07643588, -0.16774067, -0.18373287,
+    -0.23388213, 0.2574869, 0.30191594, -0.16577785, 0.18862897, 0.03403346, -0.12447532, 0.045004062,
+    -0.09800972, -0.08447174, -0.0584387, -0.1586598, 0.015078701, 0.13999863, -0.021093404, 0.012499287,
+    -0.20735826, 0.37505037, -0.02828921, 0.12524663, -0.049017236, 0.09178875, -0.077280164, -0.113778844,
+    0.069672175, 0.06717373, 0.19066708, 0.18990162, -0.035868634, 0.3244781, 0.14234808, 0.011567325,
+    -0.087526165, -0.04714989, -0.019293517, -0.11778368, -0.08723294, -0.14453793, 0.10176485, -0.025239853,
+    -0.10746262, 0.053739276, 0.024118625, 0.29833645, 0.2157736, -0.09981745, 0.026560972, 0.11260733,
+    0.053851813, -0.09237972, -0.059157103, 0.19728358, 0.1109483, -0.19458275, 0.21937275, -0.00028789337,
+    0.17596593, 0.32793012, 0.24886073, -0.2630216, 0.2836301, -0.18957159, -0.11506479, 0.24126625,
+    0.03577726, -0.14993553, -0.17796983, 0.13433899, -0.203903, 0.17606336, 0.10801614, 0.23908257,
+    -0.056424424, 0.09511211, 0.61708283, 0.10538918, -0.37110713, -0.03631498, 0.22898707, 0.26804265,
+    -0.059303477, 0.033513553, 0.11666285, -0.06215691, -0.17270294, 0.10970095, 0.40555885, -0.004738203,
+    0.00041684866, 0.057823703, 0.3205305, 0.09997654, 0.3268311, 0.07316743, -0.04038749, 0.15466881,
+    -0.010555855, 0.0321214, -0.2571034, 0.02672228, 0.037557125, 0.12108683, 0.094574034, -0.074700885,
+    0.09447037, -0.47643745, -0.40151203, 0.08318385, 0.0065435213, -0.408977, -0.07355381, -0.16388994,
+    -0.13827702, 0.23816885, -0.07247802, 0.039217338, 0.02947844, 0.16830291, 0.21588303, -0.1471139,
+    -0.2132984, 0.42994288, 0.49381834, 0.28018445, -0.29720604, 0.16111083, -0.114919096, 0.06994757,
+    -0.23435578, 0.29049164, -0.18236306, 0.041236326, 0.06923877, 0.29064414, 0.18002476, 0.08220022,
+    0.039374847, 0.44524604, 0.015412938, -0.14380981, 0.15985276, 0.12213795, -0.10237587, -0.104898386,
+    -0.07771984, 0.15997176, -0.06899846, -0.2341787, -0.079112425, 0.29100478, 0.15196827, -0.0422796,
+    -0.119076684, -0.031256303, 0.039463904, -0.23509906, -0.06399597, -0.1288163, 0.05614444, -0.25344622,
+    0.014627654, 0.056706198, 0.052375056, -0.19704491, -0.0011769603, -0.31673184, 0.003940792, -0.05775988,
+    0.11503128, -0.2895587, -0.091512725, 0.025729435, 0.2220208, 0.039017335, 0.097326614, -0.21220495,
+    0.43177828, -0.0577806, -0.14216016, 0.10013565, 0.063548155, 0.015036281, -0.23445846, -0.10569758,
+    -0.019422889, 0.4151181, -0.10560581, 0.23264712, -0.23459336, 0.20067848, -0.07330457, 0.059194468,
+    -0.31154647, -0.0870783, -0.040042, -0.20532496, -0.066723876, -0.16971949, 0.09411154, -0.06825755,
+    0.28102785, -0.19883029, 0.29445451, 0.14489047, -0.04039426, -0.116852246, 0.019154284, -0.10850875,
+    0.12661786, -0.07109422, -0.31227556, 0.05089258, 0.09220778, -0.16408563, 0.06552411, 0.16664991,
+    -0.29712376, -0.101280585, -0.12124775, 0.09426636, -0.1666193, 0.1764129, -0.100733034, -0.31063554,
+    0.13239112, -0.2746634, 0.14943595, -0.04024689, -0.21283127, 0.17745402, -0.07405101, -0.021793416,
+    0.02379077, 0.1894722, -0.0063120644, -0.030652683, 0.08813393, -0.28457916, 0.055797745, 0.0065426147,
+    0.30924153, 0.17251693, -0.35705042, -0.001393679, -0.01798859, 0.36875147, 0.31283754, 0.3444331,
+    0.03177398, -0.07799756, -0.06713945, 0.16148736, -0.49751815, 0.002662627, -0.22325201, 0.079592355,
+    0.13723585, -0.031338096, -0.03896753, -0.1377057, -0.2796223, 0.0006615979, -0.119169556, 0.14396624,
+    -0.044745423, -0.14760467, 0.03223619, 0.30042318, 0.25837335, -0.18317032, 0.15972091, -0.12135907,
+    -0.38797882, -0.20977183, 0.15891255, -0.046650637, -0.0589908, 0.06996787, -0.09195638, 0.33960918,
+    0.30558544, -0.089392915, 0.040516946, 0.12863193, -0.17865998, -0.20259805, -0.053214766, 0.11205819,
+    -0.15575397, 0.33655027, -0.05714341, -0.12387548, 0.030348446, -0.009627644, 0.24639037, 0.00855514,
+    -0.2964528, 0.14572826, -0.016302777, 0.04913236, 0.014680994, -0.12824073, -0.27422118, 0.06812071,
+    -0.10051178, 0.07803028, -0.040141154, -0.30867162, 0.027023261, -0.10757272, -0.044544727, -0.071865685,
+    -0.14466804, -0.084658034, -0.020839242, -0.24899831, 0.06150956, -0.08002319, 0.08075977, -0.1541489,
+    0.21678509, 0.07692987, -0.2406655, -0.16908789, -0.11799599, -0.51591396, 0.13209817, -0.16968034,
+    0.14355394, -0.24805959, -0.32676253, -0.1581273, 0.22627884, -0.48290423, -0.21119647, -0.088547885,
+    0.27737996, 0.22342308, 0.17009582, 0.0823702, 0.008302306, -0.010975425, 0.14234306, -0.20302045,
+    -0.06515298, -0.1688516, 0.31200486, 0.12663226, -0.41559342, 0.30308384, 0.12783638, -0.015004357,
+    0.16413248, 0.10295746, 0.005460099, -0.08375494, 0.21841004, -0.20680057, -0.25309694, 0.069277346,
+    -0.08957271, -0.04570884, -0.08408603, -0.04475789, 0.14454745, 0.11582319, 0.0631538, -0.040083937,
+    -0.09738703, -0.13241498, 0.1328349, 0.0039187064, 0.17800954, 0.06452633, -0.13557275, -0.249977,
+    -0.28083536, 0.25338513, 0.017071195, 0.3028766, 0.047574963, 0.17332083, 0.05507948, 0.24826059,
+    0.14622284, 0.05226953, 0.11468461, 0.07767129, 0.0027437143, -0.307823, -0.040220246, -0.08736779,
+    -0.028416626, -0.3002524, -0.17976415, -0.1805884, -0.024669942, -0.11849439, 0.09328441, 0.2714837,
+    0.09390313, 0.30780312, 0.043577183, -0.008572833, 0.16807055, 0.08349335, -0.056554582, 0.0060130106,
+    0.17518415, 0.3969546, 0.36852288, 0.1372079, 0.022093808, -0.11737495, 0.113748565, -0.030940369,
+    -0.056677263, 0.2622273, 0.033936277, -0.080372915, 0.111552805, 0.36169285, -0.07607493, 0.10845724,
+    0.017944824, -0.18933503, 0.2858721, -0.123017155, 0.13133578, 0.13830803, -0.09440389, -0.028816244,
+    0.007649942, -0.118891, -0.012813665, -0.06436962, 0.055258237, 0.015447098, 0.085253455, -0.4442648,
+    -0.17948681, 0.013307607, 0.20716229, -0.4785629, 0.11492709, -0.30909467, 0.061844673, 0.09723705,
+    0.045791063, 0.29139587, -0.3475797, 0.17393653, 0.21350741, 0.4406959, -0.25498077, -0.14733386,
+    0.20282015, 0.17707694, -0.0026580403, -0.29378933, -0.086691275, 0.17182045, -0.00055943447, 0.21004577,
+    0.07786198, 0.22781682, 0.05439954, -0.11878351, 0.16654606, 0.2806278, 0.015719892, -0.74514383,
+    -0.4421768, -0.22990738, -0.15083206, -0.0052460753, -0.19576977, 0.19175096, 0.12725228, -0.16964482,
+    0.1098426, 0.06330987, 0.21053597, 0.13043658, -0.15094434, 0.207937, 0.021761265, -0.15013,
+    -0.037208956, -0.26359197, -0.8046696, -0.21134603, 0.22820492, 0.094544925, -0.021792306, 0.1613537,
+    0.14861158, 0.069297984, -0.14458206, -0.05240233, 0.24441889, -0.26384082, 0.187011, 0.2141643,
+    -0.06936065, 0.36720347, -0.02758126, -0.2106201, -0.28367484, 0.07577943, -0.06229391, 0.15995532,
+    0.014626533, -0.20282081, 0.07333531, 0.013741658, -0.10612139, 0.082004294, 0.16208738, -0.18403585,
+    -0.04066807, 0.21099614, -0.28128704, 0.04398979, 0.048635636, -0.1130532, -0.061395183, 0.02766105,
+    0.23603678, 0.04068076, -0.1395108, -0.05005932, 0.049656782, 0.17350152, 0.09591572, -0.0047644945,
+    -0.13061549, -0.017115803, -0.22379512, 0.45522338, -0.2669954, -0.2117887, -0.090401374, 0.40851054,
+    0.22145525, 0.12777096, 0.010909045, 0.25144044, 0.04470253, -0.23003483, 0.42580238, 0.33435175,
+    0.050686125, -0.21323355, 0.1816594, 0.101038896, -0.15324165, -0.052564036, -0.0582677, -0.10221329,
+    -0.017304588, -0.54948604, 0.18264312, 0.044888727, -0.07229385, 0.30993134, -0.0017507311, 0.069732554,
+    0.029291753, -0.12604062, -0.43991053, -0.2612684, -0.20254974, 0.038191903, 0.13753104, -0.012816307,
+    0.009440897, 0.1111792, -0.1842658, -0.36242974, -0.056285024, -0.43088874, -0.2515638, -0.1483904,
+    -0.17136544, -0.334571, -0.38123426, -0.032135308, -0.12952977, -0.14202058, 0.18843375, 0.04197453,
+    -0.02144118, 0.29069752, -0.18238454, 0.23895793, -0.102713734, -0.23358332, -0.19900581, -0.16212355,
+    0.341049, 0.42320114, -0.032814976, 0.037144307, 0.049885996, 0.004971646, -0.17955129, 0.19671677,
+    -0.1202302, -0.14486606, -0.17991938, 0.166233, -0.12132495, 0.13358468, -0.7795453, 0.058088306,
+    0.07825928, 0.15776937, 0.12500735, -0.051090866, 0.043877225, 0.25755462, 0.28842878, -0.06349706,
+    -0.41092595, 0.048588894, 0.08784897, -0.18693542, -0.27175462, 0.015052964, 0.01794355, 0.060092896,
+    0.49251845, 0.058457598, 0.16493686, -0.08953149, -0.15935968, -0.04217113, 0.08176343, -0.22304453,
+    -0.24879496, 0.005199228, 0.15698431, -0.06668207, 0.3367414, 0.0056302445, 0.19682956, 0.0141076455,
+    0.23675594, 0.028760582, 0.23004574, 0.2325393, -0.29505396, -0.1386985, -0.023216058, 0.021581791,
+    0.066919476, 0.2561623, -0.001541523, 0.12739529, 0.14304484, 0.112586424, 0.078992404, -0.2675346,
+    -0.20300831, 0.16900982, 0.057002444, 0.20229167, 0.12513721, 0.11820203, -0.037695225, 0.039983075,
+    0.09603429, -0.1417577, -0.027301084, 0.29065987, -0.13091165, 0.12609681, 0.017622575, -0.19686484,
+    0.0011723518, 0.08213507, 0.053872015, 0.3649966, -0.18143842, -0.17810333, 0.13330245, -0.328941,
+    0.12031571, 0.01804144, 0.20374231, 0.100383826, 0.17035349, 0.20015956, 0.2560976, 0.20906281,
+    0.35749626, -0.364237, -0.34274963, -0.26987612, -0.3321343, -0.049203835, 0.17068273, 0.22242114,
+    0.12297969, -0.26376617, -0.06987526, 0.11940975, -0.24751715, -0.17817466, -0.040638186, 0.2285347,
+    -0.030497832, 0.024756266, -0.173823, 0.38348266, 0.20244616, 0.27833432, 0.08651881, -0.25726375,
+    0.17510957, -0.3387597, -0.1550471, 0.14910361, 0.059366476, -0.0830266, 0.20012468, 0.68357897,
+    0.027883882, -0.29916543, 0.063884996, 0.20771523, 0.02065977, -0.15030909, -0.09439187, 0.0011627529,
+    -0.059103668, 0.33405578, -0.033946548, 0.045104567, -0.11142319, 0.08157472, -0.2148583, -0.109048456,
+    0.15562539, 0.07721577, 0.16681121, 0.38243386, 0.009679349, -0.16893493, -0.035798457, 0.14823057,
+    -0.023495147, -0.02796594, 0.15375493, -0.074991494, -0.027329171, 0.03411019, -0.03905848, -0.14219151,
+    0.07193028, 0.115925305, 0.11174853, 0.07051635, -0.065440126, 0.19876677, 0.15935765, 0.08021645,
+    0.21578425, 0.3878709, -0.16627854, -0.04246053, 0.08389645, -0.0711616, 0.13451253, -0.087431565,
+    -0.07798501, -0.1283043, -0.458726, 0.15118247, 0.106487, -0.050363075, 0.0010158047, -0.1461024,
+    0.49030432, 0.14631622, 0.09224052, -0.0015188982, -0.10457671, -0.11102829, -0.103511386, -0.16487247,
+    0.14958277, 0.10613374, 0.051967435, -0.25597692, -0.22307532, 0.49136293, -0.1889673, 0.15178698,
+    -0.10091279, -0.27786726, 0.01977342, -0.051533524, 0.007616842, -0.38762254, -0.1175877, -0.10532693,
+    -0.11222898, -0.09530943, -0.026853058, 0.22335342, -0.044080798, 0.17017323, 0.20317894, 0.22042356,
+    0.040648818, -0.044506002, -0.047917534, -0.004005142, 0.096598946, -0.2461741, 0.15115018, -0.052551746,
+    0.31776142, -0.013419018, 0.2118654, -0.07568468, -0.17332718, 0.08599125, 0.24114655, 0.12368287,
+    0.07788363, -0.09063065, -0.14411567, -0.009178176, -0.065995894, 0.368648, 0.31855327, 0.286144,
+    0.08417726, -0.18214053, 0.40870905, 0.117290765, 0.11908982, 0.23606552, -0.012609014, 0.07727108,
+    0.043345757, 0.07965969, -0.109631635, -0.21872452, 0.040690284, 0.26325592, 0.23158938, -0.08927475,
+    -0.21116391, 0.0734449, -0.15426415, -0.24930206, 0.16227497, -0.06495117, -0.084157445, 0.19384712,
+    -0.097302556, -0.36335722, 0.17296366, -0.20384721, 0.09660802, 0.4638891, -0.008661756, -0.15213206,
+    -0.0366497, 0.19592075, -0.0037225059, 0.079917155, 0.003370057, 0.36763123, -0.08159202, 0.123680905,
+    -0.08485693, 0.23116682, 0.032876756, -0.017306823, -0.3132039, -0.009901015, 0.1056324, -0.28654447,
+    -0.21346997, -0.09322408, -0.19121768, -0.028802881, 0.34430146, 0.051832415, 0.18089667, -0.057947125,
+];
+
+#[rustfmt::skip]
+pub(super) static GRU_RECURRENT_WEIGHTS: [f32; 3 * GRU_SIZE * GRU_SIZE] = [
+    -0.11361435, 0.08029258, -0.035578202, 0.056822944, 0.11833797, 0.022575881, 0.018680664, 0.31739956,
+    0.002814847, -0.25524554, -0.14873534, 0.27403295, 0.020825606, 0.22252369, -0.1478016, 0.3400813,
+    0.12371618, 0.23648268, 0.15002875, -0.04096234, -0.31934926, -0.118881606, -0.017416865, 0.10623241,
+    -0.23322739, -0.012273465, -0.087039314, 0.087376274, 0.057863686, 0.023013992, 0.014358224, 0.21773288,
+    -0.13957497, -0.110676914, -0.069097504, 0.35900134, 0.21247211, -0.33384144, 0.120937414, -0.12235847,
+    -0.25334027, -0.140488, 0.47146174, 0.20554072, -0.13312307, 0.3565513, -0.22347163, -0.23121892,
+    0.09067971, 0.24884975, -0.18706419, -0.2468016, -0.52258724, 0.21167706, 0.39130902, -0.16718085,
+    0.7961387, -0.52280056, -0.29958394, -0.41988623, -0.15786481, -0.0050221896, -0.058736045, 0.24503157,
+    0.048967365, -0.341282, 0.05516534, 0.024802247, 0.19267781, 0.259349, -0.17589688, -0.30185366,
+    0.36065945, -0.07189327, 0.26496086, 0.22019245, -0.6305387, 0.07438528, -0.0024333, 0.4676249,
+    -0.27386937, 0.08394435, -0.59156704, 0.35565466, 0.49016753, 0.0099237235, -0.087041825, 0.07422876,
+    -0.07052449, -0.11143943, 0.44675064, -0.2990371, -0.07310371, 0.2738179, -0.0040240944, -0.019861901,
+    0.4002833, 0.06912756, 0.17200132, 0.6392087, -0.12022702, 0.3143922, 0.39363086, 0.6922395,
+    -0.14745922, -0.13644391, -0.20875514, -0.35067648, -0.91169375, -0.19923511, -0.16201924, -0.24931182,
+    0.14771613, -0.21334974, -0.06567266, 0.13290621, -0.10661119, 0.53554183, -0.12796007, -0.09701721,
+    -0.080076344, -0.10442096, 0.30755943, -0.06371349, 0.319336, -0.51630175, 0.4006737, -0.1046743,
+    0.21307088, -0.23359612, -0.30566633, 0.08017008, 0.08868063, -0.119416095, -0.028354649, 0.18043841,
+    -0.23240125, 0.021603916, 0.22488993, -0.09068602, 0.0616199, -0.4725207, -0.29821092, -0.2183591,
+    -0.1716129, -0.23860435, 0.19114923, 0.010570372, -0.09941252, 0.2111848, 0.048605304, -0.20517656,
+    -0.26664686, 0.059523694, 0.019798273, 0.020518918, -0.40182617, -0.22526537, -0.16752012, -0.18865667,
+    0.3855114, -0.28219572, -0.23912703, 0.16796365, 0.17847039, -0.097150706, -0.10144872, -0.28965428,
+    -0.23104843, 0.102020375, 0.012873172, -0.10231702, -0.03607801, -0.12758888, 0.30825365, -0.40979806,
+    0.39781028, -0.05615039, -0.47349387, 0.14289905, 0.21993975, -0.14980157, 0.19366032, 0.14018475,
+    -0.31956184, -0.042229086, -0.18672714, 0.35803443, -0.18134862, -0.38669032, -0.19660358, -0.35845095,
+    -0.26592967, -0.21230818, 0.015659906, -0.07085643, 0.19849928, -0.105004884, 0.10087645, 0.37653822,
+    0.303796, -0.044736926, 0.13880648, 0.034679297, -0.017183375, 0.018252982, -0.21769114, 0.2642895,
+    -0.1679639, -0.57938737, 0.09834239, 0.2550593, -0.33293942, 0.1800153, 0.21069485, -0.10234173,
+    -0.09205598, -0.4947971, -0.36021063, -0.3352759, 0.13364227, 0.09172892, -0.1557464, 0.10565459,
+    0.17715298, 0.18049447, 0.22148775, -0.49696216, -0.07294404, -0.27831876, 0.16206244, -0.0129094105,
+    0.029000139, -0.10657409, -0.10506199, -0.045203153, -0.3847911, -0.16013683, 0.2720737, 0.6747343,
+    -0.053303912, -0.15398194, -0.3045922, 0.05484907, -0.009298921, 0.29027915, -0.2858955, 0.03613103,
+    0.06777843, 0.4666237, 0.16344438, 0.051778026, 0.1802465, 0.040494993, -0.17603214, 0.15805031,
+    -0.35719156, 0.17825642, 0.028323876, -0.001568974, -0.1830218, -0.3162368, 0.39992958, -0.18080948,
+    0.27183115, -0.3237969, -0.397, -0.1499055, 0.17564745, 0.39320725, -0.14198516, -0.39424813,
+    0.05066187, -0.06804216, 0.11668812, -0.1152969, 0.13495046, 0.09394095, 0.20255983, 0.014104674,
+    0.3513189, 0.32732955, -0.1689286, 0.39158213, -0.39267272, -0.15954186, 0.13542455, 0.14706287,
+    0.24718446, -0.29599464, 0.25092682, 0.24559121, -0.2770757, 0.19819987, -0.069886096, 0.4220254,
+    0.08008561, -0.28240156, 0.11134502, 0.17624511, -0.39422688, 0.07025773, -0.41431695, 0.12067417,
+    0.82406914, 0.043844085, 0.3898624, 0.26579517, -0.4419573, 0.4564742, -0.061993398, -0.25876898,
+    0.02399318, -0.28324893, 0.30563962, -0.320014, 0.6201448, 0.12714823, 0.03588943, 0.46062064,
+    0.083040334, 0.1609285, 0.11386797, 0.061068468, 0.3031176, -0.41765898, -0.09911733, -0.080258116,
+    -0.95207405, 0.10022501, 0.114315726, -0.032846514, -0.19896051, -0.0038651666, -0.1629809, -0.1613118,
+    0.31589174, -0.13086301, -0.1007521, 0.036183763, -0.06501572, 0.49011967, 0.31002402, 0.19276622,
+    -0.0045379535, -0.37687916, 0.074321, -0.077510074, -0.04719617, 0.2559639, -0.5092904, 0.07825768,
+    0.27057928, 0.088472016, 0.16549443, -0.029311953, -0.021828713, 0.32771668, -0.02430411, -0.007484919,
+    -0.21058506, 0.07106873, 0.0755079, -0.019812703, 0.26371312, -0.19008398, 0.07851967, 0.06953515,
+    -0.0033407805, 0.12814714, 0.15444805, -0.03462648, 0.09451906, -0.19440013, 0.009321519, -0.09863059,
+    -0.6300961, 0.051075444, 0.09469, 0.34854057, -0.44239575, -0.28103423, 0.05917651, -0.1736688,
+    -0.047335975, -0.13536814, 0.23691493, 0.36898813, -0.105041035, 0.5146071, 0.40274122, 0.29174536,
+    -0.32337597, -0.64539295, -0.20354065, 0.013073668, -0.6266703, -0.2167125, 0.052635856, -0.12127412,
+    -0.10797691, -0.1480477, -0.14704402, 0.0682867, 0.28592134, 0.04932974, 0.006789031, -0.20988762,
+    -0.38135716, 0.10978172, 0.015630424, 0.23901938, 0.088082306, -0.25732833, 0.17978553, 0.08513865,
+    0.2683574, -0.057421662, -0.06349199, 0.30187955, 0.27294105, 0.05133911, 0.37071136, -0.094773576,
+    -0.08247596, -0.098289646, 0.2344446, 0.11929768, -0.69976604, 0.19834523, -0.27927458, -0.49093038,
+    -0.0011496571, 0.40811175, -0.19771656, -0.0665889, 0.2394663, 0.37567374, 0.13421611, -0.15214133,
+    -0.012924797, 0.31861705, -0.17496617, -0.24346049, 0.37008026, 0.18151203, 0.010565156, 0.21902797,
+    0.01158824, -0.27746254, -0.21894749, -0.100133, -0.12921062, -0.52269197, 0.18052146, -0.02436425,
+    -0.00026635453, -0.060606506, 0.04527054, 0.39333063, -0.1510884, -0.18326238, 0.061423693, -0.36215395,
+    -0.10000101, -0.071523726, 0.2641819, -0.25985637, 0.35834327, -0.061382063, 0.029652826, -0.0837279,
+    -0.13321345, 0.4758628, 0.38859582, 0.06376258, 0.53461725, -0.031967245, -0.42441347, -0.27657133,
+    0.44908923, -0.38220426, -0.20087151, 0.4166956, -0.18592364, 0.03128251, 0.5104872, 0.3470031,
+    -0.08653694, -0.3634097, 0.20460808, 0.2934506, -0.33657917, 0.21632582, -0.09581454, -0.024302717,
+    0.9902721, -0.20013297, 0.0016479368, -0.05184083, -0.033271104, 0.1719484, -0.45585638, -0.42385122,
+    -0.06941641, 0.27483037, 0.29327887, -0.035653956, 0.5937523, -0.33623758, 0.052169736, -0.020297106,
+    -0.0999259, 0.1921962, -0.495841, -0.20700832, 0.012037424, -0.29470327, -0.26506636, -0.3849423,
+    -0.9208623, -0.031109579, 0.17589116, -0.040355876, -0.16541983, -0.18686716, 0.10267727, -0.01379849,
+    -0.21823138, 0.24191658, 0.24941392, 0.47810712, -0.13714965, 0.21350627, -0.3804259, 0.06314951,
+    -0.4322025, 0.24233957, -0.6551709, -0.14923458, 0.09334296, -0.36199242, 0.34584117, -0.054820206,
+    0.23882626, 0.21977028, -0.18681416, -0.28407294, -0.12050686, -0.3923952, -0.32565147, 0.39438677,
+    0.10854696, 0.047142558, 0.10932702, 0.24895771, 0.13835178, 0.29329535, 0.3280614, -0.23961619,
+    0.039795674, -0.21863712, -0.030009655, 0.21159738, 0.051309284, 0.21575709, 0.071640305, -0.052419227,
+    0.13539091, 0.12688908, -0.1278033, -0.156872, -0.13016188, 0.6140951, -0.26840922, -0.07904756,
+    0.21974526, -0.13288607, 0.11029128, 0.26308236, -0.2277405, 0.48092356, 0.36181137, 0.27096704,
+    -0.19107947, -0.009048807, -0.08836439, 0.12186537, -0.49774402, 0.14053561, -0.11332102, 0.08304932,
+    -0.34462583, -0.1978787, 0.10557406, 0.26089358, 0.29633892, 0.28542528, -0.1672111, -0.3530753,
+    -0.09221327, 0.18067826, 0.3472713, -0.012770218, 0.003911905, -0.20830816, 0.025963802, 0.13773842,
+    0.1478304, -0.04626194, -0.18120992, 0.24382296, 0.031691566, -0.1171374, 0.25711963, -0.16863842,
+    -0.22683774, 0.10394778, 0.1845946, 0.51967674, -0.16046174, -0.23553263, 0.2312153, -0.37685218,
+    -0.10146895, 0.26777124, 0.19319172, -0.12213733, -0.21801476, -0.14230332, -0.017031677, -0.01957214,
+    0.08031378, -0.23094384, -0.38630405, 0.023438055, -0.39935312, -0.41698065, -0.119121134, -0.18066125,
+    0.2805207, -0.2077749, 0.12447187, 0.29088548, 0.2717033, 0.05857121, -0.141712, 0.043718442,
+    0.23840442, -0.007191978, 0.20428309, -0.13170314, -0.45453393, -0.113549404, 0.16676706, 0.21367955,
+    -0.054625243, -0.07710672, -0.21413323, 0.15073778, 0.11574323, -0.17884986, 0.12643732, 0.12528825,
+    0.2288943, -0.38682824, 0.049122076, 0.09548269, 0.2948963, -0.0900008, -0.06908904, 0.061571762,
+    0.09619561, 0.11852084, 0.16050532, 0.05798822, -0.29199472, -0.0019311785, 0.1748493, 0.96623254,
+    -0.01931232, 0.12771037, -0.70179814, -0.65387386, -0.62410545, -0.022261504, 0.099578306, -0.09079261,
+    -0.12883122, -0.17871311, -0.31031254, 0.36823815, -0.18761507, 0.56543285, 0.15321149, -0.09173913,
+    -0.18557914, -0.3184942, -0.021442853, -0.018714866, -0.046680517, -0.0928018, 0.24666362, 0.3290407,
+    0.081661925, -0.014636135, -0.1820741, -0.07179493, 0.052380417, -0.306888, 0.17435768, 0.16001399,
+    0.32321766, -0.16384704, -0.02548265, -0.06385121, -0.16813284, -0.11448034, -0.014938349, 0.3752818,
+    -0.15977304, -0.029621745, -0.09416626, 0.133919, -0.21051426, 0.1583086, 0.36508656, 0.60045415,
+    0.16836032, -0.25193045, -0.34087947, 0.030621594, -0.6784407, -0.32876933, -0.19078629, -0.13791059,
+    -0.11055175, -0.21519144, 0.16120863, 0.0865162, -0.17784017, 0.62197936, 0.1812566, 0.06018281,
+    0.14898148, -0.18691152, 0.25188273, -0.24476416, 0.09663964, -0.06950178, 0.3960772, 0.119520836,
+    -0.11046451, 0.27715755, 0.016785659, -0.037873477, -0.16255367, -0.2504339, 0.12637286, -0.1790059,
+    -0.21841246, 0.098899476, -0.23145871, 0.29088047, 0.09698725, -0.29840857, -0.23902363, 0.055894434,
+    0.030903053, -0.14622033, -0.164995, -0.21777561, 0.20687664, -0.120821215, -0.041956604, -0.5617976,
+    0.019867847, 0.3883767, 0.48008442, 0.09016179, 0.14001152, -0.0012504518, -0.42155978, -0.22327107,
+    -0.08400077, -0.08717092, -0.03640493, 0.28228754, 0.11700295, -0.2492952, 0.012829379, -0.04286714,
+    0.16851547, -0.44151333, -0.29370737, -0.09105044, -0.22150275, 0.021643627, -0.48077625, -0.21395327,
+    -0.10000607, 0.45340124, 0.2333104, -0.18543722, -0.30509105, 0.11560704, -0.17028514, 0.23345026,
+    0.15328282, 0.024114339, 0.04412601, -0.1830851, -0.022645827, -0.120813474, 0.08408607, 0.15570219,
+    0.17077786, 0.37967354, 0.20477675, 0.19991966, 0.40627033, -0.18147126, -0.1794879, 0.47394958,
+    -0.3745609, -0.27065825, -0.31114885, -0.15417731, 0.020680249, -0.28639197, 0.41424215, 0.57040334,
+    -0.65838224, 0.94606394, -0.28968847, 0.16045287, 0.24531597, -0.5171093, 0.5565932, 0.27980745,
+    -0.33321783, -0.36045673, -0.62699234, -0.16221505, -0.3106032, 0.28949887, -0.3070244, 0.06696219,
+    -0.1952309, 0.15392525, -0.09701738, -0.19117232, -0.1957176, 0.36055836, 0.4331036, 0.67566466,
+    0.3294547, -0.22091314, 0.11200246, 0.70710397, -0.38132906, 0.5218538, 0.063798204, 0.21963932,
+    0.1640516, -0.1768855, 0.2876544, 0.3019287, 0.09776129, 0.26864356, -0.07724575, -0.013472493,
+    -0.16260049, -0.2975715, -0.20569459, -0.2788054, -0.099087015, 0.059180558, -0.2170158, -0.23769917,
+    -0.039219927, 0.09352572, 0.03594733, -0.31096148, 0.39790842, 0.38961953, -0.37568158, 0.15767898,
+    0.01664619, -0.08627778, 0.37057954, -0.02747697, -0.17604263, 0.27123109, 0.09707564, 0.1547717,
+    0.19456866, 0.19520803, 0.06547617, 0.10971894, 0.13593088, 0.10098062, 0.00821249, 0.029317573,
+    -0.42195147, -0.28822228, 0.2309585, -0.25352293, -0.023516417, 0.14505593, -0.07445999, -0.56083524,
+    0.04278731, -0.19362696, -0.48064005, 0.38340497, -0.24529473, -0.095996246, 0.3772809, -0.037887525,
+    0.9042867, 0.027789276, -0.188968, -0.10762566, -0.023826007, 0.24192125, -0.3427794, 0.24998352,
+    0.084848285, -0.38012093, -0.36412126, 0.44615784, 0.01180229, 0.036882192, -0.24589384, -0.1285958,
+    0.15625724, -0.3119472, 0.20831697, -0.0032793554, -0.07685066, -0.30700648, -0.2675525, 0.05572281,
+    -0.16115873, 0.06792786, -0.5470359, -0.25171468, 0.36678493, -0.38061282, 0.1616678, -0.20425941,
+    0.004571866, 0.33148265, 0.5989166, -0.24972674, -0.41340956, 0.25056255, 0.49956006, -0.09473121,
+    -0.14038472, -0.27439943, 0.042982515, -0.0045730723, 0.47427613, -0.35160297, -0.19042762, -0.2206704,
+    0.079976715, 0.15890078, -0.14308892, 0.099702016, 0.08107039, 0.009624972, 0.22939916, 0.23714013,
+    -0.26654673, 0.53367645, -0.27200267, 0.18237373, -0.1098026, -0.6730055, 0.28767598, -0.009018171,
+    -0.2744394, -0.12558758, -0.2332905, 0.03118322, -0.046903495, 0.22895367, -0.17232072, -0.30024323,
+    0.04292848, -0.04885138, 0.32171574, -0.27209723, -0.02888219, 0.33310452, 0.27559716, 0.5081246,
+    -0.20983818, -0.3679605, -0.1384466, 0.3784125, -0.49238455, 0.20125447, 0.27030632, 0.37066677,
+    0.2991765, -0.2834398, -0.11816504, -0.23620711, 0.2572679, 0.23256749, 0.07232751, 0.6049369,
+    -0.066721596, 0.041639894, -0.10816317, 0.11454512, 0.34658098, 0.3035509, -0.37289652, -0.015215908,
+    -0.2286871, 0.0718171, -0.017852005, 0.5220068, -0.34256408, -0.19376619, -0.020932255, -0.054439172,
+    -0.10712036, -0.07977249, 0.028222354, -0.04691114, 0.4958378, 0.014488808, -0.123326786, -0.48269343,
+    -0.050392497, -0.006554232, 0.02830203, -0.2886162, -0.2582926, -0.09019055, 0.206733, -0.14422877,
+    0.14450596, 0.284609, 0.013433425, 0.09717611, -0.19664916, 0.20257387, 0.0071567334, 0.15576315,
+    0.34840795, 0.16896513, 0.13045953, 0.29605386, -0.03433408, 0.18694237, 0.087724194, 0.5008449,
+    -0.43824664, -0.1833691, -0.08902041, -0.14908585, -0.2549244, 0.06326494, -0.14326549, 0.18075864,
+    0.06524053, 0.19375068, -0.07302186, -0.46126926, 0.27131188, -0.031673472, 0.16919397, 0.05055307,
+    0.0054461947, 0.49646896, -0.4526173, 0.30599284, 0.02220201, -0.7108721, 0.21462809, 0.06610251,
+    -0.23770042, -0.16210002, -0.46632764, -0.07814046, 0.11665377, -0.03646261, 0.3514846, -0.29057482,
+    0.06375965, 0.25342765, 0.0061510443, 0.11640588, -0.21909536, 0.051788066, -0.25505424, -0.5358778,
+    0.18591884, 0.4070016, 0.38149026, 0.2532063, -0.35237983, 0.21898077, 0.09437557, 0.71481794,
+    0.48346066, -0.42937297, -0.37670594, -0.4709098, -0.74100107, -0.40524372, 0.12997681, 0.038021903,
+    -0.2266178, -0.16969982, -0.46858582, -0.19044675, 0.24661286, 0.12792282, -0.05191408, -0.08737602,
+    -0.27951142, 0.029580247, 0.23182805, 0.32078275, -0.14367038, -0.3232845, 0.31718177, -0.101887286,
+    -0.014461994, -0.09505813, -0.26815403, -0.036650434, 0.29776034, 0.11560671, 0.30078486, 0.122156695,
+    -0.01934471, -0.184666, 0.28259987, 0.25899547, 0.16593042, -0.1387249, -0.25048178, 0.11669199,
+    0.26534614, -0.09209958, 0.3853662, 0.04393037, -0.036387414, 0.537947, 0.21592373, 0.6891781,
+    -0.19641693, -0.998697, 0.017714454, -0.24387296, -0.6861663, 0.16034715, -0.4942269, 0.3239336,
+    -0.1580135, 0.03391298, 0.12487065, 0.15504603, 0.39188695, 0.6887213, -0.26534846, -0.11752817,
+    -0.21314925, -0.06799594, -0.006265033, -0.060689233, -0.26332098, -0.21079066, 0.29417488, 0.5121785,
+    0.31818402, 0.26914918, 0.078818396, -0.056282062, -0.027501358, -0.40163505, 0.30225322, 0.11807653,
+    0.11525342, -0.10937665, 0.2849167, 0.17606309, -0.51639843, -0.07368139, 0.17582391, -0.00035459833,
+    0.15749788, 0.8792891, -0.1386454, -0.0134805925, -0.15864624, 0.35276407, 0.45365363, 0.35704163,
+    0.22759584, 0.050107725, -0.27092028, -0.48559865, -0.04373274, 0.2682008, -0.2726075, 0.1541929,
+    0.005330146, 0.0030324885, -0.47693354, -0.1411976, 0.44527587, -0.119892344, 0.24324326, -0.39151794,
+    0.19549455, 0.120132335, 0.0060870335, 0.025923118, -0.4447865, 0.05106767, 0.033015016, 0.34733152,
+    -0.54578936, -0.14828335, -0.80486745, -0.4181461, 0.47166395, -0.122411914, -0.020182637, 0.13597369,
+    0.55316794, 0.075203784, 0.3800963, -0.041331876, 0.036003962, 0.3335917, 0.06485019, 0.6363647,
+    0.1072101, -0.14582583, -0.20324674, -0.48383284, 0.05121411, -0.1342056, -0.06589749, -0.12405291,
+    -0.14411023, -0.13063434, 0.43140557, -0.14811641, -0.24959205, 0.39975324, -0.5232352, -0.07674631,
+    -0.40805456, 0.17199774, 0.12003122, -0.16027892, -0.38732973, 0.015979001, -0.008471338, -0.04026907,
+    -0.036204923, -0.25902632, -0.12660192, -0.19594319, 0.38098663, -0.03305195, -0.27610704, 0.14370558,
+    0.08172315, -0.080827855, 0.1598387, -0.14817983, 0.0056371843, 0.09759629, 0.115124844, 0.09492913,
+    -0.21457313, 0.6177507, 0.086798705, 0.12221456, -0.37055093, -0.06791183, -0.2267769, -0.035257008,
+    -0.31314817, 0.29375085, 0.17911862, 0.08764557, -0.55734336, 0.32255355, 0.17358877, -0.23958987,
+    0.123506695, -0.39323917, 0.15744187, 0.20203477, -0.15089865, 0.17626715, 0.37939048, -0.2636212,
+    -0.18258815, -0.34839207, 0.34354064, 0.072930954, 0.23245575, 0.17607291, -1.1741962, -0.039916437,
+    0.086405195, 0.27364105, 0.23863435, -0.07979412, -0.48909253, -0.16475728, -0.063718185, -0.05968566,
+    0.35799664, 0.09328173, 0.08547678, 0.12243312, 0.28523907, -0.1550579, 0.13329667, -0.41035137,
+    0.06949998, -0.37797612, -0.07556306, -0.70129055, 0.2599892, -0.006329022, -0.03402795, -0.5418307,
+    0.30485365, 0.14349882, -0.13047676, -0.077685915, -0.055382084, 0.09172179, -0.061761566, 0.14787675,
+    -0.083233945, -0.13943231, 0.017584436, -0.0553959, -0.31781977, 0.29706204, -0.15012094, 0.5863349,
+    0.0753264, 0.22144777, -0.22018978, -0.2755883, -0.51142627, -0.31987777, 0.23614535, -0.11999448,
+    0.001604601, 0.1683308, 0.02293176, -0.39896148, 0.011770674, -0.37439737, -0.105928116, 0.11201014,
+    -0.03187111, 0.11548374, 0.13386267, -0.25669482, 0.02588639, -0.12988856, 0.18414177, -0.0075027323,
+    -0.33432025, -0.14387019, 0.36293486, 0.4011734, -0.50414157, 0.39149287, -0.050430674, 0.24241345,
+    -0.10674164, 0.32847354, 0.7738083, 0.11004841, -0.17389715, 0.30304986, -0.060703892, -0.17415169,
+    -0.19824153, -0.3718819, -0.37447777, 0.2343797, -0.309178, -0.033078454, 0.39124185, -0.09440973,
+    0.16699843, -0.0086781755, 0.09755566, -0.097499184, 0.5747927, -0.100835696, -0.8117023, 0.42741293,
+    -0.15568505, 0.40920156, 0.2249201, 0.41699532, -0.23149875, 0.10903455, 0.10078387, 0.18056856,
+    0.5038166, -0.098796256, 0.15863854, -0.007991694, 0.076541655, 0.04008106, 0.1489708, -0.5207097,
+    0.58120835, -0.4087193, 0.02368676, -0.29759452, 0.030927466, -0.25121453, -0.059086625, -0.3841585,
+    0.31887874, 0.10820361, 0.21373765, 0.21297522, -0.043633733, 0.4911874, 0.4293332, 0.4244502,
+    0.18938653, -0.50540376, -0.11878283, -0.15264654, -0.4461659, 0.04425396, 0.16010961, -0.0485216,
+    -0.28896913, -0.060362082, -0.28181255, -0.15708391, 0.18618335, 0.104533955, -0.17937505, -0.45131508,
+    -0.09733439, 0.048366044, 0.35585362, -0.116799995, -0.012426127, -0.044871397, -0.03226506, 0.38830727,
+    -0.17004119, 0.028565975, -0.61576295, 0.37174574, 0.10971274, -0.091737024, 0.3918259, 0.0031756065,
+    0.18837534, 0.18379708, 0.45481062, -0.009680535, -0.50959784, 0.14444692, 0.3562199, -0.0006119996,
+    -0.14998038, -0.0715492, -0.35115483, -0.26099592, -0.011802878, 0.0528767, -0.5095355, 0.29630354,
+    -0.08065822, -0.02470507, -0.059557084, -0.12889934, 0.14947765, 0.28687, -0.25684893, 0.0383688,
+    -0.38817838, -0.21619514, -0.11358108, -0.0050405934, 0.15199189, 0.33249387, 0.24540606, -0.22805417,
+    -0.3809095, -0.17772667, 0.03790463, 0.06508154, 0.22305562, 0.2001924, 0.23244455, 0.20728882,
+    0.1675519, 0.10785181, 0.39963052, -0.15529528, -0.11900182, 0.2962109, 0.18681441, 0.06098674,
+    -0.132682, 0.21414113, -0.2905184, -0.05112278, 0.012440684, -0.08976517, 0.17360365, 0.15743607,
+    0.40869185, 0.05431645, 0.15948485, 0.413824, -0.27581775, 0.38499928, 0.14488403, 0.5834286,
+    0.04707613, -0.3873505, -0.3707858, 0.0043532085, -0.3067319, 0.058905788, -0.057403017, 0.17346704,
+    -0.112569556, -0.059151858, -0.30221823, -0.03856481, 0.0767164, 0.14748275, -0.32649702, 0.015018313,
+    -0.07972491, 0.36800015, 0.09824062, 0.020596206, -0.24010889, -0.544483, 0.36509, 0.06760153,
+    0.25124195, -0.22704299, -0.42263764, 0.2897839, 0.14210999, -0.26926878, 0.3607586, -0.09937483,
+    -0.3933728, -0.010286629, 0.24585722, -0.07403566, 0.109242454, -0.09658488, -0.29494044, -0.314523,
+    0.113399915, 0.12438871, 0.11345402, -0.0888849, -0.39347947, 0.10047464, 0.1536353, 0.44600496,
+    -0.037209272, -0.11422652, -0.12068776, -0.4920747, -0.13217631, 0.100056775, 0.23643781, 0.26980305,
+    0.13145398, -0.48246375, -0.091925316, 0.37642312, -0.006602054, 0.38806492, 0.051175408, -0.1376071,
+    0.1463518, -0.33512306, 0.22670552, 0.02800332, -0.27260172, 0.067410305, 0.031519197, 0.21863338,
+    0.3641344, 0.18888342, -0.09569117, -0.084482245, 0.17376274, -0.5728834, -0.092737846, 0.144454,
+    -0.28843868, -0.23836854, 0.45335865, 0.11179258, 0.14120239, 0.024808368, 0.09383873, 0.13300993,
+    0.025405964, -0.12989129, 0.02167879, 0.17639878, -0.34941494, 0.4027522, 0.07891366, 0.34470457,
+    -0.059742797, 0.1420295, 0.005810749, 0.34509233, -0.3718131, 0.06651533, -0.1654255, 0.13715698,
+    0.60046947, 0.15683328, 0.23462993, -0.08753267, -0.21275169, 0.22598031, -0.16684219, -0.086736426,
+    -0.31229168, 0.08433639, 0.3088284, 0.11851618, 0.3369202, -0.04764165, 0.28769758, -0.1673659,
+    0.40875193, 0.02191109, 0.16370441, 0.42372942, 0.1928645, -0.027185716, -0.2807067, -0.28053948,
+    -1.1306958, 0.08114764, 0.10671065, 0.07681974, -0.17828321, 0.10442456, -0.30490497, -0.16357063,
+    -0.025826503, -0.2929171, 0.6454197, 0.40013835, 0.015078845, 0.4066094, 0.182967, 0.24427128,
+    0.0045931707, -0.13644676, -0.16745707, -0.3849356, -0.18772891, -0.29352996, 0.3533675, 0.3400317,
+    -0.13527356, 0.03619502, -0.21143083, 0.13979225, 0.7317848, 0.12947306, -0.33478874, -0.26270506,
+    -0.1646105, 0.20259911, 0.06890828, -0.17895684, -0.19594947, -0.19079246, -0.40550217, 0.25146112,
+    0.017637547, -0.109041266, 0.101166144, -0.2436316, 0.023603458, -0.18618315, 0.25551686, 0.07928112,
+    0.32326436, -0.36182654, -0.2260277, -0.08116072, -0.14283969, -0.1109917, 0.41756073, 0.33414707,
+    0.207783, 0.08835634, 0.39617214, 0.17896776, -0.38274902, -0.1105768, 0.06944739, -0.15359098,
+    0.15042587, -0.36458826, -0.24251096, -0.2559933, -0.23741177, -0.1716893, 0.41762462, 0.09455437,
+    0.37212706, -0.38590053, -0.051624198, 0.2856795, 0.568156, 0.16746981, -0.372803, -0.048218526,
+    0.07980642, -0.24736866, 0.119787395, 0.03465084, -0.47427088, -0.18692164, 0.37922254, 0.018919557,
+    0.3196533, 0.23999974, -0.045732364, 0.20653322, 0.059172332, -0.31410035, 0.2144059, -0.14393066,
+    0.22000763, -0.3453651, 0.007947887, -0.42793044, -0.15587057, 0.2380805, 0.21516918, 0.24671538,
+    0.15588918, -0.30959615, -0.077335015, -0.06496363, 0.22709335, 0.02080745, -0.071097955, 0.12856986,
+    0.008822355, 0.52907646, 0.22093458, -0.04764152, 0.012552668, -0.17394024, -0.05692026, -0.36814937,
+    -0.0019784307, -0.16565248, 0.11636231, -0.0028920064, -0.10598238, -0.031387076, -0.19901785, -0.358399,
+    0.119272396, -0.115430176, 0.12478569, 0.25276953, -0.29188722, 0.23982345, -0.12670168, -0.52600414,
+    0.074537575, -0.12842292, -0.1108605, 0.16850263, -0.068410866, 0.19358905, -0.36932647, 0.1363466,
+    0.060632143, 0.133558, 0.22108397, -0.2560897, -0.17533576, -0.17769158, 0.05403969, -0.08697172,
+    0.31475478, -0.13566773, 0.03657476, 0.21720015, -0.3307997, 0.4531774, 0.45670718, 0.25278047,
+    0.3148961, -0.7623201, -0.22999999, -0.07817652, -0.31901112, 0.05567326, -0.07998359, 0.48242816,
+    -0.2319283, -0.20164296, -0.043822493, -0.3420082, -0.06241149, 0.41461626, 0.0145057505, -0.1473207,
+    -0.24824019, -0.03277242, 0.29927352, 0.26425838, -0.68039435, 0.02320622, 0.3721203, 0.17021546,
+    0.053899854, 0.031660005, -0.1862306, 0.0066647334, 0.27147162, -0.26170948, 0.5054297, -0.18743673,
+    0.23600526, -0.068592094, 0.42206994, 0.21690312, 0.08603925, -0.385847, -0.24289754, -0.1402875,
+    0.14864428, 0.5310219, -0.009023973, 0.10411858, -0.16811568, 0.17876832, 0.15119332, 0.19137524,
+    0.24832694, 0.015018158, -0.024075925, -0.19041854, -0.08855979, 0.037187908, 0.10104954, -0.039586734,
+    -0.18268076, 0.045709174, 0.018581973, -0.049765617, 0.32326782, 0.39454266, -0.011609788, 0.010544791,
+    -0.11700993, 0.07302762, 0.3997419, -0.41415673, -0.6421505, -0.048662435, 0.19905205, 0.48135033,
+    -0.36197034, -0.1158373, -0.07602235, 0.070817426, 0.048873626, -0.03666907, -0.096955806, -0.23329592,
+    0.48699197, 0.121860765, 0.07202135, -0.28026575, 0.06281465, -0.11000515, 0.047436826, 0.075136244,
+    0.374115, -0.2229816, -0.32711816, -0.25514507, -0.110234916, -0.2026778, -0.033943176, 0.19987176,
+    0.44146845, 0.20239246, 0.1578267, 0.06304621, -0.28694102, -0.064602144, -0.25312564, 0.16488163,
+    0.004381515, 0.19832602, 0.04838211, 0.299692, -0.13623929, -0.25789168, 0.036525995, -0.35729384,
+    0.4767877, 0.0095570125, -0.3567587, -0.44934836, -0.0141459135, 0.28056547, -0.33064142, -0.09417482,
+    -0.16786072, 0.47224796, 0.059395738, -0.35923937, 0.057580788, 0.07294675, 0.27682206, 0.10616624,
+    -0.08611183, -0.09298013, 0.012063291, 0.3849134, -0.21136141, -0.15995663, 0.36034387, 0.18970338,
+    0.4888183, 0.2574839, -0.29538596, -0.04019407, -0.054872643, -0.081516534, 0.5638895, -0.19549327,
+    -0.23921084, -0.23094799, 0.24164943, -0.40001073, -0.34023893, -0.15535301, -0.45803714, 0.061460882,
+    0.9099862, -0.51075476, 0.17186527, 0.12396798, -0.37058324, 0.005495324, -0.049269736, -0.15182972,
+    0.4253962, 0.11321915, 0.0037801045, 0.31769812, 0.58473873, -0.33392256, 0.28108138, -0.23638558,
+    -0.3030986, -0.39256933, -0.29417542, -0.029589374, 0.009219097, 0.008909371, -0.3289567, -0.40882868,
+    -0.31203008, 0.19675422, 0.64222205, -0.4206526, 0.022445166, 0.40928307, 0.04975225, -0.16893584,
+    0.15713893, -0.23071185, 0.28265882, 0.33245194, 0.16061206, 0.084286354, 0.015488604, 0.3755882,
+    -0.20599335, -0.34589884, 0.21985817, 0.28061616, -0.5009696, 0.036294453, -0.19172573, 0.027165847,
+    1.0551646, -0.23207057, 0.53465396, 0.082971245, -0.37960058, 0.45130786, -0.12364822, -0.22130917,
+    0.08035748, -0.19891258, 0.37374642, 0.0012783722, 0.47974908, -0.04307691, 0.19105616, 0.2843115,
+    -0.03626668, 0.08859611, 0.23428924, 0.04583741, 0.26028538, -0.39852944, -0.09146698, -0.08255327,
+    -0.9965331, -0.29489946, 0.20273079, -0.12832345, -0.511512, -0.23775594, -0.13188174, -0.17992675,
+    0.24053314, 0.54031044, -0.4343479, -0.34791064, 0.09229851, -0.007951677, 0.4390137, 0.12277076,
+    -0.025678206, -0.0013024199, 0.4196051, -0.12391902, -0.069452114, 0.40291533, -0.5312037, -0.10980028,
+    -0.11745273, -0.03680454, -0.4873269, -0.026773296, -0.41006756, -0.2683057, 0.12554196, -0.35119423,
+    -0.040908758, -0.07702697, -0.06003373, -0.0703983, 0.7800507, -0.37501246, -0.039952364, -0.4098827,
+    -0.4965791, -0.12030609, -0.4734183, -0.29680964, 0.32083374, 0.34756827, 0.062541366, 0.4149183,
+    -0.32029146, 0.30059844, 0.5292166, 0.9812755, -0.17718497, 0.59374946, -0.00023874281, -0.05209557,
+    0.03977502, 0.09815155, 0.30627507, -0.21594253, -0.15142266, 0.15519097, -0.00739653, -0.16383232,
+    0.3244907, -0.16982985, -0.058114797, -0.12627243, -0.46019033, 0.04972385, -0.010299287, 0.04042441,
+    -0.11915497, -0.40349892, -0.22923385, 0.30748203, -0.0016916862, 0.5330089, -0.18666776, -0.14366935,
+    -0.04349098, -0.43559343, 0.21431844, -0.12178326, -0.41241935, -0.35888433, 0.19706374, 0.26903006,
+    0.17998143, 0.19299752, -0.19016194, -0.22684588, 0.023589492, 0.19971623, 0.15680607, 0.18865862,
+    0.35194832, -0.047370315, -0.022442318, -0.31650665, -0.052458968, 0.09928902, 0.38658935, 0.30627912,
+    -0.05365905, -0.027361434, 0.3765253, 0.16275446, 0.14961815, 0.12636319, -0.22362296, 0.17316844,
+    -0.16268438, 0.09401152, -0.3944055, 0.033228785, -0.19017214, -0.100382075, 0.3418833, 0.25909418,
+    0.097614035, 0.816668, 0.0047211563, 0.1699727, -0.3008666, 0.06769972, -0.14163554, 0.046111338,
+    -0.30714992, 0.083728276, 0.052600965, -0.20514236, 0.05700058, -0.055841155, 0.062760934, -0.19549456,
+    0.29883924, 0.47153112, -0.22850966, 0.4022629, 0.03665558, 0.028010497, -0.011995707, 0.06030768,
+    -0.40726873, -0.14429851, -0.21529254, 0.28687885, -0.04891399, 0.2659768, -0.07233989, -0.032204244,
+    0.3570468, -0.09842999, 0.46733963, 0.048100237, -0.054824285, 0.14747982, 0.20164399, 0.28019902,
+    0.2525372, -0.32873622, -0.16939369, -0.16847321, -0.2745586, 0.0035168917, 0.0095951585, 0.51999855,
+    0.09826551, -0.44092625, 0.051378574, 0.29357964, -0.08256818, 0.08463409, -0.17106712, -0.0005952833,
+    -0.1340685, 0.041746404, -0.04529556, -0.21182433, 0.06249038, -0.24213277, 0.09577662, 0.5038013,
+    -0.12301723, -0.05513778, -0.23140383, -0.34073046, -0.029860323, 0.08866231, 0.07369203, 0.24557249,
+    0.11495657, 0.023544261, 0.20070171, -0.17411935, -0.044055402, 0.22326536, 0.23985834, 0.45944545,
+    -0.19111104, 0.0027366278, -0.020743893, -0.009679046, 0.26951352, 0.4757665, -0.12726656, 0.45988348,
+    -0.21166639, -0.055463843, -0.11682806, -0.046513442, -0.4678929, -0.17098533, -0.020647425, 0.09967656,
+    -0.5027771, 0.68109256, 0.17037372, -0.06608558, 0.12060181, -0.14839774, 0.07966955, 0.0110101765,
+    -0.6397489, -0.2773624, -0.5608972, -0.34864822, -0.11174901, 0.30668727, -0.13627265, 0.6813257,
+    -0.19185588, 0.043365978, -0.19635333, -0.16300574, -0.049745522, 0.3855095, 0.2384362, 0.52263933,
+    0.33685458, -0.04498574, -0.1486181, 0.67196846, -0.24500139, 0.45977822, 0.092857786, 0.105509564,
+    -0.07233102, 0.029166698, -0.2013369, -0.23405185, -0.18380901, 0.17030278, -0.17876928, 0.16436377,
+    0.1520775, -0.23289697, 0.004838964, -0.12655698, -0.16471393, 0.11929329, -0.12079875, 0.25834632,
+    0.20735471, -0.31053832, -0.37154156, 0.107526526, -0.16238889, 0.10613501, 0.18066102, -0.13319749,
+    -0.016719, -0.076228514, 0.042327836, -0.16219719, 0.1380954, -0.025612615, -0.112070516, -0.12589897,
+    0.16875325, -0.20771405, -0.28487268, 0.38908952, -0.040096708, -0.092376344, -0.06561752, -0.07841355,
+    -0.043440852, -0.12686433, 0.20408653, 0.035422176, -0.19324078, 0.068255685, -0.15345326, 0.06911535,
+    -0.2683121, -0.14364606, 0.4107588, 0.007241753, -0.18011709, -0.033353563, -0.27437338, 0.6167732,
+    -0.42362648, -0.3989649, -0.16121916, 0.3343472, -0.27703294, -0.26789713, -0.21470939, 0.25312597,
+    0.17079277, -0.18496124, 0.04471293, -0.05449646, 0.0020457332, -0.016866375, -0.22373316, 0.1895978,
+    -0.18841445, -0.083812766, 0.17715716, -0.22135891, 0.062257502, -0.24238755, 0.20996332, 0.05590545,
+    -0.029521808, 0.0756497, -0.12032829, 0.051685847, -0.0723241, 0.17869005, 0.25905278, -0.039913706,
+    -0.045695923, -0.057155065, -0.2823299, 0.10958101, -0.05124765, 0.121818125, -0.26954925, -0.2535352,
+    -0.16356255, 0.057884194, 0.16351244, -0.21405762, -0.009247376, 0.0017457347, 0.13665302, 0.26488864,
+    -0.08036772, 0.21814266, 0.004033499, -0.04318348, 0.15739557, 0.025245951, 0.028279291, 0.13321479,
+    0.12487166, 0.031015525, -0.18123947, 0.21122427, 0.35271758, -0.11653297, 0.25819713, -0.05469429,
+    -0.18373895, -0.07547558, -0.0023675307, 0.10983218, 0.18590832, 0.13769948, 0.032956954, 0.0049034767,
+    0.2993292, -0.39939865, -0.1324272, 0.28042057, -0.07378136, -0.16272195, 0.08684416, -0.013557836,
+    0.14805171, 0.16948758, 0.28091037, 0.2986079, -0.053292084, -0.34217814, -0.29184207, 0.30331865,
+    -0.48696, -0.43911478, 0.07463422, -0.0063917274, -0.10224244, -0.0780902, -0.36426023, -0.29548728,
+    0.17465469, 0.5106504, 0.027210208, 0.09449794, 0.32695016, 0.102330096, -0.18618894, -0.27259454,
+    0.16384909, -0.084658094, 0.23288919, 0.03645286, 0.01662308, -0.24413937, 0.04166446, -0.014885722,
+    0.40691495, -0.29988223, 0.19177498, -0.22146837, -0.07105292, 0.387454, -0.5456814, -0.45641032,
+    -0.2371931, 0.37477395, -0.059868734, -0.07637443, -0.27084157, -0.0411114, -0.20912994, 0.02522148,
+    -0.2704675, -0.13389507, -0.42077017, -0.17562856, -0.1272577, -0.1289174, 0.19310366, 0.22976488,
+    0.1717006, 0.35988498, 0.13892153, 0.22778708, -0.04862347, 0.0139050875, -0.1561396, 0.08180352,
+    -0.30896068, 0.17155784, -0.28418884, 0.24151252, -0.06323708, -0.22375172, 0.2555426, 0.07255759,
+    0.056908693, -0.045278937, -0.16331297, -0.12503822, -0.20509738, -0.093770094, -0.11835668, 0.24164943,
+    -0.11065238, 0.14466983, -0.24615909, -0.075716734, -0.1132434, -0.00070714735, 0.028673291, 0.21513516,
+    -0.16751641, 0.28372964, 0.12594366, -0.017070582, 0.07910275, 0.073895566, 0.1484676, -0.0766073,
+    -0.15146886, -0.13369086, -0.11665168, -0.13207759, -0.14846157, -0.208999, -0.0068622143, 0.060174108,
+    0.09500146, -0.26019642, -0.25471604, -0.32790586, 0.05075413, -0.29445058, -0.1903722, -0.12819162,
+    0.28751376, 0.024394475, 0.3515692, 0.27167508, 0.3621293, -0.11229993, -0.060681146, 0.01364379,
+    0.1522166, -0.17503653, 0.21059753, 0.11467452, -0.09834143, -0.26424295, -0.10283187, -0.06884193,
+    0.08046937, 0.10934436, -0.14251953, 0.1375315, 0.12507284, -0.035454147, 0.019165346, -0.1625599,
+    0.13243751, 0.3830613, 0.045809023, -0.18718389, 0.024982952, 0.23164272, -0.051964972, -0.11042088,
+    -0.24411258, 0.20884496, -0.18933393, -0.03762809, 0.058032837, -0.07639594, 0.2801969, -0.18649209,
+    0.20354854, 0.32226247, -0.23068525, -0.048448637, 0.094530754, -0.23544852, -0.0868051, -0.04774704,
+    0.15314989, 0.39962405, -0.022876278, -0.113370106, 0.10243104, 0.01883884, 0.14664632, 0.22027768,
+    -0.0811212, 0.24379474, 0.197926, 0.09441624, -0.11160561, -0.15561308, 0.040047027, -0.15872402,
+    0.2491508, 0.17975526, 0.05398347, 0.12379686, 0.18163943, -0.1465245, -0.015183179, -0.036871374,
+    -0.14857475, 0.20128094, -0.15274854, 0.12590148, 0.21494819, -0.115179576, 0.22337297, 0.014386399,
+    -0.027541028, 0.03806265, -0.049091887, 0.15701526, 0.17133425, 0.043426305, -0.094040476, 0.14307348,
+    0.10091885, 0.21387118, 0.042719357, -0.2434321, 0.27402306, -0.3036368, 0.0764637, 0.18460491,
+    -0.017462822, -0.18312614, -0.078863055, 0.025655894, -0.36580896, 0.068811245, -0.022281209, 0.26889122,
+    -0.17550357, 0.17459007, -0.35548106, 0.10686756, -0.04685582, -0.08937106, 0.2910317, -0.16065446,
+    0.09817786, 0.0036980817, -0.24747616, 0.07191015, -0.19960111, 0.04207016, -0.17327207, 0.1375489,
+    -0.027259437, -0.00784908, -0.023779722, 0.033820756, -0.06287827, 0.0592288, 0.36615232, 0.16004634,
+    -0.030290935, 0.1419662, -0.055145018, 0.09699407, -0.017014736, 0.124537975, -0.12214947, 0.27048695,
+    0.06934217, -0.03525445, -0.12586823, -0.28202122, -0.16397387, 0.318906, -0.060198385, -0.3163222,
+    -0.07060049, -0.31612104, 0.012409509, -0.24623325, -0.16812962, 0.110926785, -0.1504677, -0.32536566,
+    0.33626387, -0.3985192, 0.07693407, -0.0037782767, -0.0065251268, 0.4037986, -0.37718618, 0.176986,
+    -0.10554119, 0.24520598, 0.32981154, 0.55472285, -0.26295918, -0.42979896, -0.24026547, 0.23763129,
+    0.10042095, -0.29407227, 0.32692814, -0.03003775, 0.5119983, -0.41114375, 0.018155074, 0.12736836,
+    -0.16943888, -0.15486369, -0.0013767713, -0.07379232, -0.075199015, -0.2508677, -0.124509975, -0.5376317,
+    -0.18267113, -0.4304497, 0.13994396, -0.0024834797, 0.07431407, -0.06720238, 0.011374769, -0.028352806,
+    -0.020163532, 0.3142922, 0.00055593316, 0.076853625, 0.072522625, 0.04369178, -0.007068463, -0.20877871,
+    0.20570287, -0.005508052, -0.12780467, -0.03342426, -0.29504877, -0.023780333, -0.111702584, -0.3499495,
+    0.20572376, -0.2065853, 0.2782399, -0.2077133, -0.010534557, 0.24544631, -0.22599015, -0.11003496,
+    0.20638804, -0.10254174, -0.06313033, 0.069916666, 0.17247938, -0.24181584, 0.102388896, -0.010209487,
+    -0.4218668, 0.17655966, -0.045966964, 0.0751939, -0.16071612, -0.18387175, 0.11852525, -0.22952539,
+    0.017882595, -0.089446604, 0.19396417, 0.22280197, -0.16350496, 0.29981282, 0.10991468, 0.03515211,
+    0.11743411, -0.35367683, -0.12136329, 0.044688888, -0.44274613, -0.15243848, -0.26661506, -0.036915097,
+    -0.0027119797, 0.18150489, 0.09687538, 0.3058845, 0.049545217, 0.32287526, -0.10460173, -0.13938433,
+    0.09475092, 0.02494755, 0.18358453, 0.29095662, 0.09263484, -0.17301552, 0.28641194, 0.032554124,
+    -0.027557971, -0.26318604, -0.10210074, 0.13867936, -0.16054231, -0.1931428, 0.21718521, -0.27300528,
+    0.08008628, 0.014741989, 0.22894597, 0.03326385, 0.3242005, 0.111159205, 0.24817723, 0.013684673,
+    0.24567372, -0.15864314, -0.05824566, 0.005492381, 0.0052472153, 0.026673255, 0.0013522852, -0.37479472,
+    0.15862316, 0.093170434, 0.11962396, -0.19901244, -0.058425166, 0.36009112, -0.05934291, 0.03729653,
+    0.048721172, -0.5257012, 0.06050266, -0.1319069, -0.15314329, 0.346682, 0.113222055, -0.116206795,
+    -0.187026, 0.0452234, 0.05198002, -0.26072383, 0.08505645, -0.22855295, 0.15641919, -0.2476814,
+    0.07762887, -0.1465062, -0.08198763, -0.17173539, 0.031868793, -0.27017525, -0.31790844, -0.09073696,
+    -0.44115815, 0.14222021, -0.042108353, -0.14079694, 0.08198964, 0.0018458635, 0.06383559, -0.28324664,
+    -0.17738199, -0.2912519, -0.25573134, 0.021010006, 0.27208057, -0.38746, -0.20583963, -0.083053164,
+    0.20977248, 0.53125197, 0.025657143, 0.16896158, -0.12618981, -0.18544732, 0.06268179, -0.14866202,
+    -0.17327963, -0.19951159, 0.12615505, 0.23908433, 0.063207425, -0.39151147, 0.18826583, -0.32913834,
+    0.4260023, -0.13549331, 0.07500699, -0.23674521, 0.08139009, 0.26731887, -0.07240066, -0.44742164,
+    -0.17917158, -0.01951451, 0.046984345, -0.49976206, -0.36495313, 0.06818278, 0.021879869, -0.13917792,
+    -0.11152848, 0.16347842, 0.068926975, -0.21180564, 0.13060054, -0.26683083, 0.33008325, 0.3401065,
+    -0.08987914, -0.27803636, 0.036702663, 0.2889391, 0.3909911, 0.17767408, -0.11995406, 0.16819748,
+    -0.09176755, -0.024867691, -0.30963293, -0.05322671, -0.02580959, -0.18919852, 0.4408853, -0.013518732,
+    0.16768044, 0.38556024, 0.24624287, -0.08072864, 0.21519592, -0.06805529, -0.10565459, -0.032258347,
+    -0.17081466, -0.19199343, 0.08913919, -0.25321698, 0.2010369, -0.069351025, -0.068641976, -0.46209553,
+    0.33208632, 0.20002687, -0.14934067, 0.14106886, -0.33379918, 0.19763173, -0.17334843, 0.03179509,
+    0.3856601, -0.3541364, 0.07344535, 0.33908385, -0.093158595, 0.2213332, 0.20865329, 0.23981696,
+    0.35205552, 0.0051918654, -0.23176263, -0.16309868, 0.03255716, 0.38628066, 0.18822803, 0.114986114,
+    0.24888512, -0.11135041, 0.03003429, -0.2536816, -0.002352291, 0.08993116, -0.11200145, 0.09311337,
+    -0.124092996, 0.22060858, 0.051151626, -0.29886732, 0.20333199, -0.042740297, 0.05377011, -0.3036309,
+    0.12876259, 0.04276216, 0.20778719, 0.101198204, -0.12344922, -0.21440992, -0.17427982, -0.04941392,
+    -0.08404164, 0.040086344, -0.1882805, 0.25451583, -0.14650209, -0.3345893, 0.14022551, -0.21095441,
+    0.5725493, -0.13757227, 0.34687924, 0.46390235, -0.18373537, -0.1167857, -0.25028342, -0.21129261,
+    -0.3878003, -0.27377054, 0.13754213, 0.24000357, -0.15365045, -0.03055596, -0.2480877, 0.1298871,
+    0.08780951, -0.04997037, -0.14025126, 0.38875818, 0.14859937, -0.2504972, -0.12969236, -0.054451026,
+    0.29122555, -0.2900511, 0.07496947, 0.13907026, -0.154637, 0.46398237, 0.03600566, 0.10232729,
+    -0.27829954, -0.06981714, -0.07348107, -0.08425391, -0.33323288, -0.017730795, -0.16866378, -0.16782632,
+    0.10665757, 0.06764311, 0.13277015, -0.026462566, -0.1339443, 0.04720435, -0.012337905, -0.1446752,
+    -0.21162003, -0.029384488, -0.2852272, 0.20466092, -0.071231455, 0.0315639, -0.22810017, -0.020961374,
+    -0.17546979, -0.3022406, 0.076648146, 0.17805056, 0.15693116, -0.05453596, -0.18833026, -0.113430336,
+    0.11663423, 0.14428386, 0.17793533, 0.030264813, -0.03331044, 0.044836525, -0.26887333, -0.40733066,
+    0.31814542, 0.1341426, 0.21372055, 0.22219606, -0.11924408, 0.1851938, 0.002989893, -0.21373476,
+    0.052946065, 0.04067284, -0.114768095, 0.26429135, 0.25837085, -0.15685152, -0.1764857, 0.10184116,
+    0.070921294, 0.12320536, -0.008781554, -0.0155373635, -0.27653262, 0.06257228, -0.015776375, -0.15194249,
+    0.21838138, 0.011263336, 0.0857836, 0.25057992, -0.038859118, 0.010764864, 0.38275522, 0.13748743,
+    -0.3086219, -0.08450484, 0.19504534, 0.073900804, -0.051027365, 0.31244764, -0.08205859, 0.11379645,
+    0.29042158, 0.17797181, -0.19426173, 0.2771155, -0.036350258, 0.061884187, 0.038899984, -0.07591436,
+    -0.066774525, 0.2062309, 0.007587581, 0.5750277, -0.09096209, -0.0066539054, 0.20929691, -0.19668254,
+    -0.47938478, -0.2937035, -0.2975475, 0.08717716, 0.22099334, 0.15455276, 0.020086188, 0.07719757,
+    0.20247573, 0.32475007, -0.056705594, -0.14211716, -0.36245602, -0.100439936, 0.100584246, 0.28289384,
+    -0.03635848, -0.34288037, 0.0056634154, 0.1479534, -0.12933536, 0.17532457, 0.46266574, -0.015602073,
+    0.1080245, 0.2724138, -0.01214339, 0.1462335, -0.05814888, -0.29950592, -0.15648179, 0.05143816,
+    0.18350443, -0.1760634, -0.09268775, 0.12985867, -0.08589554, 0.08891989, 0.073391005, -0.43473977,
+    0.14149056, 0.18911533, -0.080917165, -0.1292598, 0.0946005, 0.08134391, -0.30130157, -0.30558926,
+    0.02664296, -0.18353738, 0.29266897, -0.10857069, 0.061631437, -0.038926225, -0.017413367, 0.050248437,
+    -0.29337323, -0.0046802955, -0.24108768, 0.044894345, 0.19317964, 0.17391825, -0.1645104, -0.31396717,
+    -0.31130445, 0.07334779, 0.13492009, -0.08066823, 0.055153426, 0.077398725, -0.007338022, 0.16116197,
+    0.07925292, 0.10480945, -0.2351709, -0.0060887714, -0.0420045, -0.032337047, -0.13926797, -0.0059928303,
+    0.3138318, 0.108325355, 0.115053825, 0.16845514, 0.35641494, 0.06373772, 0.09218215, -0.08472065,
+    0.099711984, 0.011431152, 0.043033876, -0.041666266, -0.046107374, 0.29428524, -0.05686351, -0.02571778,
+    -0.11084805, 0.22474921, 0.1649879, 0.3376092, 0.29800156, -0.3383186, -0.29306224, -0.025679527,
+    0.1776067, -0.23714866, 0.07340573, 0.3432472, -0.043045472, 0.044190895, -0.23026577, 8.786035e-5,
+    -0.54585344, -0.030993141, 0.14301895, -0.31140944, -0.21213812, -0.043910053, 0.116574906, -0.23357986,
+    -0.050992742, 0.07769272, 0.12214352, -0.105201334, -0.22476593, -0.14963107, -0.00627261, -0.25546247,
+    -0.07631106, 0.15087667, 0.17768735, 0.037021518, 0.35214654, -0.25393263, 0.08188158, 0.006788698,
+    -0.25643486, 0.036511265, -0.0064223777, 0.037726294, -0.32623768, 0.05173468, 0.117379, -0.15378252,
+    -0.25045758, -0.00631239, -0.19758214, -0.019366292, -0.21618477, -0.1578029, -0.2667456, 0.27565837,
+    0.005051681, 0.31405655, 0.3931155, -0.48839328, 0.17978434, 0.29368278, 0.3602129, 0.18563817,
+    -0.32656085, -0.34546572, -0.016661476, 0.28418458, -0.44411626, -0.0007770718, 0.03925843, 0.08967355,
+    0.32140267, -0.06973296, 0.08302739, 0.04726807, -0.08204942, 0.29024625, 0.29740584, 0.35147068,
+    -0.2924065, -0.20599937, 0.15200348, 0.28055507, -0.3397609, -0.09133302, -0.13823545, 0.1057053,
+    0.071329966, 0.20110716, -0.0552986, 0.35824162, 0.22744311, 0.15659752, -0.06275114, -0.18306373,
+    0.017798202, -0.2633782, 0.15207048, -0.22690502, 0.15465069, -0.27850893, -0.17028436, -0.18204066,
+    0.0767131, -0.17719509, -0.030176682, 0.040226523, 0.122387655, 0.036529414, 0.0693173, -0.088458724,
+    0.5864551, 0.00752507, 0.3235139, -0.06370451, 0.00061989645, -0.15127946, 0.120163456, 0.09368976,
+    -0.11994283, -0.14672765, 0.13483472, -0.008290774, -0.2847844, -0.45140278, 0.038251303, -0.09574392,
+    -0.035492983, 0.32228518, 0.19407375, 0.08119421, 0.030900033, 0.034149386, 0.06099444, -0.065444656,
+    0.24684301, -0.052547526, -0.17866965, -0.28584263, 0.24152996, 0.12401503, 0.1495553, 0.019332498,
+    0.32334873, 0.12268857, -0.17888187, 0.13702865, -0.16755052, 0.001381232, 0.093139455, -0.3413413,
+    0.13158776, 0.010802078, 0.0935352, -0.16867964, -0.097195394, 0.026843796, -0.15902534, -0.09521,
+    -0.3778128, -0.20951687, -0.22395633, -0.34671748, 0.22841437, -0.0031431147, 0.101145655, -0.14759672,
+    -0.019189132, -0.15367179, 0.046372704, -0.20213242, -0.27046514, 0.269018, -0.063297346, -0.006417017,
+    -0.15725136, 0.08874415, 0.14433326, 0.003931072, -0.01914377, -0.028775705, -0.15893501, -0.29554072,
+    0.28709862, -0.40857777, 0.23519184, 0.17048626, -0.36836237, -0.120414905, -0.28746194, -0.28359437,
+    0.066749625, 0.2607329, 0.14785066, -0.105289906, 0.11515903, 0.02467045, 0.012854595, -0.121082164,
+    -0.13988106, -0.34395117, 0.24347235, -0.09563426, 0.013034937, -0.43821684, -0.22136857, -0.22675367,
+    -0.18889004, 0.06787871, 0.17026806, 0.21951975, -0.058990616, 0.10516433, -0.19653499, -0.32513052,
+    0.095582224, -0.033139866, -0.09866124, -0.07137099, -0.2580756, 0.08197145, 0.092471294, 0.42731005,
+    -0.050234392, 0.045754623, 0.0246727, -0.24616778, -0.2291054, 0.03149816, -0.22118948, 0.115134545,
+    0.31543237, 0.2506334, 0.08259804, -0.1250812, -0.29422793, 0.21301883, 0.026465494, -0.1355691,
+    0.07052458, -0.12435285, 0.040467948, 0.087565474, -0.030761374, 0.13562244, -0.08241426, -0.12681808,
+    -0.32401294, -0.010858958, -0.12134069, 0.15118021, -0.061909482, -0.22688532, -0.088013075, -0.17352259,
+    -0.22901994, -0.045836728, 0.10427075, -0.17130885, -0.08873708, 0.028767215, 0.008251414, -0.105095506,
+    -0.08140034, -0.176923, 0.21459958, -0.21746612, -0.40062147, -0.11703127, -0.08760898, -0.22171225,
+    0.23267546, 0.09649698, 0.08688362, 0.11513125, -0.18069066, -0.11197715, 0.046021696, 0.11562627,
+    -0.048610635, -0.6840243, -0.26888692, -0.10550561, -0.054280028, 0.044844244, -0.3026266, 0.09369503,
+    -0.020752234, -0.09072136, 0.22739479, -0.09848768, -0.24412324, 0.06064596, 0.16568293, -0.19067584,
+    -0.17073421, -0.07627285, 0.1048552, -0.1550864, 0.008552581, 0.12136784, -0.28122663, 0.035129942,
+    -0.5471782, 0.32296014, -0.022897778, -0.33689192, 0.0251474, -0.18293023, -0.2982153, 0.02301736,
+    0.017706042, 0.45820165, -0.013943654, -0.18319584, -0.08866199, -0.04856004, -0.36582768, 0.01666648,
+    -0.068230435, -0.32725534, 0.14014137, 0.12662713, -0.19280237, 0.17837901, 0.11501346, 0.08916648,
+    -0.28838146, 0.31741044, -0.14292645, -0.2682515, -0.191688, 0.2406664, 0.27812523, -0.06828945,
+    -0.13824958, -0.14253694, -0.14554499, -0.103599146, -0.05170958, -0.19291455, 0.22493912, 0.28190076,
+    0.13595493, 0.15607338, -0.07691927, -0.1970363, -0.03118842, -0.040969037, 0.041934222, 0.1669891,
+    -0.7860014, 0.22572571, -0.27034268, 0.24382438, 0.07585922, 0.055299222, 0.023506803, -0.11240926,
+    -0.04488395, -0.18018055, 0.19156833, 0.22791143, -0.23604503, 0.09564989, 0.04073113, -0.013237471,
+    -0.37505448, 0.32730547, 0.16284992, 0.40342468, -0.17655443, -0.09945825, 0.15236206, 0.036382325,
+    -0.110391274, 0.22245, -0.0868227, 0.18271373, -0.47167942, -0.027465336, -0.16529053, 0.12430316,
+    -0.13398504, -0.07441583, 0.14733325, -0.19027498, 0.08599123, 0.19711296, 0.05071949, 0.3440678,
+    0.17167918, 0.010478858, 0.19122389, 0.031495232, -0.05608555, 0.13092051, -0.34451845, 0.0655769,
+    -0.29872787, -0.28962123, -0.3277888, -0.015130914, 0.08931026, -0.08399203, -0.04757892, 0.2711241,
+    -0.03121267, -0.37055865, -0.19511133, -0.42835218, 0.04836892, 0.08779841, 0.16140759, 0.02392863,
+    0.08390302, -0.027463272, 0.29939568, -0.12813027, 0.11399694, 0.15240037, 0.06554333, -0.028293733,
+    -0.10821583, 0.122064285, -0.17989488, 0.2159375, -0.055300392, -0.08629611, 0.040816497, -0.023761945,
+    0.046693902, -0.24088193, 0.011351324, -0.19756208, 0.040231615, -0.085722074, -0.32680452, 0.16856192,
+    0.1627777, 0.073832534, 0.10250489, -0.083000764, -0.11344246, 0.030161975, 0.14435555, 0.12878299,
+    0.05202444, -0.13153942, 0.0104473075, 0.1576959, 0.03498749, 0.014091898, -0.26121435, -0.07165355,
+    0.19029954, 0.15831876, -0.23016088, -0.36209556, -0.07268794, 0.1035128, -0.26143518, -0.231628,
+    -0.28810483, 0.05315618, -0.18507536, 0.011569521, 0.2666513, 0.34409764, -0.29505464, 0.1806701,
+    -0.091400236, -0.4329492, -0.51337, -0.11421996, 0.19421576, -0.19766293, -0.071589455, 0.108587965,
+    0.041608166, 0.09688843, -0.0046470775, -0.030203141, -0.067350194, -0.12616219, 0.18601535, -0.1511697,
+    -0.21304391, 0.101350434, 0.27313846, 0.15380836, 0.27295533, -0.12881882, 0.16216259, 0.0043240045,
+    -0.01399493, -0.05599803, -0.105322205, -0.150946, 0.28720117, 0.10680319, -0.43588585, -0.23043118,
+    0.14045839, 0.1353135, 0.09394894, -0.32056108, -0.07947534, 0.18883033, 0.0018642098, 0.09470113,
+    0.18852223, -0.12094582, 0.27841455, -0.032693807, 0.07312146, -0.0863784, -0.017947456, -0.0323521,
+    0.45291588, -0.17978273, 0.16510743, -0.07183736, -0.4439619, -0.12043987, 0.19806407, -0.013319612,
+    -0.2577121, -0.06255984, -0.14452922, 0.15936983, -0.35828638, 0.11684369, -0.041035593, 0.1388178,
+    0.08716642, -0.17368253, -0.10050745, -0.20436385, 0.24102896, -0.10660571, 0.15705374, -0.14088236,
+    -0.48272604, -0.07323941, 0.18746626, 0.049164746, -0.23136199, -0.15172873, 0.17734523, 0.001211857,
+    0.01481093, -0.2879431, -0.12512502, 0.052400313, -0.15266337, -0.1748072, -0.38923287, -0.15519571,
+    0.12394024, 0.033439673, -0.14756355, 0.08604141, 0.014251314, 0.068876706, -0.28079852, 0.10598281,
+    0.124458276, -0.0024319927, 0.39638808, 0.2575132, -0.14486387, 0.17501707, 0.3332933, -0.0035646074,
+    0.09976316, 0.06345381, 0.12454963, 0.022231774, -0.089218214, -0.07487694, -0.28127986, 0.026722603,
+    -0.105968714, 0.23007493, 0.039883237, -0.09156745, -0.10986778, 0.04358939, 0.009410201, -0.04041384,
+    -0.17404154, 0.114935875, 0.04999928, -0.007206374, -0.14887913, -0.30159414, 0.21256372, -0.13232446,
+    0.20562866, 0.23552085, 0.26153827, -0.13499178, -0.2066342, -0.16190378, 0.31775725, 0.5433739,
+    0.10800356, -0.18056872, -0.3794352, -0.08000909, -0.17958525, -0.22841553, 0.03295649, 0.13065694,
+    -0.037736263, 0.07880925, 0.019672396, 0.031568874, -0.027137827, -0.005511189, 0.08469819, -0.07133107,
+    0.19403167, -0.121273525, -0.17472188, -0.21776098, 0.120313294, 0.1511737, 0.0532086, -0.13937023,
+    -0.10063086, 0.13273045, -0.08241513, 0.033247564, -0.08381027, -0.18264686, 0.33133382, 0.010475393,
+    -0.07389849, -0.3441488, -0.08608875, 0.21583718, 0.30917028, 0.09688666, -0.29008073, 0.25257686,
+    0.18104692, -0.053179815, 0.111403964, -0.23100686, 0.10784729, 0.31010523, -0.3432625, 0.24545324,
+    0.19318461, -0.13184033, 0.22882618, -0.07421372, 0.3021358, -0.026274612, -0.21331364, -0.04517437,
+    -0.27551773, 0.57788277, -0.028941505, 0.19785559, -0.506623, -0.19044001, -0.21946812, -0.3462377,
+    0.16088714, 0.11485267, -0.096337214, -0.4408012, 0.0031364027, 0.12728332, -0.2894377, 0.43517566,
+    -0.34361142, -0.27019906, 0.033551447, 0.17898211, 0.10686776, -0.09834193, -0.1446901, -0.1989079,
+    -0.40608248, 0.07196932, -0.21642886, -0.2296645, 0.16496596, 0.1464717, -0.00088990486, -0.267446,
+    -0.1149764, 0.019426005, -0.374391, -0.062527485, -0.09267744, -0.271562, -0.017524302, -0.23363109,
+    0.24870645, 0.33015716, 0.087043315, 0.03679664, 0.09058333, 0.36871624, -0.22737293, -0.14739452,
+    0.0655198, -0.27538702, -0.107346475, -0.21843585, 0.30112293, 0.029732028, -0.15011951, -0.3485141,
+    -0.071704686, -0.12998472, -0.01180072, -0.05879604, -0.10995148, 0.004636703, -0.25343466, -0.35506466,
+    -0.1203061, -0.11265731, -0.13217457, -0.23147382, 0.22256404, 0.18113461, -0.15230496, -0.13094845,
+    0.0896135, 0.16529396, 0.082320556, 0.1738927, 0.024180198, -0.02376796, 0.008038912, 0.13944387,
+    0.2197516, 0.108509, -0.27774888, -0.3371323, 0.17847483, -0.2319204, 0.43443683, -0.13478795,
+    -0.007965834, -0.20131968, 0.33637077, -0.26033822, -0.2719202, 0.12647398, -0.29995477, 0.10157392,
+    0.35812098, -0.013080511, -0.5445366, -0.0045440905, 0.021639181, -0.5367787, 0.050695695, 0.24754615,
+    0.09355508, -0.103687584, 0.15042427, -0.048109215, 0.12335345, 0.024298318, -0.09911681, -0.15608406,
+    0.06105126, -0.14070623, -0.1644993, -0.3023068, 0.058888607, 0.17245165, 0.32475996, -0.096392736,
+    -0.46117055, -0.053955853, 0.41356272, 0.22796811, 0.1405608, 0.17964828, -0.18633969, 0.030479647,
+    -0.09738067, 0.09676592, 0.0694842, 0.037163526, 0.15469764, -0.103857405, 0.14772065, 0.42459443,
+    -0.08459735, -0.31337637, 0.28056663, -0.28687698, -0.1652883, 0.3210092, -0.30337748, 0.06712402,
+    0.10414823, 0.26394916, -0.22139308, 0.11585667, 0.07845817, -0.081574015, 0.13603833, -0.39716968,
+    -0.007804915, -0.06954302, -0.09685167, -0.014757201, 0.22020636, -0.15638046, -0.05898784, 0.3864872,
+    0.1083244, -0.25418434, 0.011745103, -0.20045298, 0.13597219, 0.067581154, -0.25765297, 0.11775297,
+    0.08134319, -0.1735892, 0.03363475, 0.05471526, -0.2460569, 0.27331218, 0.14130355, 0.0946218,
+    -0.024632508, 0.27467528, 0.26656878, 0.14602539, -0.24084197, 0.2012841, 0.05129333, -0.0139711825,
+    -0.17780396, -0.10245637, 0.053518586, 0.16877541, 0.023807282, 0.2013152, 0.24311718, 0.09599829,
+    -0.111620516, 0.20477094, -0.18285793, 0.07388124, -0.31309947, 0.26083204, -0.21593371, -0.21823014,
+    -0.27039924, 0.080022454, 0.21915564, -0.13049172, 0.11359155, -0.28584114, -0.080308214, 0.15677582,
+    -0.023531878, 0.10582433, 0.13560234, 0.08471756, -0.060983125, 0.18508616, -0.107965946, 0.05118857,
+    0.58351904, -0.16847274, -0.1935416, -0.2891008, -0.06784461, 0.14597815, 0.13102889, 0.07750252,
+    -0.10193441, 0.14773837, -0.09803307, 0.21234898, 0.060861338, 0.033850353, -0.11987688, 0.06387453,
+    -0.026575947, 0.14108048, -0.1232851, -0.10061744, -0.13931817, 0.12990385, 0.021145148, 0.1191231,
+    0.26718345, -0.2217459, 0.20886831, 0.17115289, -0.21741548, 0.23413007, -0.18289158, -0.30706534,
+    0.29425997, -0.13190743, -0.10497958, 0.01369868, 0.045553513, -0.25880882, 0.2606545, -0.30826005,
+    0.051527787, -0.13185069, 0.19601734, -0.12644078, -0.1108168, -0.21839522, -0.0020508845, -0.06021465,
+    0.03675677, 0.14418273, -0.09932648, -0.015256154, -0.24739441, 0.27311873, 0.24219754, -0.06476453,
+    0.31006, 0.10887346, -0.29995883, -0.16464888, -0.12246995, 0.09771287, 0.2499833, -0.057948433,
+    0.051214665, 0.00051724934, -0.10096607, -0.32600865, -0.08848212, 0.18062286, -0.04541981, 0.03446829,
+    0.057482924, -0.16738583, -0.39500138, -0.018392375, 0.23578033, -0.23504847, 0.16448936, 0.0040540067,
+    0.07858096, 0.1853125, 0.36513215, 0.20130338, -0.13943629, -0.053467683, 0.3905963, -0.10912586,
+    0.12693647, 0.1352351, -0.23014094, 0.033165906, 0.28260756, -0.31371367, -0.02978024, -0.028553624,
+    1.1385075, 0.14775388, 0.20952699, 0.05893626, 0.051576875, 0.034512788, -0.20614335, 0.09353054,
+    0.011524349, -0.035721164, -0.21169087, 0.20354716, -0.11850423, 0.15451536, -0.21490398, -0.3323712,
+    -0.15127473, 0.089950256, 0.14121012, -0.07779304, 0.030982912, 0.08343566, -0.0076011242, 0.0006455953,
+    -0.15229864, 0.2227107, -0.031086687, 0.1474879, -0.25188002, 0.12238338, 0.038543325, -0.18405986,
+    0.06255261, -0.01359603, 0.12512736, 0.04458475, 0.072054274, -0.036333285, -0.04454486, 0.22588736,
+    -0.11638781, -0.22846141, 0.14985669, 0.23737533, 0.09737298, 0.092703395, -0.20361571, 0.081814535,
+    -0.1445902, 0.033460677, -0.04758814, -0.16456826, 0.0959336, -0.16811092, -0.21224663, -0.076717824,
+    0.3133848, -0.0015065555, -0.23202454, -0.008154327, -0.102256, 0.28699732, 0.36225855, 0.2789174,
+    0.08274807, -0.11146966, -0.03264069, -0.1714647, -0.27052104, 0.030076046, 0.09425427, -0.22674921,
+    -0.07642365, 0.24718632, -0.20304163, -0.27836034, 0.10242519, 0.15744904, -0.20552202, -0.15709637,
+    0.047560092, -0.38449395, 0.2065165, -0.09856888, -0.18324682, -0.06779191, 0.19963798, 0.056737795,
+    0.025304582, 0.09665172, -0.33949745, 0.084385455, 0.19700702, -0.19701494, -0.0027838002, 0.17077787,
+    0.38410717, -0.07343129, -0.004920798, -0.08564456, -0.10142453, 0.0692563, 0.05941628, 0.07452404,
+    0.20176713, 0.3207742, 0.3316359, 0.10099761, 0.16542803, -0.27320224, -0.0592527, 0.1978493,
+    -0.27891782, 0.05357466, 0.042052843, -0.1476342, 0.03946332, 0.060801215, 0.22708578, -0.033333693,
+    0.19383143, 0.5365229, -0.0739536, 0.16852742, -0.3141569, 0.26812744, 0.010437925, 0.174325,
+    0.016415004, 0.061163317, -0.23461796, -0.26179147, -0.06592173, -0.050045226, 0.20509559, 0.04531652,
+    0.08310654, -0.12158248, -0.19531217, 0.14170201, 0.05153253, -0.012123859, -0.07988555, -0.13830687,
+    0.11161023, -0.1341995, 0.20129262, 0.39731342, 0.110797614, -0.058948033, -0.079589106, 0.2770193,
+    -0.14066847, 0.004645435, -0.27175245, 0.08533526, -0.031819493, 0.11810299, 0.2733307, 0.35853225,
+    0.11983152, -0.3170626, 0.5223272, -0.19985999, 0.07582251, -0.123367034, 0.14487344, -0.095783055,
+    0.30046618, 0.108466156, -0.15210055, 0.0462563, -0.3093984, 0.06904162, 0.25199947, 0.109350435,
+    0.1304325, -0.105547115, -0.16766977, -0.2723809, 0.3251879, -0.093756, 0.07872987, 0.26971132,
+    -0.19455624, 0.1941145, 0.14338855, -0.17850645, 0.13073796, 0.20579562, -0.0747282, 0.04684652,
+    0.24715327, 0.0333616, 0.17307308, 0.43354, -0.12474643, 0.21039966, 0.08287708, -0.026928307,
+    -0.009038681, 0.16085678, 0.19347239, 0.24262318, 0.17126743, 0.06480757, 0.037319753, 0.044334557,
+    0.10301783, -0.04036258, -0.3947254, 0.2802596, -0.23604597, 0.11336432, 0.014724398, 0.134815,
+    -0.099992156, 0.28231543, 0.3068137, 0.01823984, 0.040152114, -0.029296383, -0.16623765, 0.27934384,
+    0.07591022, 0.06773389, -0.2728024, -0.26560873, -0.27025893, 0.29410663, 0.12459715, 0.18906923,
+    0.0047327005, 0.08350062, -0.26349458, 0.07559418, 0.16004337, 0.25797346, 0.05329837, 0.16284314,
+    0.5459552, -0.3221135, 0.10591695, 0.0149047505, 0.25024286, -0.078415126, 0.23294921, 0.09433122,
+    -0.29394218, -0.012785677, 0.050280575, 0.3260195, 0.11007356, -0.10824869, -0.15904117, -0.26342204,
+    -0.12200618, 0.096527256, -0.22026187, 0.14694864, 0.119519345, -0.13415797, 0.16195484, -0.23159374,
+    -0.2155079, 0.275648, 0.30698755, -0.032424778, -0.13508914, 0.20467035, 0.03511231, -0.1294098,
+    -0.21764223, -0.22705697, 0.060464375, -0.17102967, -0.13204364, -0.011918288, -0.029151583, 0.07744204,
+    0.21576354, 0.21234886, -0.0041457186, 0.11303743, 0.050505526, 0.34679917, -0.09155735, 0.22614655,
+    -0.20153312, 0.07215934, -0.0038330872, 0.15587619, -0.21305472, 0.14829239, -0.12600261, 0.07913565,
+    0.091866344, 0.45569924, -0.20081516, 0.07486796, -0.217595, -0.4481317, -0.23953067, -0.14423557,
+    0.110556856, -0.04881563, -0.08786997, -0.04478508, 0.1799415, 0.24907716, -0.25597453, 0.21536486,
+    0.1964726, 0.119418435, 0.07160891, -0.10860932, 0.07609861, 0.10644274, 0.21325754, -0.07001765,
+    -0.028071158, 0.0916467, -0.15547541, -0.04956134, 0.041029375, -0.2345758, 0.24097669, 0.39235675,
+    -0.015718283, -0.1302977, 0.3717465, 0.17368755, 0.06655913, 0.21567512, 0.12061538, 0.13368073,
+    -0.75525695, -0.22125739, 0.07070172, -0.03575434, -0.02177096, -0.0035457863, 0.111404166, 0.17433244,
+    -0.093462504, 0.30004478, 0.20348823, -0.036741, -0.093414895, -0.19091536, -0.07363099, -0.08474058,
+    0.11982702, -0.19574355, 0.19480725, -0.026583897, -0.091268085, 0.0722503, 0.10010432, -0.118665665,
+    0.11692547, 0.058116555, 0.060298566, -0.13511196, -0.23187023, 0.068494104, 0.04971201, 0.2618021,
+    0.11487252, 0.35861334, -0.16055843, -0.2624378, -0.22550754, 0.23821284, 0.29716602, 0.032783914,
+    0.19800875, 0.2433791, 0.304038, 0.08444353, 0.06098448, -0.12269996, 0.1349388, -0.23748352,
+    -0.6638489, -0.14294963, -0.3377518, -0.054411396, -0.050461374, -0.1407712, 0.1865355, -0.2488629,
+    0.28978282, 0.03200565, -0.36347893, 0.08389183, -0.10546344, 0.23605502, 0.31527758, 0.38898155,
+    -0.27526194, -0.113811195, -0.25199965, -0.17719515, -0.046340574, 0.36059037, -0.11382547, -0.17369963,
+    0.11726344, 0.1678208, -0.38428175, -0.23957606, 0.03517283, -0.29707414, -0.10107188, 0.015145786,
+    -0.26565367, 0.39375943, 0.10220895, 0.31667647, 0.19798557, -0.25329632, -0.018085472, 0.19358763,
+    0.020500747, -0.30539024, -0.4435074, 0.1049643, 0.24234104, 0.05931495, -0.13010813, 0.012268508,
+    0.100642934, 0.14916928, 0.18585145, 0.057860658, 0.095802896, -0.11485447, -0.5635483, -0.25382268,
+    0.16459516, 0.10537446, -0.23455176, -0.21310492, -0.052537236, 0.13635656, 0.28941125, 0.3068161,
+    -0.05889667, -0.045150314, -0.23929888, -0.2120876, 0.16702223, 0.32162532, -0.24179375, -0.0048532467,
+    -0.17058991, 0.10466261, -0.1833159, -0.27618095, 0.16691738, -0.4392968, -0.007802493, 0.24139541,
+    -0.016284142, -0.0022572915, 0.212336, -0.048523203, -0.17044733, -0.4685871, 0.45600286, 0.2606133,
+    -0.08525146, 0.28750047, -0.34071156, 0.11310458, -0.06180098, 0.34389463, -0.19986099, 0.54775566,
+    0.051867675, 0.1341463, -0.09556161, -0.0025875508, 0.16810665, -0.27497196, 0.55037075, -0.00087107706,
+    -0.1344792, -0.109981775, 0.09031591, 0.23342358, 0.22611918, 0.13100687, -0.003458225, 0.45874205,
+    -0.4035398, -0.1821793, -0.28500813, -0.012861273, -0.14178598, -0.1167082, -0.14265434, 0.05450078,
+    -0.2181964, 0.44034335, 0.19380341, 0.11359776, -0.44923863, 0.27275705, -0.3670785, 0.0851277,
+    -0.2462913, -0.084017254, -0.06978757, 0.16596518, 0.1333401, -0.1964087, -0.0033481154, 0.11317584,
+    0.42311603, -0.17907462, 0.036025878, 0.15743552, -0.16595447, -0.017698158, 0.06794814, 0.08847,
+    -0.5420677, -0.17815648, 0.09258252, -0.3920562, 0.08089317, 0.32742178, -0.004401355, 0.047831655,
+    -0.057567827, -0.11212062, -0.0024073147, 0.350828, -0.1941969, 0.2264509, -0.06203947, 0.03033255,
+    0.03018801, 0.006216169, -0.17104429, 0.13917707, -0.10500883, -0.19111732, 0.25262573, 0.04316299,
+    -0.039853163, 0.62484, 0.20300056, -0.23811148, -0.27132043, -0.12091511, 0.17352255, 0.26080358,
+    0.001397897, 0.077652775, 0.085067496, -0.19579107, 0.061569896, -0.06836953, -0.106408045, -0.11095276,
+    -0.059576556, 0.23811291, 0.25799125, 0.28595567, -0.13351285, -0.092777945, 0.1960346, -0.08917734,
+    -0.20255448, -0.031466063, -0.097085886, -0.23872204, -0.31573173, 0.043879457, 0.10265812, -0.19531155,
+    -0.16790754, -0.17164, 0.119067065, 0.15415177, 0.2787139, -0.19235101, -0.29728338, -0.17478508,
+    0.20771873, -0.01458645, 0.2248831, 0.11596464, -0.045749355, 0.02787153, 0.17862529, 0.023944924,
+    -0.02698915, -0.0015820566, -0.009855472, 0.13483226, 0.044673074, 0.025932292, 0.5027424, -0.040190723,
+    0.112992726, -0.22332963, -0.14464757, -0.14245173, -0.19834529, 0.528523, -0.25803804, -0.04807241,
+    0.138286, -0.1314598, 0.08720251, -0.09020071, -0.20247501, 0.050932202, -0.12527165, 0.074410416,
+    -0.18562742, -0.008383463, -0.28474942, 0.18396305, 0.15772259, 0.22501889, -0.16570108, -0.040603988,
+    0.12730706, -0.057280853, 0.19727837, 0.11671077, -0.17979433, 0.17786895, -0.08475805, 0.04349832,
+    -0.18344411, -0.31009194, 0.11894243, 0.0009998062, -0.3677799, -0.21845478, 0.014011183, 0.0065903356,
+    -0.24549149, 0.13705838, -0.06448743, -0.18085991, -0.19856006, -0.342968, -0.02250032, 0.17399302,
+    -0.09984757, 0.14754887, -0.12121793, 0.1087925, -0.102882296, -0.30375186, 0.034887828, 0.054322787,
+    0.29148373, -0.274482, -0.39617875, -0.20975816, 0.11450003, 0.22515337, 0.2010993, -0.32387453,
+    0.013340997, 0.28919095, 0.120477825, -0.09102926, -0.32171765, -0.28361908, 0.13018408, -0.1538113,
+    0.035217438, 0.1840459, -0.3358568, -0.008491673, -0.2021397, 0.2939576, 0.0681769, 0.2183828,
+    -0.06561091, -0.18452282, 0.08455299, 0.01712701, -0.005172451, 0.063326575, 0.079231605, -0.029884,
+    -0.121050574, 0.24173377, -0.50198734, -0.08706041, 0.121654324, -0.24163881, -0.066747285, 0.07272865,
+    0.10379852, -0.073303126, 0.29766592, -0.16806038, 0.11276785, -0.050448, 0.018167607, -0.0047757863,
+    0.19462162, -0.01397372, -0.45435652, -0.07788806, -0.20646718, -0.092996314, 0.107530095, 0.14632022,
+    0.19263808, 0.09188206, 0.26657826, 0.3112781, -0.056774314, 0.050684743, -0.18131639, -0.1394811,
+    0.19108069, -0.030829256, -0.22021748, 0.41201863, 0.19537902, 0.35094842, 0.069017045, 0.5111055,
+    -0.33275583, 0.1388007, -0.17541762, 0.039978556, -0.013007461, -0.08287481, -0.38191947, -0.032945994,
+    -0.13927041, 0.44851723, 0.1932017, -0.012487293, -0.19772923, 0.017456848, 0.053283673, -0.11080857,
+    -0.3059627, 0.15933745, -0.24899635, -0.17445882, 0.32400164, -0.16244853, 0.066506386, 0.21214993,
+    0.041407797, -0.121885344, -0.124958284, -0.25100958, -0.030710706, 0.029592482, -0.15621223, 0.14447956,
+    -0.1441786, 0.18312226, -0.27050436, 0.3333026, -0.06377772, 0.09935725, -0.1115009, 0.11693369,
+    0.039197892, -0.25707865, 0.085735925, -0.22803557, 0.012075154, -0.017620401, 0.3287203, -0.19859082,
+    0.611106, -0.164844, 0.14632529, -0.29245594, -0.16127874, 0.07861716, 0.2414802, -0.19007707,
+    0.05543114, -0.50024045, 0.044141017, -0.17968751, -0.0046657138, 0.15545678, 0.09261629, 0.2224749,
+    0.3106193, -0.10917648, 0.14480771, -0.07425276, -0.3558913, 0.26910532, -0.027104145, -0.026806526,
+    -0.08707146, -0.15651153, -0.05860898, -0.2530987, 0.10018018, -0.013968237, 0.1706289, 0.0007476681,
+    0.33489704, -0.17191179, -0.030170472, -0.041556735, -0.15976524, -0.032934226, -0.2645779, 0.1771167,
+    -0.2878467, -0.28141692, -0.02734398, -0.0061415895, 0.17676464, -0.22805986, 0.048043694, -0.4799542,
+    0.04976373, 0.18846892, 0.17128325, 0.33433166, 0.30991185, 0.096720606, 0.37084073, -0.16793086,
+    0.18513873, -0.11114451, 0.051075704, 0.14017491, 0.4289393, -0.16483732, -0.12198858, 0.2121943,
+    0.0918675, 0.17584816, -0.024552858, -0.06113131, -0.37766457, 0.054854196, 0.031091992, -0.3786964,
+    -0.41965538, 0.22669914, 0.27940905, 0.14562444, 0.15405305, -0.11266993, -0.3195657, -0.18514077,
+    0.05541773, -0.022242906, 0.13158605, -0.2853363, 0.06394165, -0.37575814, -0.13785107, 0.24974827,
+    0.19028117, -0.09256502, -0.21114852, -0.2430315, -0.021414753, -0.2992757, 0.36103883, -0.19763897,
+    0.35845366, 0.31815118, 0.5411526, 0.28843868, 0.25803608, 0.030133061, 0.25544542, -0.41607103,
+    0.42132142, -0.5625041, 0.1962168, -0.33768, 0.092727296, 0.31357884, 0.19156133, -0.17504998,
+    0.346012, -0.27045697, -0.0952647, 0.03185356, -0.06731587, -0.23276964, -0.27529165, -0.42711237,
+    -0.2150554, -0.6506, 0.018462818, 0.072980046, 0.43794551, -0.40672728, 0.12278403, -0.44815454,
+    -0.0010725904, -0.027137427, 0.1528533, -0.0057728197, -0.036915727, 0.08943034, -0.10855551, -0.29195473,
+    0.11786474, -0.34504494, 0.11054032, 0.028461479, 0.2186207, 0.14901137, -0.061660394, -0.06649229,
+    0.23321658, 0.018301995, -0.04470979, 0.023742275, -0.26230672, -0.045263838, 0.18562977, -0.30442804,
+    0.22653517, -0.14628997, 0.32490617, 0.23556697, -0.30450395, 0.37570167, 0.24963793, -0.3060908,
+    0.3236687, -0.0020118984, -0.16539386, -0.38512883, 0.08995773, 0.005675923, -0.19131203, -0.036349732,
+    0.20600463, -0.034890164, 0.2796369, -0.07096014, 0.27866697, -0.14643915, -0.20227756, -0.20818792,
+    -0.20832199, -0.042380773, -0.25611004, -0.38502327, 0.30098855, -0.029284658, 0.01027167, 0.09912891,
+    -0.038336456, -0.043093912, -0.27276406, -0.22688676, -0.03844567, -0.09672852, 0.0626544, -0.26583996,
+    0.35205442, 0.16389117, 0.08017514, 0.34394968, 0.3541207, 0.29640707, -0.04187718, 0.010812485,
+    0.21197684, -0.38225904, 0.18883999, -0.3046346, 0.35824135, 0.07391034, -0.058450364, 0.0041505294,
+    -0.0686415, -0.28041807, -0.061616037, 0.0964847, -0.32325712, 0.11246634, -0.22953333, 0.11583494,
+    -0.44414797, -0.011148102, 0.2927593, 0.15187941, -0.10167362, -0.22796267, -0.16377923, 0.20609148,
+    0.27048337, 0.01016033, 0.088987, -0.003377424, 0.16489173, -0.025795272, 0.028299639, -0.14975218,
+    0.22922331, -0.18503684, -0.50481784, -0.501876, -0.1189276, 0.018752702, 0.039382737, -0.4085806,
+    0.32875177, -0.03287791, 0.073804155, -0.036348537, 0.10833901, 0.009841207, -0.17545454, -0.094312415,
+    -0.0017812254, -0.40484023, -0.19801915, -0.19374989, 0.3770799, -0.3342723, 0.15377972, -0.34672508,
+    0.06715228, -0.10597039, 0.04605505, 0.17700976, 0.23008935, 0.10751647, -0.24326824, -0.23439983,
+    -0.36607745, -0.32831854, 0.2217324, -0.25108364, 0.13877967, -0.091188975, -0.031567488, 0.22680417,
+    0.36097294, 0.4101631, -0.11467615, -0.18173857, 0.055992387, 0.085794374, -0.226125, 0.067237996,
+    -0.0059769107, 0.12459057, 0.21712352, 0.54781556, 0.22449926, 0.087609746, -0.11290474, 0.34795392,
+    -0.4389935, -0.18938576, -0.13431354, -0.10091204, 0.066245005, -0.05774719, -0.513985, -0.04985746,
+    -0.42769665, 0.4135072, 0.35200077, 0.057691798, -0.23507573, -0.29063633, -0.09840908, 0.23230714,
+    -0.40997759, 0.105044745, 0.184028, -0.104935184, -0.030516492, -0.2786275, 0.19123651, 0.06110004,
+    0.36306602, 0.38135016, 0.016196806, 0.075553514, -0.1903216, 0.41317934, -0.08871689, 0.4430997,
+    -0.36392322, -0.17892149, -0.12198703, -0.27045882, 0.115025155, 0.06386743, 0.35022137, 0.18009289,
+    0.25299558, 0.26138622, -0.27725184, 0.28722507, -0.18000676, -0.058540475, -0.07093553, 0.08475071,
+    -0.13538036, -0.12903018, 0.019217659, -0.19114074, -0.26347947, -0.12838614, -0.3328107, 0.09474516,
+    -0.31974137, 0.39212018, -0.21946904, 0.17968653, -0.23597711, -0.5043889, -0.13058384, 0.06913188,
+    -0.35594535, 0.27693096, 0.079239, -0.28907037, 0.26470226, -0.01942823, -0.1952277, 0.35504666,
+    0.13499695, 0.27265155, -0.037962418, -0.12439204, -0.0060139764, -0.04761829, 0.16160545, 0.01569291,
+    -0.26250458, 0.036080953, -0.054001078, 0.32795706, -0.18332489, 0.31987584, 0.25672302, 0.26461747,
+    -0.20687488, -0.24888825, -0.49726796, -0.32081068, 0.14381167, -0.13739756, -0.010382305, -0.43751928,
+    0.17317127, 0.24224252, 0.18548687, 0.19307767, 0.0926272, 0.49451265, 0.20300658, -0.16716501,
+    0.32582423, 0.015899612, 0.15055741, 0.11918075, -0.047355797, -0.36995018, 0.37227353, -0.28922147,
+    0.19191773, -0.04578536, -0.019719394, -0.1415962, -0.09094956, 0.17619282, -0.31986833, -0.55706215,
+    -0.25512725, 0.1768791, 0.16500968, 0.019622993, -0.45631745, -0.07544103, -0.43192786, -0.038027078,
+    0.0998818, 0.048084825, -0.09530504, 0.04580086, -0.21522182, -0.16084875, 0.33263063, -0.1065938,
+    -0.12766175, 0.107278235, -0.066997424, -0.46556652, -0.3672068, 0.57636356, 0.050927963, -0.08665127,
+    -0.37792638, 0.2602988, -0.06899173, 0.09942757, 0.23220456, 0.16118424, -0.33284426, -0.55071735,
+    -0.39363003, -0.06844673, -0.16754349, -0.0023543257, 0.16690065, -0.05559936, -0.08988381, -0.10771011,
+    0.20029055, -0.3028529, -0.029472744, -0.27018484, 0.3836494, 0.17531925, 0.031119142, -0.27467722,
+    0.18176623, -0.019251958, -0.01664297, 0.24029128, 0.024464402, 0.19905613, -0.1989768, -0.03648172,
+    -0.16398717, 0.078846246, 0.38680562, -0.050237395, -0.08289708, 0.2553882, -0.20919187, 0.11806728,
+    -0.0994657, -0.10339761, 0.087841734, -0.14947218, 0.0027964858, -0.39007315, -0.21741933, -0.18587007,
+    0.20572756, 0.43210876, 0.0818931, 0.14209135, 0.13933265, -0.09307491, -0.10068746, 0.14083749,
+    0.20213641, -0.221938, 0.12828419, 0.04765786, -0.37167612, 0.39305276, 0.19318578, 0.025951479,
+    0.26538244, -0.14002791, 0.11328788, -0.29763687, -0.002690619, 0.10097149, -0.1445023, 0.1818274,
+    0.08082222, -0.48752227, 0.41975453, -0.014092075, 0.08718934, 0.12016975, -0.27564123, -0.4672939,
+    -0.36530778, -0.32153982, -0.067168936, -0.55409515, 0.15757342, 0.13030922, -0.20309888, -0.029973004,
+    -0.013910755, -0.20339023, 0.53387785, 0.23649414, 0.12498528, 0.12613535, -0.061471336, 0.121843055,
+    0.0037741223, 0.4194849, 0.31266057, 0.37750942, -0.13488182, -0.28289258, 0.11686755, 0.05196702,
+    0.363118, -0.19912633, 0.50609595, 0.10163258, -0.0011678044, 0.69307107, -0.018457975, -0.41678655,
+    0.28105608, -0.38469803, -0.25505129, -0.04016152, 0.098451465, 0.18187363, -0.29223847, -0.24174033,
+    0.06540935, -0.5361218, 0.12919024, -0.1957949, 0.034774583, -0.051027, 0.20829238, -0.2852216,
+    -0.031299103, -0.24366303, -0.20515215, 0.18797784, -0.2252961, 0.18255334, 0.0111251855, -0.024271984,
+    -0.06322288, 0.03530765, 0.14449966, 0.5138088, 0.12864101, -0.057868436, -0.19757307, 0.26892498,
+    0.03076277, 0.02298662, -0.1916766, -0.19569927, -0.034819655, -0.018840883, -0.10615666, -0.05536373,
+    -0.2903895, -0.25169936, 0.25619727, -0.07115864, -0.08572697, -0.1960009, -0.03731379, -0.11888349,
+    -0.067095205, 0.1324175, 0.27349743, -0.22097029, 0.28075334, -0.26977786, -0.14041105, -0.09701031,
+    0.0057117925, -0.10748, -0.17989941, 0.30849174, 0.19611125, 0.08050424, -0.15262818, 0.15577984,
+    0.15650995, 0.14220826, -0.075186074, 0.2467255, 0.06463334, -0.6856548, 0.036560107, 0.30782023,
+    0.41198677, 0.20250545, -0.19046922, 0.13865043, 0.01088815, -0.04975009, 0.17517164, 0.43852243,
+    0.4798941, -0.05116848, -0.06941376, -0.482601, -0.32233363, -0.24176626, 0.035870835, -0.13362212,
+    0.23060955, 0.1505007, -0.52169096, 0.03115167, -0.47764906, 0.24656636, 0.0569994, 0.06283795,
+    -0.18319462, -0.022801207, -0.2251872, 0.15988837, 0.3944025, -0.09305672, -0.2185739, 0.44354308,
+    0.10624405, -0.16661516, -0.32930872, -0.21644066, -0.11336395, -0.20049025, 0.13534315, 0.046711948,
+    -0.2578861, 0.22839102, 0.10773101, -0.04495977, 0.28882882, -0.1811953, 0.21054538, -0.22840057,
+    -0.10795251, 0.13010094, -0.32498583, -0.20943798, 0.22075257, -0.17418364, -0.2424553, -0.10998762,
+    0.45527694, 0.12992452, 0.062193274, 0.15362424, 0.51583153, -0.0893416, -0.4721235, -0.22574769,
+    0.082749456, 0.1289701, 0.028663535, -0.010199401, 0.16864455, 0.07427616, 0.13784231, -0.38133365,
+    0.21622132, -0.032801047, -0.10997189, -0.18137553, -0.044100422, 0.38137978, -0.080699585, -0.17693765,
+    0.18952198, -0.24323635, -0.26682326, -0.26370546, 0.10479603, -0.011097772, 0.0068557905, 0.39794576,
+    0.22770792, 0.18372053, 0.013338323, 0.33372107, 0.043623265, 0.07030094, 0.12993017, 0.15988976,
+    0.07380829, -0.14990142, -0.01658976, 0.2676087, -0.1833986, 0.0063214004, -0.3425113, 0.31832618,
+    -0.35546827, -0.36544076, -0.025230214, -0.4130393, 0.06542707, 0.114534006, 0.47623873, 0.11890792,
+    -0.06575763, 0.33507735, 0.30085647, 0.15772448, -0.19288059, 0.032106593, -0.06353489, -0.031594582,
+    -0.14483641, -0.016445065, 0.18206961, 0.34437802, 0.15345621, 0.10062581, 0.17565252, 0.082637966,
+    0.18353672, 0.23782045, 0.19925585, -0.08299926, 0.058515634, -0.03690776, 0.40522242, -0.3536414,
+    -0.00087227224, -0.074371666, 0.27305564, -0.05109516, -0.010971621, 0.23741846, 0.47942024, 0.04114151,
+    -0.22956066, 0.038661994, -0.12806207, -0.12498571, -0.16323416, 0.013209926, 0.24623734, -0.24314104,
+    0.16183655, 0.12314024, 0.20526612, -0.04501898, 0.24901497, 0.11538632, 0.4430831, -0.2530481,
+    0.30065686, -0.48615828, 0.09461741, 0.060061786, 0.08518774, 0.64113724, 0.062202524, 0.007704217,
+    0.2925652, 0.06859305, -0.07697512, 0.20868109, -0.31448114, 0.13592571, -0.08754797, -0.28462154,
+    -0.26064634, -0.2768453, 0.40969273, -0.24949351, 0.14430945, -0.2186626, 0.16270867, -0.36536396,
+    0.22730002, -0.049092535, 0.07788089, -0.3000281, 0.12470266, -0.25849602, -0.23230635, 0.17178725,
+    0.06368916, 0.269653, -0.08870049, 0.22822796, -0.194247, -0.13166444, -0.06621045, 0.16437241,
+    -0.3407898, -0.37936258, 0.028052667, -0.28659928, 0.28005856, 0.27642098, -0.050300602, 0.0677482,
+    0.079488866, 0.29843733, 0.20831776, -0.013091699, -0.04300088, -0.3495088, -0.20421955, -0.012256931,
+    -0.36271435, 0.19126017, -0.015166769, 0.26032418, 0.09269089, -0.2156016, -0.013945156, 0.27470416,
+    0.071463086, 0.50797373, -0.057241186, -0.069529295, -0.034820125, 0.27047428, 0.33443698, 0.16865483,
+    0.0040568626, 0.18152072, 0.038393814, 0.16874062, 0.16503607, 0.15061192, 0.13960415, -0.5079759,
+    0.18539338, 0.18152776, 0.17944846, -0.012523006, -0.4453317, 0.23173755, 0.04495542, 0.04123958,
+    -0.38331822, -0.2508054, 0.11652485, -0.26674926, -0.29364622, 0.28086245, 0.15533587, -0.09353057,
+    0.13379681, -0.43619806, -0.010296074, -0.1253408, 0.1619276, -0.18914235, -0.27459517, 0.029880008,
+    -0.101740114, 0.07387994, 0.27275094, 0.6160186, 0.3040144, -0.31359416, 0.04254863, -0.00220815,
+    -0.092052, -0.2403133, -0.13169707, -0.15805785, -0.10134281, -0.11357938, -0.22829717, 0.1542329,
+    0.0031080795, -0.0017588607, 0.11433016, -0.14717403, -0.068398125, -0.3951172, -0.050049175, -0.33904204,
+    0.03753049, 0.07614165, -0.2873114, -0.025308046, -0.086994015, -0.120752804, 0.07004274, -0.22023982,
+    -0.09564676, -0.116018966, -0.15620919, -0.10673544, 0.22567396, 0.16321309, 0.17221326, -0.05836564,
+    -0.35290605, -0.022578605, -0.44054478, -0.22377133, 0.79714334, -0.6375729, -0.44510573, 0.6033535,
+    -0.14370175, 0.17075095, 0.52244097, 0.29171517, -0.27190563, -0.1934024, 0.49150276, -0.15159015,
+    0.29856762, 0.22859333, -0.17748725, 0.3164548, -0.30504736, -0.12632702, -0.1482142, 0.57398105,
+    -0.044013694, 0.2636497, 0.15353738, -0.2624733, -0.10142316, -0.07953872, -0.16986297, -0.10580254,
+    0.35615128, -0.16309756, 0.06866607, -0.05787621, -0.18101674, 0.3683932, -0.08939288, -0.19661567,
+    0.024953907, -0.06320786, 0.3717461, 0.18633044, -0.031884816, -0.05476718, -0.14447778, -0.152967,
+    0.0010689074, -0.044294227, 0.064671315, -0.022553023, 0.006015295, -0.45315558, -0.260746, -0.051239446,
+    0.15229675, 0.011886396, 0.15731186, -0.07451605, -0.034038402, 0.031655643, -0.13247433, 0.14063698,
+    0.12561013, 0.24461251, -0.15652937, 0.13233827, 0.15207553, 0.23519361, -0.09142679, 0.18147172,
+    -0.0013254264, 0.24291763, -0.061942976, 0.26094612, -0.181562, -0.15096524, -0.23086755, -0.33770153,
+    -0.43775752, -0.21752046, 0.02953493, 0.047068764, 0.38058802, 0.045536373, -0.41709056, 0.12550294,
+    -0.26024136, 0.55134326, -0.3267561, -0.1486559, 0.14839025, -0.24168618, 0.1441456, 0.14479932,
+    -0.082198784, -0.15282208, 0.19064699, 0.34065562, -0.038050137, 0.5076981, 0.45080534, -0.14506143,
+    0.16048643, -0.18259995, -0.3872433, -0.2530452, -0.021504799, -0.14275719, -0.1565665, -0.24219783,
+    0.09908211, -0.57491225, -0.24810381, -0.01052558, -0.019344257, -0.045143347, -0.003261597, -0.1539827,
+    0.051855143, -0.23085327, -0.32651317, -0.13094175, 0.45210564, 0.29669467, 0.16126758, 0.10732481,
+    0.051355604, 0.22082783, 0.08273568, 0.054231763, -0.1421399, 0.18608342, 0.21545447, 0.25581622,
+    -0.2337511, -0.30442756, -0.23773018, -0.20871742, -0.50846666, 0.081730366, 0.015183522, 0.26294738,
+    -0.14739926, -0.039050844, 0.08252388, -0.06901957, -0.10083284, -0.22236736, -0.22527461, 0.3902238,
+    -0.08980929, -0.17627999, 0.21018095, -0.16186184, 0.08243487, -0.105155274, 0.57682586, 0.07936491,
+    -0.0150465835, 0.008408676, -0.087328784, -0.09113362, -0.17735103, 0.1243291, 0.2624218, -0.12101144,
+    -0.14323322, -0.07660119, -0.19402148, 0.20712958, 0.19618192, -0.19849451, 0.29521313, 0.015057986,
+    0.37881094, 0.23257425, -0.20053759, 0.3684748, 0.20135376, -0.09156218, -0.04684596, 0.42743507,
+    -0.124059714, -0.18260853, -0.1695771, -0.40180638, 0.03267382, 0.077934556, -0.2164726, 0.08072734,
+    0.12913647, 0.5824975, -0.17642163, -0.19527459, -0.16338551, 0.14392047, -0.007265634, -0.08085977,
+    -0.32179868, 0.18157145, -0.036933523, 0.09562969, 0.00079575565, -0.42642102, 0.114680275, 0.25672778,
+    0.34194526, -0.03452051, -0.15621006, -0.18979149, 0.23452103, 0.11580107, 0.1108936, 0.018421778,
+    0.017092258, 0.22554442, -0.04472491, 0.19451044, 0.21720804, 0.43395767, -0.08243846, 0.10213876,
+    -0.12676017, -0.033720348, 0.5034376, 0.16419002, 0.31195047, 0.19767419, 0.08269605, 0.3032549,
+    -0.38589743, -0.21835916, -0.09000633, -0.18099141, -0.14912434, -0.053705685, 0.15328014, 0.4065955,
+    -0.24868357, 0.44465503, 0.240873, 0.2227914, -0.30765012, 0.115030594, -0.12961666, 0.040043954,
+    -0.22827317, 0.07935266, 0.14926703, -0.127974, 0.07471315, 0.060775533, 0.10779759, 0.07585504,
+    0.026407488, -0.112948, -0.17217849, -0.090790026, 0.11930856, 0.0028808573, 0.12632702, -0.20428383,
+    -0.116987795, -0.37917715, -0.14613828, -0.23368278, 0.15262601, 0.24795566, 0.18614209, 0.042320795,
+    -0.49612573, -0.38869154, 0.32104233, -0.09724999, 0.093728244, -0.16403632, -0.3913508, -0.5073882,
+    -0.045476202, -0.04476761, 0.062477726, 0.002395873, 0.14681455, -0.17316921, 0.28852797, 0.015097416,
+    0.2421307, -0.37269002, 0.10779546, 0.12280738, -0.34115246, 0.82114637, 0.13066684, -0.48397085,
+    0.627417, 0.13466257, -0.056249402, -0.33711708, -0.07518379, 0.1483746, -0.1608029, 0.09142123,
+    0.09125753, -0.8742171, -0.03027173, 0.028944774, 0.007423456, 0.23133598, 0.08646606, -0.23785216,
+    0.3635232, -0.23946907, -0.44898963, 0.030403648, -0.113223866, 0.20068896, 0.13917616, -0.0067939255,
+    -0.07183072, -0.119516015, 0.3469794, -0.21730943, -0.058473833, -0.16300263, 0.050393183, -0.33981115,
+    -0.16499656, 0.04820186, -0.36040375, -0.012952352, 0.07987961, -0.059755296, 0.104500666, 0.18469913,
+    0.13728501, -0.14365582, 0.077488504, 0.074866004, -0.4307138, 0.4421847, 0.13754982, -0.0035362695,
+    -0.12257809, 0.014058136, -0.3524649, 0.081298746, -0.24418582, -0.07734573, 0.14230901, -0.011020386,
+    -0.059513327, -0.12562336, 0.094462834, -0.028592568, -0.18302685, 0.24099039, 0.29782888, -0.32406092,
+    -0.19215615, -0.20996529, -0.4706648, -0.5919981, -0.15854678, 0.1184366, 0.1552016, 0.24975006,
+    -0.0063565467, -0.28208476, -0.008995287, 0.5066546, 0.23809506, 0.024335507, -0.24359488, 0.20374988,
+    -0.38523862, -0.044926815, 0.042660493, -0.18661317, 0.14451034, -0.0054489262, 0.32493284, -0.13708653,
+    -0.039090525, 0.27844897, 0.15767519, 0.06996652, -0.31241608, -0.117189154, -0.3053032, 0.24814484,
+    -0.27975124, 0.22195393, 0.25866503, 0.018183062, 0.05940479, -0.26216936, -0.015106339, 0.22800213,
+    0.076517284, 0.51208335, -0.19510114, 0.44116876, 0.037107233, -0.034478124, 0.17934646, -0.13118005,
+    0.02098724, -0.34388068, -0.014472663, -0.31607226, -0.04067469, -0.10580046, -0.16120858, -0.32962352,
+    0.28392398, 0.22701943, -0.4017023, 0.27140483, -0.09017537, 0.19316275, 0.40909174, 0.3490266,
+    0.020369904, -0.4594581, -0.11212654, -0.2498679, -0.09734969, -0.17917325, -0.16262841, 0.15567194,
+    -0.47053644, 0.12583545, -0.4848809, -0.2547148, 0.2578793, -0.6726447, -0.2353568, 0.28585765,
+    -0.4758388, -0.09815418, 0.13817652, 0.16077308, -0.111797646, -0.17972955, -0.022361318, 0.13674554,
+    -0.11597816, 0.65153015, -0.05958639, 0.24990205, -0.10786678, -0.11198587, -0.010012183, 0.6464309,
+    0.08112556, 0.12540829, 0.12842567, 0.24678238, 0.0024493474, -0.1698183, -0.026077256, 0.19101228,
+    -0.04943454, 0.06203392, -0.22723266, -0.055612285, -0.02825165, -0.19107163, -0.35545123, -0.36789957,
+    -0.61798835, 0.4039778, -0.016307801, 0.22858249, 0.08604402, 0.10809733, 0.22988407, 0.15100633,
+    0.23181663, 0.21635132, 0.11216915, 0.16880502, 0.2158213, 0.004771252, 0.23188941, 0.013215537,
+    0.22408958, -0.023671266, -0.24438323, -0.08433173, 0.023964027, 0.018322198, 0.15039684, -0.25638726,
+    0.04749789, 0.32355416, 0.25120333, 0.010516572, 0.027408637, 0.1400715, -0.003689819, 0.030271541,
+    -0.105797626, -0.12868662, -0.3320205, 0.11790426, -0.10079984, 0.15007105, -0.23920582, -0.17579417,
+    -0.04267251, -0.07906835, -0.053582, 0.026176857, 0.1299646, 0.15819843, 0.0139212655, 0.5071707,
+    0.03540385, -0.3565347, -0.42341405, -0.39048862, 0.07847688, -0.24112418, -0.22035663, 0.42303252,
+    -0.31667387, 0.64781886, 0.106096014, 0.19540276, -0.015866034, 0.12259104, -0.1592477, 0.16793169,
+    -0.19499455, -0.14871788, -0.117478184, -0.19166268, 0.102135636, -0.4494887, 0.36619467, 0.43686086,
+    0.31618464, -0.056154147, -0.41570324, -0.25738856, 0.2033945, 0.115161166, 0.06389184, 0.20188132,
+    -0.1593883, -0.14648785, 0.085356496, 0.22421457, 0.12948813, 0.25869986, 0.13606322, -0.1508609,
+    -0.22009242, -0.116660886, 0.31295598, -0.14848426, 0.067245886, 0.20293188, -0.20068699, -0.04292899,
+    -0.25477237, -0.08368966, -0.137177, 0.4610036, 0.3186804, 0.042226657, 0.1963915, -0.2624228,
+    -0.22486188, 0.108967304, 0.0017044166, 0.14101484, 0.20591386, -0.17246386, -0.112748794, -0.052551545,
+    0.015239593, 0.17229569, -0.26264834, 0.13487285, 0.2737402, 0.50742465, -0.33220312, 0.0025389777,
+    0.20192343, -0.08360862, -0.14897017, -0.33148757, -0.25270978, 0.23776604, -0.23732929, -0.32021672,
+    0.031381227, -0.15596321, -0.24705894, 0.49536186, -0.35382366, 0.043983933, 0.08144267, -0.11247835,
+    -0.27128375, 0.08401746, 0.26864728, 0.13717131, -0.23631936, -0.013817188, -0.22314772, -0.2949409,
+    -0.070778586, 0.3484284, 0.10529646, 0.25075153, 0.23110692, -0.21930738, 0.084170535, 0.11572107,
+    -0.006051873, -0.29206166, 0.539129, -0.052768335, 0.12296317, 0.15168259, 0.09811824, -0.38157973,
+    0.03843111, -0.45084122, 0.3384139, -0.2299615, -0.353422, -0.22463705, 0.117984705, 0.04808957,
+    0.21848615, -0.19077468, 0.26171556, 0.14414413, -0.14567271, 0.13607563, -0.08796146, -0.5509302,
+    -0.06785311, -0.16624603, 0.1799231, -0.37829053, -0.18084791, -0.4708911, 0.18746518, 0.43946984,
+    0.2742823, 0.302573, -0.4022815, 0.012975743, -0.15330605, -0.11825321, 0.03444063, -0.4864229,
+    -0.011953194, 0.17275953, -0.054006148, 0.23912366, -0.09211241, 0.26471418, -0.21216032, -0.0827767,
+    -0.0961552, -0.59452635, -0.16757777, -0.00464475, 0.5622948, -0.81450474, 0.0010971039, 0.03491811,
+    0.059912276, -0.08011748, 0.3685634, 0.014362778, -0.02332932, 0.014307854, -0.0257537, 0.059321303,
+    0.10113269, -0.19046986, -0.21207474, 0.16075175, 0.15979937, -0.03815166, -0.24742031, 0.13011634,
+    0.2788353, 0.28539595, 0.118321925, -0.14556631, 0.08037794, -0.14399566, -0.41989124, 0.098541304,
+    0.39327568, -0.12571736, -0.24452718, 0.06571114, -0.29386678, 0.3363354, 0.26274872, 0.1454151,
+    -0.096714586, -0.1803371, -0.112306766, -0.14285141, -0.41471136, 0.4106046, -0.22789119, -0.11693081,
+    0.051806934, -0.12193676, -0.44395313, -0.19105706, 0.3283845, -0.56109244, -0.13854069, 0.09965707,
+    0.019554831, 0.29445645, 0.36447653, 0.061743252, 0.021673877, -0.2784534, 0.2944922, 0.20146264,
+    -0.24444336, 0.05580682, -0.11812738, 0.065767415, 0.08765713, -0.036759783, -0.09040064, 0.24294193,
+    0.033675715, 0.30948013, 0.6761423, 0.29844564, -0.31326804, -0.1679695, -0.24185647, -0.19491258,
+    0.09350069, -0.03433465, -0.04127388, -0.43653482, 0.19907506, 0.05920522, -0.1928002, 0.09935985,
+    -0.016454704, 0.3498128, -0.07944347, 0.07663734, 0.32720342, -0.24945816, -0.3072868, -0.0073913657,
+    0.04852547, 0.00068643136, 0.3877237, 0.15009685, 0.32815787, -0.27425942, 0.28039175, 0.058943674,
+    0.3669901, -0.082512334, -0.1895658, -0.40581033, 0.012109458, 0.07102928, 0.0265966, -0.31421125,
+    0.030315546, 0.32304132, 0.3974482, -0.08700267, -0.29823577, 0.40315753, 0.1813092, 0.33704996,
+    -0.1081694, 0.1582738, 0.026611865, 0.41839787, 0.116265304, -0.25455016, 0.016024565, 0.0054398878,
+    -0.06885193, 0.2603302, -0.10815773, -0.060657106, 0.10683084, 0.10447663, 0.04082328, -0.096838154,
+    -0.05961041, -0.12600988, -0.31617907, -0.049838543, 0.107255764, 0.1177652, 0.21582693, -0.07304778,
+    -0.112554334, -0.025003163, 0.04795159, -0.12821625, 0.11380368, -0.18923846, -0.2884753, 0.49627927,
+    -0.3666376, 0.24062873, -0.08812703, 0.124644324, 0.03011803, -0.44690025, 0.5201653, 0.37907854,
+    0.14815304, 0.35215312, -0.1656442, 0.124376155, -0.34551716, 0.24619336, -0.04501532, 0.3181766,
+    -0.10550734, 0.09400847, 0.13786577, -0.16834651, -0.084028736, -0.01743433, 0.68498886, -0.12320146,
+    0.050604504, 0.05214321, -0.46809912, 0.021986749, 0.017030306, 0.2831584, 0.20490438, 0.08128535,
+    -0.16653273, -0.21501718, 0.02531217, -0.10840348, 0.4692379, 0.43044105, 0.069830626, -0.18221927,
+    -0.24468513, 0.24172077, -0.026204607, -0.13722159, -0.26358926, -0.22323796, -0.015965529, 0.13967837,
+    0.0630693, 0.23963784, -0.11935483, -0.13976152, 0.24891047, -0.013323636, -0.0608007, 0.10207419,
+    -0.09859167, 0.046938986, -0.12332632, 0.009415863, 0.01256048, -0.06971921, -0.10738587, 0.046598166,
+    -0.5890778, 0.22838652, 0.10602042, -0.1084386, -0.06218884, 0.08383689, 0.09926587, -0.06794664,
+    -0.13040671, -0.017541088, 0.22206552, -0.16013283, -0.021809665, -0.13890284, 0.04175804, -0.14732505,
+    -0.10625076, -0.03548618, 0.4062515, 0.13312785, 0.25684458, -0.13614558, 0.18303277, -0.11593899,
+    0.40116706, -0.114522524, 0.10107399, 0.027987158, -0.13581887, 0.7450769, 0.5148353, -0.31274098,
+    0.34759104, -0.124008656, -0.1191495, -0.270632, 0.02494603, 0.2622803, -0.3789197, 0.08979705,
+    0.05464815, -0.402282, 0.25936988, -0.11527705, 0.2728637, 0.30108532, -0.13331303, -0.06350593,
+    0.31903177, -0.20405489, 0.035614345, -0.31606787, 0.032397874, 0.07424447, -0.39125246, -0.027467031,
+    -0.018817952, 0.09091017, 0.07051459, -0.03455168, -0.078428715, 0.16003133, 0.27637443, 0.0036308346,
+    0.469779, 0.06286515, 0.44640866, 0.18798324, 0.053105835, 0.1945294, 0.13416937, -0.08702687,
+    0.10769408, -0.16736855, -0.08474235, 0.16873622, 0.3274221, 0.058049396, 0.35788304, -0.37973914,
+    -0.081992015, -0.3141768, -0.25877786, -0.109754376, 0.008666903, 0.013251435, -0.123788126, -0.1969775,
+    -0.42120075, 0.11865906, -0.18364544, -0.18249245, 0.30729938, -0.0032012733, -0.044938378, -0.027718302,
+    0.10728438, -0.21611403, -0.20903337, -0.052484397, -0.49725667, 0.11682997, -0.12517257, 0.36161286,
+];
+
+#[rustfmt::skip]
+pub(super) static GRU_BIAS: [f32; 3 * GRU_SIZE] = [
+    -0.22027858, -0.40742913, 0.006456671, -0.10092837, 0.09464471, 0.3113782, -0.08014255, 0.054830655,
+    0.13071805, -0.05522445, -0.49914885, -0.10488332, -0.033977084, -0.21687591, -0.36296675, -0.21829951,
+    0.26442137, 0.4455486, -0.43114382, -0.7232403, 0.12730348, -0.026717667, -0.13083513, 0.110994846,
+    -0.09318728, -0.5627969, 0.1744459, -0.062442485, 0.042127214, -0.10574195, -0.34849507, -0.21001591,
+    -0.08153548, -0.31215277, -0.16195254, -0.21262814, -0.42029518, -0.18228503, 0.07240045, -0.3107475,
+    -0.017285157, -0.091062754, -0.2550172, 0.30939886, -0.24611254, 0.26522687, -0.30158564, 0.25730884,
+    0.11071081, -0.0012048016, -0.12374076, 0.057002578, 0.034371916, 0.11947909, 0.049711302, 0.19282371,
+    0.0064145736, 0.003068718, -0.004713512, -0.08797931, 0.041154575, -0.084440105, -0.05605922, 0.042470716,
+    0.013645279, 0.23046672, -0.06700509, -0.049289368, 0.14502977, 0.09205934, 0.080826625, 0.057725374,
+    0.017438954, -0.014089702, 0.18120487, 0.16735806, 0.050158866, 0.054388605, 0.09451181, 0.05873856,
+    -0.04576889, 0.11897947, 0.037682354, -0.043098446, 0.0479811, 0.025189284, -0.03760603, -0.14638801,
+    0.08540155, -0.07676928, 0.014234877, 0.1530572, -0.004555822, 0.1437188, 0.01523373, 0.047726944,
+    -0.22943436, -0.12957978, -0.112389326, -0.08181575, 0.21887806, -0.071536936, -0.120904624, -0.00067840423,
+    -0.10736487, 0.12196755, -0.02641526, 0.13850035, 0.07104521, 0.06920513, -0.028569898, -0.031672183,
+    -0.1539317, -0.015593508, 0.13506427, 0.29327375, -0.025908818, -0.014669545, 0.12872873, -0.095797755,
+    -0.012491457, -0.15427414, -0.24416915, -0.30086806, -0.11569111, 0.16765246, -0.19051738, -0.08147152,
+    -0.0042652884, 0.39099148, 0.111712806, -0.16710232, -0.19469295, 0.24529444, 0.0962064, 0.26500815,
+    -0.05803164, -0.14069144, -0.20793565, 0.14333773, -0.14674242, -0.10778722, 0.1238999, 0.18902133,
+];
+
+#[rustfmt::skip]
+pub(super) static OUTPUT_WEIGHTS: [f32; NB_BANDS * GRU_SIZE] = [
+    0.025838245, 0.19196472, 0.33313566, -0.017603168, -0.2526748, 0.0054705725, 0.3606827, -0.12282703,
+    0.1886975, -0.098370016, -0.32788718, -0.35266894, -0.3412871, -0.5792274, 0.4844128, -0.0125574805,
+    0.068197675, -0.6030488, 0.046092834, -0.2941385, 0.22260922, 0.20754631, -1.149613, 0.20604245,
+    -0.24082884, 0.4768434, 0.12444768, 0.41308197, 0.006734166, -0.12114638, 0.09597237, -0.054208312,
+    0.33681223, -0.02361153, -0.054384947, 0.9096067, 0.054207355, 0.24021345, -0.13484257, -0.31492004,
+    -0.5748622, 0.045049198, 0.05584092, -0.391792, 0.6101357, 0.13386875, -0.5150821, -0.72905266,
+    0.29418817, -0.033254724, 0.20568304, 0.36341873, -0.22458224, -0.032082874, -0.08194475, 0.11923194,
+    -0.019441625, -0.057625562, -0.5352753, -0.14786935, -0.26592845, -0.44471723, 0.60750186, 0.39802048,
+    0.17282224, -0.21953407, 0.16630597, -0.38045248, 0.1950651, 0.12804936, -0.82188815, -0.24306837,
+    -0.0714048, 0.18458644, 0.2784289, 0.49171007, -0.14110239, 0.09142981, 0.23788589, 0.02595317,
+    0.44808054, -0.2582724, -0.24975468, 1.0517344, 0.22949968, 0.17770728, 0.21162243, -0.31137067,
+    -0.72602296, -0.22275704, 0.1692925, -0.21454102, 0.610409, 0.10862471, -0.5319815, -0.5526355,
+    0.022461487, 0.16604204, 0.55521494, 0.2948267, -0.3110882, 0.043967005, 0.16740242, -0.0024412486,
+    -0.096594796, 0.09891252, -0.5443887, -0.20099561, -0.37929314, -0.54980254, 0.32689944, 0.39596826,
+    -0.035736635, 0.15866749, -0.15462145, -0.21411595, 0.28059795, 0.17007796, -0.41029212, -0.017053716,
+    -0.45133868, 0.30333567, 0.31845278, 0.5233201, -0.042611748, 0.011045031, 0.16799115, 0.024009889,
+    0.48402977, -0.13072896, -0.12686172, 0.96969676, 0.1144922, -0.0371123, 0.38909486, -0.09692894,
+    -0.78093094, -0.3611153, 0.20255023, -0.14150915, 0.49542025, 0.103689864, -0.39669734, -0.5199314,
+    0.17561363, 0.22512823, 0.59776884, 0.5761322, -0.1279911, 0.021069715, 0.104136616, 0.33251336,
+    -0.52001274, -0.18269527, -0.45703745, -0.2437165, -0.27238774, -0.37365615, 0.45326814, 0.26241446,
+    -0.2597282, 0.053519547, -0.45600498, -0.25128224, -0.044186946, 0.11156485, -0.12969719, 0.5396041,
+    -0.36834732, 0.26092803, 0.19371025, 0.5720482, 0.05935239, -0.017131511, 0.17945358, 0.22512162,
+    0.496677, -0.13668045, -0.2559133, 0.8872973, 0.015067916, 0.23412624, 0.60810983, -0.2590939,
+    -0.12187061, -0.42359808, 0.012079233, -0.24435993, 0.17568281, 0.17630899, -0.2921503, -0.6390547,
+    -0.06965368, 0.48405963, 0.4889865, 0.49861756, -0.12341814, -0.113374, 0.07182902, 0.007299745,
+    -0.46000004, -0.079900056, -0.37562534, -0.237302, -0.4822895, -0.55961376, 0.2632389, 0.2969843,
+    -0.1872405, 0.22392435, -0.64271176, -0.23809645, 0.037834834, 0.4776953, -0.023273837, 0.7014972,
+    -0.441011, 0.4306846, 0.24885125, 0.5220884, 0.16260497, -0.1914074, 0.08417585, 0.28351986,
+    0.4753502, -0.25728014, -0.21569498, 0.7970249, 0.077646874, 0.26115638, 0.67100453, -0.015796356,
+    0.27879325, -0.41807038, 0.017311344, -0.19363321, 0.03930987, 0.39405665, -0.06824121, -0.6107449,
+    0.21085255, 0.2672691, 0.38365066, 0.511745, -0.18167613, 0.11521552, 0.24329232, 0.07163101,
+    -0.6203877, -0.19765162, -0.27204138, -0.27224094, -0.60418856, -0.3100082, 0.58414483, 0.085092895,
+    -0.07589108, 0.2389691, -0.655305, -0.20249176, 0.32232583, 0.20892507, 0.038222544, 0.6891196,
+    -0.48439208, 0.7607123, -0.17549576, 0.4251333, 0.26273018, -0.06706111, -0.11102411, 0.35040402,
+    0.35242364, -0.16738525, -0.22420876, 0.79179204, 0.2769923, 0.19973667, 0.5485642, -0.21470743,
+    0.23471992, -0.5670464, 0.040776663, -0.31721768, 0.097911194, 0.13367078, -0.0947164, -0.2757036,
+    0.07609638, 0.17953958, 0.3506444, 0.57240456, -0.26302388, -0.091749325, 0.012188557, 0.26272288,
+    -0.6662226, -0.10454514, -0.40761817, -0.15411831, -0.40081224, -0.43125978, 0.46389782, 0.43418798,
+    -0.24034724, 0.4456805, -0.5282081, -0.16606349, 0.18647787, 0.13142775, -0.012104589, 0.5524472,
+    -0.32617825, 0.626946, 0.34593636, 0.43804505, 0.1983353, -0.07909761, -0.15379845, 0.12397827,
+    0.16495356, -0.28709397, -0.26072207, 0.7460616, 0.476635, -0.08705106, 0.7345256, 0.025918728,
+    0.3851599, -0.593157, 0.4308779, -0.27048454, 0.32137617, 0.35142693, -0.05067527, -0.4084134,
+    0.2869545, 0.32071978, 0.4485649, 0.61232656, -0.30477017, 0.0073734163, 0.191858, 0.092730395,
+    -0.44121426, -0.25210875, -0.46281368, -0.13350762, -0.44110024, -0.20262732, 0.57360065, 0.25272068,
+    -0.21534953, 0.38110313, -0.48191234, -0.28451842, 0.38087094, -0.17576212, -0.12881748, 0.8881311,
+    -0.4638357, 0.4939615, -0.08944364, 0.38393128, 0.19226305, -0.38306054, 0.054242842, 0.19529521,
+    0.3136754, -0.35367996, -0.120255224, 0.62881345, 0.3151696, -0.024074374, 0.68842286, -0.107326135,
+    0.2266915, -0.328526, 0.2685659, -0.014445143, 0.28460738, 0.27539608, 0.006620111, -0.2553883,
+    0.19483128, 0.17653817, 0.46495125, 0.6015924, -0.24488465, 0.2000559, 0.13928095, 0.1690622,
+    -0.5362869, -0.39286667, -0.30213314, -0.4290899, -0.40953326, -0.25458524, 0.43101683, 0.3959061,
+    -0.13418709, 0.12236726, -0.6612155, -0.54052055, 0.34774715, -0.25213742, -0.082912356, 1.0488322,
+    -0.6570677, 0.30433854, 0.06350871, 0.35615078, 0.21751082, -0.13573214, 0.5660977, 0.19552837,
+    0.11337705, -0.25321853, -0.30585116, 0.36016196, 0.22252184, 0.11218069, 0.42712855, 0.112615086,
+    0.19371712, -0.33740497, 0.25486875, 0.0032340437, 0.5185431, 0.2836579, -0.30855724, -0.10779874,
+    0.20437203, 0.23452243, 0.28946203, 0.44125873, -0.1000462, 0.025464127, 0.25248775, 0.13527858,
+    -0.61202013, -0.5366548, -0.27569866, -0.33897611, -0.3654995, -0.29547176, 0.58523834, 0.36144906,
+    -0.25430462, 0.14363432, -0.44689003, -0.54061854, 0.31461114, -0.11307885, -0.22611047, 0.73769933,
+    -0.3713722, 0.19214132, 0.047836095, 0.37366015, 0.21420516, -0.3403207, 0.78908384, 0.057483166,
+    0.35776612, -0.2878905, -0.3025792, -0.32808605, 0.3280338, 0.10797243, 0.61341375, 0.0864874,
+    0.24009636, -0.47216994, 0.22021438, 0.09201303, 0.612007, 0.3149356, -0.5716497, -0.061216313,
+    0.17185241, 0.54228413, 0.45929703, 0.25707933, -0.19278221, -0.0075038034, 0.42083904, 0.37740606,
+    -0.6918407, -0.50345206, -0.35944438, -0.11934216, -0.08563452, -0.60276055, 0.7845583, 0.36089256,
+    -0.05125278, 0.058504194, -0.6390299, -0.44582114, 0.25905624, -0.17520685, -0.27342364, 0.5155434,
+    -0.22871242, 0.33121896, -0.039793853, 0.2686933, 0.13195759, -0.1508176, 0.61545885, 0.31648454,
+    0.2005624, -0.033528216, -0.33099478, -0.3704653, 0.21176448, -0.07481709, 0.36513454, 0.052451566,
+    0.10258123, -0.2597119, 0.35264117, 0.094261505, 0.3482966, 0.11635437, -0.83619744, -0.018927762,
+    0.076845534, 0.60113263, 0.17649224, 0.14061332, 0.002487605, -0.09128244, 0.26628676, 0.40302286,
+    -0.50430727, -0.5106525, -0.28482062, -0.46433035, -0.38195482, -0.5539954, 0.5423138, 0.19604613,
+    -0.17661253, 0.20284626, -0.51505077, -0.41988796, 0.334775, 0.07292459, -0.27418974, 0.31669316,
+    -0.55182135, 0.3836635, 0.33822894, 0.31698248, -0.0018248893, -0.32161152, 0.4114216, 0.28826568,
+    0.40146983, -0.22044812, -0.37950203, -0.3776586, 0.18695329, 0.01694878, 0.42139223, 0.09735548,
+    0.42695302, -0.38954943, 0.30025622, 0.14856094, 0.72891134, 0.23575549, -0.57806873, -0.011427285,
+    0.18320835, 0.59339523, 0.21233903, 0.109578185, -0.085340805, -0.054439917, 0.23021026, 0.37296408,
+    -0.5796295, -0.50561017, -0.49903718, -0.46907488, -0.15022074, -0.5205972, 0.57449305, 0.24600044,
+    0.030619556, 0.11731723, -0.42566752, -0.39547217, 0.24497668, 0.13316278, -0.20455055, -0.0125704715,
+    -0.51979107, 0.35491294, 0.12248304, 0.340407, -0.031264104, -0.10461917, 0.43202904, 0.336839,
+    0.27814767, -0.30422735, -0.5325262, -0.27676237, 0.47685656, -0.035172597, 0.4632256, -0.3466311,
+    0.60205364, -0.26083362, 0.39070815, 0.26178923, 0.86643267, 0.36429867, -0.2215447, -0.0667847,
+    0.26844952, 0.7714659, 0.17312804, -0.074847154, -0.07529349, -0.057264715, 0.6202747, 0.2172886,
+    -0.35898668, -0.4432353, -0.33334368, -0.30091906, -0.2792659, -0.5320696, 0.5514258, 0.18357809,
+    0.06566279, 0.15505737, -0.4004176, -0.4263215, 0.44479483, 0.28883588, -0.14049213, 0.046797715,
+    -0.42374176, 0.4621261, -0.03377271, 0.39113432, -0.20226377, -0.15972248, 0.4632003, 0.3733187,
+    0.27220163, -0.4769012, -0.27773845, -0.122342564, 0.452183, -0.17013337, 0.2890616, -0.27063042,
+    0.5291888, 0.019049244, 0.24446139, 0.38816562, 0.63565075, 0.4170219, -0.15625596, -0.03413201,
+    0.41741207, 0.79237413, 0.18964106, -0.04066697, 0.19518715, 0.14923657, 0.49234694, 0.3229994,
+    -0.09718029, -0.49933085, -0.3019615, -0.4147924, -0.19573899, -0.40410525, 0.27948362, 0.18471797,
+    -0.08407413, 0.07127366, -0.40298864, -0.33184934, 0.270277, 0.2493646, 0.06694337, -0.029683784,
+    -0.52310413, 0.2585663, 0.14013888, 0.31020257, -0.2090238, -0.46701607, 0.3400359, 0.2696571,
+    0.18119442, -0.3389055, -0.50244033, -0.07945368, 0.49093235, -0.021365736, 0.0027642841, -0.73969024,
+    0.5951507, 0.1088835, 0.36462697, 0.030188408, 0.72221804, 0.1491997, -0.17632642, -0.22531852,
+    0.20165625, 0.750298, 0.2825507, -0.023883501, -0.21853513, 0.29512468, 0.4868005, 0.31687728,
+    0.0331252, -0.43500414, -0.3594176, -0.32020044, -0.08879024, 0.076760754, 0.2575615, 0.41754362,
+    0.061874457, -0.016551457, -0.3885011, -0.7488057, 0.4339071, 0.26239252, 0.0660089, -0.13432226,
+    -0.44835433, 0.32407987, 0.260505, 0.30972093, -0.10661251, -0.3369517, 0.49616572, 0.27663776,
+    -0.12323685, -0.27910823, -0.6291158, 0.04013362, 0.51501715, 0.045939956, 0.18456239, -0.85350627,
+    0.5245174, 0.1305162, 0.6027533, -0.03188458, 0.28146678, 0.15482256, 0.029837342, -0.16741456,
+    0.33765382, 0.6896314, -0.24383004, 0.17839853, -0.373605, 0.21411937, 0.6465306, 0.3874394,
+    0.091069214, -0.5005422, -0.09382123, -0.2829453, -0.06148637, -0.14998485, 0.24757186, 0.3878565,
+    0.07874802, -0.07751152, -0.4957311, -0.8054952, 0.4890954, 0.21113892, -0.03309798, -0.06711455,
+    -0.36645392, 0.3513407, 0.32244554, 0.46076423, 0.048077796, -0.5382635, 0.32607067, 0.3005035,
+    -0.16785091, -0.2601151, -0.5854036, 0.075959295, 0.7740467, -0.17955026, 0.014155599, -0.35233128,
+    0.30393448, 0.038235363, 0.4815894, -0.11623605, 0.2670824, 0.097828306, -0.33431837, -0.13493079,
+    0.29452664, 0.49068922, -0.082547635, 0.12563448, -0.37682956, 0.29117137, 0.6137523, 0.084005244,
+    -0.15785635, -0.32787764, 0.005788729, -0.6067488, -0.31237578, 0.15983482, 0.064607814, 0.23349757,
+    0.00269542, -0.20171024, -0.44714352, -0.90902567, 0.33691213, 0.011449963, -0.05846785, 0.028284885,
+    -0.13037287, 0.24352689, -0.024705838, 0.5767233, -0.013528428, -0.5670023, 0.094182454, 0.2124423,
+    0.2661323, -0.58740336, -0.45805806, 0.2167674, 0.39257464, -0.70616543, 0.25109664, -0.46367106,
+    0.14661583, 0.19328395, 0.5660915, 0.035860833, 0.08431687, 0.18051355, -0.4175787, -0.07364262,
+    0.50865316, 0.42415735, -0.0014622642, 0.07075802, -0.2458944, -0.032941382, 0.64253044, 0.21176568,
+    0.1247539, -0.22491907, -0.34848237, -0.3396591, -0.13017638, 0.027919153, -0.09702761, -0.006042018,
+    -0.06558016, -0.048700728, -0.54922235, -0.5376642, 0.2570935, 0.09840593, -0.26309094, 0.22794227,
+    -0.32160684, 0.4145037, 0.36381698, 0.6442067, 0.2390043, -0.40769863, 0.25052622, 0.36139682,
+    -0.22762108, -0.33077493, -0.28982446, 0.032821875, 0.8769651, -0.73545235, -0.058568843, -0.20955363,
+    0.40115947, 0.17572135, 0.69520587, -0.10641069, 0.28451335, 0.26452282, -0.4815132, -0.1493425,
+    0.22610885, 0.5351223, -0.31124857, 0.21703972, -0.33987877, 0.20433904, 0.55145866, 0.16036859,
+    -0.07427002, -0.24338835, -0.1574486, -0.484593, -0.18150535, 0.040148724, -0.025126329, 0.0987226,
+    0.19609709, -0.29796642, -0.5492226, -0.6224919, 0.3359278, 0.07346453, -0.34059605, 0.10119175,
+    -0.37971768, 0.3156758, 0.31489927, 0.6438489, 0.2916697, -0.40061092, 0.3548812, 0.3960676,
+    -0.07719203, -0.38278154, -0.30375734, 0.21025866, 0.7446, -0.8490559, -0.00830104, -0.031090735,
+    0.23117761, 0.20607765, 0.6782007, -0.17410998, 0.26171416, 0.2599827, -0.43800807, 0.123879656,
+    0.30139208, 0.39007282, -0.23064065, 0.21446437, -0.38607734, 0.06703208, 0.30052018, 0.3346648,
+    -0.077914365, -0.2156518, -0.3107608, -0.5266813, 0.03799383, 0.22755699, -0.08355043, 0.08745675,
+    0.21134502, -0.23719127, -0.37960535, -0.5079641, 0.42855605, 0.13331562, -0.34802264, 0.25042385,
+    -0.18398766, 0.07956478, 0.4538511, 0.4351298, 0.34421784, -0.33794388, 0.4646278, 0.048809573,
+    -0.1514323, -0.5728171, -0.38086078, 0.27923822, 0.9293781, -0.6721469, 0.29242134, -0.03929342,
+    0.3399511, 0.24618392, 0.70411474, -0.42953232, 0.25690833, 0.35588312, -0.5594791, 0.08285151,
+    0.48540616, 0.389907, -0.09665959, 0.22937003, -0.08951326, 0.09407174, 0.58686817, 0.14064701,
+    -0.039686147, -0.19239628, -0.44124565, -0.50512475, -0.09192152, 0.22436787, -0.09222814, 0.29120958,
+    0.1939532, -0.1548796, -0.46078968, -0.63590413, 0.31873944, 0.111887984, -0.3861109, 0.019773059,
+    -0.20149912, 0.0375534, 0.5110289, 0.7849172, 0.43173316, -0.43261462, 0.42234075, 0.12380152,
+    0.14772308, -0.5623597, -0.22253902, 0.29308158, 0.8223962, -0.91299886, 0.06696103, -0.21559986,
+    0.18630163, 0.446314, 0.3916474, -0.06486701, 0.18161106, 0.26747763, -0.2057496, 0.13945335,
+];
+
+#[rustfmt::skip]
+pub(super) static OUTPUT_BIAS: [f32; NB_BANDS] = [
+    -0.33282304, -0.28363916, -0.23870648, -0.17611708, -0.19888963, -0.13026896, -0.16922055, -0.19653614,
+    -0.03222064, -0.15936531, -0.10072296, -0.099589765, -0.07554024, -0.028246544, -0.08953657, -0.15104425,
+    -0.122851305, -0.17668945, -0.17177719, -0.21512008, -0.23586375, -0.24182354,
+];
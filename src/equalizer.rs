@@ -0,0 +1,425 @@
+//! Biquad equalizer DSP and preset files.
+
+use std::f64::consts::PI;
+use std::path::Path;
+
+use crate::{EqBand, EqPreset, FilterType};
+
+/// Normalized biquad coefficients (a0 = 1), from the RBJ audio EQ cookbook
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Coefficients {
+    b0: f64,
+    b1: f64,
+    b2: f64,
+    a1: f64,
+    a2: f64,
+}
+
+impl Coefficients {
+    const IDENTITY: Coefficients = Coefficients {
+        b0: 1.0,
+        b1: 0.0,
+        b2: 0.0,
+        a1: 0.0,
+        a2: 0.0,
+    };
+
+    fn new(band: &EqBand, rate: u32) -> Self {
+        let nyquist = rate as f64 / 2.0;
+        if !(band.frequency > 0.0 && band.frequency < nyquist) || band.q <= 0.0 {
+            return Self::IDENTITY;
+        }
+
+        let w0 = 2.0 * PI * band.frequency / rate as f64;
+        let (sin, cos) = w0.sin_cos();
+        let alpha = sin / (2.0 * band.q);
+        let a = 10f64.powf(band.gain_db / 40.0);
+
+        let (b0, b1, b2, a0, a1, a2) = match band.filter {
+            FilterType::Peaking => (
+                1.0 + alpha * a,
+                -2.0 * cos,
+                1.0 - alpha * a,
+                1.0 + alpha / a,
+                -2.0 * cos,
+                1.0 - alpha / a,
+            ),
+            FilterType::LowShelf => {
+                let sqrt_a = 2.0 * a.sqrt() * alpha;
+                (
+                    a * ((a + 1.0) - (a - 1.0) * cos + sqrt_a),
+                    2.0 * a * ((a - 1.0) - (a + 1.0) * cos),
+                    a * ((a + 1.0) - (a - 1.0) * cos - sqrt_a),
+                    (a + 1.0) + (a - 1.0) * cos + sqrt_a,
+                    -2.0 * ((a - 1.0) + (a + 1.0) * cos),
+                    (a + 1.0) + (a - 1.0) * cos - sqrt_a,
+                )
+            }
+            FilterType::HighShelf => {
+                let sqrt_a = 2.0 * a.sqrt() * alpha;
+                (
+                    a * ((a + 1.0) + (a - 1.0) * cos + sqrt_a),
+                    -2.0 * a * ((a - 1.0) + (a + 1.0) * cos),
+                    a * ((a + 1.0) + (a - 1.0) * cos - sqrt_a),
+                    (a + 1.0) - (a - 1.0) * cos + sqrt_a,
+                    2.0 * ((a - 1.0) - (a + 1.0) * cos),
+                    (a + 1.0) - (a - 1.0) * cos - sqrt_a,
+                )
+            }
+            FilterType::LowPass => (
+                (1.0 - cos) / 2.0,
+                1.0 - cos,
+                (1.0 - cos) / 2.0,
+                1.0 + alpha,
+                -2.0 * cos,
+                1.0 - alpha,
+            ),
+            FilterType::HighPass => (
+                (1.0 + cos) / 2.0,
+                -(1.0 + cos),
+                (1.0 + cos) / 2.0,
+                1.0 + alpha,
+                -2.0 * cos,
+                1.0 - alpha,
+            ),
+        };
+
+        Coefficients {
+            b0: b0 / a0,
+            b1: b1 / a0,
+            b2: b2 / a0,
+            a1: a1 / a0,
+            a2: a2 / a0,
+        }
+    }
+}
+
+/// Transposed direct form II state of one filter on one channel
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    z1: f64,
+    z2: f64,
+}
+
+impl State {
+    fn process(&mut self, c: &Coefficients, x: f64) -> f64 {
+        let y = c.b0 * x + self.z1;
+        self.z1 = c.b1 * x - c.a1 * y + self.z2;
+        self.z2 = c.b2 * x - c.a2 * y;
+        y
+    }
+}
+
+/// A chain of biquad filters applied to interleaved samples
+pub(crate) struct EqProcessor {
+    rate: u32,
+    channels: usize,
+    preamp: f64,
+    filters: Vec<Coefficients>,
+    /// `filters.len() * channels` states, filter-major
+    states: Vec<State>,
+}
+
+impl EqProcessor {
+    pub(crate) fn new(rate: u32, channels: usize, preset: &EqPreset) -> Self {
+        let mut processor = EqProcessor {
+            rate,
+            channels,
+            preamp: 1.0,
+            filters: Vec::new(),
+            states: Vec::new(),
+        };
+        processor.configure(preset);
+        processor
+    }
+
+    /// Apply new settings. Filter state is kept for bands that still exist so
+    /// live adjustments don't click.
+    pub(crate) fn configure(&mut self, preset: &EqPreset) {
+        self.preamp = 10f64.powf(preset.preamp_db / 20.0);
+        self.filters = preset
+            .bands
+            .iter()
+            .map(|band| Coefficients::new(band, self.rate))
+            .collect();
+        self.states
+            .resize(self.filters.len() * self.channels, State::default());
+    }
+
+    /// Filter interleaved samples in place
+    pub(crate) fn process(&mut self, samples: &mut [f32]) {
+        for frame in samples.chunks_exact_mut(self.channels) {
+            for (channel, sample) in frame.iter_mut().enumerate() {
+                let mut x = *sample as f64 * self.preamp;
+                for (index, filter) in self.filters.iter().enumerate() {
+                    x = self.states[index * self.channels + channel].process(filter, x);
+                }
+                *sample = x as f32;
+            }
+        }
+    }
+}
+
+impl FilterType {
+    fn as_str(self) -> &'static str {
+        match self {
+            FilterType::Peaking => "peaking",
+            FilterType::LowShelf => "low_shelf",
+            FilterType::HighShelf => "high_shelf",
+            FilterType::LowPass => "low_pass",
+            FilterType::HighPass => "high_pass",
+        }
+    }
+}
+
+impl std::fmt::Display for FilterType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for FilterType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "peaking" => Ok(FilterType::Peaking),
+            "low_shelf" => Ok(FilterType::LowShelf),
+            "high_shelf" => Ok(FilterType::HighShelf),
+            "low_pass" => Ok(FilterType::LowPass),
+            "high_pass" => Ok(FilterType::HighPass),
+            _ => Err(format!(
+                "Unknown filter type '{}', expected peaking, low_shelf, high_shelf, low_pass or high_pass",
+                s
+            )),
+        }
+    }
+}
+
+impl EqBand {
+    pub fn new(filter: FilterType, frequency: f64, gain_db: f64, q: f64) -> Self {
+        EqBand {
+            filter,
+            frequency,
+            gain_db,
+            q,
+        }
+    }
+
+    pub(crate) fn validate(&self) -> Result<(), String> {
+        if !(self.frequency.is_finite() && self.frequency > 0.0) {
+            return Err(format!("Invalid band frequency {}", self.frequency));
+        }
+        if !(self.q.is_finite() && self.q > 0.0) {
+            return Err(format!("Invalid band Q {}", self.q));
+        }
+        if !(-30.0..=30.0).contains(&self.gain_db) {
+            return Err("Band gain must be between -30 and 30 dB".to_string());
+        }
+        Ok(())
+    }
+}
+
+const BUILTIN_PRESETS: &[&str] = &["flat", "bass_boost", "treble_boost", "vocal", "loudness"];
+
+impl EqPreset {
+    /// Names of the presets available from `EqPreset::builtin`
+    pub fn builtin_names() -> &'static [&'static str] {
+        BUILTIN_PRESETS
+    }
+
+    pub fn builtin(name: &str) -> Option<EqPreset> {
+        use FilterType::*;
+
+        let (preamp_db, bands) = match name {
+            "flat" => (0.0, vec![]),
+            "bass_boost" => (-4.0, vec![EqBand::new(LowShelf, 120.0, 6.0, 0.7)]),
+            "treble_boost" => (-4.0, vec![EqBand::new(HighShelf, 6000.0, 6.0, 0.7)]),
+            "vocal" => (
+                -3.0,
+                vec![
+                    EqBand::new(HighPass, 80.0, 0.0, 0.7),
+                    EqBand::new(Peaking, 250.0, -2.0, 1.0),
+                    EqBand::new(Peaking, 3000.0, 3.0, 1.0),
+                ],
+            ),
+            "loudness" => (
+                -5.0,
+                vec![
+                    EqBand::new(LowShelf, 100.0, 5.0, 0.7),
+                    EqBand::new(HighShelf, 8000.0, 4.0, 0.7),
+                ],
+            ),
+            _ => return None,
+        };
+
+        Some(EqPreset {
+            name: name.to_string(),
+            preamp_db,
+            bands,
+        })
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if !(-30.0..=30.0).contains(&self.preamp_db) {
+            return Err("Preamp must be between -30 and 30 dB".to_string());
+        }
+        self.bands.iter().try_for_each(EqBand::validate)
+    }
+
+    /// Read a preset written by `save`
+    pub fn load(path: &Path) -> Result<EqPreset, String> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        Self::parse(&text).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    /// Write the preset as a text file with one `band = <type> <frequency>
+    /// <gain_db> <q>` line per band
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        std::fs::write(path, self.to_text())
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+    }
+
+    fn to_text(&self) -> String {
+        let mut text = format!(
+            "# fa-control equalizer preset\nname = {}\npreamp = {}\n",
+            self.name, self.preamp_db
+        );
+        for band in &self.bands {
+            text.push_str(&format!(
+                "band = {} {} {} {}\n",
+                band.filter, band.frequency, band.gain_db, band.q
+            ));
+        }
+        text
+    }
+
+    fn parse(text: &str) -> Result<EqPreset, String> {
+        let mut preset = EqPreset {
+            name: String::new(),
+            preamp_db: 0.0,
+            bands: Vec::new(),
+        };
+
+        for (number, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let error = |message: &str| format!("line {}: {}", number + 1, message);
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| error("expected 'key = value'"))?;
+            let value = value.trim();
+            match key.trim() {
+                "name" => preset.name = value.to_string(),
+                "preamp" => {
+                    preset.preamp_db = value.parse().map_err(|_| error("invalid preamp"))?
+                }
+                "band" => {
+                    let fields: Vec<&str> = value.split_whitespace().collect();
+                    let [filter, frequency, gain_db, q] = fields[..] else {
+                        return Err(error("expected 'band = <type> <frequency> <gain_db> <q>'"));
+                    };
+                    let number = |s: &str| s.parse::<f64>().map_err(|_| error("invalid number"));
+                    preset.bands.push(EqBand {
+                        filter: filter.parse().map_err(|e: String| error(&e))?,
+                        frequency: number(frequency)?,
+                        gain_db: number(gain_db)?,
+                        q: number(q)?,
+                    });
+                }
+                other => return Err(error(&format!("unknown key '{}'", other))),
+            }
+        }
+
+        preset.validate()?;
+        Ok(preset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Amplitude of a filtered sine after the filter has settled, from its RMS
+    fn response(preset: &EqPreset, frequency: f64) -> f64 {
+        let rate = 48000;
+        let mut processor = EqProcessor::new(rate, 1, preset);
+        let mut samples: Vec<f32> = (0..rate)
+            .map(|i| (2.0 * PI * frequency * i as f64 / rate as f64).sin() as f32)
+            .collect();
+        processor.process(&mut samples);
+        let settled = &samples[rate as usize / 2..];
+        let power: f64 = settled.iter().map(|&s| (s as f64).powi(2)).sum::<f64>();
+        (2.0 * power / settled.len() as f64).sqrt()
+    }
+
+    fn preset(preamp_db: f64, bands: Vec<EqBand>) -> EqPreset {
+        EqPreset {
+            name: "test".to_string(),
+            preamp_db,
+            bands,
+        }
+    }
+
+    #[test]
+    fn test_flat_is_transparent() {
+        let flat = EqPreset::builtin("flat").unwrap();
+        assert!((response(&flat, 1000.0) - 1.0).abs() < 1e-3);
+
+        let zero_gain = preset(
+            0.0,
+            vec![EqBand::new(FilterType::Peaking, 1000.0, 0.0, 1.0)],
+        );
+        assert!((response(&zero_gain, 1000.0) - 1.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_peaking_gain() {
+        let boost = preset(
+            0.0,
+            vec![EqBand::new(FilterType::Peaking, 1000.0, 6.0, 1.0)],
+        );
+        // +6 dB at the center frequency, untouched far away
+        assert!((response(&boost, 1000.0) - 1.995).abs() < 0.02);
+        assert!((response(&boost, 50.0) - 1.0).abs() < 0.02);
+    }
+
+    #[test]
+    fn test_pass_filters_and_preamp() {
+        let low_pass = preset(
+            0.0,
+            vec![EqBand::new(FilterType::LowPass, 500.0, 0.0, 0.707)],
+        );
+        assert!(response(&low_pass, 100.0) > 0.95);
+        assert!(response(&low_pass, 8000.0) < 0.01);
+
+        let high_pass = preset(
+            -6.0,
+            vec![EqBand::new(FilterType::HighPass, 500.0, 0.0, 0.707)],
+        );
+        assert!(response(&high_pass, 50.0) < 0.01);
+        assert!((response(&high_pass, 8000.0) - 0.501).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_preset_round_trip() {
+        for name in EqPreset::builtin_names() {
+            let preset = EqPreset::builtin(name).unwrap();
+            let parsed = EqPreset::parse(&preset.to_text()).unwrap();
+            assert_eq!(parsed, preset);
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(EqPreset::parse("band = peaking 1000 3").is_err());
+        assert!(EqPreset::parse("band = notch 1000 3 1").is_err());
+        assert!(EqPreset::parse("preamp = loud").is_err());
+        assert!(EqPreset::parse("band = peaking 1000 90 1").is_err());
+        assert!(EqPreset::parse("volume = 1").is_err());
+    }
+}
@@ -1,7 +1,13 @@
 #![deny(clippy::all)]
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+mod denoise;
+mod equalizer;
 mod platform;
+mod ring_buffer;
+mod routing;
+mod tone;
+mod wav;
 
 // ============== PyO3 Bindings (Python) ==============
 // These are only compiled when the "pyo3" feature is enabled
@@ -16,6 +22,597 @@ pub struct AppInfo {
     pub name: String,
     pub volume: f64,
     pub muted: bool,
+    /// Details of the process playing the audio, where the platform
+    /// reports them
+    pub process: Option<ProcessInfo>,
+}
+
+/// The process behind an audio stream; each field is `None` (or empty) when
+/// it can't be determined
+#[derive(Debug, Clone, Default)]
+pub struct ProcessInfo {
+    /// Full path of the executable
+    pub exe: Option<String>,
+    /// Executable name, e.g. "firefox"
+    pub binary: Option<String>,
+    pub cmdline: Vec<String>,
+    pub uid: Option<u32>,
+    /// Name of the user owning the process
+    pub user: Option<String>,
+    /// Parent process ID
+    pub ppid: Option<u32>,
+    /// Flatpak application ID, e.g. "org.telegram.desktop"
+    pub flatpak_id: Option<String>,
+    /// Systemd service or scope the process runs in, e.g.
+    /// "app-flatpak-org.telegram.desktop-12345.scope"
+    pub systemd_unit: Option<String>,
+}
+
+/// A profile a sound card can be switched to
+#[derive(Debug, Clone)]
+pub struct CardProfile {
+    pub name: String,
+    pub description: String,
+    pub priority: u32,
+    pub available: bool,
+}
+
+/// Information about a sound card and its profiles
+#[derive(Debug, Clone)]
+pub struct CardInfo {
+    pub index: u32,
+    pub name: String,
+    pub description: String,
+    pub driver: String,
+    pub profiles: Vec<CardProfile>,
+    pub active_profile: Option<String>,
+}
+
+/// A port of an output or input device, e.g. "Headphones" or "Speakers"
+#[derive(Debug, Clone)]
+pub struct PortInfo {
+    pub name: String,
+    pub description: String,
+    pub priority: u32,
+    /// Jack detection state: `Some(true)` when plugged in, `None` if the port
+    /// does not support jack detection
+    pub available: Option<bool>,
+    pub active: bool,
+}
+
+/// A channel of a device, in channel map order
+#[derive(Debug, Clone)]
+pub struct ChannelInfo {
+    /// Channel position, e.g. "front-left"
+    pub name: String,
+    /// Human readable position, e.g. "Front Left"
+    pub label: String,
+}
+
+/// Whether a device is in use
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeviceState {
+    /// At least one stream is playing to or recording from the device
+    Running,
+    /// Open, but no stream is using it
+    Idle,
+    /// Closed to save power or release the hardware
+    Suspended,
+    /// The server does not report device states
+    Unknown,
+}
+
+impl DeviceState {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DeviceState::Running => "running",
+            DeviceState::Idle => "idle",
+            DeviceState::Suspended => "suspended",
+            DeviceState::Unknown => "unknown",
+        }
+    }
+}
+
+/// Information about an output (sink) or input (source) device
+#[derive(Debug, Clone)]
+pub struct DeviceInfo {
+    pub index: u32,
+    pub name: String,
+    pub description: String,
+    pub ports: Vec<PortInfo>,
+    pub active_port: Option<String>,
+    /// Jack detection state of the active port
+    pub plugged: Option<bool>,
+    /// Source carrying what an output device plays; `None` for input devices
+    pub monitor_source: Option<String>,
+    pub channels: Vec<ChannelInfo>,
+    pub state: DeviceState,
+}
+
+/// Information about the sound server
+#[derive(Debug, Clone)]
+pub struct ServerInfo {
+    /// Server package name, e.g. "pulseaudio" or
+    /// "PulseAudio (on PipeWire 1.0.5)"
+    pub name: String,
+    pub version: String,
+    pub host: String,
+    /// User the server runs as
+    pub user: String,
+    pub default_sink: Option<String>,
+    pub default_source: Option<String>,
+    /// Default sample format, e.g. "s16le" or "float32le"
+    pub sample_format: String,
+    pub sample_rate: u32,
+    pub channels: u8,
+    pub channel_map: Vec<ChannelInfo>,
+}
+
+impl ServerInfo {
+    /// Whether the server is PipeWire's PulseAudio compatibility layer
+    pub fn is_pipewire(&self) -> bool {
+        self.name.contains("PipeWire")
+    }
+}
+
+/// A client connected to the sound server
+#[derive(Debug, Clone)]
+pub struct ClientInfo {
+    pub index: u32,
+    pub name: String,
+    /// Server component the client connected through, e.g. "protocol-native.c"
+    pub driver: String,
+    pub pid: Option<u32>,
+    /// The client's property list, e.g. "application.process.binary"
+    pub properties: std::collections::BTreeMap<String, String>,
+}
+
+/// Direction of a stream
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreamKind {
+    /// A playback stream (sink input)
+    SinkInput,
+    /// A recording stream (source output)
+    SourceOutput,
+}
+
+impl StreamKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            StreamKind::SinkInput => "sink_input",
+            StreamKind::SourceOutput => "source_output",
+        }
+    }
+}
+
+impl std::str::FromStr for StreamKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sink_input" => Ok(StreamKind::SinkInput),
+            "source_output" => Ok(StreamKind::SourceOutput),
+            _ => Err(format!(
+                "Unknown stream kind '{}': expected sink_input or source_output",
+                s
+            )),
+        }
+    }
+}
+
+/// A playback or recording stream
+#[derive(Debug, Clone)]
+pub struct StreamInfo {
+    pub index: u32,
+    pub kind: StreamKind,
+    pub name: String,
+    /// Index of the client that owns the stream
+    pub client: Option<u32>,
+    pub pid: Option<u32>,
+    /// Index of the sink or source the stream is connected to
+    pub device: u32,
+    pub properties: std::collections::BTreeMap<String, String>,
+}
+
+/// A module loaded into the sound server
+#[derive(Debug, Clone)]
+pub struct ModuleInfo {
+    pub index: u32,
+    /// e.g. "module-null-sink"
+    pub name: String,
+    /// Arguments the module was loaded with, e.g. "sink_name=stream_mix"
+    pub argument: String,
+    /// How many clients or devices use the module, if the module reports it
+    pub usage: Option<u32>,
+}
+
+/// An entry in module-stream-restore's database, which sets the volume,
+/// mute state and device of new streams that match it
+#[derive(Debug, Clone)]
+pub struct RestoreEntry {
+    /// Key the entry matches streams by, e.g.
+    /// "sink-input-by-application-name:Firefox"
+    pub name: String,
+    /// Volume from 0.0 to 1.0, or `None` to leave the stream's volume alone
+    pub volume: Option<f32>,
+    pub mute: bool,
+    /// Sink or source to connect matching streams to
+    pub device: Option<String>,
+}
+
+impl RestoreEntry {
+    /// Key of the entry for playback streams of an application, by its
+    /// `application.name` property
+    pub fn application_key(application_name: &str) -> String {
+        format!("sink-input-by-application-name:{}", application_name)
+    }
+}
+
+/// Options for an echo-cancelled source/sink pair
+#[derive(Debug, Clone)]
+pub struct EchoCancelOptions {
+    /// Microphone to cancel echo from; the default input device if `None`
+    pub source_master: Option<String>,
+    /// Output device whose audio is the echo reference; the default output
+    /// device if `None`
+    pub sink_master: Option<String>,
+    /// Name of the created echo-cancelled source
+    pub source_name: String,
+    /// Name of the created sink that applications should play to
+    pub sink_name: String,
+    /// Echo canceller implementation, e.g. "webrtc" or "speex"
+    pub method: String,
+    /// Extra arguments for the echo canceller, e.g. "analog_gain_control=0"
+    pub aec_args: Option<String>,
+    /// Enable noise suppression (webrtc only)
+    pub noise_suppression: bool,
+    /// Make the echo-cancelled source the default input device
+    pub make_default: bool,
+}
+
+impl Default for EchoCancelOptions {
+    fn default() -> Self {
+        EchoCancelOptions {
+            source_master: None,
+            sink_master: None,
+            source_name: "echo_cancel_source".to_string(),
+            sink_name: "echo_cancel_sink".to_string(),
+            method: "webrtc".to_string(),
+            aec_args: None,
+            noise_suppression: true,
+            make_default: false,
+        }
+    }
+}
+
+/// PCM sample format, always little endian
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SampleFormat {
+    S16Le,
+    S32Le,
+    F32Le,
+}
+
+impl SampleFormat {
+    pub fn bytes_per_sample(self) -> usize {
+        match self {
+            SampleFormat::S16Le => 2,
+            SampleFormat::S32Le | SampleFormat::F32Le => 4,
+        }
+    }
+}
+
+impl std::str::FromStr for SampleFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "s16le" => Ok(SampleFormat::S16Le),
+            "s32le" => Ok(SampleFormat::S32Le),
+            "f32le" | "float32le" => Ok(SampleFormat::F32Le),
+            _ => Err(format!(
+                "Unknown sample format '{}': expected s16le, s32le or f32le",
+                s
+            )),
+        }
+    }
+}
+
+/// Format, rate and channel count of interleaved PCM audio
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SampleSpec {
+    pub format: SampleFormat,
+    pub rate: u32,
+    pub channels: u8,
+}
+
+impl SampleSpec {
+    /// Size of one frame (one sample for every channel) in bytes
+    pub fn frame_size(&self) -> usize {
+        self.format.bytes_per_sample() * self.channels as usize
+    }
+
+    pub fn bytes_per_second(&self) -> usize {
+        self.frame_size() * self.rate as usize
+    }
+}
+
+impl Default for SampleSpec {
+    fn default() -> Self {
+        SampleSpec {
+            format: SampleFormat::S16Le,
+            rate: 48000,
+            channels: 2,
+        }
+    }
+}
+
+/// Callback receiving chunks of interleaved PCM frames
+pub type PcmCallback = Box<dyn FnMut(&[u8]) + Send>;
+
+/// Where recorded audio goes
+pub enum RecordingOutput {
+    /// Write a WAV file at the given path
+    Wav(std::path::PathBuf),
+    /// Call back with chunks of interleaved PCM frames
+    Callback(PcmCallback),
+}
+
+/// Options for recording from a device
+#[derive(Debug, Clone, Default)]
+pub struct RecordingOptions {
+    /// Input device to record from, or an output device to record what it
+    /// plays. The default input device if `None`.
+    pub device: Option<String>,
+    pub spec: SampleSpec,
+    /// Stop automatically after this much audio has been recorded
+    pub max_duration: Option<std::time::Duration>,
+}
+
+/// Options for an instant replay buffer
+#[derive(Debug, Clone)]
+pub struct ReplayOptions {
+    /// Device to capture, as for `RecordingOptions::device`
+    pub device: Option<String>,
+    /// Capture only the audio of the application with this PID instead of a
+    /// device
+    pub pid: Option<u32>,
+    pub spec: SampleSpec,
+    /// How much audio to keep. The buffer uses this times
+    /// `spec.bytes_per_second()` bytes of memory.
+    pub duration: std::time::Duration,
+}
+
+impl Default for ReplayOptions {
+    fn default() -> Self {
+        ReplayOptions {
+            device: None,
+            pid: None,
+            spec: SampleSpec::default(),
+            duration: std::time::Duration::from_secs(30),
+        }
+    }
+}
+
+/// Audio to play
+#[derive(Debug, Clone)]
+pub enum PlaybackSource {
+    /// A WAV file with 16 or 32 bit integer or 32 bit float samples
+    Wav(std::path::PathBuf),
+    /// Interleaved PCM frames in the given format
+    Pcm { data: Vec<u8>, spec: SampleSpec },
+}
+
+/// Options for playing audio
+#[derive(Debug, Clone)]
+pub struct PlaybackOptions {
+    /// Output device, or the default output device if `None`
+    pub device: Option<String>,
+    /// Stream name, listed as the application name by `get_active_audio_apps`
+    pub name: String,
+    /// Media role, e.g. "event", "music" or "phone", used by the server to
+    /// apply role-based policies such as ducking
+    pub role: Option<String>,
+    /// Initial stream volume (0.0 to 1.0), or the server's choice if `None`
+    pub volume: Option<f64>,
+}
+
+impl Default for PlaybackOptions {
+    fn default() -> Self {
+        PlaybackOptions {
+            device: None,
+            name: "fa-control playback".to_string(),
+            role: None,
+            volume: None,
+        }
+    }
+}
+
+/// Signal played by a speaker test
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TestSignal {
+    /// Sine tone at the given frequency in Hz
+    Sine { frequency: f64 },
+    /// Pink noise, with equal energy per octave
+    PinkNoise,
+}
+
+/// Options for playing a test signal on an output device
+#[derive(Debug, Clone)]
+pub struct TestToneOptions {
+    /// Output device, or the default output device if `None`
+    pub device: Option<String>,
+    pub signal: TestSignal,
+    /// Channel position to play on, e.g. "front-left" (see
+    /// `DeviceInfo::channels`), or every channel if `None`
+    pub channel: Option<String>,
+    /// Peak amplitude (0.0 to 1.0)
+    pub level: f64,
+    pub duration: std::time::Duration,
+}
+
+impl Default for TestToneOptions {
+    fn default() -> Self {
+        TestToneOptions {
+            device: None,
+            signal: TestSignal::Sine { frequency: 440.0 },
+            channel: None,
+            level: 0.5,
+            duration: std::time::Duration::from_secs(1),
+        }
+    }
+}
+
+/// A sound stored in the server's sample cache
+#[derive(Debug, Clone)]
+pub struct CachedSample {
+    pub index: u32,
+    pub name: String,
+    pub duration: std::time::Duration,
+    /// Size of the sample data in bytes
+    pub bytes: u32,
+    pub rate: u32,
+    pub channels: u8,
+    /// Lazily loaded samples are read from `filename` on first use
+    pub lazy: bool,
+    pub filename: Option<String>,
+}
+
+/// Options for a soundboard virtual microphone
+#[derive(Debug, Clone)]
+pub struct SoundboardOptions {
+    /// Prefix for the devices created; the virtual microphone is
+    /// `<name>_mic` and the mix sink `<name>_mix`
+    pub name: String,
+    /// Real microphone to mix in, or the default input device if `None`
+    pub microphone: Option<String>,
+    /// Also play clips on this output device so the user hears them
+    pub monitor_device: Option<String>,
+    /// Latency of the microphone loopback in milliseconds
+    pub latency_ms: u32,
+}
+
+impl Default for SoundboardOptions {
+    fn default() -> Self {
+        SoundboardOptions {
+            name: "soundboard".to_string(),
+            microphone: None,
+            monitor_device: None,
+            latency_ms: 50,
+        }
+    }
+}
+
+/// Shape of an equalizer band
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterType {
+    Peaking,
+    LowShelf,
+    HighShelf,
+    LowPass,
+    HighPass,
+}
+
+/// One biquad filter of an equalizer
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EqBand {
+    pub filter: FilterType,
+    /// Center or corner frequency in Hz
+    pub frequency: f64,
+    /// Boost or cut in dB; ignored by low and high pass filters
+    pub gain_db: f64,
+    pub q: f64,
+}
+
+/// Complete equalizer settings, loadable from and savable to preset files
+#[derive(Debug, Clone, PartialEq)]
+pub struct EqPreset {
+    pub name: String,
+    /// Gain applied before the bands, to leave headroom for boosts
+    pub preamp_db: f64,
+    pub bands: Vec<EqBand>,
+}
+
+#[derive(Debug, Clone)]
+pub struct EqualizerOptions {
+    /// Name of the virtual sink applications are routed to
+    pub name: String,
+    /// Device the equalized audio is played on, or the default output device
+    /// if `None`
+    pub output: Option<String>,
+    pub preset: EqPreset,
+}
+
+impl Default for EqualizerOptions {
+    fn default() -> Self {
+        EqualizerOptions {
+            name: "equalizer".to_string(),
+            output: None,
+            preset: EqPreset {
+                name: "flat".to_string(),
+                preamp_db: 0.0,
+                bands: Vec::new(),
+            },
+        }
+    }
+}
+
+/// Options for a noise-suppressed virtual microphone
+#[derive(Debug, Clone)]
+pub struct NoiseSuppressionOptions {
+    /// Name of the denoised source; the internal sink it is fed through is
+    /// `<name>_sink`
+    pub name: String,
+    /// Microphone to denoise, or the default input device if `None`
+    pub microphone: Option<String>,
+    /// How much noise to remove, from 0.0 (none) to 1.0 (up to 30 dB)
+    pub strength: f64,
+    /// Make the denoised source the default input device
+    pub make_default: bool,
+}
+
+impl Default for NoiseSuppressionOptions {
+    fn default() -> Self {
+        NoiseSuppressionOptions {
+            name: "noise_suppressed".to_string(),
+            microphone: None,
+            strength: 0.7,
+            make_default: false,
+        }
+    }
+}
+
+/// Ordered device preferences for `PreferredDevicesController::watch`
+#[derive(Debug, Clone)]
+pub struct PreferredDeviceOptions {
+    /// Output devices by name or description, highest priority first; an
+    /// empty list leaves the default output device alone
+    pub outputs: Vec<String>,
+    /// Input devices by name or description, highest priority first
+    pub inputs: Vec<String>,
+    /// Move streams playing on (or recording from) the previous default to
+    /// the new one
+    pub move_streams: bool,
+}
+
+impl Default for PreferredDeviceOptions {
+    fn default() -> Self {
+        PreferredDeviceOptions {
+            outputs: Vec::new(),
+            inputs: Vec::new(),
+            move_streams: true,
+        }
+    }
+}
+
+/// A rule sending an application's playback streams to an output device
+#[derive(Debug, Clone)]
+pub struct RoutingRule {
+    /// Streams the rule applies to: `key=value` on a stream property, e.g.
+    /// "application.name=Spotify", or a bare application name or binary
+    pub selector: String,
+    /// Name of the output device
+    pub device: String,
 }
 
 /// Get current platform
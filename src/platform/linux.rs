@@ -3,11 +3,135 @@ use libpulse_binding::callbacks::ListResult;
 use libpulse_binding::context::{Context, FlagSet as ContextFlagSets, State as ContextState};
 use libpulse_binding::def::Retval;
 use libpulse_binding::mainloop::standard::{IterateResult, Mainloop};
-use libpulse_binding::operation::State as OperationState;
-use libpulse_binding::proplist::properties::APPLICATION_PROCESS_ID;
+use libpulse_binding::operation::{Operation, State as OperationState};
+use libpulse_binding::proplist::properties::{
+    APPLICATION_NAME, APPLICATION_PROCESS_BINARY, APPLICATION_PROCESS_ID,
+};
 use libpulse_binding::volume::ChannelVolumes;
 
-use crate::AppInfo;
+use crate::routing::selector_matches;
+use crate::{AppInfo, ProcessInfo, StreamKind};
+
+mod cards;
+mod clients;
+mod devices;
+mod echo_cancel;
+mod equalizer;
+mod events;
+mod loopback;
+mod modules;
+mod noise_suppression;
+mod pipeline;
+mod playback;
+mod preferred_devices;
+mod process;
+mod process_tree;
+mod recording;
+mod replay;
+mod routing;
+mod sample_cache;
+mod sandbox;
+mod server;
+mod soundboard;
+mod speaker_test;
+mod stream_group;
+mod stream_restore;
+mod stream_thread;
+mod streams;
+mod virtual_devices;
+
+pub use cards::CardController;
+pub use clients::ClientController;
+pub use devices::DeviceController;
+pub use echo_cancel::{EchoCancel, EchoCancelController};
+pub use equalizer::{Equalizer, EqualizerController};
+pub use loopback::{Loopback, LoopbackController};
+pub use modules::ModuleController;
+pub use noise_suppression::{NoiseSuppressionController, NoiseSuppressor};
+pub use playback::{Playback, PlaybackController};
+pub use preferred_devices::{PreferredDevices, PreferredDevicesController};
+pub use process_tree::ProcessTreeController;
+pub use recording::{Recording, RecordingController};
+pub use replay::ReplayBuffer;
+pub use routing::{RoutingController, RoutingWatcher};
+pub use sample_cache::SampleCacheController;
+pub use sandbox::SandboxController;
+pub use server::ServerController;
+pub use soundboard::{Soundboard, SoundboardController};
+pub use speaker_test::SpeakerTestController;
+pub use stream_restore::StreamRestoreController;
+pub use virtual_devices::{VirtualDevice, VirtualDeviceController};
+
+/// A context connected to the PulseAudio server together with the mainloop
+/// driving it. The context is declared first so it is dropped before its
+/// mainloop.
+struct Connection {
+    context: Context,
+    mainloop: Mainloop,
+}
+
+impl Connection {
+    fn new(name: &str) -> Result<Self, String> {
+        let mainloop = Mainloop::new().ok_or_else(|| "Failed to create mainloop".to_string())?;
+        let mut context =
+            Context::new(&mainloop, name).ok_or_else(|| "Failed to create context".to_string())?;
+
+        context
+            .connect(None, ContextFlagSets::empty(), None)
+            .map_err(|e| format!("Failed to connect: {}", e))?;
+
+        let mut connection = Connection { context, mainloop };
+
+        loop {
+            match connection.mainloop.iterate(false) {
+                IterateResult::Quit(_) | IterateResult::Err(_) => {
+                    return Err("Failed to iterate pulseaudio mainloop".to_string());
+                }
+                IterateResult::Success(_) => {}
+            }
+
+            match connection.context.get_state() {
+                ContextState::Ready => break,
+                ContextState::Failed | ContextState::Terminated => {
+                    return Err("Failed to connect to pulseaudio server".to_string());
+                }
+                _ => {}
+            }
+        }
+
+        Ok(connection)
+    }
+
+    /// Iterate the mainloop until `operation` has completed.
+    fn wait<T: ?Sized>(&mut self, operation: &Operation<T>) -> Result<(), String> {
+        loop {
+            match self.mainloop.iterate(true) {
+                IterateResult::Quit(_) | IterateResult::Err(_) => {
+                    return Err("Mainloop error".to_string());
+                }
+                IterateResult::Success(_) => {}
+            }
+            match operation.get_state() {
+                OperationState::Done | OperationState::Cancelled => return Ok(()),
+                OperationState::Running => {}
+            }
+        }
+    }
+
+    /// Wait for an operation whose callback reports success on `rx`.
+    fn wait_for_success<T: ?Sized>(
+        &mut self,
+        operation: &Operation<T>,
+        rx: &std::sync::mpsc::Receiver<bool>,
+        what: &str,
+    ) -> Result<(), String> {
+        self.wait(operation)?;
+        match rx.try_recv() {
+            Ok(true) => Ok(()),
+            _ => Err(format!("Failed to {}: {}", what, self.context.errno())),
+        }
+    }
+}
 
 pub struct AudioController;
 
@@ -43,61 +167,15 @@ impl AudioController {
     }
 
     fn get_default_sink_name() -> Result<String, String> {
-        let mut mainloop =
-            Mainloop::new().ok_or_else(|| "Failed to create mainloop".to_string())?;
-        let mut context = Context::new(&mainloop, "fa-control-master")
-            .ok_or_else(|| "Failed to create context".to_string())?;
-
-        context
-            .connect(None, ContextFlagSets::empty(), None)
-            .map_err(|e| format!("Failed to connect: {}", e))?;
-
-        loop {
-            match mainloop.iterate(false) {
-                IterateResult::Quit(_) | IterateResult::Err(_) => {
-                    return Err("Failed to iterate pulseaudio mainloop".to_string());
-                }
-                IterateResult::Success(_) => {}
-            }
-
-            if let ContextState::Ready = context.get_state() {
-                break;
-            }
+        // Fall back to the first sink if the server has no default
+        match ServerController::get_server_info()?.default_sink {
+            Some(name) => Ok(name),
+            None => DeviceController::get_output_devices()?
+                .into_iter()
+                .next()
+                .map(|device| device.name)
+                .ok_or_else(|| "No sink found".to_string()),
         }
-
-        let introspector = context.introspect();
-        let (sink_name_tx, sink_name_rx) = std::sync::mpsc::channel();
-
-        // Fallback strategy: Get ANY sink if default server info hangs
-        let operation = introspector.get_sink_info_list(move |result| {
-            if let ListResult::Item(sink) = result {
-                if let Some(name) = sink.name.as_ref() {
-                    let _ = sink_name_tx.send(name.to_string());
-                }
-            }
-        });
-
-        // Iterate until operation is done
-        loop {
-            match mainloop.iterate(true) {
-                IterateResult::Quit(_) | IterateResult::Err(_) => {
-                    return Err("Mainloop error".to_string());
-                }
-                _ => {}
-            }
-            if let Ok(name) = sink_name_rx.try_recv() {
-                return Ok(name);
-            }
-            match operation.get_state() {
-                OperationState::Done | OperationState::Cancelled => break,
-                _ => {}
-            }
-        }
-
-        if let Ok(name) = sink_name_rx.try_recv() {
-            return Ok(name);
-        }
-        Err("No sink found".to_string())
     }
 
     fn get_sink_volume() -> Result<f32, String> {
@@ -336,6 +414,23 @@ impl AppVolumeController {
         Ok(())
     }
 
+    /// Move the application's stream to the output device named `device`
+    pub fn set_app_output_device(pid: u32, device: &str) -> Result<(), String> {
+        let index = Self::find_sink_input_index_by_pid(pid)?;
+        let mut connection = Connection::new("fa-control-move-app")?;
+        let (success_tx, success_rx) = std::sync::mpsc::channel();
+
+        let operation = connection.context.introspect().move_sink_input_by_name(
+            index,
+            device,
+            Some(Box::new(move |success| {
+                let _ = success_tx.send(success);
+            })),
+        );
+
+        connection.wait_for_success(&operation, &success_rx, "move app to device")
+    }
+
     pub fn get_active_audio_apps() -> Result<Vec<AppInfo>, String> {
         let mut mainloop =
             Mainloop::new().ok_or_else(|| "Failed to create mainloop".to_string())?;
@@ -365,15 +460,10 @@ impl AppVolumeController {
 
         let operation = introspector.get_sink_input_info_list(move |result| {
             if let ListResult::Item(sink_input) = result {
-                let app_name = sink_input
-                    .name
-                    .as_ref()
-                    .map(|s| s.to_string())
-                    .unwrap_or_else(|| "Unknown".to_string());
+                let proplist = &sink_input.proplist;
 
                 // Extract PID from proplist
-                let pid_val = sink_input
-                    .proplist
+                let pid_val = proplist
                     .get_str(APPLICATION_PROCESS_ID)
                     .and_then(|s| s.parse::<u32>().ok())
                     .unwrap_or(0);
@@ -386,6 +476,39 @@ impl AppVolumeController {
                     pid_val
                 };
 
+                // Sandboxed clients report PIDs from their own namespace, so
+                // read /proc through the host PID when the server knows it,
+                // and otherwise only if the reported PID is really the client
+                let binary = proplist.get_str(APPLICATION_PROCESS_BINARY);
+                let host_pid = proplist
+                    .get_str(sandbox::SECURITY_PID)
+                    .and_then(|s| s.parse::<u32>().ok())
+                    .or_else(|| {
+                        (pid_val != 0 && process::is_host_pid(pid_val, binary.as_deref()))
+                            .then_some(pid_val)
+                    })
+                    .unwrap_or(0);
+
+                // The binary the client reports wins over /proc, which can't
+                // see processes in other PID namespaces
+                let mut process = (host_pid != 0)
+                    .then(|| process::process_info(host_pid))
+                    .flatten();
+                if let Some(binary) = binary {
+                    process.get_or_insert_with(ProcessInfo::default).binary = Some(binary);
+                }
+                if let Some(app_id) = proplist.get_str(sandbox::PORTAL_APP_ID) {
+                    process.get_or_insert_with(ProcessInfo::default).flatpak_id = Some(app_id);
+                }
+
+                // The stream name is often generic ("Playback"), so prefer
+                // the application's own name
+                let app_name = proplist
+                    .get_str(APPLICATION_NAME)
+                    .or_else(|| process.as_ref().and_then(|p| p.binary.clone()))
+                    .or_else(|| sink_input.name.as_ref().map(|s| s.to_string()))
+                    .unwrap_or_else(|| "Unknown".to_string());
+
                 let avg_volume =
                     sink_input.volume.avg().0 as f32 / pulse::volume::Volume::NORMAL.0 as f32;
 
@@ -394,6 +517,7 @@ impl AppVolumeController {
                     name: app_name,
                     volume: avg_volume as f64,
                     muted: sink_input.mute,
+                    process,
                 };
 
                 // Send all sink inputs, not just those with PIDs
@@ -677,8 +801,14 @@ impl AppVolumeController {
                     .and_then(|s| s.parse::<u32>().ok())
                     .unwrap_or(0);
 
-                // Check if this is the process we're looking for
-                if pid_val == pid {
+                let host_pid = sink_input
+                    .proplist
+                    .get_str(sandbox::SECURITY_PID)
+                    .and_then(|s| s.parse::<u32>().ok());
+
+                // Check if this is the process we're looking for, by the PID
+                // it reports or, for sandboxed apps, its host PID
+                if pid_val == pid || host_pid == Some(pid) {
                     let _ = index_tx.send(sink_input.index);
                 }
             }
@@ -711,13 +841,26 @@ impl AppVolumeController {
 
         Err(format!("Sink input with PID {} not found", pid))
     }
+
+    /// Index of the first sink input whose properties match `selector`,
+    /// written as in routing rules: `key=value` or a bare application name
+    fn find_sink_input_index_by_selector(selector: &str) -> Result<u32, String> {
+        ClientController::get_streams()?
+            .into_iter()
+            .find(|stream| {
+                stream.kind == StreamKind::SinkInput
+                    && selector_matches(selector, &stream.properties)
+            })
+            .map(|stream| stream.index)
+            .ok_or_else(|| format!("No sink input matches '{}'", selector))
+    }
 }
 
 pub struct InputController;
 
 impl InputController {
     pub fn get_microphone_volume() -> Result<f64, String> {
-        let volume = Self::get_source_volume()?;
+        let volume = Self::get_source_volume(&Self::get_default_source_name()?)?;
         Ok(volume as f64)
     }
 
@@ -726,84 +869,41 @@ impl InputController {
             return Err("Volume must be between 0.0 and 1.0".to_string());
         }
 
-        Self::set_source_volume(volume as f32)?;
+        Self::set_source_volume(&Self::get_default_source_name()?, volume as f32)?;
         Ok(())
     }
 
     pub fn is_microphone_muted() -> Result<bool, String> {
-        let muted = Self::get_source_mute()?;
+        let muted = Self::get_source_mute(&Self::get_default_source_name()?)?;
         Ok(muted)
     }
 
     pub fn toggle_microphone_mute() -> Result<bool, String> {
         let current_muted = Self::is_microphone_muted()?;
-        Self::set_source_mute(!current_muted)?;
+        Self::set_source_mute(&Self::get_default_source_name()?, !current_muted)?;
         Ok(!current_muted)
     }
 
     pub fn set_microphone_mute(muted: bool) -> Result<(), String> {
-        Self::set_source_mute(muted)?;
+        Self::set_source_mute(&Self::get_default_source_name()?, muted)?;
         Ok(())
     }
 
     fn get_default_source_name() -> Result<String, String> {
-        let mut mainloop =
-            Mainloop::new().ok_or_else(|| "Failed to create mainloop".to_string())?;
-        let mut context = Context::new(&mainloop, "fa-control-source")
-            .ok_or_else(|| "Failed to create context".to_string())?;
-
-        context
-            .connect(None, ContextFlagSets::empty(), None)
-            .map_err(|e| format!("Failed to connect: {}", e))?;
-
-        loop {
-            match mainloop.iterate(false) {
-                IterateResult::Quit(_) | IterateResult::Err(_) => {
-                    return Err("Failed to iterate pulseaudio mainloop".to_string());
-                }
-                IterateResult::Success(_) => {}
-            }
-
-            if let ContextState::Ready = context.get_state() {
-                break;
-            }
-        }
-
-        let introspector = context.introspect();
-        let (source_name_tx, source_name_rx) = std::sync::mpsc::channel();
-
-        let operation = introspector.get_source_info_list(move |result| {
-            if let ListResult::Item(source) = result {
-                if let Some(name) = source.name.as_ref() {
-                    let _ = source_name_tx.send(name.to_string());
-                }
-            }
-        });
-
-        loop {
-            match mainloop.iterate(true) {
-                IterateResult::Quit(_) | IterateResult::Err(_) => {
-                    return Err("Mainloop error".to_string());
-                }
-                _ => {}
-            }
-            if let Ok(name) = source_name_rx.try_recv() {
-                return Ok(name);
-            }
-            match operation.get_state() {
-                OperationState::Done | OperationState::Cancelled => break,
-                _ => {}
-            }
-        }
-
-        if let Ok(name) = source_name_rx.try_recv() {
-            return Ok(name);
+        // Prefer the server's default source, so that a newly selected default
+        // (e.g. an echo-cancelled source) is what the microphone controls act
+        // on, falling back to the first source if there is none
+        match ServerController::get_server_info()?.default_source {
+            Some(name) => Ok(name),
+            None => DeviceController::get_input_devices()?
+                .into_iter()
+                .next()
+                .map(|device| device.name)
+                .ok_or_else(|| "No source found".to_string()),
         }
-        Err("No source found".to_string())
     }
 
-    fn get_source_volume() -> Result<f32, String> {
-        let source_name = Self::get_default_source_name()?;
+    fn get_source_volume(source_name: &str) -> Result<f32, String> {
         let mut mainloop =
             Mainloop::new().ok_or_else(|| "Failed to create mainloop".to_string())?;
         let mut context = Context::new(&mainloop, "fa-control-get-source-volume")
@@ -829,7 +929,7 @@ impl InputController {
         let introspector = context.introspect();
         let (volume_tx, volume_rx) = std::sync::mpsc::channel();
 
-        let operation = introspector.get_source_info_by_name(&source_name, move |result| {
+        let operation = introspector.get_source_info_by_name(source_name, move |result| {
             if let ListResult::Item(source) = result {
                 let avg_volume =
                     source.volume.avg().0 as f32 / pulse::volume::Volume::NORMAL.0 as f32;
@@ -860,8 +960,7 @@ impl InputController {
         Err("Timeout getting source volume".to_string())
     }
 
-    fn set_source_volume(volume: f32) -> Result<(), String> {
-        let source_name = Self::get_default_source_name()?;
+    fn set_source_volume(source_name: &str, volume: f32) -> Result<(), String> {
         let mut mainloop =
             Mainloop::new().ok_or_else(|| "Failed to create mainloop".to_string())?;
         let mut context = Context::new(&mainloop, "fa-control-set-source-volume")
@@ -891,7 +990,7 @@ impl InputController {
 
             context
                 .introspect()
-                .set_source_volume_by_name(&source_name, &cv, None)
+                .set_source_volume_by_name(source_name, &cv, None)
         };
 
         loop {
@@ -910,8 +1009,7 @@ impl InputController {
         Ok(())
     }
 
-    fn get_source_mute() -> Result<bool, String> {
-        let source_name = Self::get_default_source_name()?;
+    fn get_source_mute(source_name: &str) -> Result<bool, String> {
         let mut mainloop =
             Mainloop::new().ok_or_else(|| "Failed to create mainloop".to_string())?;
         let mut context = Context::new(&mainloop, "fa-control-get-source-mute")
@@ -937,7 +1035,7 @@ impl InputController {
         let introspector = context.introspect();
         let (mute_tx, mute_rx) = std::sync::mpsc::channel();
 
-        let operation = introspector.get_source_info_by_name(&source_name, move |result| {
+        let operation = introspector.get_source_info_by_name(source_name, move |result| {
             if let ListResult::Item(source) = result {
                 let _ = mute_tx.send(source.mute);
             }
@@ -966,8 +1064,7 @@ impl InputController {
         Err("Timeout getting source mute state".to_string())
     }
 
-    fn set_source_mute(muted: bool) -> Result<(), String> {
-        let source_name = Self::get_default_source_name()?;
+    fn set_source_mute(source_name: &str, muted: bool) -> Result<(), String> {
         let mut mainloop =
             Mainloop::new().ok_or_else(|| "Failed to create mainloop".to_string())?;
         let mut context = Context::new(&mainloop, "fa-control-set-source-mute")
@@ -992,7 +1089,7 @@ impl InputController {
 
         let operation = context
             .introspect()
-            .set_source_mute_by_name(&source_name, muted, None);
+            .set_source_mute_by_name(source_name, muted, None);
 
         loop {
             match mainloop.iterate(true) {
@@ -0,0 +1,102 @@
+use libpulse_binding::callbacks::ListResult;
+use libpulse_binding::context::introspect::CardProfileInfo;
+use libpulse_binding::proplist::properties::DEVICE_DESCRIPTION;
+
+use super::Connection;
+use crate::{CardInfo, CardProfile};
+
+pub struct CardController;
+
+impl CardController {
+    pub fn get_cards() -> Result<Vec<CardInfo>, String> {
+        let mut connection = Connection::new("fa-control-get-cards")?;
+        let (card_tx, card_rx) = std::sync::mpsc::channel();
+
+        let operation = connection
+            .context
+            .introspect()
+            .get_card_info_list(move |result| {
+                if let ListResult::Item(card) = result {
+                    let name = card
+                        .name
+                        .as_ref()
+                        .map(|s| s.to_string())
+                        .unwrap_or_default();
+
+                    let _ = card_tx.send(CardInfo {
+                        index: card.index,
+                        description: card
+                            .proplist
+                            .get_str(DEVICE_DESCRIPTION)
+                            .unwrap_or_else(|| name.clone()),
+                        name,
+                        driver: card
+                            .driver
+                            .as_ref()
+                            .map(|s| s.to_string())
+                            .unwrap_or_default(),
+                        profiles: card.profiles.iter().map(to_card_profile).collect(),
+                        active_profile: card
+                            .active_profile
+                            .as_ref()
+                            .and_then(|profile| profile.name.as_ref())
+                            .map(|s| s.to_string()),
+                    });
+                }
+            });
+
+        connection.wait(&operation)?;
+
+        Ok(card_rx.try_iter().collect())
+    }
+
+    pub fn get_card(card: &str) -> Result<CardInfo, String> {
+        Self::get_cards()?
+            .into_iter()
+            .find(|info| info.name == card || info.index.to_string() == card)
+            .ok_or_else(|| format!("Card '{}' not found", card))
+    }
+
+    /// Switch `card` (name or index) to `profile`, e.g. "output:hdmi-stereo" or "off"
+    pub fn set_card_profile(card: &str, profile: &str) -> Result<(), String> {
+        let info = Self::get_card(card)?;
+        if !info.profiles.iter().any(|p| p.name == profile) {
+            return Err(format!(
+                "Card '{}' has no profile named '{}'",
+                info.name, profile
+            ));
+        }
+
+        let mut connection = Connection::new("fa-control-set-card-profile")?;
+        let (success_tx, success_rx) = std::sync::mpsc::channel();
+
+        let operation = connection.context.introspect().set_card_profile_by_index(
+            info.index,
+            profile,
+            Some(Box::new(move |success| {
+                let _ = success_tx.send(success);
+            })),
+        );
+
+        connection.wait_for_success(&operation, &success_rx, "set card profile")
+    }
+}
+
+fn to_card_profile(profile: &CardProfileInfo) -> CardProfile {
+    let name = profile
+        .name
+        .as_ref()
+        .map(|s| s.to_string())
+        .unwrap_or_default();
+
+    CardProfile {
+        description: profile
+            .description
+            .as_ref()
+            .map(|s| s.to_string())
+            .unwrap_or_else(|| name.clone()),
+        name,
+        priority: profile.priority,
+        available: profile.available,
+    }
+}
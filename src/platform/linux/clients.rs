@@ -0,0 +1,148 @@
+use std::collections::BTreeMap;
+
+use libpulse_binding::callbacks::ListResult;
+use libpulse_binding::proplist::{properties, Proplist};
+
+use super::Connection;
+use crate::{ClientInfo, StreamInfo, StreamKind};
+
+fn property_map(proplist: &Proplist) -> BTreeMap<String, String> {
+    proplist
+        .iter()
+        .filter_map(|key| proplist.get_str(&key).map(|value| (key, value)))
+        .collect()
+}
+
+pub(super) fn process_id(proplist: &Proplist) -> Option<u32> {
+    proplist
+        .get_str(properties::APPLICATION_PROCESS_ID)
+        .and_then(|s| s.parse().ok())
+}
+
+pub struct ClientController;
+
+impl ClientController {
+    /// List the clients connected to the server
+    pub fn get_clients() -> Result<Vec<ClientInfo>, String> {
+        let mut connection = Connection::new("fa-control-get-clients")?;
+        let (client_tx, client_rx) = std::sync::mpsc::channel();
+
+        let operation = connection
+            .context
+            .introspect()
+            .get_client_info_list(move |result| {
+                if let ListResult::Item(client) = result {
+                    let _ = client_tx.send(ClientInfo {
+                        index: client.index,
+                        name: client
+                            .name
+                            .as_ref()
+                            .map(|s| s.to_string())
+                            .unwrap_or_default(),
+                        driver: client
+                            .driver
+                            .as_ref()
+                            .map(|s| s.to_string())
+                            .unwrap_or_default(),
+                        pid: process_id(&client.proplist),
+                        properties: property_map(&client.proplist),
+                    });
+                }
+            });
+
+        connection.wait(&operation)?;
+
+        Ok(client_rx.try_iter().collect())
+    }
+
+    /// List playback streams (sink inputs) followed by recording streams
+    /// (source outputs)
+    pub fn get_streams() -> Result<Vec<StreamInfo>, String> {
+        let mut connection = Connection::new("fa-control-get-streams")?;
+        let (stream_tx, stream_rx) = std::sync::mpsc::channel();
+
+        let sink_input_tx = stream_tx.clone();
+        let operation = connection
+            .context
+            .introspect()
+            .get_sink_input_info_list(move |result| {
+                if let ListResult::Item(sink_input) = result {
+                    let _ = sink_input_tx.send(StreamInfo {
+                        index: sink_input.index,
+                        kind: StreamKind::SinkInput,
+                        name: sink_input
+                            .name
+                            .as_ref()
+                            .map(|s| s.to_string())
+                            .unwrap_or_default(),
+                        client: sink_input.client,
+                        pid: process_id(&sink_input.proplist),
+                        device: sink_input.sink,
+                        properties: property_map(&sink_input.proplist),
+                    });
+                }
+            });
+        connection.wait(&operation)?;
+
+        let operation =
+            connection
+                .context
+                .introspect()
+                .get_source_output_info_list(move |result| {
+                    if let ListResult::Item(source_output) = result {
+                        let _ = stream_tx.send(StreamInfo {
+                            index: source_output.index,
+                            kind: StreamKind::SourceOutput,
+                            name: source_output
+                                .name
+                                .as_ref()
+                                .map(|s| s.to_string())
+                                .unwrap_or_default(),
+                            client: source_output.client,
+                            pid: process_id(&source_output.proplist),
+                            device: source_output.source,
+                            properties: property_map(&source_output.proplist),
+                        });
+                    }
+                });
+        connection.wait(&operation)?;
+
+        Ok(stream_rx.try_iter().collect())
+    }
+
+    /// Disconnect a client from the server, closing all of its streams
+    pub fn kill_client(index: u32) -> Result<(), String> {
+        let mut connection = Connection::new("fa-control-kill-client")?;
+        let (success_tx, success_rx) = std::sync::mpsc::channel();
+
+        let operation = connection
+            .context
+            .introspect()
+            .kill_client(index, move |success| {
+                let _ = success_tx.send(success);
+            });
+
+        connection.wait_for_success(&operation, &success_rx, &format!("kill client {}", index))
+    }
+
+    /// Close a single stream; its client stays connected
+    pub fn kill_stream(kind: StreamKind, index: u32) -> Result<(), String> {
+        let mut connection = Connection::new("fa-control-kill-stream")?;
+        let (success_tx, success_rx) = std::sync::mpsc::channel();
+        let callback = move |success| {
+            let _ = success_tx.send(success);
+        };
+
+        let mut introspector = connection.context.introspect();
+        let operation = match kind {
+            StreamKind::SinkInput => introspector.kill_sink_input(index, callback),
+            StreamKind::SourceOutput => introspector.kill_source_output(index, callback),
+        };
+
+        connection.wait_for_success(
+            &operation,
+            &success_rx,
+            &format!("kill {} {}", kind.as_str().replace('_', " "), index),
+        )
+    }
+}
@@ -0,0 +1,315 @@
+use libpulse_binding::callbacks::ListResult;
+use libpulse_binding::channelmap::{Map, Position};
+use libpulse_binding::context::introspect::{SinkInfo, SinkPortInfo, SourceInfo, SourcePortInfo};
+use libpulse_binding::def::{PortAvailable, SinkState, SourceState};
+
+use super::Connection;
+use crate::{ChannelInfo, DeviceInfo, DeviceState, PortInfo};
+
+pub struct DeviceController;
+
+impl DeviceController {
+    /// Get all output devices (sinks)
+    pub fn get_output_devices() -> Result<Vec<DeviceInfo>, String> {
+        let mut connection = Connection::new("fa-control-get-sinks")?;
+        let (device_tx, device_rx) = std::sync::mpsc::channel();
+
+        let operation = connection
+            .context
+            .introspect()
+            .get_sink_info_list(move |result| {
+                if let ListResult::Item(sink) = result {
+                    let _ = device_tx.send(sink_to_device(sink));
+                }
+            });
+
+        connection.wait(&operation)?;
+
+        Ok(device_rx.try_iter().collect())
+    }
+
+    /// Get all input devices (sources), including monitors of output devices
+    pub fn get_input_devices() -> Result<Vec<DeviceInfo>, String> {
+        let mut connection = Connection::new("fa-control-get-sources")?;
+        let (device_tx, device_rx) = std::sync::mpsc::channel();
+
+        let operation = connection
+            .context
+            .introspect()
+            .get_source_info_list(move |result| {
+                if let ListResult::Item(source) = result {
+                    let _ = device_tx.send(source_to_device(source));
+                }
+            });
+
+        connection.wait(&operation)?;
+
+        Ok(device_rx.try_iter().collect())
+    }
+
+    /// Activate `port` on the output or input device named `device`
+    pub fn set_device_port(device: &str, port: &str) -> Result<(), String> {
+        let (info, is_output) = Self::find_device(device)?;
+        if !info.ports.iter().any(|p| p.name == port) {
+            return Err(format!(
+                "Device '{}' has no port named '{}'",
+                info.name, port
+            ));
+        }
+
+        let mut connection = Connection::new("fa-control-set-port")?;
+        let (success_tx, success_rx) = std::sync::mpsc::channel();
+        let callback = Box::new(move |success| {
+            let _ = success_tx.send(success);
+        });
+
+        let mut introspector = connection.context.introspect();
+        let operation = if is_output {
+            introspector.set_sink_port_by_index(info.index, port, Some(callback))
+        } else {
+            introspector.set_source_port_by_index(info.index, port, Some(callback))
+        };
+
+        connection.wait_for_success(&operation, &success_rx, "set device port")
+    }
+
+    /// Make the output device named `device` the default for new streams
+    pub fn set_default_output_device(device: &str) -> Result<(), String> {
+        let mut connection = Connection::new("fa-control-set-default-sink")?;
+        let (success_tx, success_rx) = std::sync::mpsc::channel();
+
+        let operation = connection.context.set_default_sink(device, move |success| {
+            let _ = success_tx.send(success);
+        });
+
+        connection.wait_for_success(&operation, &success_rx, "set default output device")
+    }
+
+    /// Make the input device named `device` the default for new streams
+    pub fn set_default_input_device(device: &str) -> Result<(), String> {
+        let mut connection = Connection::new("fa-control-set-default-source")?;
+        let (success_tx, success_rx) = std::sync::mpsc::channel();
+
+        let operation = connection
+            .context
+            .set_default_source(device, move |success| {
+                let _ = success_tx.send(success);
+            });
+
+        connection.wait_for_success(&operation, &success_rx, "set default input device")
+    }
+
+    /// Suspend the output or input device named `device`, closing it until
+    /// it is resumed, or resume it. Streams on a suspended device are
+    /// corked; new streams are not routed to it automatically.
+    pub fn suspend_device(device: &str, suspend: bool) -> Result<(), String> {
+        let (_, is_output) = Self::find_device(device)?;
+
+        let mut connection = Connection::new("fa-control-suspend-device")?;
+        let (success_tx, success_rx) = std::sync::mpsc::channel();
+        let callback = Box::new(move |success| {
+            let _ = success_tx.send(success);
+        });
+
+        let mut introspector = connection.context.introspect();
+        let operation = if is_output {
+            introspector.suspend_sink_by_name(device, suspend, Some(callback))
+        } else {
+            introspector.suspend_source_by_name(device, suspend, Some(callback))
+        };
+
+        let what = if suspend {
+            "suspend device"
+        } else {
+            "resume device"
+        };
+        connection.wait_for_success(&operation, &success_rx, what)
+    }
+
+    /// Look a device up by name among outputs, then inputs. The flag tells
+    /// whether it is an output device.
+    fn find_device(device: &str) -> Result<(DeviceInfo, bool), String> {
+        if let Some(info) = Self::get_output_devices()?
+            .into_iter()
+            .find(|info| info.name == device)
+        {
+            return Ok((info, true));
+        }
+
+        Self::get_input_devices()?
+            .into_iter()
+            .find(|info| info.name == device)
+            .map(|info| (info, false))
+            .ok_or_else(|| format!("Device '{}' not found", device))
+    }
+}
+
+fn port_available(available: PortAvailable) -> Option<bool> {
+    match available {
+        PortAvailable::Yes => Some(true),
+        PortAvailable::No => Some(false),
+        PortAvailable::Unknown => None,
+    }
+}
+
+fn sink_port_to_port(port: &SinkPortInfo, active_port: Option<&str>) -> PortInfo {
+    let name = port
+        .name
+        .as_ref()
+        .map(|s| s.to_string())
+        .unwrap_or_default();
+
+    PortInfo {
+        description: port
+            .description
+            .as_ref()
+            .map(|s| s.to_string())
+            .unwrap_or_else(|| name.clone()),
+        active: active_port == Some(name.as_str()),
+        name,
+        priority: port.priority,
+        available: port_available(port.available),
+    }
+}
+
+fn source_port_to_port(port: &SourcePortInfo, active_port: Option<&str>) -> PortInfo {
+    let name = port
+        .name
+        .as_ref()
+        .map(|s| s.to_string())
+        .unwrap_or_default();
+
+    PortInfo {
+        description: port
+            .description
+            .as_ref()
+            .map(|s| s.to_string())
+            .unwrap_or_else(|| name.clone()),
+        active: active_port == Some(name.as_str()),
+        name,
+        priority: port.priority,
+        available: port_available(port.available),
+    }
+}
+
+/// Name and channel map of an output device, or of the default output device
+/// if `None`
+pub(super) fn output_channel_map(device: Option<&str>) -> Result<(String, Map), String> {
+    let device = device.unwrap_or("@DEFAULT_SINK@");
+    let mut connection = Connection::new("fa-control-get-channel-map")?;
+    let (map_tx, map_rx) = std::sync::mpsc::channel();
+
+    let operation = connection
+        .context
+        .introspect()
+        .get_sink_info_by_name(device, move |result| {
+            if let ListResult::Item(sink) = result {
+                let name = sink
+                    .name
+                    .as_ref()
+                    .map(|s| s.to_string())
+                    .unwrap_or_default();
+                let _ = map_tx.send((name, sink.channel_map));
+            }
+        });
+
+    connection.wait(&operation)?;
+
+    map_rx
+        .try_recv()
+        .map_err(|_| format!("Output device '{}' not found", device))
+}
+
+pub(super) fn channel_infos(map: &Map) -> Vec<ChannelInfo> {
+    map.get()
+        .iter()
+        .map(|&position| ChannelInfo {
+            name: Position::to_string(position)
+                .map(|s| s.to_string())
+                .unwrap_or_default(),
+            label: Position::to_pretty_string(position).unwrap_or_default(),
+        })
+        .collect()
+}
+
+fn sink_to_device(sink: &SinkInfo) -> DeviceInfo {
+    let name = sink
+        .name
+        .as_ref()
+        .map(|s| s.to_string())
+        .unwrap_or_default();
+    let active_port = sink
+        .active_port
+        .as_ref()
+        .and_then(|port| port.name.as_ref())
+        .map(|s| s.to_string());
+
+    DeviceInfo {
+        index: sink.index,
+        description: sink
+            .description
+            .as_ref()
+            .map(|s| s.to_string())
+            .unwrap_or_else(|| name.clone()),
+        name,
+        ports: sink
+            .ports
+            .iter()
+            .map(|port| sink_port_to_port(port, active_port.as_deref()))
+            .collect(),
+        plugged: sink
+            .active_port
+            .as_ref()
+            .and_then(|port| port_available(port.available)),
+        active_port,
+        monitor_source: sink.monitor_source_name.as_ref().map(|s| s.to_string()),
+        channels: channel_infos(&sink.channel_map),
+        state: match sink.state {
+            SinkState::Running => DeviceState::Running,
+            SinkState::Idle => DeviceState::Idle,
+            SinkState::Suspended => DeviceState::Suspended,
+            SinkState::Invalid => DeviceState::Unknown,
+        },
+    }
+}
+
+fn source_to_device(source: &SourceInfo) -> DeviceInfo {
+    let name = source
+        .name
+        .as_ref()
+        .map(|s| s.to_string())
+        .unwrap_or_default();
+    let active_port = source
+        .active_port
+        .as_ref()
+        .and_then(|port| port.name.as_ref())
+        .map(|s| s.to_string());
+
+    DeviceInfo {
+        index: source.index,
+        description: source
+            .description
+            .as_ref()
+            .map(|s| s.to_string())
+            .unwrap_or_else(|| name.clone()),
+        name,
+        ports: source
+            .ports
+            .iter()
+            .map(|port| source_port_to_port(port, active_port.as_deref()))
+            .collect(),
+        plugged: source
+            .active_port
+            .as_ref()
+            .and_then(|port| port_available(port.available)),
+        active_port,
+        monitor_source: None,
+        channels: channel_infos(&source.channel_map),
+        state: match source.state {
+            SourceState::Running => DeviceState::Running,
+            SourceState::Idle => DeviceState::Idle,
+            SourceState::Suspended => DeviceState::Suspended,
+            SourceState::Invalid => DeviceState::Unknown,
+        },
+    }
+}
@@ -0,0 +1,123 @@
+use super::devices::DeviceController;
+use super::modules::{quote_argument, validate_device_name, OwnedModule};
+use crate::EchoCancelOptions;
+
+/// An echo-cancelled source/sink pair backed by module-echo-cancel. Both
+/// devices are removed when the handle is destroyed or dropped.
+#[derive(Debug)]
+pub struct EchoCancel {
+    source_name: String,
+    sink_name: String,
+    module: OwnedModule,
+}
+
+impl EchoCancel {
+    /// Name of the echo-cancelled source to record from
+    pub fn source_name(&self) -> &str {
+        &self.source_name
+    }
+
+    /// Name of the sink to play the far end of the call to
+    pub fn sink_name(&self) -> &str {
+        &self.sink_name
+    }
+
+    /// Index of the module backing this pair
+    pub fn module_index(&self) -> u32 {
+        self.module.index()
+    }
+
+    /// Unload the backing module, removing both devices
+    pub fn destroy(self) -> Result<(), String> {
+        self.module.unload()
+    }
+}
+
+pub struct EchoCancelController;
+
+impl EchoCancelController {
+    pub fn create_echo_cancel(options: &EchoCancelOptions) -> Result<EchoCancel, String> {
+        let argument = Self::module_argument(options)?;
+        let echo_cancel = EchoCancel {
+            source_name: options.source_name.clone(),
+            sink_name: options.sink_name.clone(),
+            module: OwnedModule::load("module-echo-cancel", &argument)?,
+        };
+
+        if options.make_default {
+            DeviceController::set_default_input_device(&echo_cancel.source_name)?;
+        }
+
+        Ok(echo_cancel)
+    }
+
+    fn module_argument(options: &EchoCancelOptions) -> Result<String, String> {
+        validate_device_name(&options.source_name)?;
+        validate_device_name(&options.sink_name)?;
+        validate_device_name(&options.method)?;
+
+        let mut argument = format!(
+            "source_name={} sink_name={} aec_method={}",
+            options.source_name, options.sink_name, options.method
+        );
+
+        if let Some(source_master) = &options.source_master {
+            validate_device_name(source_master)?;
+            argument.push_str(&format!(" source_master={}", source_master));
+        }
+        if let Some(sink_master) = &options.sink_master {
+            validate_device_name(sink_master)?;
+            argument.push_str(&format!(" sink_master={}", sink_master));
+        }
+
+        let mut aec_args = Vec::new();
+        if options.method == "webrtc" && options.noise_suppression {
+            aec_args.push("noise_suppression=1");
+        }
+        if let Some(extra) = options.aec_args.as_deref() {
+            aec_args.push(extra);
+        }
+        if !aec_args.is_empty() {
+            argument.push_str(&format!(
+                " aec_args={}",
+                quote_argument(&aec_args.join(" "))
+            ));
+        }
+
+        Ok(argument)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_module_argument() {
+        let options = EchoCancelOptions {
+            source_master: Some("alsa_input.usb".to_string()),
+            aec_args: Some("analog_gain_control=0".to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            EchoCancelController::module_argument(&options).unwrap(),
+            "source_name=echo_cancel_source sink_name=echo_cancel_sink aec_method=webrtc \
+             source_master=alsa_input.usb \
+             aec_args=\"noise_suppression=1 analog_gain_control=0\""
+        );
+    }
+
+    #[test]
+    fn test_module_argument_speex() {
+        let options = EchoCancelOptions {
+            method: "speex".to_string(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            EchoCancelController::module_argument(&options).unwrap(),
+            "source_name=echo_cancel_source sink_name=echo_cancel_sink aec_method=speex"
+        );
+    }
+}
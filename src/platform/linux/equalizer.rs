@@ -0,0 +1,173 @@
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use super::devices::output_channel_map;
+use super::pipeline::{run_pipeline, PipelineEnds};
+use super::stream_thread::StreamThread;
+use super::virtual_devices::{VirtualDevice, VirtualDeviceController};
+use crate::equalizer::EqProcessor;
+use crate::{EqBand, EqPreset, EqualizerOptions, SampleFormat, SampleSpec};
+
+const EQUALIZER_SPEC: SampleSpec = SampleSpec {
+    format: SampleFormat::F32Le,
+    rate: 48000,
+    channels: 2,
+};
+
+/// Settings shared with the processing thread
+struct Shared {
+    preset: Mutex<EqPreset>,
+    changed: AtomicBool,
+}
+
+/// A virtual sink whose audio is equalized and played on an output device.
+/// Route applications to `sink_name()` to equalize them. The sink is removed
+/// when the handle is destroyed or dropped.
+pub struct Equalizer {
+    // Fields are dropped in order: stop reading the sink before removing it
+    thread: StreamThread,
+    shared: Arc<Shared>,
+    output: String,
+    sink: VirtualDevice,
+}
+
+impl Equalizer {
+    /// Name of the virtual sink to route applications to
+    pub fn sink_name(&self) -> &str {
+        self.sink.name()
+    }
+
+    /// Device the equalized audio is played on
+    pub fn output_device(&self) -> &str {
+        &self.output
+    }
+
+    /// Current settings
+    pub fn preset(&self) -> Result<EqPreset, String> {
+        Ok(self.lock()?.clone())
+    }
+
+    /// Replace all settings; takes effect immediately
+    pub fn apply_preset(&self, preset: &EqPreset) -> Result<(), String> {
+        preset.validate()?;
+        self.update(|current| *current = preset.clone())
+    }
+
+    /// Replace the bands, keeping the preamp
+    pub fn set_bands(&self, bands: Vec<EqBand>) -> Result<(), String> {
+        bands.iter().try_for_each(EqBand::validate)?;
+        self.update(|preset| preset.bands = bands)
+    }
+
+    /// Change one band in place
+    pub fn set_band(&self, index: usize, band: EqBand) -> Result<(), String> {
+        band.validate()?;
+        let mut preset = self.lock()?;
+        let count = preset.bands.len();
+        let slot = preset
+            .bands
+            .get_mut(index)
+            .ok_or_else(|| format!("Band {} out of range ({} bands)", index, count))?;
+        *slot = band;
+        self.shared.changed.store(true, Ordering::Release);
+        Ok(())
+    }
+
+    pub fn set_preamp(&self, preamp_db: f64) -> Result<(), String> {
+        if !(-30.0..=30.0).contains(&preamp_db) {
+            return Err("Preamp must be between -30 and 30 dB".to_string());
+        }
+        self.update(|preset| preset.preamp_db = preamp_db)
+    }
+
+    /// Save the current settings to a preset file under `name`
+    pub fn save_preset(&self, path: &Path, name: &str) -> Result<(), String> {
+        let mut preset = self.preset()?;
+        preset.name = name.to_string();
+        preset.save(path)
+    }
+
+    /// Stop equalizing and remove the virtual sink
+    pub fn destroy(self) -> Result<(), String> {
+        let Equalizer { thread, sink, .. } = self;
+        let stopped = thread.stop();
+        let removed = sink.destroy();
+        stopped.and(removed)
+    }
+
+    fn update(&self, change: impl FnOnce(&mut EqPreset)) -> Result<(), String> {
+        let mut preset = self.lock()?;
+        change(&mut preset);
+        self.shared.changed.store(true, Ordering::Release);
+        Ok(())
+    }
+
+    fn lock(&self) -> Result<MutexGuard<'_, EqPreset>, String> {
+        self.shared
+            .preset
+            .lock()
+            .map_err(|_| "Equalizer lock poisoned".to_string())
+    }
+}
+
+pub struct EqualizerController;
+
+impl EqualizerController {
+    /// Create an equalizer sink named `options.name` playing on
+    /// `options.output`
+    pub fn create_equalizer(options: &EqualizerOptions) -> Result<Equalizer, String> {
+        options.preset.validate()?;
+        // Resolve the default now so the equalizer keeps playing on the same
+        // device if its own sink is made the default
+        let (output, _) = output_channel_map(options.output.as_deref())?;
+
+        let sink = VirtualDeviceController::create_null_sink(&options.name, "Equalizer")?;
+        let shared = Arc::new(Shared {
+            preset: Mutex::new(options.preset.clone()),
+            changed: AtomicBool::new(false),
+        });
+
+        let source = format!("{}.monitor", sink.name());
+        let thread_output = output.clone();
+        let thread_shared = shared.clone();
+        let (thread, ()) = StreamThread::spawn(move |stop, ready| {
+            let preset = thread_shared
+                .preset
+                .lock()
+                .map_err(|_| "Equalizer lock poisoned".to_string())?
+                .clone();
+            let mut processor = EqProcessor::new(
+                EQUALIZER_SPEC.rate,
+                EQUALIZER_SPEC.channels as usize,
+                &preset,
+            );
+            let ends = PipelineEnds {
+                source: &source,
+                sink: &thread_output,
+                name: "fa-control equalizer",
+            };
+            run_pipeline(
+                &ends,
+                &EQUALIZER_SPEC,
+                stop,
+                || ready.send(()),
+                |samples| {
+                    if thread_shared.changed.swap(false, Ordering::Acquire) {
+                        if let Ok(preset) = thread_shared.preset.lock() {
+                            processor.configure(&preset);
+                        }
+                    }
+                    processor.process(samples);
+                },
+            )
+        })?;
+
+        Ok(Equalizer {
+            thread,
+            shared,
+            output,
+            sink,
+        })
+    }
+}
@@ -0,0 +1,68 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use libpulse_binding::context::subscribe::InterestMaskSet;
+use libpulse_binding::context::State as ContextState;
+use libpulse_binding::mainloop::standard::IterateResult;
+
+use super::Connection;
+
+/// How often the event connection is polled; events arriving in between are
+/// handled together
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Call `handle` once, then again after every burst of server events in
+/// `mask` and whenever `changed_elsewhere` reports a change outside the
+/// server, until `stop` is set. `changed_elsewhere` is polled between
+/// events. `handle` runs on the calling thread and may open its own
+/// connections. `on_ready` is called once subscribed. Returns
+/// an error once the connection to the server is lost.
+pub(super) fn watch_server(
+    name: &str,
+    mask: InterestMaskSet,
+    stop: &AtomicBool,
+    on_ready: impl FnOnce(),
+    mut changed_elsewhere: impl FnMut() -> bool,
+    mut handle: impl FnMut(),
+) -> Result<(), String> {
+    let mut connection = Connection::new(name)?;
+    let changed = Rc::new(Cell::new(true));
+
+    let callback_changed = changed.clone();
+    connection
+        .context
+        .set_subscribe_callback(Some(Box::new(move |_, _, _| {
+            callback_changed.set(true);
+        })));
+
+    let (success_tx, success_rx) = std::sync::mpsc::channel();
+    let operation = connection.context.subscribe(mask, move |success| {
+        let _ = success_tx.send(success);
+    });
+    connection.wait_for_success(&operation, &success_rx, "subscribe to server events")?;
+    on_ready();
+
+    while !stop.load(Ordering::Relaxed) {
+        // A lost server only shows in the context's state; the main loop
+        // keeps iterating successfully without it
+        let lost = match connection.mainloop.iterate(false) {
+            IterateResult::Quit(_) | IterateResult::Err(_) => true,
+            IterateResult::Success(_) => matches!(
+                connection.context.get_state(),
+                ContextState::Failed | ContextState::Terminated
+            ),
+        };
+        if lost {
+            return Err("Lost connection to the server".to_string());
+        }
+        let elsewhere = changed_elsewhere();
+        if changed.replace(false) || elsewhere {
+            handle();
+        }
+        std::thread::sleep(POLL_INTERVAL);
+    }
+
+    Ok(())
+}
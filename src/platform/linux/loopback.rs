@@ -0,0 +1,79 @@
+use super::modules::{validate_device_name, OwnedModule};
+use super::streams::{find_sink_input_by_owner_module, set_sink_input_mute, set_sink_input_volume};
+
+/// A loopback forwarding audio from a source to a sink, backed by
+/// module-loopback. The loopback is removed when the handle is destroyed or
+/// dropped.
+#[derive(Debug)]
+pub struct Loopback {
+    source: String,
+    sink: String,
+    module: OwnedModule,
+}
+
+impl Loopback {
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    pub fn sink(&self) -> &str {
+        &self.sink
+    }
+
+    /// Index of the module backing this loopback
+    pub fn module_index(&self) -> u32 {
+        self.module.index()
+    }
+
+    /// Get the volume of the loopback stream (0.0 to 1.0)
+    pub fn get_volume(&self) -> Result<f64, String> {
+        let stream = find_sink_input_by_owner_module(self.module.index())?;
+        Ok(stream.volume as f64)
+    }
+
+    /// Set the volume of the loopback stream (0.0 to 1.0)
+    pub fn set_volume(&self, volume: f64) -> Result<(), String> {
+        let stream = find_sink_input_by_owner_module(self.module.index())?;
+        set_sink_input_volume(stream.index, stream.channels, volume)
+    }
+
+    pub fn is_muted(&self) -> Result<bool, String> {
+        let stream = find_sink_input_by_owner_module(self.module.index())?;
+        Ok(stream.muted)
+    }
+
+    pub fn set_mute(&self, muted: bool) -> Result<(), String> {
+        let stream = find_sink_input_by_owner_module(self.module.index())?;
+        set_sink_input_mute(stream.index, muted)
+    }
+
+    /// Unload the backing module, stopping the loopback
+    pub fn destroy(self) -> Result<(), String> {
+        self.module.unload()
+    }
+}
+
+pub struct LoopbackController;
+
+impl LoopbackController {
+    /// Route audio from `source` (a microphone or a `.monitor` source) to
+    /// `sink`, with the given target latency in milliseconds
+    pub fn create_loopback(source: &str, sink: &str, latency_ms: u32) -> Result<Loopback, String> {
+        validate_device_name(source)?;
+        validate_device_name(sink)?;
+        if latency_ms == 0 {
+            return Err("Latency must be greater than 0 ms".to_string());
+        }
+
+        let argument = format!(
+            "source={} sink={} latency_msec={} source_dont_move=true sink_dont_move=true",
+            source, sink, latency_ms
+        );
+
+        Ok(Loopback {
+            source: source.to_string(),
+            sink: sink.to_string(),
+            module: OwnedModule::load("module-loopback", &argument)?,
+        })
+    }
+}
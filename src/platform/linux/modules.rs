@@ -0,0 +1,191 @@
+use libpulse_binding::callbacks::ListResult;
+use libpulse_binding::def::INVALID_INDEX;
+
+use super::Connection;
+use crate::ModuleInfo;
+
+/// Load a server module and return its index
+pub(super) fn load_module(name: &str, argument: &str) -> Result<u32, String> {
+    let mut connection = Connection::new("fa-control-load-module")?;
+    let (index_tx, index_rx) = std::sync::mpsc::channel();
+
+    let operation = connection
+        .context
+        .introspect()
+        .load_module(name, argument, move |index| {
+            let _ = index_tx.send(index);
+        });
+
+    connection.wait(&operation)?;
+
+    match index_rx.try_recv() {
+        Ok(index) if index != INVALID_INDEX => Ok(index),
+        _ => Err(format!(
+            "Failed to load {}: {}",
+            name,
+            connection.context.errno()
+        )),
+    }
+}
+
+/// Unload the server module with the given index
+pub(super) fn unload_module(index: u32) -> Result<(), String> {
+    let mut connection = Connection::new("fa-control-unload-module")?;
+    let (success_tx, success_rx) = std::sync::mpsc::channel();
+
+    let operation = connection
+        .context
+        .introspect()
+        .unload_module(index, move |success| {
+            let _ = success_tx.send(success);
+        });
+
+    connection.wait_for_success(&operation, &success_rx, "unload module")
+}
+
+/// A module loaded by this library. It is unloaded again when dropped, unless
+/// it was unloaded explicitly before.
+#[derive(Debug)]
+pub(super) struct OwnedModule {
+    index: u32,
+    loaded: bool,
+}
+
+impl OwnedModule {
+    pub(super) fn load(name: &str, argument: &str) -> Result<Self, String> {
+        let index = load_module(name, argument)?;
+        Ok(OwnedModule {
+            index,
+            loaded: true,
+        })
+    }
+
+    pub(super) fn index(&self) -> u32 {
+        self.index
+    }
+
+    pub(super) fn unload(mut self) -> Result<(), String> {
+        self.loaded = false;
+        unload_module(self.index)
+    }
+}
+
+impl Drop for OwnedModule {
+    fn drop(&mut self) {
+        if self.loaded {
+            let _ = unload_module(self.index);
+        }
+    }
+}
+
+pub struct ModuleController;
+
+impl ModuleController {
+    /// List the modules loaded into the server, including those loaded by
+    /// other tools
+    pub fn get_modules() -> Result<Vec<ModuleInfo>, String> {
+        let mut connection = Connection::new("fa-control-get-modules")?;
+        let (module_tx, module_rx) = std::sync::mpsc::channel();
+
+        let operation = connection
+            .context
+            .introspect()
+            .get_module_info_list(move |result| {
+                if let ListResult::Item(module) = result {
+                    let _ = module_tx.send(ModuleInfo {
+                        index: module.index,
+                        name: module
+                            .name
+                            .as_ref()
+                            .map(|s| s.to_string())
+                            .unwrap_or_default(),
+                        argument: module
+                            .argument
+                            .as_ref()
+                            .map(|s| s.to_string())
+                            .unwrap_or_default(),
+                        usage: module.n_used,
+                    });
+                }
+            });
+
+        connection.wait(&operation)?;
+
+        Ok(module_rx.try_iter().collect())
+    }
+
+    /// Load a module and return its index. Unlike the modules behind the
+    /// high-level handles, it stays loaded until `unload_module` is called.
+    pub fn load_module(name: &str, argument: &str) -> Result<u32, String> {
+        if name.is_empty()
+            || !name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-'))
+        {
+            return Err(format!("Invalid module name '{}'", name));
+        }
+        load_module(name, argument)
+    }
+
+    /// Unload a module by index
+    pub fn unload_module(index: u32) -> Result<(), String> {
+        unload_module(index)
+    }
+}
+
+/// Quote a value for use in a module argument, e.g. a device description.
+/// Quote characters are dropped since module arguments have no escaping.
+pub(super) fn quote_argument(value: &str) -> String {
+    let value: String = value
+        .chars()
+        .filter(|c| !matches!(c, '"' | '\'' | '\\'))
+        .collect();
+    format!("\"{}\"", value)
+}
+
+/// Build a `*_properties` argument value setting the device description
+pub(super) fn description_properties(description: &str) -> String {
+    format!("'device.description={}'", quote_argument(description))
+}
+
+/// Device names end up unquoted in module arguments and pactl commands, so
+/// only allow a conservative set of characters.
+pub(super) fn validate_device_name(name: &str) -> Result<(), String> {
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+    {
+        return Err(format!(
+            "Invalid device name '{}': use letters, digits, '_', '-' or '.'",
+            name
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_description_properties() {
+        assert_eq!(
+            description_properties("Stream Mix"),
+            "'device.description=\"Stream Mix\"'"
+        );
+        assert_eq!(
+            description_properties("It's \"quoted\""),
+            "'device.description=\"Its quoted\"'"
+        );
+    }
+
+    #[test]
+    fn test_validate_device_name() {
+        assert!(validate_device_name("stream_mix").is_ok());
+        assert!(validate_device_name("virtual-mic.1").is_ok());
+        assert!(validate_device_name("").is_err());
+        assert!(validate_device_name("stream mix").is_err());
+        assert!(validate_device_name("mix sink_name=evil").is_err());
+    }
+}
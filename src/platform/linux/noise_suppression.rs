@@ -0,0 +1,197 @@
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::Arc;
+
+use super::devices::DeviceController;
+use super::modules::validate_device_name;
+use super::pipeline::{run_pipeline, PipelineEnds};
+use super::stream_thread::StreamThread;
+use super::virtual_devices::{VirtualDevice, VirtualDeviceController};
+use super::InputController;
+use crate::denoise::Denoiser;
+use crate::{NoiseSuppressionOptions, SampleFormat, SampleSpec};
+
+/// Microphones are denoised as mono; the sink upmixes to its own layout
+const DENOISE_SPEC: SampleSpec = SampleSpec {
+    format: SampleFormat::F32Le,
+    rate: 48000,
+    channels: 1,
+};
+
+/// Settings shared with the processing thread
+struct Settings {
+    enabled: AtomicBool,
+    /// `f32` bits
+    strength: AtomicU32,
+}
+
+impl Settings {
+    fn strength(&self) -> f32 {
+        f32::from_bits(self.strength.load(Ordering::Relaxed))
+    }
+}
+
+/// A virtual microphone carrying a denoised copy of a real one. The devices
+/// are removed when the handle is destroyed or dropped.
+///
+/// The microphone is denoised on a background thread and played into a null
+/// sink, whose monitor is exposed as a remapped source.
+pub struct NoiseSuppressor {
+    // Fields are dropped in order: stop the pipeline before removing its sink
+    thread: StreamThread,
+    settings: Arc<Settings>,
+    microphone: String,
+    source: VirtualDevice,
+    sink: VirtualDevice,
+}
+
+impl NoiseSuppressor {
+    /// Name of the denoised source to record from
+    pub fn source_name(&self) -> &str {
+        self.source.name()
+    }
+
+    /// The real microphone being denoised
+    pub fn microphone(&self) -> &str {
+        &self.microphone
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.settings.enabled.load(Ordering::Relaxed)
+    }
+
+    /// Turn suppression on or off; when off the microphone passes through
+    /// unchanged, with the same latency so nothing clicks
+    pub fn set_enabled(&self, enabled: bool) {
+        self.settings.enabled.store(enabled, Ordering::Relaxed);
+    }
+
+    pub fn strength(&self) -> f64 {
+        self.settings.strength() as f64
+    }
+
+    /// How much noise to remove, from 0.0 (none) to 1.0 (up to 30 dB)
+    pub fn set_strength(&self, strength: f64) -> Result<(), String> {
+        validate_strength(strength)?;
+        self.settings
+            .strength
+            .store((strength as f32).to_bits(), Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Volume of the denoised source, as `InputController` reports it when
+    /// the source is the default input device
+    pub fn get_volume(&self) -> Result<f64, String> {
+        InputController::get_source_volume(self.source_name()).map(|volume| volume as f64)
+    }
+
+    pub fn set_volume(&self, volume: f64) -> Result<(), String> {
+        if !(0.0..=1.0).contains(&volume) {
+            return Err("Volume must be between 0.0 and 1.0".to_string());
+        }
+        InputController::set_source_volume(self.source_name(), volume as f32)
+    }
+
+    pub fn is_muted(&self) -> Result<bool, String> {
+        InputController::get_source_mute(self.source_name())
+    }
+
+    pub fn set_mute(&self, muted: bool) -> Result<(), String> {
+        InputController::set_source_mute(self.source_name(), muted)
+    }
+
+    /// Stop denoising and remove the virtual devices
+    pub fn destroy(self) -> Result<(), String> {
+        let NoiseSuppressor {
+            thread,
+            source,
+            sink,
+            ..
+        } = self;
+        // Remove everything even if one step fails, reporting the first error
+        [thread.stop(), source.destroy(), sink.destroy()]
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()
+            .map(|_| ())
+    }
+}
+
+fn validate_strength(strength: f64) -> Result<(), String> {
+    if !(0.0..=1.0).contains(&strength) {
+        return Err("Strength must be between 0.0 and 1.0".to_string());
+    }
+    Ok(())
+}
+
+pub struct NoiseSuppressionController;
+
+impl NoiseSuppressionController {
+    /// Create a denoised copy of a microphone as the source `options.name`
+    pub fn create_noise_suppressor(
+        options: &NoiseSuppressionOptions,
+    ) -> Result<NoiseSuppressor, String> {
+        validate_device_name(&options.name)?;
+        validate_strength(options.strength)?;
+        let microphone = match &options.microphone {
+            Some(microphone) => microphone.clone(),
+            None => InputController::get_default_source_name()?,
+        };
+        if microphone == options.name {
+            return Err(format!("'{}' is already noise suppressed", microphone));
+        }
+
+        let sink = VirtualDeviceController::create_null_sink(
+            &format!("{}_sink", options.name),
+            "Noise Suppression",
+        )?;
+        let source = VirtualDeviceController::create_remapped_source(
+            &options.name,
+            "Noise Suppressed Microphone",
+            &format!("{}.monitor", sink.name()),
+        )?;
+
+        let settings = Arc::new(Settings {
+            enabled: AtomicBool::new(true),
+            strength: AtomicU32::new((options.strength as f32).to_bits()),
+        });
+
+        let thread_microphone = microphone.clone();
+        let thread_sink = sink.name().to_string();
+        let thread_settings = settings.clone();
+        let (thread, ()) = StreamThread::spawn(move |stop, ready| {
+            let mut denoiser = Denoiser::new(DENOISE_SPEC.rate, thread_settings.strength());
+            let ends = PipelineEnds {
+                source: &thread_microphone,
+                sink: &thread_sink,
+                name: "fa-control noise suppression",
+            };
+            run_pipeline(
+                &ends,
+                &DENOISE_SPEC,
+                stop,
+                || ready.send(()),
+                |samples| {
+                    denoiser.set_strength(if thread_settings.enabled.load(Ordering::Relaxed) {
+                        thread_settings.strength()
+                    } else {
+                        0.0
+                    });
+                    denoiser.process(samples);
+                },
+            )
+        })?;
+
+        let suppressor = NoiseSuppressor {
+            thread,
+            settings,
+            microphone,
+            source,
+            sink,
+        };
+
+        if options.make_default {
+            DeviceController::set_default_input_device(suppressor.source_name())?;
+        }
+
+        Ok(suppressor)
+    }
+}
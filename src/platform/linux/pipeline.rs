@@ -0,0 +1,160 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use libpulse_binding::def::BufferAttr;
+use libpulse_binding::mainloop::standard::IterateResult;
+use libpulse_binding::proplist::{properties, Proplist};
+use libpulse_binding::stream::{
+    FlagSet as StreamFlagSet, PeekResult, SeekMode, State as StreamState, Stream,
+};
+
+use super::recording::pulse_spec;
+use super::Connection;
+use crate::{SampleFormat, SampleSpec};
+
+/// Latency of each side of a pipeline, kept low since the audio is live
+const FRAGMENT: Duration = Duration::from_millis(10);
+const TARGET_LATENCY: Duration = Duration::from_millis(40);
+
+/// The ends of a record → process → play pipeline
+pub(super) struct PipelineEnds<'a> {
+    /// Source to read, e.g. the monitor of a virtual sink
+    pub source: &'a str,
+    /// Sink the processed audio is played to
+    pub sink: &'a str,
+    /// Name of the streams, as shown in mixers
+    pub name: &'a str,
+}
+
+/// Read `ends.source`, pass each chunk of interleaved float samples to
+/// `process`, and play the result on `ends.sink`, on the current thread.
+/// `spec` must be F32Le. Both streams are pinned to their devices so stream
+/// restore can't move them into a loop. `on_ready` is called once both
+/// streams are connected; returns when `stop` is set.
+pub(super) fn run_pipeline(
+    ends: &PipelineEnds,
+    spec: &SampleSpec,
+    stop: &AtomicBool,
+    on_ready: impl FnOnce(),
+    mut process: impl FnMut(&mut [f32]),
+) -> Result<(), String> {
+    if spec.format != SampleFormat::F32Le {
+        return Err("Pipelines process F32Le samples".to_string());
+    }
+    if ends.source == format!("{}.monitor", ends.sink) {
+        return Err(format!("Pipeline would play '{}' into itself", ends.sink));
+    }
+    let pulse_spec = pulse_spec(spec)?;
+
+    let mut connection = Connection::new("fa-control-pipeline")?;
+    let mut proplist = Proplist::new().ok_or_else(|| "Failed to create proplist".to_string())?;
+    let _ = proplist.set_str(properties::MEDIA_NAME, ends.name);
+    let _ = proplist.set_str(properties::APPLICATION_NAME, ends.name);
+
+    let bytes = |duration: Duration| {
+        ((duration.as_secs_f64() * spec.rate as f64) as usize * spec.frame_size()) as u32
+    };
+    let attr = BufferAttr {
+        maxlength: u32::MAX,
+        tlength: bytes(TARGET_LATENCY),
+        prebuf: u32::MAX,
+        minreq: u32::MAX,
+        fragsize: bytes(FRAGMENT),
+    };
+    let flags = StreamFlagSet::ADJUST_LATENCY | StreamFlagSet::DONT_MOVE;
+
+    let mut record = Stream::new_with_proplist(
+        &mut connection.context,
+        ends.name,
+        &pulse_spec,
+        None,
+        &mut proplist,
+    )
+    .ok_or_else(|| "Failed to create record stream".to_string())?;
+    record
+        .connect_record(Some(ends.source), Some(&attr), flags)
+        .map_err(|e| format!("Failed to connect record stream: {}", e))?;
+
+    let mut playback = Stream::new_with_proplist(
+        &mut connection.context,
+        ends.name,
+        &pulse_spec,
+        None,
+        &mut proplist,
+    )
+    .ok_or_else(|| "Failed to create playback stream".to_string())?;
+    playback
+        .connect_playback(Some(ends.sink), Some(&attr), flags, None, None)
+        .map_err(|e| format!("Failed to connect playback stream: {}", e))?;
+
+    loop {
+        match connection.mainloop.iterate(true) {
+            IterateResult::Quit(_) | IterateResult::Err(_) => {
+                return Err("Mainloop error".to_string());
+            }
+            IterateResult::Success(_) => {}
+        }
+        match (record.get_state(), playback.get_state()) {
+            (StreamState::Ready, StreamState::Ready) => break,
+            (StreamState::Failed | StreamState::Terminated, _)
+            | (_, StreamState::Failed | StreamState::Terminated) => {
+                return Err(format!(
+                    "Failed to connect pipeline streams: {}",
+                    connection.context.errno()
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    on_ready();
+
+    let mut samples = Vec::new();
+    while !stop.load(Ordering::Relaxed) {
+        match connection.mainloop.iterate(false) {
+            IterateResult::Quit(_) | IterateResult::Err(_) => {
+                return Err("Mainloop error".to_string());
+            }
+            IterateResult::Success(_) => {}
+        }
+        for stream in [&record, &playback] {
+            if let StreamState::Failed | StreamState::Terminated = stream.get_state() {
+                return Err(format!(
+                    "Pipeline stream failed: {}",
+                    connection.context.errno()
+                ));
+            }
+        }
+
+        loop {
+            match record
+                .peek()
+                .map_err(|e| format!("Failed to read record stream: {}", e))?
+            {
+                PeekResult::Empty => break,
+                PeekResult::Hole(_) => {}
+                PeekResult::Data(data) => {
+                    samples.clear();
+                    samples.extend(
+                        data.chunks_exact(4)
+                            .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]])),
+                    );
+                    process(&mut samples);
+                    let output: Vec<u8> = samples.iter().flat_map(|s| s.to_le_bytes()).collect();
+                    playback
+                        .write_copy(&output, 0, SeekMode::Relative)
+                        .map_err(|e| format!("Failed to write playback stream: {}", e))?;
+                }
+            }
+            record
+                .discard()
+                .map_err(|e| format!("Failed to read record stream: {}", e))?;
+        }
+
+        std::thread::sleep(Duration::from_millis(5));
+    }
+
+    let _ = record.disconnect();
+    let _ = playback.disconnect();
+    Ok(())
+}
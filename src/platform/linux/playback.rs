@@ -0,0 +1,202 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use libpulse_binding as pulse;
+use libpulse_binding::channelmap::Map;
+use libpulse_binding::mainloop::standard::IterateResult;
+use libpulse_binding::operation::State as OperationState;
+use libpulse_binding::proplist::{properties, Proplist};
+use libpulse_binding::stream::{FlagSet as StreamFlagSet, SeekMode, State as StreamState, Stream};
+use libpulse_binding::volume::ChannelVolumes;
+
+use super::recording::pulse_spec;
+use super::stream_thread::StreamThread;
+use super::Connection;
+use crate::{PlaybackOptions, PlaybackSource, SampleSpec};
+
+/// Play `data`, whole frames in `spec` laid out as `map` (the default layout
+/// for the channel count if `None`), on the current thread. `on_ready`
+/// receives the sink input index once the stream is connected. Returns once
+/// everything has been played, or early when `stop` is set.
+pub(super) fn run_playback_stream(
+    data: &[u8],
+    spec: &SampleSpec,
+    map: Option<&Map>,
+    options: &PlaybackOptions,
+    stop: &AtomicBool,
+    on_ready: impl FnOnce(u32),
+) -> Result<(), String> {
+    let mut connection = Connection::new("fa-control-playback")?;
+
+    let mut proplist = Proplist::new().ok_or_else(|| "Failed to create proplist".to_string())?;
+    let _ = proplist.set_str(properties::MEDIA_NAME, &options.name);
+    let _ = proplist.set_str(properties::APPLICATION_NAME, &options.name);
+    if let Some(role) = &options.role {
+        let _ = proplist.set_str(properties::MEDIA_ROLE, role);
+    }
+
+    let volume = match options.volume {
+        Some(volume) if !(0.0..=1.0).contains(&volume) => {
+            return Err("Volume must be between 0.0 and 1.0".to_string());
+        }
+        Some(volume) => {
+            let mut cv = ChannelVolumes::default();
+            cv.set(
+                spec.channels,
+                pulse::volume::Volume((volume * pulse::volume::Volume::NORMAL.0 as f64) as u32),
+            );
+            Some(cv)
+        }
+        None => None,
+    };
+
+    let mut stream = Stream::new_with_proplist(
+        &mut connection.context,
+        &options.name,
+        &pulse_spec(spec)?,
+        map,
+        &mut proplist,
+    )
+    .ok_or_else(|| "Failed to create playback stream".to_string())?;
+
+    stream
+        .connect_playback(
+            options.device.as_deref(),
+            None,
+            StreamFlagSet::ADJUST_LATENCY,
+            volume.as_ref(),
+            None,
+        )
+        .map_err(|e| format!("Failed to connect playback stream: {}", e))?;
+
+    loop {
+        match connection.mainloop.iterate(true) {
+            IterateResult::Quit(_) | IterateResult::Err(_) => {
+                return Err("Mainloop error".to_string());
+            }
+            IterateResult::Success(_) => {}
+        }
+        match stream.get_state() {
+            StreamState::Ready => break,
+            StreamState::Failed | StreamState::Terminated => {
+                return Err(format!(
+                    "Failed to connect playback stream: {}",
+                    connection.context.errno()
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    on_ready(stream.get_index().unwrap_or_default());
+
+    let frame_size = spec.frame_size();
+    let mut offset = 0;
+    let mut drain = None;
+    while !stop.load(Ordering::Relaxed) {
+        match connection.mainloop.iterate(false) {
+            IterateResult::Quit(_) | IterateResult::Err(_) => {
+                return Err("Mainloop error".to_string());
+            }
+            IterateResult::Success(_) => {}
+        }
+        if let StreamState::Failed | StreamState::Terminated = stream.get_state() {
+            return Err(format!(
+                "Playback stream failed: {}",
+                connection.context.errno()
+            ));
+        }
+
+        if offset < data.len() {
+            let writable = stream.writable_size().unwrap_or(0);
+            let len = writable.min(data.len() - offset) / frame_size * frame_size;
+            if len > 0 {
+                stream
+                    .write_copy(&data[offset..offset + len], 0, SeekMode::Relative)
+                    .map_err(|e| format!("Failed to write playback stream: {}", e))?;
+                offset += len;
+            }
+        } else {
+            // Everything is queued; wait for the server to play it
+            let operation = drain.get_or_insert_with(|| stream.drain(None));
+            if operation.get_state() != OperationState::Running {
+                break;
+            }
+        }
+
+        std::thread::sleep(Duration::from_millis(5));
+    }
+
+    let _ = stream.disconnect();
+    Ok(())
+}
+
+/// Sample spec and PCM data of a WAV file or validated PCM buffer
+pub(super) fn decode(source: PlaybackSource) -> Result<(SampleSpec, Vec<u8>), String> {
+    match source {
+        PlaybackSource::Wav(path) => crate::wav::read(&path),
+        PlaybackSource::Pcm { data, spec } => {
+            pulse_spec(&spec)?;
+            if data.len() % spec.frame_size() != 0 {
+                return Err("PCM data must contain whole frames".to_string());
+            }
+            Ok((spec, data))
+        }
+    }
+}
+
+/// Audio playing on a background thread. Dropping the handle stops playback.
+pub struct Playback {
+    thread: StreamThread,
+    sink_input: u32,
+}
+
+impl Playback {
+    /// Index of the playback stream on the server
+    pub fn sink_input_index(&self) -> u32 {
+        self.sink_input
+    }
+
+    /// Whether everything has been played
+    pub fn is_finished(&self) -> bool {
+        self.thread.is_finished()
+    }
+
+    /// Block until everything has been played
+    pub fn wait(self) -> Result<(), String> {
+        self.thread.wait()
+    }
+
+    /// Stop playing immediately
+    pub fn stop(self) -> Result<(), String> {
+        self.thread.stop()
+    }
+}
+
+pub struct PlaybackController;
+
+impl PlaybackController {
+    /// Start playing a WAV file or PCM buffer in the background, returning
+    /// once the stream is connected. Call `wait` on the returned handle to
+    /// block until it has been played.
+    pub fn play(source: PlaybackSource, options: &PlaybackOptions) -> Result<Playback, String> {
+        let (spec, data) = decode(source)?;
+        Self::play_pcm(data, spec, None, options)
+    }
+
+    pub(super) fn play_pcm(
+        data: Vec<u8>,
+        spec: SampleSpec,
+        map: Option<Map>,
+        options: &PlaybackOptions,
+    ) -> Result<Playback, String> {
+        let options = options.clone();
+        let (thread, sink_input) = StreamThread::spawn(move |stop, ready| {
+            run_playback_stream(&data, &spec, map.as_ref(), &options, stop, |index| {
+                ready.send(index)
+            })
+        })?;
+
+        Ok(Playback { thread, sink_input })
+    }
+}
@@ -0,0 +1,172 @@
+use std::sync::atomic::AtomicBool;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use libpulse_binding::context::subscribe::InterestMaskSet;
+
+use super::clients::ClientController;
+use super::devices::DeviceController;
+use super::events::watch_server;
+use super::server::ServerController;
+use super::stream_thread::StreamThread;
+use super::streams::move_stream;
+use crate::routing::choose_device;
+use crate::{DeviceInfo, PreferredDeviceOptions, StreamKind};
+
+/// Settings shared with the watcher thread
+struct Shared {
+    options: Mutex<PreferredDeviceOptions>,
+    /// Serializes switches made by the watcher and by setters
+    applying: Mutex<()>,
+}
+
+/// Keeps the default output and input devices on the highest-priority
+/// available device of their preference lists. The lists are re-applied
+/// whenever devices appear, disappear or change, until the handle is
+/// stopped or dropped.
+pub struct PreferredDevices {
+    thread: StreamThread,
+    shared: Arc<Shared>,
+}
+
+impl PreferredDevices {
+    /// Output devices, highest priority first
+    pub fn outputs(&self) -> Result<Vec<String>, String> {
+        Ok(self.lock()?.outputs.clone())
+    }
+
+    /// Input devices, highest priority first
+    pub fn inputs(&self) -> Result<Vec<String>, String> {
+        Ok(self.lock()?.inputs.clone())
+    }
+
+    /// Replace the output list and switch to its best device now
+    pub fn set_outputs(&self, outputs: Vec<String>) -> Result<(), String> {
+        self.lock()?.outputs = outputs;
+        apply(&self.shared)
+    }
+
+    /// Replace the input list and switch to its best device now
+    pub fn set_inputs(&self, inputs: Vec<String>) -> Result<(), String> {
+        self.lock()?.inputs = inputs;
+        apply(&self.shared)
+    }
+
+    /// Whether the watcher is still running; it stops if the connection to
+    /// the server is lost
+    pub fn is_running(&self) -> bool {
+        !self.thread.is_finished()
+    }
+
+    /// Stop watching; the current defaults are left as they are
+    pub fn stop(self) -> Result<(), String> {
+        self.thread.stop()
+    }
+
+    fn lock(&self) -> Result<MutexGuard<'_, PreferredDeviceOptions>, String> {
+        self.shared
+            .options
+            .lock()
+            .map_err(|_| "Preferred devices lock poisoned".to_string())
+    }
+}
+
+/// Switch the defaults to the best available devices, moving streams from
+/// the previous defaults if requested
+fn apply(shared: &Shared) -> Result<(), String> {
+    let _applying = shared
+        .applying
+        .lock()
+        .map_err(|_| "Preferred devices lock poisoned".to_string())?;
+    let options = shared
+        .options
+        .lock()
+        .map_err(|_| "Preferred devices lock poisoned".to_string())?
+        .clone();
+    let server = ServerController::get_server_info()?;
+
+    if !options.outputs.is_empty() {
+        switch_default(
+            StreamKind::SinkInput,
+            &options.outputs,
+            &DeviceController::get_output_devices()?,
+            server.default_sink.as_deref(),
+            options.move_streams,
+        )?;
+    }
+    if !options.inputs.is_empty() {
+        switch_default(
+            StreamKind::SourceOutput,
+            &options.inputs,
+            &DeviceController::get_input_devices()?,
+            server.default_source.as_deref(),
+            options.move_streams,
+        )?;
+    }
+    Ok(())
+}
+
+fn switch_default(
+    kind: StreamKind,
+    priority: &[String],
+    devices: &[DeviceInfo],
+    current: Option<&str>,
+    move_streams: bool,
+) -> Result<(), String> {
+    let Some(target) = choose_device(priority, devices) else {
+        return Ok(());
+    };
+    if current == Some(target.name.as_str()) {
+        return Ok(());
+    }
+
+    match kind {
+        StreamKind::SinkInput => DeviceController::set_default_output_device(&target.name)?,
+        StreamKind::SourceOutput => DeviceController::set_default_input_device(&target.name)?,
+    }
+
+    let previous = devices
+        .iter()
+        .find(|device| Some(device.name.as_str()) == current);
+    if let (true, Some(previous)) = (move_streams, previous) {
+        for stream in ClientController::get_streams()? {
+            if stream.kind == kind && stream.device == previous.index {
+                // Streams may refuse to move or be gone already; the rest
+                // should still follow the default
+                let _ = move_stream(kind, stream.index, &target.name);
+            }
+        }
+    }
+    Ok(())
+}
+
+pub struct PreferredDevicesController;
+
+impl PreferredDevicesController {
+    /// Apply the preference lists now and keep applying them as devices come
+    /// and go
+    pub fn watch(options: &PreferredDeviceOptions) -> Result<PreferredDevices, String> {
+        let shared = Arc::new(Shared {
+            options: Mutex::new(options.clone()),
+            applying: Mutex::new(()),
+        });
+        apply(&shared)?;
+
+        let thread_shared = shared.clone();
+        let (thread, ()) = StreamThread::spawn(move |stop: &AtomicBool, ready| {
+            watch_server(
+                "fa-control-preferred-devices",
+                InterestMaskSet::SINK | InterestMaskSet::SOURCE | InterestMaskSet::SERVER,
+                stop,
+                || ready.send(()),
+                || false,
+                // Failures are usually devices vanishing mid-switch; the
+                // events that follow trigger another attempt
+                || {
+                    let _ = apply(&thread_shared);
+                },
+            )
+        })?;
+
+        Ok(PreferredDevices { thread, shared })
+    }
+}
@@ -0,0 +1,279 @@
+use std::path::Path;
+
+use crate::ProcessInfo;
+
+/// Deepest process tree `descends_from` walks
+const MAX_TREE_DEPTH: usize = 1024;
+
+/// What /proc reports about a process, or `None` if it has exited or
+/// belongs to another PID namespace. The executable path is only readable
+/// for processes of the same user.
+pub(super) fn process_info(pid: u32) -> Option<ProcessInfo> {
+    let dir = Path::new("/proc").join(pid.to_string());
+    let status = std::fs::read_to_string(dir.join("status")).ok()?;
+
+    let exe = std::fs::read_link(dir.join("exe")).ok().map(|path| {
+        let path = path.to_string_lossy();
+        path.strip_suffix(" (deleted)").unwrap_or(&path).to_string()
+    });
+    let systemd_unit = systemd_unit_of(pid);
+    let uid = status_field(&status, "Uid")
+        .and_then(|ids| ids.split_whitespace().next())
+        .and_then(|uid| uid.parse().ok());
+
+    Some(ProcessInfo {
+        // The kernel truncates the status name to 15 characters
+        binary: exe
+            .as_deref()
+            .and_then(|exe| Path::new(exe).file_name())
+            .map(|name| name.to_string_lossy().into_owned())
+            .or_else(|| status_field(&status, "Name").map(str::to_string)),
+        exe,
+        cmdline: std::fs::read(dir.join("cmdline"))
+            .map(|bytes| parse_cmdline(&bytes))
+            .unwrap_or_default(),
+        uid,
+        user: uid.and_then(|uid| {
+            let passwd = std::fs::read_to_string("/etc/passwd").ok()?;
+            user_name(&passwd, uid)
+        }),
+        ppid: parent_pid(&status),
+        flatpak_id: systemd_unit.as_deref().and_then(flatpak_id_from_unit),
+        systemd_unit,
+    })
+}
+
+/// Whether `pid`, as a client reported it, is that client's PID on the host.
+/// A sandboxed client reports a PID from its own namespace, which on the host
+/// can belong to an unrelated process, so the executable there must match
+/// the binary the client reports. Without a binary to check, the PID is
+/// trusted unless its process runs in a different PID namespace from ours.
+pub(super) fn is_host_pid(pid: u32, binary: Option<&str>) -> bool {
+    let Some(binary) = binary else {
+        return in_own_pid_namespace(pid);
+    };
+    let dir = Path::new("/proc").join(pid.to_string());
+    match std::fs::read_link(dir.join("exe")) {
+        Ok(exe) => exe.file_name().is_some_and(|name| {
+            let name = name.to_string_lossy();
+            name.strip_suffix(" (deleted)").unwrap_or(&name) == binary
+        }),
+        // Another user's process, so only its status name is readable, which
+        // the kernel truncates to 15 bytes
+        Err(_) => std::fs::read_to_string(dir.join("status"))
+            .ok()
+            .and_then(|status| status_field(&status, "Name").map(str::to_string))
+            .is_some_and(|name| {
+                name.as_bytes() == binary.as_bytes().get(..15).unwrap_or(binary.as_bytes())
+            }),
+    }
+}
+
+/// Whether the process `pid` exists and shares our PID namespace, assuming
+/// it does when the namespace isn't readable, as for other users' processes
+fn in_own_pid_namespace(pid: u32) -> bool {
+    let dir = Path::new("/proc").join(pid.to_string());
+    if !dir.exists() {
+        return false;
+    }
+    match (
+        std::fs::read_link(dir.join("ns/pid")),
+        std::fs::read_link("/proc/self/ns/pid"),
+    ) {
+        (Ok(theirs), Ok(ours)) => theirs == ours,
+        _ => true,
+    }
+}
+
+/// The systemd service or scope a process runs in, from its cgroup
+pub(super) fn systemd_unit_of(pid: u32) -> Option<String> {
+    let cgroup = std::fs::read_to_string(format!("/proc/{}/cgroup", pid)).ok()?;
+    systemd_unit(&cgroup)
+}
+
+/// The innermost service or scope in /proc cgroup contents, from the
+/// unified hierarchy or else the legacy systemd one
+fn systemd_unit(cgroup: &str) -> Option<String> {
+    let path = |prefix: &str| {
+        cgroup
+            .lines()
+            .find_map(|line| line.strip_prefix(prefix))
+            .map(str::to_string)
+    };
+    let path = path("0::").or_else(|| {
+        cgroup.lines().find_map(|line| {
+            let (_, rest) = line.split_once(':')?;
+            rest.strip_prefix("name=systemd:").map(str::to_string)
+        })
+    })?;
+
+    path.rsplit('/')
+        .find(|unit| unit.ends_with(".service") || unit.ends_with(".scope"))
+        .map(str::to_string)
+}
+
+/// The application ID in a Flatpak scope name such as
+/// "app-flatpak-org.telegram.desktop-12345.scope"
+pub(super) fn flatpak_id_from_unit(unit: &str) -> Option<String> {
+    let instance = unit.strip_prefix("app-flatpak-")?.strip_suffix(".scope")?;
+    let (app_id, number) = instance.rsplit_once('-')?;
+    number
+        .chars()
+        .all(|c| c.is_ascii_digit())
+        .then(|| app_id.to_string())
+}
+
+/// Parent PID of a running process
+pub(super) fn parent_of(pid: u32) -> Option<u32> {
+    let status = std::fs::read_to_string(format!("/proc/{}/status", pid)).ok()?;
+    parent_pid(&status)
+}
+
+/// Whether `pid` is `root` or one of its descendants, following `parent`
+/// links up the process tree
+pub(super) fn descends_from(pid: u32, root: u32, parent: impl Fn(u32) -> Option<u32>) -> bool {
+    let mut current = pid;
+    // Bounded in case a reused PID makes the links loop while walking
+    for _ in 0..MAX_TREE_DEPTH {
+        if current == root {
+            return true;
+        }
+        match parent(current) {
+            Some(next) => current = next,
+            None => return false,
+        }
+    }
+    false
+}
+
+/// Parent PID from a /proc status file; `None` for processes started by the
+/// kernel
+fn parent_pid(status: &str) -> Option<u32> {
+    status_field(status, "PPid")
+        .and_then(|ppid| ppid.parse().ok())
+        .filter(|&ppid| ppid != 0)
+}
+
+/// The value of a `Key:\tvalue` line of a /proc status file
+fn status_field<'a>(status: &'a str, key: &str) -> Option<&'a str> {
+    status.lines().find_map(|line| {
+        let (name, value) = line.split_once(':')?;
+        (name == key).then(|| value.trim())
+    })
+}
+
+/// Split a NUL-separated /proc cmdline
+fn parse_cmdline(bytes: &[u8]) -> Vec<String> {
+    bytes
+        .split(|&b| b == 0)
+        .filter(|arg| !arg.is_empty())
+        .map(|arg| String::from_utf8_lossy(arg).into_owned())
+        .collect()
+}
+
+/// Look up a user name in passwd file contents
+fn user_name(passwd: &str, uid: u32) -> Option<String> {
+    passwd.lines().find_map(|line| {
+        let mut fields = line.split(':');
+        let name = fields.next()?;
+        let id = fields.nth(1)?.parse::<u32>().ok()?;
+        (id == uid).then(|| name.to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_fields() {
+        let status = "Name:\tfirefox\nUmask:\t0022\nPPid:\t1234\nUid:\t1000\t1000\t1000\t1000\n";
+        assert_eq!(status_field(status, "Name"), Some("firefox"));
+        assert_eq!(parent_pid(status), Some(1234));
+        assert_eq!(parent_pid("PPid:\t0\n"), None);
+        assert_eq!(status_field(status, "Gid"), None);
+    }
+
+    #[test]
+    fn test_parse_cmdline() {
+        assert_eq!(
+            parse_cmdline(b"/usr/bin/python3\0-m\0fa_control\0"),
+            ["/usr/bin/python3", "-m", "fa_control"]
+        );
+        assert!(parse_cmdline(b"").is_empty());
+    }
+
+    #[test]
+    fn test_user_name() {
+        let passwd = "root:x:0:0:root:/root:/bin/bash\nalice:x:1000:1000::/home/alice:/bin/sh\n";
+        assert_eq!(user_name(passwd, 1000).as_deref(), Some("alice"));
+        assert_eq!(user_name(passwd, 0).as_deref(), Some("root"));
+        assert_eq!(user_name(passwd, 42), None);
+    }
+
+    #[test]
+    fn test_descends_from() {
+        // 1 -> 100 (browser) -> 200 (zygote) -> 300 (audio service)
+        let parents = std::collections::HashMap::from([(100, 1), (200, 100), (300, 200), (400, 1)]);
+        let parent = |pid| parents.get(&pid).copied();
+
+        assert!(descends_from(300, 100, parent));
+        assert!(descends_from(100, 100, parent));
+        assert!(!descends_from(400, 100, parent));
+        assert!(!descends_from(100, 300, parent));
+
+        // A loop is given up on rather than followed forever
+        assert!(!descends_from(1, 5, |pid| Some(pid % 2 + 1)));
+    }
+
+    #[test]
+    fn test_systemd_unit() {
+        let flatpak = "0::/user.slice/user-1000.slice/user@1000.service/app.slice/\
+                       app-flatpak-org.telegram.desktop-12345.scope\n";
+        let unit = systemd_unit(flatpak).unwrap();
+        assert_eq!(unit, "app-flatpak-org.telegram.desktop-12345.scope");
+        assert_eq!(
+            flatpak_id_from_unit(&unit).as_deref(),
+            Some("org.telegram.desktop")
+        );
+
+        let legacy = "12:cpu,cpuacct:/\n1:name=systemd:/system.slice/spotify.service\n";
+        assert_eq!(systemd_unit(legacy).as_deref(), Some("spotify.service"));
+        assert_eq!(flatpak_id_from_unit("spotify.service"), None);
+        assert_eq!(
+            flatpak_id_from_unit("app-flatpak-org.foo.Bar-x.scope"),
+            None
+        );
+
+        // Inside a container with no systemd units
+        assert_eq!(systemd_unit("0::/\n"), None);
+    }
+
+    #[test]
+    fn test_is_host_pid() {
+        let pid = std::process::id();
+        let binary = process_info(pid).unwrap().binary.unwrap();
+        assert!(is_host_pid(pid, Some(&binary)));
+
+        // A PID from another namespace belonging to a different program here
+        assert!(!is_host_pid(pid, Some("telegram-desktop")));
+
+        // Without a binary, a process in our own namespace is trusted
+        assert!(is_host_pid(pid, None));
+        assert!(!is_host_pid(u32::MAX, None));
+    }
+
+    #[test]
+    fn test_own_process() {
+        let info = process_info(std::process::id()).unwrap();
+        assert!(info.binary.is_some());
+        assert!(info.ppid.is_some());
+        assert!(!info.cmdline.is_empty());
+        assert_eq!(parent_of(std::process::id()), info.ppid);
+        assert!(descends_from(
+            std::process::id(),
+            info.ppid.unwrap(),
+            parent_of
+        ));
+    }
+}
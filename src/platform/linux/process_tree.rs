@@ -0,0 +1,57 @@
+use super::clients::ClientController;
+use super::process::{descends_from, parent_of};
+use super::stream_group::StreamGroup;
+use crate::StreamInfo;
+
+/// Control the playback streams of a process and all of its descendants,
+/// for applications such as Chromium and Electron apps that play audio from
+/// child processes
+pub struct ProcessTreeController;
+
+impl ProcessTreeController {
+    /// Playback and recording streams of `root` and its descendants
+    pub fn get_streams(root: u32) -> Result<Vec<StreamInfo>, String> {
+        Ok(ClientController::get_streams()?
+            .into_iter()
+            .filter(|stream| {
+                stream
+                    .pid
+                    .is_some_and(|pid| descends_from(pid, root, parent_of))
+            })
+            .collect())
+    }
+
+    /// Average volume of the process tree's streams
+    pub fn get_volume(root: u32) -> Result<f64, String> {
+        Ok(Self::find_streams(root)?.volume())
+    }
+
+    /// Set the volume of every stream of the process tree, returning how
+    /// many were changed
+    pub fn set_volume(root: u32, volume: f64) -> Result<usize, String> {
+        Self::find_streams(root)?.set_volume(volume)
+    }
+
+    /// Whether every stream of the process tree is muted
+    pub fn is_muted(root: u32) -> Result<bool, String> {
+        Ok(Self::find_streams(root)?.is_muted())
+    }
+
+    pub fn set_mute(root: u32, muted: bool) -> Result<usize, String> {
+        Self::find_streams(root)?.set_mute(muted)
+    }
+
+    /// Move every stream of the process tree to the output device named
+    /// `device`
+    pub fn set_output_device(root: u32, device: &str) -> Result<usize, String> {
+        Self::find_streams(root)?.set_output_device(device)
+    }
+
+    fn find_streams(root: u32) -> Result<StreamGroup, String> {
+        StreamGroup::find(&format!("process {} or its descendants", root), |stream| {
+            stream
+                .pid
+                .is_some_and(|pid| descends_from(pid, root, parent_of))
+        })
+    }
+}
@@ -0,0 +1,319 @@
+use std::fs::File;
+use std::io::BufWriter;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use libpulse_binding::mainloop::standard::IterateResult;
+use libpulse_binding::sample::{Format, Spec};
+use libpulse_binding::stream::{
+    FlagSet as StreamFlagSet, PeekResult, State as StreamState, Stream,
+};
+
+use super::devices::DeviceController;
+use super::stream_thread::StreamThread;
+use super::streams::sink_input_monitor_source;
+use super::{AppVolumeController, Connection};
+use crate::wav::WavWriter;
+use crate::{PcmCallback, RecordingOptions, RecordingOutput, SampleFormat, SampleSpec};
+
+/// What a record stream reads from
+pub(super) struct RecordSource {
+    /// Source to connect to, or the default source if `None`
+    pub device: Option<String>,
+    /// Only record this sink input; `device` must be the monitor of its sink
+    pub sink_input: Option<u32>,
+}
+
+pub(super) fn pulse_spec(spec: &SampleSpec) -> Result<Spec, String> {
+    let pulse_spec = Spec {
+        format: match spec.format {
+            SampleFormat::S16Le => Format::S16le,
+            SampleFormat::S32Le => Format::S32le,
+            SampleFormat::F32Le => Format::F32le,
+        },
+        rate: spec.rate,
+        channels: spec.channels,
+    };
+
+    if !pulse_spec.is_valid() {
+        return Err(format!(
+            "Invalid sample spec: {} Hz, {} channels",
+            spec.rate, spec.channels
+        ));
+    }
+    Ok(pulse_spec)
+}
+
+/// Run a record stream on the current thread. `on_ready` is called once the
+/// stream is connected; `on_data` receives chunks of whole frames and returns
+/// whether to keep recording. Returns when `stop` is set or `on_data` asks to
+/// stop.
+pub(super) fn run_record_stream(
+    source: &RecordSource,
+    spec: &SampleSpec,
+    stop: &AtomicBool,
+    on_ready: impl FnOnce(),
+    mut on_data: impl FnMut(&[u8]) -> Result<bool, String>,
+) -> Result<(), String> {
+    let mut connection = Connection::new("fa-control-record")?;
+    let mut stream = Stream::new(
+        &mut connection.context,
+        "fa-control recording",
+        &pulse_spec(spec)?,
+        None,
+    )
+    .ok_or_else(|| "Failed to create record stream".to_string())?;
+
+    if let Some(index) = source.sink_input {
+        stream
+            .set_monitor_stream(index)
+            .map_err(|e| format!("Failed to monitor sink input {}: {}", index, e))?;
+    }
+
+    stream
+        .connect_record(
+            source.device.as_deref(),
+            None,
+            StreamFlagSet::ADJUST_LATENCY,
+        )
+        .map_err(|e| format!("Failed to connect record stream: {}", e))?;
+
+    loop {
+        match connection.mainloop.iterate(true) {
+            IterateResult::Quit(_) | IterateResult::Err(_) => {
+                return Err("Mainloop error".to_string());
+            }
+            IterateResult::Success(_) => {}
+        }
+        match stream.get_state() {
+            StreamState::Ready => break,
+            StreamState::Failed | StreamState::Terminated => {
+                return Err(format!(
+                    "Failed to connect record stream: {}",
+                    connection.context.errno()
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    on_ready();
+
+    let mut recording = true;
+    while recording && !stop.load(Ordering::Relaxed) {
+        match connection.mainloop.iterate(false) {
+            IterateResult::Quit(_) | IterateResult::Err(_) => {
+                return Err("Mainloop error".to_string());
+            }
+            IterateResult::Success(_) => {}
+        }
+
+        while recording {
+            match stream
+                .peek()
+                .map_err(|e| format!("Failed to read record stream: {}", e))?
+            {
+                PeekResult::Empty => break,
+                PeekResult::Hole(_) => {}
+                PeekResult::Data(data) => recording = on_data(data)?,
+            }
+            stream
+                .discard()
+                .map_err(|e| format!("Failed to read record stream: {}", e))?;
+        }
+
+        std::thread::sleep(Duration::from_millis(5));
+    }
+
+    let _ = stream.disconnect();
+    Ok(())
+}
+
+/// Consumer of recorded audio, running on the recording thread
+pub(super) trait RecordSink: Send + 'static {
+    /// Handle a chunk of whole frames; return `false` to stop recording
+    fn write(&mut self, data: &[u8]) -> Result<bool, String>;
+
+    /// Called once after the stream has stopped
+    fn finish(self: Box<Self>) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+/// Writes to a `RecordingOutput`, up to an optional byte limit
+struct OutputSink {
+    output: OutputTarget,
+    max_bytes: Option<usize>,
+    written: usize,
+}
+
+enum OutputTarget {
+    Wav(WavWriter<BufWriter<File>>),
+    Callback(PcmCallback),
+}
+
+impl RecordSink for OutputSink {
+    fn write(&mut self, data: &[u8]) -> Result<bool, String> {
+        let data = match self.max_bytes {
+            Some(max) => &data[..data.len().min(max - self.written)],
+            None => data,
+        };
+
+        match &mut self.output {
+            OutputTarget::Wav(writer) => writer.write(data)?,
+            OutputTarget::Callback(callback) => callback(data),
+        }
+        self.written += data.len();
+
+        Ok(self.max_bytes.is_none_or(|max| self.written < max))
+    }
+
+    fn finish(self: Box<Self>) -> Result<(), String> {
+        match self.output {
+            OutputTarget::Wav(writer) => writer.finish().map(|_| ()),
+            OutputTarget::Callback(_) => Ok(()),
+        }
+    }
+}
+
+/// A recording running on a background thread. Dropping the handle stops
+/// the recording.
+pub struct Recording {
+    thread: StreamThread,
+}
+
+impl Recording {
+    /// Start a record stream on a background thread feeding `sink`, returning
+    /// once the stream is connected
+    pub(super) fn start(
+        source: RecordSource,
+        spec: SampleSpec,
+        mut sink: Box<dyn RecordSink>,
+    ) -> Result<Self, String> {
+        let (thread, ()) = StreamThread::spawn(move |stop, ready| {
+            run_record_stream(
+                &source,
+                &spec,
+                stop,
+                || ready.send(()),
+                |data| sink.write(data),
+            )?;
+            sink.finish()
+        })?;
+
+        Ok(Recording { thread })
+    }
+
+    /// Whether the recording has stopped, e.g. because its duration limit was
+    /// reached
+    pub fn is_finished(&self) -> bool {
+        self.thread.is_finished()
+    }
+
+    /// Block until the recording stops by itself, e.g. at its duration limit
+    pub fn wait(self) -> Result<(), String> {
+        self.thread.wait()
+    }
+
+    /// Stop recording, finishing the output
+    pub fn stop(self) -> Result<(), String> {
+        self.thread.stop()
+    }
+}
+
+pub struct RecordingController;
+
+impl RecordingController {
+    /// Start recording from a device to a WAV file or a callback
+    pub fn start_recording(
+        options: &RecordingOptions,
+        output: RecordingOutput,
+    ) -> Result<Recording, String> {
+        Self::start(
+            Self::device_source(options.device.as_deref())?,
+            options,
+            output,
+        )
+    }
+
+    /// Start recording only the audio played by the application with the
+    /// given PID, through a monitor stream of its sink input. `options.device`
+    /// is ignored; the monitor of the sink the application plays to is used.
+    pub fn start_app_recording(
+        pid: u32,
+        options: &RecordingOptions,
+        output: RecordingOutput,
+    ) -> Result<Recording, String> {
+        Self::start(Self::app_source(pid)?, options, output)
+    }
+
+    /// Start recording only the audio of the first playback stream whose
+    /// properties match `selector`, e.g. "application.name=Discord" or
+    /// "spotify", as in routing rules
+    pub fn start_app_recording_by_selector(
+        selector: &str,
+        options: &RecordingOptions,
+        output: RecordingOutput,
+    ) -> Result<Recording, String> {
+        let index = AppVolumeController::find_sink_input_index_by_selector(selector)?;
+        Self::start(Self::sink_input_source(index)?, options, output)
+    }
+
+    /// Record a device, or the default input device if `None`
+    pub(super) fn device_source(device: Option<&str>) -> Result<RecordSource, String> {
+        let device = match device {
+            Some(device) => Some(Self::record_device(device)?),
+            None => None,
+        };
+
+        Ok(RecordSource {
+            device,
+            sink_input: None,
+        })
+    }
+
+    /// Record the sink input of the application with the given PID
+    pub(super) fn app_source(pid: u32) -> Result<RecordSource, String> {
+        Self::sink_input_source(AppVolumeController::find_sink_input_index_by_pid(pid)?)
+    }
+
+    /// Record a sink input through a monitor of the sink it plays to
+    fn sink_input_source(index: u32) -> Result<RecordSource, String> {
+        Ok(RecordSource {
+            device: Some(sink_input_monitor_source(index)?),
+            sink_input: Some(index),
+        })
+    }
+
+    /// Start recording `source` with the spec and limits from `options`
+    fn start(
+        source: RecordSource,
+        options: &RecordingOptions,
+        output: RecordingOutput,
+    ) -> Result<Recording, String> {
+        let spec = options.spec;
+        let output = match output {
+            RecordingOutput::Wav(path) => OutputTarget::Wav(WavWriter::create(&path, &spec)?),
+            RecordingOutput::Callback(callback) => OutputTarget::Callback(callback),
+        };
+        let sink = OutputSink {
+            output,
+            max_bytes: options
+                .max_duration
+                .map(|d| (d.as_secs_f64() * spec.rate as f64) as usize * spec.frame_size()),
+            written: 0,
+        };
+
+        Recording::start(source, spec, Box::new(sink))
+    }
+
+    /// Record output devices through their monitor source
+    fn record_device(device: &str) -> Result<String, String> {
+        let monitor = DeviceController::get_output_devices()?
+            .into_iter()
+            .find(|info| info.name == device)
+            .and_then(|info| info.monitor_source);
+
+        Ok(monitor.unwrap_or_else(|| device.to_string()))
+    }
+}
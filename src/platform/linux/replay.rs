@@ -0,0 +1,136 @@
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use super::recording::{RecordSink, Recording, RecordingController};
+use crate::ring_buffer::RingBuffer;
+use crate::wav::WavWriter;
+use crate::{ReplayOptions, SampleSpec};
+
+/// Upper bound on the memory a single replay buffer may use
+const MAX_REPLAY_BYTES: usize = 256 * 1024 * 1024;
+
+/// Feeds recorded audio into a shared ring buffer
+struct ReplaySink {
+    ring: Arc<Mutex<RingBuffer>>,
+}
+
+impl RecordSink for ReplaySink {
+    fn write(&mut self, data: &[u8]) -> Result<bool, String> {
+        self.ring
+            .lock()
+            .map_err(|_| "Replay buffer lock poisoned".to_string())?
+            .push(data);
+        Ok(true)
+    }
+}
+
+/// Continuously captures audio into a fixed-size in-memory buffer, keeping
+/// only the most recent audio so it can be saved on demand. Capture stops
+/// when the handle is stopped or dropped.
+pub struct ReplayBuffer {
+    ring: Arc<Mutex<RingBuffer>>,
+    spec: SampleSpec,
+    recording: Recording,
+}
+
+impl ReplayBuffer {
+    /// How much audio the buffer can hold
+    pub fn capacity(&self) -> Duration {
+        self.bytes_to_duration(self.lock().map_or(0, |ring| ring.capacity()))
+    }
+
+    /// How much audio is currently buffered
+    pub fn buffered(&self) -> Duration {
+        self.bytes_to_duration(self.lock().map_or(0, |ring| ring.len()))
+    }
+
+    /// Whether audio is still being captured
+    pub fn is_running(&self) -> bool {
+        !self.recording.is_finished()
+    }
+
+    /// Write the last `duration` of audio (everything buffered if `None`) to
+    /// a WAV file, returning the duration actually written. Capture keeps
+    /// running.
+    pub fn save(&self, path: &Path, duration: Option<Duration>) -> Result<Duration, String> {
+        let data = {
+            let ring = self.lock()?;
+            let count = match duration {
+                Some(duration) => self.duration_to_bytes(duration),
+                None => ring.len(),
+            };
+            ring.latest(count)
+        };
+
+        let mut writer = WavWriter::create(path, &self.spec)?;
+        writer.write(&data)?;
+        writer.finish()?;
+
+        Ok(self.bytes_to_duration(data.len()))
+    }
+
+    /// Drop everything buffered so far
+    pub fn clear(&self) -> Result<(), String> {
+        self.lock()?.clear();
+        Ok(())
+    }
+
+    /// Stop capturing, discarding the buffer
+    pub fn stop(self) -> Result<(), String> {
+        self.recording.stop()
+    }
+
+    fn lock(&self) -> Result<std::sync::MutexGuard<'_, RingBuffer>, String> {
+        self.ring
+            .lock()
+            .map_err(|_| "Replay buffer lock poisoned".to_string())
+    }
+
+    /// Whole frames covering `duration`
+    fn duration_to_bytes(&self, duration: Duration) -> usize {
+        (duration.as_secs_f64() * self.spec.rate as f64) as usize * self.spec.frame_size()
+    }
+
+    fn bytes_to_duration(&self, bytes: usize) -> Duration {
+        Duration::from_secs_f64(bytes as f64 / self.spec.bytes_per_second() as f64)
+    }
+}
+
+impl RecordingController {
+    /// Start capturing a device, or the application `options.pid`, into an
+    /// instant replay buffer holding the last `options.duration` of audio
+    pub fn start_replay_buffer(options: &ReplayOptions) -> Result<ReplayBuffer, String> {
+        let spec = options.spec;
+        let frames = (options.duration.as_secs_f64() * spec.rate as f64) as usize;
+        if frames == 0 {
+            return Err("Replay duration must be greater than 0".to_string());
+        }
+        let capacity = frames
+            .checked_mul(spec.frame_size())
+            .filter(|&bytes| bytes <= MAX_REPLAY_BYTES)
+            .ok_or_else(|| {
+                format!(
+                    "Replay buffer would exceed {} MiB; use a shorter duration or smaller format",
+                    MAX_REPLAY_BYTES / (1024 * 1024)
+                )
+            })?;
+
+        let source = match (options.pid, &options.device) {
+            (Some(_), Some(_)) => {
+                return Err("Either a device or a PID can be captured, not both".to_string());
+            }
+            (Some(pid), None) => Self::app_source(pid)?,
+            (None, device) => Self::device_source(device.as_deref())?,
+        };
+
+        let ring = Arc::new(Mutex::new(RingBuffer::new(capacity)));
+        let sink = ReplaySink { ring: ring.clone() };
+
+        Ok(ReplayBuffer {
+            ring,
+            spec,
+            recording: Recording::start(source, spec, Box::new(sink))?,
+        })
+    }
+}
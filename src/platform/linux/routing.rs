@@ -0,0 +1,143 @@
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use libpulse_binding::context::subscribe::InterestMaskSet;
+
+use super::clients::ClientController;
+use super::devices::DeviceController;
+use super::events::watch_server;
+use super::stream_thread::StreamThread;
+use super::streams::move_stream;
+use crate::routing::{is_available, load_rules, rule_for, save_rules, validate_rule};
+use crate::{RoutingRule, StreamKind};
+
+/// Keeps playback streams on the devices their routing rules name. The
+/// rules file is read again whenever streams or output devices change and
+/// whenever the file itself is modified, so edits made elsewhere apply too.
+/// Watching stops when the handle is stopped or dropped.
+pub struct RoutingWatcher {
+    thread: StreamThread,
+    path: PathBuf,
+}
+
+impl RoutingWatcher {
+    /// The rules file being applied
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Whether the watcher is still running; it stops if the connection to
+    /// the server is lost
+    pub fn is_running(&self) -> bool {
+        !self.thread.is_finished()
+    }
+
+    pub fn stop(self) -> Result<(), String> {
+        self.thread.stop()
+    }
+}
+
+pub struct RoutingController;
+
+impl RoutingController {
+    /// The rules saved at `path`, in the order they are tried
+    pub fn get_rules(path: &Path) -> Result<Vec<RoutingRule>, String> {
+        load_rules(path)
+    }
+
+    /// Save a rule, replacing any rule with the same selector, and move
+    /// matching streams now
+    pub fn set_rule(path: &Path, rule: &RoutingRule) -> Result<(), String> {
+        let rule = RoutingRule {
+            selector: rule.selector.trim().to_string(),
+            device: rule.device.clone(),
+        };
+        validate_rule(&rule)?;
+
+        let mut rules = load_rules(path)?;
+        match rules.iter_mut().find(|r| r.selector == rule.selector) {
+            Some(existing) => existing.device = rule.device,
+            None => rules.push(rule),
+        }
+        save_rules(path, &rules)?;
+        apply_rules(&rules)
+    }
+
+    /// Remove the rule for `selector`, returning whether there was one.
+    /// Streams stay where they are.
+    pub fn remove_rule(path: &Path, selector: &str) -> Result<bool, String> {
+        let mut rules = load_rules(path)?;
+        let count = rules.len();
+        rules.retain(|rule| rule.selector != selector.trim());
+        if rules.len() == count {
+            return Ok(false);
+        }
+        save_rules(path, &rules)?;
+        Ok(true)
+    }
+
+    /// Apply the rules saved at `path` now and whenever streams, output
+    /// devices or the file change
+    pub fn watch(path: &Path) -> Result<RoutingWatcher, String> {
+        // Report a broken rules file up front rather than on the thread
+        load_rules(path)?;
+
+        let thread_path = path.to_path_buf();
+        let (thread, ()) = StreamThread::spawn(move |stop, ready| {
+            let mut modified = modified_time(&thread_path);
+            watch_server(
+                "fa-control-routing",
+                InterestMaskSet::SINK | InterestMaskSet::SINK_INPUT,
+                stop,
+                || ready.send(()),
+                // Edits to the file, by hand or from another process, raise
+                // no server event
+                || {
+                    let current = modified_time(&thread_path);
+                    current != std::mem::replace(&mut modified, current)
+                },
+                // A file being rewritten or a device vanishing mid-move is
+                // retried on the next event
+                || {
+                    let _ = load_rules(&thread_path).and_then(|rules| apply_rules(&rules));
+                },
+            )
+        })?;
+
+        Ok(RoutingWatcher {
+            thread,
+            path: path.to_path_buf(),
+        })
+    }
+}
+
+/// When the file at `path` was last modified, or `None` if it doesn't exist
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// Move every playback stream with a matching rule to the rule's device, if
+/// that device is present
+fn apply_rules(rules: &[RoutingRule]) -> Result<(), String> {
+    if rules.is_empty() {
+        return Ok(());
+    }
+    let devices = DeviceController::get_output_devices()?;
+
+    for stream in ClientController::get_streams()? {
+        if stream.kind != StreamKind::SinkInput {
+            continue;
+        }
+        let Some(rule) = rule_for(rules, &stream.properties) else {
+            continue;
+        };
+        let target = devices
+            .iter()
+            .find(|device| device.name == rule.device && is_available(device));
+        if let Some(target) = target.filter(|target| target.index != stream.device) {
+            // Streams may refuse to move or be gone already
+            let _ = move_stream(StreamKind::SinkInput, stream.index, &target.name);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,154 @@
+use std::time::Duration;
+
+use libpulse_binding as pulse;
+use libpulse_binding::callbacks::ListResult;
+use libpulse_binding::mainloop::standard::IterateResult;
+use libpulse_binding::stream::{SeekMode, State as StreamState, Stream};
+
+use super::playback::decode;
+use super::recording::pulse_spec;
+use super::Connection;
+use crate::{CachedSample, PlaybackSource};
+
+pub struct SampleCacheController;
+
+impl SampleCacheController {
+    /// Upload a WAV file or PCM buffer to the sample cache under `name`,
+    /// replacing any sample with the same name
+    pub fn upload_sample(name: &str, source: PlaybackSource) -> Result<(), String> {
+        if name.is_empty() {
+            return Err("Sample name must not be empty".to_string());
+        }
+        let (spec, data) = decode(source)?;
+        if data.is_empty() {
+            return Err("Sample must not be empty".to_string());
+        }
+
+        let mut connection = Connection::new("fa-control-upload-sample")?;
+        let mut stream = Stream::new(&mut connection.context, name, &pulse_spec(&spec)?, None)
+            .ok_or_else(|| "Failed to create upload stream".to_string())?;
+        stream
+            .connect_upload(data.len())
+            .map_err(|e| format!("Failed to start upload: {}", e))?;
+
+        let mut offset = 0;
+        let mut finished = false;
+        loop {
+            match connection.mainloop.iterate(true) {
+                IterateResult::Quit(_) | IterateResult::Err(_) => {
+                    return Err("Mainloop error".to_string());
+                }
+                IterateResult::Success(_) => {}
+            }
+
+            match stream.get_state() {
+                StreamState::Ready if !finished => {
+                    let writable = stream.writable_size().unwrap_or(0);
+                    let len =
+                        writable.min(data.len() - offset) / spec.frame_size() * spec.frame_size();
+                    if len > 0 {
+                        stream
+                            .write_copy(&data[offset..offset + len], 0, SeekMode::Relative)
+                            .map_err(|e| format!("Failed to upload sample: {}", e))?;
+                        offset += len;
+                    }
+                    if offset == data.len() {
+                        stream
+                            .finish_upload()
+                            .map_err(|e| format!("Failed to finish upload: {}", e))?;
+                        finished = true;
+                    }
+                }
+                // The server terminates the stream once the sample is stored
+                StreamState::Terminated if finished => return Ok(()),
+                StreamState::Failed | StreamState::Terminated => {
+                    return Err(format!(
+                        "Failed to upload sample: {}",
+                        connection.context.errno()
+                    ));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Play a cached sample on `device` (the default output device if
+    /// `None`), at `volume` (0.0 to 1.0) or the sample's own volume if `None`
+    pub fn play_sample(
+        name: &str,
+        device: Option<&str>,
+        volume: Option<f64>,
+    ) -> Result<(), String> {
+        let volume = match volume {
+            Some(volume) if !(0.0..=1.0).contains(&volume) => {
+                return Err("Volume must be between 0.0 and 1.0".to_string());
+            }
+            Some(volume) => Some(pulse::volume::Volume(
+                (volume * pulse::volume::Volume::NORMAL.0 as f64) as u32,
+            )),
+            None => None,
+        };
+
+        let mut connection = Connection::new("fa-control-play-sample")?;
+        let (success_tx, success_rx) = std::sync::mpsc::channel();
+
+        let operation = connection.context.play_sample(
+            name,
+            device,
+            volume,
+            Some(Box::new(move |success| {
+                let _ = success_tx.send(success);
+            })),
+        );
+
+        connection.wait_for_success(&operation, &success_rx, &format!("play sample '{}'", name))
+    }
+
+    /// List the samples in the sample cache
+    pub fn get_samples() -> Result<Vec<CachedSample>, String> {
+        let mut connection = Connection::new("fa-control-get-samples")?;
+        let (sample_tx, sample_rx) = std::sync::mpsc::channel();
+
+        let operation = connection
+            .context
+            .introspect()
+            .get_sample_info_list(move |result| {
+                if let ListResult::Item(sample) = result {
+                    let _ = sample_tx.send(CachedSample {
+                        index: sample.index,
+                        name: sample
+                            .name
+                            .as_ref()
+                            .map(|s| s.to_string())
+                            .unwrap_or_default(),
+                        duration: Duration::from_micros(sample.duration.0),
+                        bytes: sample.bytes,
+                        rate: sample.sample_spec.rate,
+                        channels: sample.sample_spec.channels,
+                        lazy: sample.lazy,
+                        filename: sample.filename.as_ref().map(|s| s.to_string()),
+                    });
+                }
+            });
+
+        connection.wait(&operation)?;
+
+        Ok(sample_rx.try_iter().collect())
+    }
+
+    /// Remove a sample from the sample cache
+    pub fn remove_sample(name: &str) -> Result<(), String> {
+        let mut connection = Connection::new("fa-control-remove-sample")?;
+        let (success_tx, success_rx) = std::sync::mpsc::channel();
+
+        let operation = connection.context.remove_sample(name, move |success| {
+            let _ = success_tx.send(success);
+        });
+
+        connection.wait_for_success(
+            &operation,
+            &success_rx,
+            &format!("remove sample '{}'", name),
+        )
+    }
+}
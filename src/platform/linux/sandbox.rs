@@ -0,0 +1,183 @@
+use std::collections::{BTreeMap, HashMap};
+
+use libpulse_binding::proplist::properties;
+
+use super::clients::ClientController;
+use super::process::{flatpak_id_from_unit, is_host_pid, systemd_unit_of};
+use super::stream_group::StreamGroup;
+use crate::{StreamInfo, StreamKind};
+
+/// Flatpak application ID PipeWire records for clients that connect through
+/// the portal
+pub(super) const PORTAL_APP_ID: &str = "pipewire.access.portal.app_id";
+
+/// PID of the client's peer as the server sees it, which unlike
+/// "application.process.id" is in the host's PID namespace
+pub(super) const SECURITY_PID: &str = "pipewire.sec.pid";
+
+/// Which sandbox a stream's application runs in
+#[derive(Debug, Default)]
+struct Identity {
+    flatpak_id: Option<String>,
+    systemd_unit: Option<String>,
+}
+
+impl Identity {
+    /// Identify a stream from its properties, falling back to its client's,
+    /// and the cgroup of its host process
+    fn of(stream: &StreamInfo, client: Option<&BTreeMap<String, String>>) -> Self {
+        let property = |key: &str| {
+            stream
+                .properties
+                .get(key)
+                .or_else(|| client.and_then(|properties| properties.get(key)))
+        };
+
+        // Without the server's host PID, only trust the reported one if it
+        // isn't from a sandbox's own PID namespace
+        let binary = property(properties::APPLICATION_PROCESS_BINARY);
+        let host_pid = property(SECURITY_PID)
+            .and_then(|pid| pid.parse().ok())
+            .or_else(|| {
+                stream
+                    .pid
+                    .filter(|&pid| is_host_pid(pid, binary.map(String::as_str)))
+            });
+        Self::from_parts(
+            property(PORTAL_APP_ID).cloned(),
+            host_pid.and_then(systemd_unit_of),
+        )
+    }
+
+    fn from_parts(portal_app_id: Option<String>, systemd_unit: Option<String>) -> Self {
+        Identity {
+            flatpak_id: portal_app_id
+                .or_else(|| systemd_unit.as_deref().and_then(flatpak_id_from_unit)),
+            systemd_unit,
+        }
+    }
+
+    /// Whether `app_id` names the Flatpak app or the systemd unit
+    fn matches(&self, app_id: &str) -> bool {
+        self.flatpak_id.as_deref() == Some(app_id) || self.systemd_unit.as_deref() == Some(app_id)
+    }
+}
+
+/// Control applications by Flatpak app ID (e.g. "org.telegram.desktop") or
+/// systemd unit (e.g. "spotify.service"), for sandboxed apps whose reported
+/// PIDs come from their own namespace or are missing
+pub struct SandboxController;
+
+impl SandboxController {
+    /// Playback and recording streams of the app
+    pub fn get_streams(app_id: &str) -> Result<Vec<StreamInfo>, String> {
+        let clients: HashMap<u32, BTreeMap<String, String>> = ClientController::get_clients()?
+            .into_iter()
+            .map(|client| (client.index, client.properties))
+            .collect();
+
+        Ok(ClientController::get_streams()?
+            .into_iter()
+            .filter(|stream| {
+                let client = stream.client.and_then(|index| clients.get(&index));
+                Identity::of(stream, client).matches(app_id)
+            })
+            .collect())
+    }
+
+    /// Average volume of the app's streams
+    pub fn get_volume(app_id: &str) -> Result<f64, String> {
+        Ok(Self::find_streams(app_id)?.volume())
+    }
+
+    /// Set the volume of every stream of the app, returning how many were
+    /// changed
+    pub fn set_volume(app_id: &str, volume: f64) -> Result<usize, String> {
+        Self::find_streams(app_id)?.set_volume(volume)
+    }
+
+    /// Whether every stream of the app is muted
+    pub fn is_muted(app_id: &str) -> Result<bool, String> {
+        Ok(Self::find_streams(app_id)?.is_muted())
+    }
+
+    pub fn set_mute(app_id: &str, muted: bool) -> Result<usize, String> {
+        Self::find_streams(app_id)?.set_mute(muted)
+    }
+
+    /// Move every stream of the app to the output device named `device`
+    pub fn set_output_device(app_id: &str, device: &str) -> Result<usize, String> {
+        Self::find_streams(app_id)?.set_output_device(device)
+    }
+
+    fn find_streams(app_id: &str) -> Result<StreamGroup, String> {
+        let indexes: Vec<u32> = Self::get_streams(app_id)?
+            .into_iter()
+            .filter(|stream| stream.kind == StreamKind::SinkInput)
+            .map(|stream| stream.index)
+            .collect();
+        StreamGroup::find(&format!("app {}", app_id), |stream| {
+            indexes.contains(&stream.state.index)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identity() {
+        let unit = "app-flatpak-org.telegram.desktop-4242.scope".to_string();
+        let flatpak = Identity::from_parts(None, Some(unit.clone()));
+        assert_eq!(flatpak.flatpak_id.as_deref(), Some("org.telegram.desktop"));
+        assert!(flatpak.matches("org.telegram.desktop"));
+        assert!(flatpak.matches(&unit));
+        assert!(!flatpak.matches("org.telegram"));
+
+        // The portal's app ID wins over the cgroup
+        let portal = Identity::from_parts(Some("com.spotify.Client".to_string()), Some(unit));
+        assert!(portal.matches("com.spotify.Client"));
+        assert!(!portal.matches("org.telegram.desktop"));
+
+        let service = Identity::from_parts(None, Some("mpd.service".to_string()));
+        assert_eq!(service.flatpak_id, None);
+        assert!(service.matches("mpd.service"));
+
+        assert!(!Identity::default().matches(""));
+    }
+
+    #[test]
+    fn test_untrusted_pid() {
+        let pid = std::process::id();
+        let binary = std::env::current_exe().unwrap();
+        let binary = binary.file_name().unwrap().to_string_lossy();
+        let stream = |binary: &str| StreamInfo {
+            index: 0,
+            kind: StreamKind::SinkInput,
+            name: "Playback".to_string(),
+            client: None,
+            pid: Some(pid),
+            device: 0,
+            properties: [(
+                properties::APPLICATION_PROCESS_BINARY.to_string(),
+                binary.to_string(),
+            )]
+            .into(),
+        };
+
+        let trusted = Identity::of(&stream(&binary), None);
+        assert_eq!(trusted.systemd_unit, systemd_unit_of(pid));
+
+        // A namespace PID that is some other program on the host
+        let untrusted = Identity::of(&stream("telegram-desktop"), None);
+        assert_eq!(untrusted.systemd_unit, None);
+        assert_eq!(untrusted.flatpak_id, None);
+
+        // A client that reports no binary keeps its PID
+        let mut unreported = stream(&binary);
+        unreported.properties.clear();
+        let unreported = Identity::of(&unreported, None);
+        assert_eq!(unreported.systemd_unit, systemd_unit_of(pid));
+    }
+}
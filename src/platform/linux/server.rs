@@ -0,0 +1,45 @@
+use super::devices::channel_infos;
+use super::Connection;
+use crate::ServerInfo;
+
+pub struct ServerController;
+
+impl ServerController {
+    /// Query the server's identity, defaults and default sample spec
+    pub fn get_server_info() -> Result<ServerInfo, String> {
+        let mut connection = Connection::new("fa-control-server-info")?;
+        let (info_tx, info_rx) = std::sync::mpsc::channel();
+
+        let operation = connection
+            .context
+            .introspect()
+            .get_server_info(move |info| {
+                let string = |s: &Option<std::borrow::Cow<str>>| {
+                    s.as_ref().map(|s| s.to_string()).unwrap_or_default()
+                };
+                let _ = info_tx.send(ServerInfo {
+                    name: string(&info.server_name),
+                    version: string(&info.server_version),
+                    host: string(&info.host_name),
+                    user: string(&info.user_name),
+                    default_sink: info.default_sink_name.as_ref().map(|s| s.to_string()),
+                    default_source: info.default_source_name.as_ref().map(|s| s.to_string()),
+                    sample_format: info
+                        .sample_spec
+                        .format
+                        .to_string()
+                        .map(|s| s.to_string())
+                        .unwrap_or_default(),
+                    sample_rate: info.sample_spec.rate,
+                    channels: info.sample_spec.channels,
+                    channel_map: channel_infos(&info.channel_map),
+                });
+            });
+
+        connection.wait(&operation)?;
+
+        info_rx
+            .try_recv()
+            .map_err(|_| "Server did not return its information".to_string())
+    }
+}
@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+
+use super::loopback::{Loopback, LoopbackController};
+use super::modules::validate_device_name;
+use super::playback::{decode, Playback, PlaybackController};
+use super::virtual_devices::{VirtualDevice, VirtualDeviceController};
+use super::InputController;
+use crate::{PlaybackOptions, PlaybackSource, SampleSpec, SoundboardOptions};
+
+/// A decoded clip ready to be played
+struct Clip {
+    spec: SampleSpec,
+    data: Vec<u8>,
+    volume: f64,
+}
+
+/// A virtual microphone carrying the real microphone mixed with clips
+/// triggered by name. Everything is torn down when the handle is destroyed
+/// or dropped.
+///
+/// The microphone is looped into a null sink which clips are also played
+/// to; the monitor of that sink is exposed as a remapped source.
+pub struct Soundboard {
+    // Fields are dropped in order: stop clips before removing their sink
+    playing: Vec<Playback>,
+    clips: HashMap<String, Clip>,
+    monitor_device: Option<String>,
+    microphone: Loopback,
+    source: VirtualDevice,
+    mix: VirtualDevice,
+}
+
+impl Soundboard {
+    /// Name of the virtual microphone to select in voice chat applications
+    pub fn source_name(&self) -> &str {
+        self.source.name()
+    }
+
+    /// Name of the sink mixing the microphone and clips
+    pub fn mix_sink_name(&self) -> &str {
+        self.mix.name()
+    }
+
+    /// Names of the loaded clips, sorted
+    pub fn clip_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.clips.keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    /// Load a clip from a WAV file or PCM buffer under `name`, replacing any
+    /// clip with the same name
+    pub fn add_clip(
+        &mut self,
+        name: &str,
+        source: PlaybackSource,
+        volume: f64,
+    ) -> Result<(), String> {
+        validate_volume(volume)?;
+        let (spec, data) = decode(source)?;
+        self.clips
+            .insert(name.to_string(), Clip { spec, data, volume });
+        Ok(())
+    }
+
+    pub fn remove_clip(&mut self, name: &str) -> Result<(), String> {
+        self.clips
+            .remove(name)
+            .map(|_| ())
+            .ok_or_else(|| format!("No clip named '{}'", name))
+    }
+
+    /// Set the volume a clip is played at (0.0 to 1.0)
+    pub fn set_clip_volume(&mut self, name: &str, volume: f64) -> Result<(), String> {
+        validate_volume(volume)?;
+        self.clip_mut(name)?.volume = volume;
+        Ok(())
+    }
+
+    pub fn get_clip_volume(&self, name: &str) -> Result<f64, String> {
+        self.clips
+            .get(name)
+            .map(|clip| clip.volume)
+            .ok_or_else(|| format!("No clip named '{}'", name))
+    }
+
+    /// Start playing a clip into the virtual microphone, and on the monitor
+    /// device if one was configured. Clips can overlap.
+    pub fn play_clip(&mut self, name: &str) -> Result<(), String> {
+        self.playing.retain(|playback| !playback.is_finished());
+
+        let clip = self
+            .clips
+            .get(name)
+            .ok_or_else(|| format!("No clip named '{}'", name))?;
+
+        let devices =
+            std::iter::once(self.mix.name().to_string()).chain(self.monitor_device.clone());
+        for device in devices {
+            let options = PlaybackOptions {
+                device: Some(device),
+                name: format!("Soundboard: {}", name),
+                role: Some("event".to_string()),
+                volume: Some(clip.volume),
+            };
+            let playback =
+                PlaybackController::play_pcm(clip.data.clone(), clip.spec, None, &options)?;
+            self.playing.push(playback);
+        }
+        Ok(())
+    }
+
+    /// Stop every clip that is playing
+    pub fn stop_clips(&mut self) -> Result<(), String> {
+        self.playing
+            .drain(..)
+            .map(Playback::stop)
+            .collect::<Result<Vec<_>, _>>()
+            .map(|_| ())
+    }
+
+    /// Volume of the real microphone in the mix (0.0 to 1.0)
+    pub fn get_microphone_volume(&self) -> Result<f64, String> {
+        self.microphone.get_volume()
+    }
+
+    pub fn set_microphone_volume(&self, volume: f64) -> Result<(), String> {
+        self.microphone.set_volume(volume)
+    }
+
+    /// Whether the real microphone is muted in the mix; clips still play
+    pub fn is_microphone_muted(&self) -> Result<bool, String> {
+        self.microphone.is_muted()
+    }
+
+    pub fn set_microphone_mute(&self, muted: bool) -> Result<(), String> {
+        self.microphone.set_mute(muted)
+    }
+
+    /// Stop all clips and remove the virtual devices
+    pub fn destroy(mut self) -> Result<(), String> {
+        let stopped = self.stop_clips();
+        let Soundboard {
+            microphone,
+            source,
+            mix,
+            ..
+        } = self;
+        // Remove everything even if one step fails, reporting the first error
+        [
+            stopped,
+            microphone.destroy(),
+            source.destroy(),
+            mix.destroy(),
+        ]
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()
+        .map(|_| ())
+    }
+
+    fn clip_mut(&mut self, name: &str) -> Result<&mut Clip, String> {
+        self.clips
+            .get_mut(name)
+            .ok_or_else(|| format!("No clip named '{}'", name))
+    }
+}
+
+fn validate_volume(volume: f64) -> Result<(), String> {
+    if !(0.0..=1.0).contains(&volume) {
+        return Err("Volume must be between 0.0 and 1.0".to_string());
+    }
+    Ok(())
+}
+
+pub struct SoundboardController;
+
+impl SoundboardController {
+    /// Create a soundboard whose virtual microphone is `<name>_mic`
+    pub fn create_soundboard(options: &SoundboardOptions) -> Result<Soundboard, String> {
+        validate_device_name(&options.name)?;
+        let microphone = match &options.microphone {
+            Some(microphone) => microphone.clone(),
+            None => InputController::get_default_source_name()?,
+        };
+
+        let mix = VirtualDeviceController::create_null_sink(
+            &format!("{}_mix", options.name),
+            "Soundboard Mix",
+        )?;
+        let source = VirtualDeviceController::create_remapped_source(
+            &format!("{}_mic", options.name),
+            "Soundboard Microphone",
+            &format!("{}.monitor", mix.name()),
+        )?;
+        let microphone =
+            LoopbackController::create_loopback(&microphone, mix.name(), options.latency_ms)?;
+
+        Ok(Soundboard {
+            playing: Vec::new(),
+            clips: HashMap::new(),
+            monitor_device: options.monitor_device.clone(),
+            microphone,
+            source,
+            mix,
+        })
+    }
+}
@@ -0,0 +1,79 @@
+use super::devices::{channel_infos, output_channel_map};
+use super::playback::{Playback, PlaybackController};
+use crate::{ChannelInfo, PlaybackOptions, SampleFormat, SampleSpec, TestSignal, TestToneOptions};
+
+const TEST_TONE_RATE: u32 = 48000;
+
+pub struct SpeakerTestController;
+
+impl SpeakerTestController {
+    /// Channels of an output device (the default output device if `None`),
+    /// in channel map order
+    pub fn get_channels(device: Option<&str>) -> Result<Vec<ChannelInfo>, String> {
+        let (_, map) = output_channel_map(device)?;
+        Ok(channel_infos(&map))
+    }
+
+    /// Play a sine tone or pink noise on one channel, or all channels, of an
+    /// output device. The stream uses the device's own channel map, so the
+    /// signal comes out of the named speaker only.
+    pub fn play_test_tone(options: &TestToneOptions) -> Result<Playback, String> {
+        if let TestSignal::Sine { frequency } = options.signal {
+            if !(frequency > 0.0 && frequency < TEST_TONE_RATE as f64 / 2.0) {
+                return Err(format!(
+                    "Frequency must be between 0 and {} Hz",
+                    TEST_TONE_RATE / 2
+                ));
+            }
+        }
+        if !(0.0..=1.0).contains(&options.level) {
+            return Err("Level must be between 0.0 and 1.0".to_string());
+        }
+        if options.duration.is_zero() {
+            return Err("Duration must be greater than 0".to_string());
+        }
+
+        let (device, map) = output_channel_map(options.device.as_deref())?;
+        let channels = channel_infos(&map);
+        let channel =
+            match &options.channel {
+                Some(name) => Some(channels.iter().position(|c| &c.name == name).ok_or_else(
+                    || {
+                        let names: Vec<&str> = channels.iter().map(|c| c.name.as_str()).collect();
+                        format!(
+                            "Device '{}' has no channel '{}' (channels: {})",
+                            device,
+                            name,
+                            names.join(", ")
+                        )
+                    },
+                )?),
+                None => None,
+            };
+
+        let frames = (options.duration.as_secs_f64() * TEST_TONE_RATE as f64) as usize;
+        let samples = crate::tone::generate(
+            options.signal,
+            TEST_TONE_RATE,
+            channels.len(),
+            channel,
+            options.level as f32,
+            frames,
+        );
+        let data = samples.iter().flat_map(|s| s.to_le_bytes()).collect();
+
+        let spec = SampleSpec {
+            format: SampleFormat::F32Le,
+            rate: TEST_TONE_RATE,
+            channels: channels.len() as u8,
+        };
+        let playback_options = PlaybackOptions {
+            device: Some(device),
+            name: "fa-control speaker test".to_string(),
+            role: Some("test".to_string()),
+            volume: None,
+        };
+
+        PlaybackController::play_pcm(data, spec, Some(map), &playback_options)
+    }
+}
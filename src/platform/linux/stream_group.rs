@@ -0,0 +1,68 @@
+use super::streams::{
+    list_sink_inputs, move_stream, set_sink_input_mute, set_sink_input_volume, SinkInputProcess,
+};
+use crate::StreamKind;
+
+/// Playback streams controlled together, such as those of a whole process
+/// tree
+pub(super) struct StreamGroup {
+    streams: Vec<SinkInputProcess>,
+}
+
+impl StreamGroup {
+    /// The sink inputs `select` accepts, or an error if there are none;
+    /// `owner` describes them for that error, e.g. "process 42"
+    pub(super) fn find(
+        owner: &str,
+        select: impl Fn(&SinkInputProcess) -> bool,
+    ) -> Result<Self, String> {
+        let streams: Vec<SinkInputProcess> =
+            list_sink_inputs()?.into_iter().filter(select).collect();
+        if streams.is_empty() {
+            return Err(format!("No audio streams found for {}", owner));
+        }
+        Ok(StreamGroup { streams })
+    }
+
+    /// Average volume of the streams
+    pub(super) fn volume(&self) -> f64 {
+        let total: f64 = self.streams.iter().map(|s| s.state.volume as f64).sum();
+        total / self.streams.len() as f64
+    }
+
+    /// Whether every stream is muted
+    pub(super) fn is_muted(&self) -> bool {
+        self.streams.iter().all(|s| s.state.muted)
+    }
+
+    pub(super) fn set_volume(&self, volume: f64) -> Result<usize, String> {
+        if !(0.0..=1.0).contains(&volume) {
+            return Err("Volume must be between 0.0 and 1.0".to_string());
+        }
+        self.for_each(|stream| {
+            set_sink_input_volume(stream.state.index, stream.state.channels, volume)
+        })
+    }
+
+    pub(super) fn set_mute(&self, muted: bool) -> Result<usize, String> {
+        self.for_each(|stream| set_sink_input_mute(stream.state.index, muted))
+    }
+
+    /// Move every stream to the output device named `device`
+    pub(super) fn set_output_device(&self, device: &str) -> Result<usize, String> {
+        self.for_each(|stream| move_stream(StreamKind::SinkInput, stream.state.index, device))
+    }
+
+    /// Apply `change` to every stream, carrying on past failures and
+    /// reporting the first; otherwise how many streams were changed
+    fn for_each(
+        &self,
+        change: impl Fn(&SinkInputProcess) -> Result<(), String>,
+    ) -> Result<usize, String> {
+        let results: Vec<_> = self.streams.iter().map(change).collect();
+        results
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()
+            .map(|changed| changed.len())
+    }
+}
@@ -0,0 +1,234 @@
+use std::ffi::{c_void, CStr, CString};
+use std::ptr::{null, null_mut};
+
+use libpulse_binding as pulse;
+use libpulse_binding::callbacks::ListResult;
+use libpulse_binding::channelmap::Map;
+use libpulse_binding::volume::ChannelVolumes;
+use libpulse_sys as capi;
+
+use super::Connection;
+use crate::RestoreEntry;
+
+const NOT_LOADED: &str = "module-stream-restore is not loaded";
+
+pub struct StreamRestoreController;
+
+impl StreamRestoreController {
+    /// List the entries module-stream-restore applies to new streams
+    pub fn get_entries() -> Result<Vec<RestoreEntry>, String> {
+        let mut connection = Connection::new("fa-control-get-restore-entries")?;
+        let (entry_tx, entry_rx) = std::sync::mpsc::channel();
+
+        let operation = connection
+            .context
+            .stream_restore()
+            .read(move |result| match result {
+                ListResult::Item(info) => {
+                    let _ = entry_tx.send(Ok(RestoreEntry {
+                        name: info
+                            .name
+                            .as_ref()
+                            .map(|s| s.to_string())
+                            .unwrap_or_default(),
+                        volume: (info.volume.len() > 0 && info.volume.is_valid()).then(|| {
+                            info.volume.avg().0 as f32 / pulse::volume::Volume::NORMAL.0 as f32
+                        }),
+                        mute: info.mute,
+                        device: info
+                            .device
+                            .as_ref()
+                            .filter(|s| !s.is_empty())
+                            .map(|s| s.to_string()),
+                    }));
+                }
+                ListResult::Error => {
+                    let _ = entry_tx.send(Err(NOT_LOADED.to_string()));
+                }
+                ListResult::End => {}
+            });
+
+        connection.wait(&operation)?;
+
+        entry_rx.try_iter().collect()
+    }
+
+    /// Add or replace an entry. With `apply`, streams already playing that
+    /// match it are updated too.
+    pub fn write_entry(entry: &RestoreEntry, apply: bool) -> Result<(), String> {
+        if entry.name.is_empty() {
+            return Err("Restore entry name must not be empty".to_string());
+        }
+        let mut channel_map = Map::default();
+        let mut volume = ChannelVolumes::default();
+        // An invalid channel map and volume leave the stream's volume alone
+        channel_map.init();
+        volume.init();
+        if let Some(level) = entry.volume {
+            if !(0.0..=1.0).contains(&level) {
+                return Err("Volume must be between 0.0 and 1.0".to_string());
+            }
+            // The server remaps a mono volume onto each stream's channels
+            channel_map.init_mono();
+            volume.set(
+                1,
+                pulse::volume::Volume((level * pulse::volume::Volume::NORMAL.0 as f32) as u32),
+            );
+        }
+
+        let name = CString::new(entry.name.as_str())
+            .map_err(|_| "Restore entry name must not contain NUL".to_string())?;
+        let device = entry
+            .device
+            .as_deref()
+            .map(CString::new)
+            .transpose()
+            .map_err(|_| "Restore entry device must not contain NUL".to_string())?;
+        let info = capi::pa_ext_stream_restore_info {
+            name: name.as_ptr(),
+            channel_map: *channel_map.as_ref(),
+            volume: *volume.as_ref(),
+            device: device.as_ref().map_or(null(), |device| device.as_ptr()),
+            mute: entry.mute as i32,
+        };
+
+        let connection = RawConnection::new("fa-control-write-restore-entry")?;
+        if !connection.write(&info, apply)? {
+            return Err(format!(
+                "Failed to write restore entry '{}': {}",
+                entry.name,
+                connection.error()
+            ));
+        }
+        Ok(())
+    }
+
+    /// Remove entries by name, so matching streams start with the server's
+    /// defaults again
+    pub fn delete_entries(names: &[&str]) -> Result<(), String> {
+        if names.iter().any(|name| name.contains('\0')) {
+            return Err("Restore entry names must not contain NUL".to_string());
+        }
+        let mut connection = Connection::new("fa-control-delete-restore-entries")?;
+        let (success_tx, success_rx) = std::sync::mpsc::channel();
+
+        let operation = connection
+            .context
+            .stream_restore()
+            .delete(names, move |success| {
+                let _ = success_tx.send(success);
+            });
+
+        connection.wait_for_success(&operation, &success_rx, "delete restore entries")
+    }
+}
+
+/// A connection made through the C API. libpulse-binding's
+/// `StreamRestore::write` hands its `Info` structs, which don't have the C
+/// layout, straight to `pa_ext_stream_restore_write`, so writes go through
+/// this instead.
+struct RawConnection {
+    mainloop: *mut capi::pa_mainloop,
+    context: *mut capi::pa_context,
+}
+
+impl RawConnection {
+    fn new(name: &str) -> Result<Self, String> {
+        let name = CString::new(name).map_err(|e| e.to_string())?;
+        let mainloop = unsafe { capi::pa_mainloop_new() };
+        if mainloop.is_null() {
+            return Err("Failed to create mainloop".to_string());
+        }
+        let context =
+            unsafe { capi::pa_context_new(capi::pa_mainloop_get_api(mainloop), name.as_ptr()) };
+        // Frees the mainloop, and the context once there is one
+        let connection = RawConnection { mainloop, context };
+        if context.is_null() {
+            return Err("Failed to create context".to_string());
+        }
+
+        if unsafe { capi::pa_context_connect(context, null(), capi::PA_CONTEXT_NOFLAGS, null()) }
+            < 0
+        {
+            return Err(format!("Failed to connect: {}", connection.error()));
+        }
+
+        loop {
+            connection.iterate(false)?;
+            match unsafe { capi::pa_context_get_state(context) } {
+                capi::pa_context_state_t::Ready => break,
+                capi::pa_context_state_t::Failed | capi::pa_context_state_t::Terminated => {
+                    return Err("Failed to connect to pulseaudio server".to_string());
+                }
+                _ => {}
+            }
+        }
+
+        Ok(connection)
+    }
+
+    fn iterate(&self, block: bool) -> Result<(), String> {
+        if unsafe { capi::pa_mainloop_iterate(self.mainloop, block as i32, null_mut()) } < 0 {
+            return Err("Failed to iterate pulseaudio mainloop".to_string());
+        }
+        Ok(())
+    }
+
+    /// Replace the entry named in `info`, returning whether the server
+    /// accepted it
+    fn write(&self, info: &capi::pa_ext_stream_restore_info, apply: bool) -> Result<bool, String> {
+        extern "C" fn on_success(_: *mut capi::pa_context, success: i32, userdata: *mut c_void) {
+            unsafe { *(userdata as *mut Option<bool>) = Some(success != 0) };
+        }
+
+        let mut success: Option<bool> = None;
+        let data = [info as *const capi::pa_ext_stream_restore_info];
+        let operation = unsafe {
+            capi::pa_ext_stream_restore_write(
+                self.context,
+                capi::PA_UPDATE_REPLACE,
+                data.as_ptr(),
+                1,
+                apply as i32,
+                Some(on_success),
+                &mut success as *mut Option<bool> as *mut c_void,
+            )
+        };
+        if operation.is_null() {
+            return Ok(false);
+        }
+
+        let mut result = Ok(());
+        while unsafe { capi::pa_operation_get_state(operation) }
+            == capi::pa_operation_state_t::Running
+        {
+            if let Err(e) = self.iterate(true) {
+                // The callback must not run once `success` is gone
+                unsafe { capi::pa_operation_cancel(operation) };
+                result = Err(e);
+                break;
+            }
+        }
+        unsafe { capi::pa_operation_unref(operation) };
+
+        result.map(|()| success == Some(true))
+    }
+
+    fn error(&self) -> String {
+        let message =
+            unsafe { CStr::from_ptr(capi::pa_strerror(capi::pa_context_errno(self.context))) };
+        message.to_string_lossy().into_owned()
+    }
+}
+
+impl Drop for RawConnection {
+    fn drop(&mut self) {
+        unsafe {
+            if !self.context.is_null() {
+                capi::pa_context_disconnect(self.context);
+                capi::pa_context_unref(self.context);
+            }
+            capi::pa_mainloop_free(self.mainloop);
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::Sender;
+use std::sync::Arc;
+use std::thread::JoinHandle;
+
+/// Reports that a stream thread has connected, handing `T` back to the
+/// thread that spawned it
+pub(super) struct Ready<T> {
+    tx: Sender<Result<T, String>>,
+}
+
+impl<T> Ready<T> {
+    pub(super) fn send(&self, value: T) {
+        let _ = self.tx.send(Ok(value));
+    }
+}
+
+/// A stream running on its own thread until it completes or is asked to
+/// stop. Dropping the handle stops the thread and waits for it.
+pub(super) struct StreamThread {
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<Result<(), String>>>,
+}
+
+impl StreamThread {
+    /// Run `body` on a new thread, returning once it has called
+    /// `Ready::send`, or with its error if it fails before that. `body` should
+    /// return soon after the stop flag it is given is set.
+    pub(super) fn spawn<T, F>(body: F) -> Result<(Self, T), String>
+    where
+        T: Send + 'static,
+        F: FnOnce(&AtomicBool, &Ready<T>) -> Result<(), String> + Send + 'static,
+    {
+        let stop = Arc::new(AtomicBool::new(false));
+        let (ready_tx, ready_rx) = std::sync::mpsc::channel();
+
+        let thread_stop = stop.clone();
+        let thread = std::thread::spawn(move || {
+            let ready = Ready { tx: ready_tx };
+            let result = body(&thread_stop, &ready);
+            if let Err(e) = &result {
+                let _ = ready.tx.send(Err(e.clone()));
+            }
+            result
+        });
+
+        match ready_rx.recv() {
+            Ok(Ok(value)) => Ok((
+                StreamThread {
+                    stop,
+                    thread: Some(thread),
+                },
+                value,
+            )),
+            Ok(Err(e)) => {
+                let _ = thread.join();
+                Err(e)
+            }
+            Err(_) => {
+                let _ = thread.join();
+                Err("Stream thread exited before connecting".to_string())
+            }
+        }
+    }
+
+    pub(super) fn is_finished(&self) -> bool {
+        self.thread.as_ref().is_none_or(|t| t.is_finished())
+    }
+
+    /// Block until the thread completes by itself
+    pub(super) fn wait(mut self) -> Result<(), String> {
+        self.join()
+    }
+
+    /// Ask the thread to stop and wait for it
+    pub(super) fn stop(mut self) -> Result<(), String> {
+        self.stop.store(true, Ordering::Relaxed);
+        self.join()
+    }
+
+    fn join(&mut self) -> Result<(), String> {
+        match self.thread.take() {
+            Some(thread) => thread
+                .join()
+                .map_err(|_| "Stream thread panicked".to_string())?,
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for StreamThread {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        let _ = self.join();
+    }
+}
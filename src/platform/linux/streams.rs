@@ -0,0 +1,175 @@
+use libpulse_binding as pulse;
+use libpulse_binding::callbacks::ListResult;
+use libpulse_binding::context::introspect::SinkInputInfo;
+use libpulse_binding::volume::ChannelVolumes;
+
+use super::clients::process_id;
+use super::Connection;
+use crate::StreamKind;
+
+/// Volume state of a single sink input
+pub(super) struct SinkInputState {
+    pub index: u32,
+    pub volume: f32,
+    pub muted: bool,
+    pub channels: u8,
+}
+
+impl SinkInputState {
+    fn from_info(sink_input: &SinkInputInfo) -> Self {
+        SinkInputState {
+            index: sink_input.index,
+            volume: sink_input.volume.avg().0 as f32 / pulse::volume::Volume::NORMAL.0 as f32,
+            muted: sink_input.mute,
+            channels: sink_input.volume.len(),
+        }
+    }
+}
+
+/// A sink input with the process that owns it
+pub(super) struct SinkInputProcess {
+    pub state: SinkInputState,
+    pub pid: Option<u32>,
+}
+
+/// List every sink input with its owning process
+pub(super) fn list_sink_inputs() -> Result<Vec<SinkInputProcess>, String> {
+    let mut connection = Connection::new("fa-control-list-streams")?;
+    let (stream_tx, stream_rx) = std::sync::mpsc::channel();
+
+    let operation = connection
+        .context
+        .introspect()
+        .get_sink_input_info_list(move |result| {
+            if let ListResult::Item(sink_input) = result {
+                let _ = stream_tx.send(SinkInputProcess {
+                    state: SinkInputState::from_info(sink_input),
+                    pid: process_id(&sink_input.proplist),
+                });
+            }
+        });
+
+    connection.wait(&operation)?;
+
+    Ok(stream_rx.try_iter().collect())
+}
+
+/// Find the sink input created by the module with the given index
+pub(super) fn find_sink_input_by_owner_module(module_index: u32) -> Result<SinkInputState, String> {
+    let mut connection = Connection::new("fa-control-find-module-stream")?;
+    let (state_tx, state_rx) = std::sync::mpsc::channel();
+
+    let operation = connection
+        .context
+        .introspect()
+        .get_sink_input_info_list(move |result| {
+            if let ListResult::Item(sink_input) = result {
+                if sink_input.owner_module == Some(module_index) {
+                    let _ = state_tx.send(SinkInputState::from_info(sink_input));
+                }
+            }
+        });
+
+    connection.wait(&operation)?;
+
+    state_rx
+        .try_recv()
+        .map_err(|_| format!("No stream found for module {}", module_index))
+}
+
+/// Name of the monitor source of the sink a sink input is playing to
+pub(super) fn sink_input_monitor_source(index: u32) -> Result<String, String> {
+    let mut connection = Connection::new("fa-control-find-stream-monitor")?;
+    let (sink_tx, sink_rx) = std::sync::mpsc::channel();
+
+    let operation = connection
+        .context
+        .introspect()
+        .get_sink_input_info(index, move |result| {
+            if let ListResult::Item(sink_input) = result {
+                let _ = sink_tx.send(sink_input.sink);
+            }
+        });
+    connection.wait(&operation)?;
+
+    let sink = sink_rx
+        .try_recv()
+        .map_err(|_| format!("Sink input {} not found", index))?;
+    let (monitor_tx, monitor_rx) = std::sync::mpsc::channel();
+
+    let operation = connection
+        .context
+        .introspect()
+        .get_sink_info_by_index(sink, move |result| {
+            if let ListResult::Item(sink) = result {
+                if let Some(name) = &sink.monitor_source_name {
+                    let _ = monitor_tx.send(name.to_string());
+                }
+            }
+        });
+    connection.wait(&operation)?;
+
+    monitor_rx
+        .try_recv()
+        .map_err(|_| format!("No monitor source found for sink {}", sink))
+}
+
+/// Set the volume of every channel of a sink input
+pub(super) fn set_sink_input_volume(index: u32, channels: u8, volume: f64) -> Result<(), String> {
+    if !(0.0..=1.0).contains(&volume) {
+        return Err("Volume must be between 0.0 and 1.0".to_string());
+    }
+
+    let mut connection = Connection::new("fa-control-set-stream-volume")?;
+    let (success_tx, success_rx) = std::sync::mpsc::channel();
+
+    let mut cv = ChannelVolumes::default();
+    let vol_val = (volume * pulse::volume::Volume::NORMAL.0 as f64) as u32;
+    cv.set(channels, pulse::volume::Volume(vol_val));
+
+    let operation = connection.context.introspect().set_sink_input_volume(
+        index,
+        &cv,
+        Some(Box::new(move |success| {
+            let _ = success_tx.send(success);
+        })),
+    );
+
+    connection.wait_for_success(&operation, &success_rx, "set stream volume")
+}
+
+pub(super) fn set_sink_input_mute(index: u32, muted: bool) -> Result<(), String> {
+    let mut connection = Connection::new("fa-control-set-stream-mute")?;
+    let (success_tx, success_rx) = std::sync::mpsc::channel();
+
+    let operation = connection.context.introspect().set_sink_input_mute(
+        index,
+        muted,
+        Some(Box::new(move |success| {
+            let _ = success_tx.send(success);
+        })),
+    );
+
+    connection.wait_for_success(&operation, &success_rx, "set stream mute")
+}
+
+/// Move a playback or recording stream to the device named `device`
+pub(super) fn move_stream(kind: StreamKind, index: u32, device: &str) -> Result<(), String> {
+    let mut connection = Connection::new("fa-control-move-stream")?;
+    let (success_tx, success_rx) = std::sync::mpsc::channel();
+    let callback = Box::new(move |success| {
+        let _ = success_tx.send(success);
+    });
+
+    let mut introspector = connection.context.introspect();
+    let operation = match kind {
+        StreamKind::SinkInput => {
+            introspector.move_sink_input_by_name(index, device, Some(callback))
+        }
+        StreamKind::SourceOutput => {
+            introspector.move_source_output_by_name(index, device, Some(callback))
+        }
+    };
+
+    connection.wait_for_success(&operation, &success_rx, "move stream")
+}
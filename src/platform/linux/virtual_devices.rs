@@ -0,0 +1,98 @@
+use super::modules::{description_properties, validate_device_name, OwnedModule};
+
+/// A virtual device backed by a module loaded by this library. The module is
+/// unloaded when the handle is destroyed or dropped.
+#[derive(Debug)]
+pub struct VirtualDevice {
+    name: String,
+    module: OwnedModule,
+}
+
+impl VirtualDevice {
+    /// Name of the sink or source, usable wherever a device name is expected
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Index of the module backing this device
+    pub fn module_index(&self) -> u32 {
+        self.module.index()
+    }
+
+    /// Unload the backing module, removing the device
+    pub fn destroy(self) -> Result<(), String> {
+        self.module.unload()
+    }
+}
+
+pub struct VirtualDeviceController;
+
+impl VirtualDeviceController {
+    /// Create a null sink that applications can play to. Its monitor source
+    /// is available as `<name>.monitor`.
+    pub fn create_null_sink(name: &str, description: &str) -> Result<VirtualDevice, String> {
+        validate_device_name(name)?;
+
+        let argument = format!(
+            "sink_name={} sink_properties={}",
+            name,
+            description_properties(description)
+        );
+
+        Ok(VirtualDevice {
+            name: name.to_string(),
+            module: OwnedModule::load("module-null-sink", &argument)?,
+        })
+    }
+
+    /// Create a virtual source that re-exposes `master`, e.g. the monitor of a
+    /// null sink as a microphone other applications can record from
+    pub fn create_remapped_source(
+        name: &str,
+        description: &str,
+        master: &str,
+    ) -> Result<VirtualDevice, String> {
+        validate_device_name(name)?;
+        validate_device_name(master)?;
+
+        let argument = format!(
+            "source_name={} master={} source_properties={}",
+            name,
+            master,
+            description_properties(description)
+        );
+
+        Ok(VirtualDevice {
+            name: name.to_string(),
+            module: OwnedModule::load("module-remap-source", &argument)?,
+        })
+    }
+
+    /// Create a sink that plays everything sent to it on all of `devices`
+    /// simultaneously
+    pub fn create_combined_output(
+        name: &str,
+        description: &str,
+        devices: &[&str],
+    ) -> Result<VirtualDevice, String> {
+        validate_device_name(name)?;
+        if devices.len() < 2 {
+            return Err("A combined output needs at least two devices".to_string());
+        }
+        for device in devices {
+            validate_device_name(device)?;
+        }
+
+        let argument = format!(
+            "sink_name={} slaves={} sink_properties={}",
+            name,
+            devices.join(","),
+            description_properties(description)
+        );
+
+        Ok(VirtualDevice {
+            name: name.to_string(),
+            module: OwnedModule::load("module-combine-sink", &argument)?,
+        })
+    }
+}
@@ -8,7 +8,20 @@ pub mod linux;
 pub use windows::{AppVolumeController, AudioController, InputController};
 
 #[cfg(target_os = "linux")]
-pub use linux::{AppVolumeController, AudioController, InputController};
+pub use linux::{
+    AppVolumeController, AudioController, ClientController, Equalizer, EqualizerController,
+    InputController, ModuleController, NoiseSuppressionController, NoiseSuppressor,
+    PreferredDevices, PreferredDevicesController, ProcessTreeController, RoutingController,
+    RoutingWatcher, SandboxController, ServerController, Soundboard, SoundboardController,
+    StreamRestoreController,
+};
+
+#[cfg(target_os = "linux")]
+pub use linux::{
+    CardController, DeviceController, EchoCancel, EchoCancelController, Loopback,
+    LoopbackController, Playback, PlaybackController, Recording, RecordingController, ReplayBuffer,
+    SampleCacheController, SpeakerTestController, VirtualDevice, VirtualDeviceController,
+};
 
 #[cfg(not(any(windows, target_os = "linux")))]
 mod unsupported {
@@ -85,6 +98,13 @@ mod unsupported {
             ))
         }
 
+        pub fn set_app_output_device(_pid: u32, _device: &str) -> Result<()> {
+            Err(Error::new(
+                Status::GenericFailure,
+                "Platform not supported. Only Windows and Linux are supported.",
+            ))
+        }
+
         pub fn get_active_audio_apps() -> Result<Vec<AppInfo>> {
             Err(Error::new(
                 Status::GenericFailure,
@@ -135,3 +155,713 @@ mod unsupported {
 
 #[cfg(not(any(windows, target_os = "linux")))]
 pub use unsupported::{AppVolumeController, AudioController, InputController};
+
+// Controllers built on PulseAudio-only concepts (cards, modules, streams...)
+// have no counterpart on the other backends yet.
+#[cfg(not(target_os = "linux"))]
+#[allow(dead_code)]
+mod pulse_only {
+    use crate::{
+        CachedSample, CardInfo, ChannelInfo, ClientInfo, DeviceInfo, EchoCancelOptions, EqBand,
+        EqPreset, EqualizerOptions, ModuleInfo, NoiseSuppressionOptions, PlaybackOptions,
+        PlaybackSource, PreferredDeviceOptions, RecordingOptions, RecordingOutput, ReplayOptions,
+        RestoreEntry, RoutingRule, ServerInfo, SoundboardOptions, StreamInfo, StreamKind,
+        TestToneOptions,
+    };
+    use std::path::Path;
+    use std::time::Duration;
+
+    const NOT_SUPPORTED: &str = "Only supported on Linux (PulseAudio/PipeWire)";
+
+    pub struct CardController;
+
+    impl CardController {
+        pub fn get_cards() -> Result<Vec<CardInfo>, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn get_card(_card: &str) -> Result<CardInfo, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn set_card_profile(_card: &str, _profile: &str) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+    }
+
+    pub struct DeviceController;
+
+    impl DeviceController {
+        pub fn get_output_devices() -> Result<Vec<DeviceInfo>, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn get_input_devices() -> Result<Vec<DeviceInfo>, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn set_device_port(_device: &str, _port: &str) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn set_default_output_device(_device: &str) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn set_default_input_device(_device: &str) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn suspend_device(_device: &str, _suspend: bool) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+    }
+
+    #[derive(Debug)]
+    pub struct VirtualDevice {
+        name: String,
+        module_index: u32,
+    }
+
+    impl VirtualDevice {
+        pub fn name(&self) -> &str {
+            &self.name
+        }
+
+        pub fn module_index(&self) -> u32 {
+            self.module_index
+        }
+
+        pub fn destroy(self) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+    }
+
+    pub struct VirtualDeviceController;
+
+    impl VirtualDeviceController {
+        pub fn create_null_sink(_name: &str, _description: &str) -> Result<VirtualDevice, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn create_remapped_source(
+            _name: &str,
+            _description: &str,
+            _master: &str,
+        ) -> Result<VirtualDevice, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn create_combined_output(
+            _name: &str,
+            _description: &str,
+            _devices: &[&str],
+        ) -> Result<VirtualDevice, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+    }
+
+    #[derive(Debug)]
+    pub struct Loopback {
+        source: String,
+        sink: String,
+        module_index: u32,
+    }
+
+    impl Loopback {
+        pub fn source(&self) -> &str {
+            &self.source
+        }
+
+        pub fn sink(&self) -> &str {
+            &self.sink
+        }
+
+        pub fn module_index(&self) -> u32 {
+            self.module_index
+        }
+
+        pub fn get_volume(&self) -> Result<f64, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn set_volume(&self, _volume: f64) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn is_muted(&self) -> Result<bool, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn set_mute(&self, _muted: bool) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn destroy(self) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+    }
+
+    pub struct LoopbackController;
+
+    impl LoopbackController {
+        pub fn create_loopback(
+            _source: &str,
+            _sink: &str,
+            _latency_ms: u32,
+        ) -> Result<Loopback, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+    }
+
+    #[derive(Debug)]
+    pub struct EchoCancel {
+        source_name: String,
+        sink_name: String,
+        module_index: u32,
+    }
+
+    impl EchoCancel {
+        pub fn source_name(&self) -> &str {
+            &self.source_name
+        }
+
+        pub fn sink_name(&self) -> &str {
+            &self.sink_name
+        }
+
+        pub fn module_index(&self) -> u32 {
+            self.module_index
+        }
+
+        pub fn destroy(self) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+    }
+
+    pub struct EchoCancelController;
+
+    impl EchoCancelController {
+        pub fn create_echo_cancel(_options: &EchoCancelOptions) -> Result<EchoCancel, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+    }
+
+    pub struct Recording;
+
+    impl Recording {
+        pub fn is_finished(&self) -> bool {
+            true
+        }
+
+        pub fn wait(self) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn stop(self) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+    }
+
+    pub struct Playback;
+
+    impl Playback {
+        pub fn sink_input_index(&self) -> u32 {
+            0
+        }
+
+        pub fn is_finished(&self) -> bool {
+            true
+        }
+
+        pub fn wait(self) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn stop(self) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+    }
+
+    pub struct PlaybackController;
+
+    impl PlaybackController {
+        pub fn play(
+            _source: PlaybackSource,
+            _options: &PlaybackOptions,
+        ) -> Result<Playback, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+    }
+
+    pub struct SampleCacheController;
+
+    impl SampleCacheController {
+        pub fn upload_sample(_name: &str, _source: PlaybackSource) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn play_sample(
+            _name: &str,
+            _device: Option<&str>,
+            _volume: Option<f64>,
+        ) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn get_samples() -> Result<Vec<CachedSample>, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn remove_sample(_name: &str) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+    }
+
+    pub struct Soundboard;
+
+    impl Soundboard {
+        pub fn source_name(&self) -> &str {
+            ""
+        }
+
+        pub fn mix_sink_name(&self) -> &str {
+            ""
+        }
+
+        pub fn clip_names(&self) -> Vec<String> {
+            Vec::new()
+        }
+
+        pub fn add_clip(
+            &mut self,
+            _name: &str,
+            _source: PlaybackSource,
+            _volume: f64,
+        ) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn remove_clip(&mut self, _name: &str) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn set_clip_volume(&mut self, _name: &str, _volume: f64) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn get_clip_volume(&self, _name: &str) -> Result<f64, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn play_clip(&mut self, _name: &str) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn stop_clips(&mut self) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn get_microphone_volume(&self) -> Result<f64, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn set_microphone_volume(&self, _volume: f64) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn is_microphone_muted(&self) -> Result<bool, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn set_microphone_mute(&self, _muted: bool) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn destroy(self) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+    }
+
+    pub struct SoundboardController;
+
+    impl SoundboardController {
+        pub fn create_soundboard(_options: &SoundboardOptions) -> Result<Soundboard, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+    }
+
+    pub struct Equalizer;
+
+    impl Equalizer {
+        pub fn sink_name(&self) -> &str {
+            ""
+        }
+
+        pub fn output_device(&self) -> &str {
+            ""
+        }
+
+        pub fn preset(&self) -> Result<EqPreset, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn apply_preset(&self, _preset: &EqPreset) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn set_bands(&self, _bands: Vec<EqBand>) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn set_band(&self, _index: usize, _band: EqBand) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn set_preamp(&self, _preamp_db: f64) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn save_preset(&self, _path: &Path, _name: &str) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn destroy(self) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+    }
+
+    pub struct EqualizerController;
+
+    impl EqualizerController {
+        pub fn create_equalizer(_options: &EqualizerOptions) -> Result<Equalizer, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+    }
+
+    pub struct NoiseSuppressor;
+
+    impl NoiseSuppressor {
+        pub fn source_name(&self) -> &str {
+            ""
+        }
+
+        pub fn microphone(&self) -> &str {
+            ""
+        }
+
+        pub fn is_enabled(&self) -> bool {
+            false
+        }
+
+        pub fn set_enabled(&self, _enabled: bool) {}
+
+        pub fn strength(&self) -> f64 {
+            0.0
+        }
+
+        pub fn set_strength(&self, _strength: f64) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn get_volume(&self) -> Result<f64, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn set_volume(&self, _volume: f64) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn is_muted(&self) -> Result<bool, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn set_mute(&self, _muted: bool) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn destroy(self) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+    }
+
+    pub struct NoiseSuppressionController;
+
+    impl NoiseSuppressionController {
+        pub fn create_noise_suppressor(
+            _options: &NoiseSuppressionOptions,
+        ) -> Result<NoiseSuppressor, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+    }
+
+    pub struct ServerController;
+
+    impl ServerController {
+        pub fn get_server_info() -> Result<ServerInfo, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+    }
+
+    pub struct ClientController;
+
+    impl ClientController {
+        pub fn get_clients() -> Result<Vec<ClientInfo>, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn get_streams() -> Result<Vec<StreamInfo>, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn kill_client(_index: u32) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn kill_stream(_kind: StreamKind, _index: u32) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+    }
+
+    pub struct ModuleController;
+
+    impl ModuleController {
+        pub fn get_modules() -> Result<Vec<ModuleInfo>, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn load_module(_name: &str, _argument: &str) -> Result<u32, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn unload_module(_index: u32) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+    }
+
+    pub struct StreamRestoreController;
+
+    impl StreamRestoreController {
+        pub fn get_entries() -> Result<Vec<RestoreEntry>, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn write_entry(_entry: &RestoreEntry, _apply: bool) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn delete_entries(_names: &[&str]) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+    }
+
+    pub struct PreferredDevices;
+
+    impl PreferredDevices {
+        pub fn outputs(&self) -> Result<Vec<String>, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn inputs(&self) -> Result<Vec<String>, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn set_outputs(&self, _outputs: Vec<String>) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn set_inputs(&self, _inputs: Vec<String>) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn is_running(&self) -> bool {
+            false
+        }
+
+        pub fn stop(self) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+    }
+
+    pub struct PreferredDevicesController;
+
+    impl PreferredDevicesController {
+        pub fn watch(_options: &PreferredDeviceOptions) -> Result<PreferredDevices, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+    }
+
+    pub struct RoutingWatcher;
+
+    impl RoutingWatcher {
+        pub fn path(&self) -> &Path {
+            Path::new("")
+        }
+
+        pub fn is_running(&self) -> bool {
+            false
+        }
+
+        pub fn stop(self) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+    }
+
+    pub struct RoutingController;
+
+    impl RoutingController {
+        pub fn get_rules(_path: &Path) -> Result<Vec<RoutingRule>, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn set_rule(_path: &Path, _rule: &RoutingRule) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn remove_rule(_path: &Path, _selector: &str) -> Result<bool, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn watch(_path: &Path) -> Result<RoutingWatcher, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+    }
+
+    pub struct ProcessTreeController;
+
+    impl ProcessTreeController {
+        pub fn get_streams(_root: u32) -> Result<Vec<StreamInfo>, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn get_volume(_root: u32) -> Result<f64, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn set_volume(_root: u32, _volume: f64) -> Result<usize, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn is_muted(_root: u32) -> Result<bool, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn set_mute(_root: u32, _muted: bool) -> Result<usize, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn set_output_device(_root: u32, _device: &str) -> Result<usize, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+    }
+
+    pub struct SandboxController;
+
+    impl SandboxController {
+        pub fn get_streams(_app_id: &str) -> Result<Vec<StreamInfo>, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn get_volume(_app_id: &str) -> Result<f64, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn set_volume(_app_id: &str, _volume: f64) -> Result<usize, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn is_muted(_app_id: &str) -> Result<bool, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn set_mute(_app_id: &str, _muted: bool) -> Result<usize, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn set_output_device(_app_id: &str, _device: &str) -> Result<usize, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+    }
+
+    pub struct SpeakerTestController;
+
+    impl SpeakerTestController {
+        pub fn get_channels(_device: Option<&str>) -> Result<Vec<ChannelInfo>, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn play_test_tone(_options: &TestToneOptions) -> Result<Playback, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+    }
+
+    pub struct ReplayBuffer;
+
+    impl ReplayBuffer {
+        pub fn capacity(&self) -> Duration {
+            Duration::ZERO
+        }
+
+        pub fn buffered(&self) -> Duration {
+            Duration::ZERO
+        }
+
+        pub fn is_running(&self) -> bool {
+            false
+        }
+
+        pub fn save(&self, _path: &Path, _duration: Option<Duration>) -> Result<Duration, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn clear(&self) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn stop(self) -> Result<(), String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+    }
+
+    pub struct RecordingController;
+
+    impl RecordingController {
+        pub fn start_recording(
+            _options: &RecordingOptions,
+            _output: RecordingOutput,
+        ) -> Result<Recording, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn start_app_recording(
+            _pid: u32,
+            _options: &RecordingOptions,
+            _output: RecordingOutput,
+        ) -> Result<Recording, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn start_app_recording_by_selector(
+            _selector: &str,
+            _options: &RecordingOptions,
+            _output: RecordingOutput,
+        ) -> Result<Recording, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+
+        pub fn start_replay_buffer(_options: &ReplayOptions) -> Result<ReplayBuffer, String> {
+            Err(NOT_SUPPORTED.to_string())
+        }
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+pub use pulse_only::{
+    CardController, ClientController, DeviceController, EchoCancel, EchoCancelController,
+    Equalizer, EqualizerController, Loopback, LoopbackController, ModuleController,
+    NoiseSuppressionController, NoiseSuppressor, Playback, PlaybackController, PreferredDevices,
+    PreferredDevicesController, ProcessTreeController, Recording, RecordingController,
+    ReplayBuffer, RoutingController, RoutingWatcher, SampleCacheController, SandboxController,
+    ServerController, Soundboard, SoundboardController, SpeakerTestController,
+    StreamRestoreController, VirtualDevice, VirtualDeviceController,
+};
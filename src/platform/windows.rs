@@ -248,6 +248,13 @@ impl AppVolumeController {
         }
     }
 
+    pub fn set_app_output_device(_pid: u32, _device: &str) -> Result<()> {
+        Err(Error::new(
+            Status::GenericFailure,
+            "Per-application output routing is not supported on Windows",
+        ))
+    }
+
     pub fn get_active_audio_apps() -> Result<Vec<AppInfo>> {
         unsafe {
             let session_manager = Self::get_session_manager()?;
@@ -294,6 +301,7 @@ impl AppVolumeController {
                                     name: process_name,
                                     volume: volume as f64,
                                     muted,
+                                    process: None,
                                 });
                             }
                         }
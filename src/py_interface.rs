@@ -3,10 +3,40 @@
 use pyo3::{
     exceptions::{PyRuntimeError, PyValueError},
     prelude::*,
+    types::PyBytes,
 };
 
-use crate::platform::{AppVolumeController, AudioController, InputController};
-use crate::AppInfo as RustAppInfo;
+use crate::platform::{
+    AppVolumeController, AudioController, CardController, ClientController, DeviceController,
+    EchoCancel as RustEchoCancel, EchoCancelController, Equalizer as RustEqualizer,
+    EqualizerController, InputController, Loopback as RustLoopback, LoopbackController,
+    ModuleController, NoiseSuppressionController, NoiseSuppressor as RustNoiseSuppressor,
+    Playback as RustPlayback, PlaybackController, PreferredDevices as RustPreferredDevices,
+    PreferredDevicesController, ProcessTreeController, Recording as RustRecording,
+    RecordingController, ReplayBuffer as RustReplayBuffer, RoutingController,
+    RoutingWatcher as RustRoutingWatcher, SampleCacheController, SandboxController,
+    ServerController, Soundboard as RustSoundboard, SoundboardController, SpeakerTestController,
+    StreamRestoreController, VirtualDevice as RustVirtualDevice, VirtualDeviceController,
+};
+use crate::CachedSample as RustCachedSample;
+use crate::ModuleInfo as RustModuleInfo;
+use crate::NoiseSuppressionOptions;
+use crate::PreferredDeviceOptions;
+use crate::RestoreEntry as RustRestoreEntry;
+use crate::RoutingRule as RustRoutingRule;
+use crate::ServerInfo as RustServerInfo;
+use crate::{AppInfo as RustAppInfo, ProcessInfo};
+use crate::{CardInfo as RustCardInfo, CardProfile as RustCardProfile};
+use crate::{
+    ChannelInfo as RustChannelInfo, DeviceInfo as RustDeviceInfo, PortInfo as RustPortInfo,
+};
+use crate::{ClientInfo as RustClientInfo, StreamInfo as RustStreamInfo, StreamKind};
+use crate::{
+    EchoCancelOptions, PcmCallback, PlaybackOptions, PlaybackSource, RecordingOptions,
+    RecordingOutput, ReplayOptions, SampleFormat, SampleSpec, SoundboardOptions, TestSignal,
+    TestToneOptions,
+};
+use crate::{EqBand as RustEqBand, EqPreset, EqualizerOptions, FilterType};
 
 /// Python module for fa-control
 #[pymodule]
@@ -22,17 +52,114 @@ pub fn fa_control(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(set_app_volume))?;
     m.add_wrapped(wrap_pyfunction!(is_app_muted))?;
     m.add_wrapped(wrap_pyfunction!(set_app_mute))?;
+    m.add_wrapped(wrap_pyfunction!(set_app_output_device))?;
     m.add_wrapped(wrap_pyfunction!(get_active_audio_apps))?;
 
+    m.add_wrapped(wrap_pyfunction!(get_clients))?;
+    m.add_wrapped(wrap_pyfunction!(get_streams))?;
+    m.add_wrapped(wrap_pyfunction!(kill_client))?;
+    m.add_wrapped(wrap_pyfunction!(kill_stream))?;
+
+    m.add_wrapped(wrap_pyfunction!(get_process_tree_streams))?;
+    m.add_wrapped(wrap_pyfunction!(get_process_tree_volume))?;
+    m.add_wrapped(wrap_pyfunction!(set_process_tree_volume))?;
+    m.add_wrapped(wrap_pyfunction!(is_process_tree_muted))?;
+    m.add_wrapped(wrap_pyfunction!(set_process_tree_mute))?;
+    m.add_wrapped(wrap_pyfunction!(set_process_tree_output_device))?;
+    m.add_wrapped(wrap_pyfunction!(get_app_streams_by_id))?;
+    m.add_wrapped(wrap_pyfunction!(get_app_volume_by_id))?;
+    m.add_wrapped(wrap_pyfunction!(set_app_volume_by_id))?;
+    m.add_wrapped(wrap_pyfunction!(is_app_muted_by_id))?;
+    m.add_wrapped(wrap_pyfunction!(set_app_mute_by_id))?;
+    m.add_wrapped(wrap_pyfunction!(set_app_output_device_by_id))?;
+
+    m.add_wrapped(wrap_pyfunction!(get_modules))?;
+    m.add_wrapped(wrap_pyfunction!(load_module))?;
+    m.add_wrapped(wrap_pyfunction!(unload_module))?;
+
+    m.add_wrapped(wrap_pyfunction!(get_restore_entries))?;
+    m.add_wrapped(wrap_pyfunction!(write_restore_entry))?;
+    m.add_wrapped(wrap_pyfunction!(delete_restore_entries))?;
+    m.add_wrapped(wrap_pyfunction!(app_restore_key))?;
+
+    m.add_wrapped(wrap_pyfunction!(watch_preferred_devices))?;
+
+    m.add_wrapped(wrap_pyfunction!(get_routing_rules))?;
+    m.add_wrapped(wrap_pyfunction!(set_routing_rule))?;
+    m.add_wrapped(wrap_pyfunction!(remove_routing_rule))?;
+    m.add_wrapped(wrap_pyfunction!(watch_routing_rules))?;
+
     m.add_wrapped(wrap_pyfunction!(get_microphone_volume))?;
     m.add_wrapped(wrap_pyfunction!(set_microphone_volume))?;
     m.add_wrapped(wrap_pyfunction!(is_microphone_muted))?;
     m.add_wrapped(wrap_pyfunction!(toggle_microphone_mute))?;
     m.add_wrapped(wrap_pyfunction!(set_microphone_mute))?;
 
+    m.add_wrapped(wrap_pyfunction!(get_cards))?;
+    m.add_wrapped(wrap_pyfunction!(set_card_profile))?;
+
+    m.add_wrapped(wrap_pyfunction!(get_output_devices))?;
+    m.add_wrapped(wrap_pyfunction!(get_input_devices))?;
+    m.add_wrapped(wrap_pyfunction!(set_device_port))?;
+    m.add_wrapped(wrap_pyfunction!(set_default_output_device))?;
+    m.add_wrapped(wrap_pyfunction!(set_default_input_device))?;
+    m.add_wrapped(wrap_pyfunction!(suspend_device))?;
+
+    m.add_wrapped(wrap_pyfunction!(create_null_sink))?;
+    m.add_wrapped(wrap_pyfunction!(create_remapped_source))?;
+    m.add_wrapped(wrap_pyfunction!(create_combined_output))?;
+    m.add_wrapped(wrap_pyfunction!(create_loopback))?;
+    m.add_wrapped(wrap_pyfunction!(create_echo_cancel))?;
+
+    m.add_wrapped(wrap_pyfunction!(start_recording))?;
+    m.add_wrapped(wrap_pyfunction!(start_app_recording))?;
+    m.add_wrapped(wrap_pyfunction!(start_app_recording_by_selector))?;
+    m.add_wrapped(wrap_pyfunction!(start_replay_buffer))?;
+
+    m.add_wrapped(wrap_pyfunction!(play_audio))?;
+    m.add_wrapped(wrap_pyfunction!(get_device_channels))?;
+    m.add_wrapped(wrap_pyfunction!(play_test_tone))?;
+
+    m.add_wrapped(wrap_pyfunction!(upload_sample))?;
+    m.add_wrapped(wrap_pyfunction!(play_sample))?;
+    m.add_wrapped(wrap_pyfunction!(get_samples))?;
+    m.add_wrapped(wrap_pyfunction!(remove_sample))?;
+
+    m.add_wrapped(wrap_pyfunction!(create_soundboard))?;
+
+    m.add_wrapped(wrap_pyfunction!(create_equalizer))?;
+    m.add_wrapped(wrap_pyfunction!(get_equalizer_presets))?;
+
+    m.add_wrapped(wrap_pyfunction!(create_noise_suppressor))?;
+
+    m.add_wrapped(wrap_pyfunction!(get_server_info))?;
     m.add_wrapped(wrap_pyfunction!(get_platform))?;
 
     m.add_class::<AppInfo>()?;
+    m.add_class::<CardInfo>()?;
+    m.add_class::<CardProfile>()?;
+    m.add_class::<DeviceInfo>()?;
+    m.add_class::<PortInfo>()?;
+    m.add_class::<ChannelInfo>()?;
+    m.add_class::<VirtualDevice>()?;
+    m.add_class::<Loopback>()?;
+    m.add_class::<EchoCancel>()?;
+    m.add_class::<Recording>()?;
+    m.add_class::<ReplayBuffer>()?;
+    m.add_class::<Playback>()?;
+    m.add_class::<CachedSample>()?;
+    m.add_class::<Soundboard>()?;
+    m.add_class::<EqBand>()?;
+    m.add_class::<Equalizer>()?;
+    m.add_class::<NoiseSuppressor>()?;
+    m.add_class::<ServerInfo>()?;
+    m.add_class::<ClientInfo>()?;
+    m.add_class::<StreamInfo>()?;
+    m.add_class::<ModuleInfo>()?;
+    m.add_class::<RestoreEntry>()?;
+    m.add_class::<PreferredDevices>()?;
+    m.add_class::<RoutingRule>()?;
+    m.add_class::<RoutingWatcher>()?;
 
     Ok(())
 }
@@ -106,6 +233,13 @@ pub fn set_app_mute(_py: Python, pid: u32, muted: bool) -> PyResult<()> {
         .map_err(|e| PyRuntimeError::new_err(format!("Failed to set app mute: {}", e)))
 }
 
+/// Move a specific application's audio, by PID, to an output device
+#[pyfunction]
+pub fn set_app_output_device(_py: Python, pid: u32, device: &str) -> PyResult<()> {
+    AppVolumeController::set_app_output_device(pid, device)
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to set app output device: {}", e)))
+}
+
 /// Get list of all active audio applications with their PIDs and names
 #[pyfunction]
 pub fn get_active_audio_apps(_py: Python) -> PyResult<Vec<AppInfo>> {
@@ -114,6 +248,299 @@ pub fn get_active_audio_apps(_py: Python) -> PyResult<Vec<AppInfo>> {
         .map_err(|e| PyRuntimeError::new_err(format!("Failed to get active audio apps: {}", e)))
 }
 
+/// Get the clients connected to the sound server
+#[pyfunction]
+pub fn get_clients(_py: Python) -> PyResult<Vec<ClientInfo>> {
+    ClientController::get_clients()
+        .map(|clients| clients.into_iter().map(ClientInfo::from).collect())
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to get clients: {}", e)))
+}
+
+/// Get all playback (sink input) and recording (source output) streams
+#[pyfunction]
+pub fn get_streams(_py: Python) -> PyResult<Vec<StreamInfo>> {
+    ClientController::get_streams()
+        .map(|streams| streams.into_iter().map(StreamInfo::from).collect())
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to get streams: {}", e)))
+}
+
+/// Disconnect a client by index, closing all of its streams
+#[pyfunction]
+pub fn kill_client(_py: Python, index: u32) -> PyResult<()> {
+    ClientController::kill_client(index)
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to kill client: {}", e)))
+}
+
+/// Close a stream by index; `kind` is "sink_input" or "source_output"
+#[pyfunction]
+#[pyo3(signature = (index, kind="sink_input"))]
+pub fn kill_stream(_py: Python, index: u32, kind: &str) -> PyResult<()> {
+    let kind: StreamKind = kind.parse().map_err(PyValueError::new_err)?;
+    ClientController::kill_stream(kind, index)
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to kill stream: {}", e)))
+}
+
+/// Get the streams of a process and all of its descendants
+#[pyfunction]
+pub fn get_process_tree_streams(_py: Python, pid: u32) -> PyResult<Vec<StreamInfo>> {
+    ProcessTreeController::get_streams(pid)
+        .map(|streams| streams.into_iter().map(StreamInfo::from).collect())
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to get process tree streams: {}", e)))
+}
+
+/// Get the average volume of a process tree's playback streams
+#[pyfunction]
+pub fn get_process_tree_volume(_py: Python, pid: u32) -> PyResult<f64> {
+    ProcessTreeController::get_volume(pid)
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to get process tree volume: {}", e)))
+}
+
+/// Set the volume of every playback stream of a process tree, returning how
+/// many streams were changed
+#[pyfunction]
+pub fn set_process_tree_volume(_py: Python, pid: u32, volume: f64) -> PyResult<usize> {
+    if !(0.0..=1.0).contains(&volume) {
+        return Err(PyValueError::new_err("Volume must be between 0.0 and 1.0"));
+    }
+    ProcessTreeController::set_volume(pid, volume)
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to set process tree volume: {}", e)))
+}
+
+/// Whether every playback stream of a process tree is muted
+#[pyfunction]
+pub fn is_process_tree_muted(_py: Python, pid: u32) -> PyResult<bool> {
+    ProcessTreeController::is_muted(pid)
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to get process tree mute: {}", e)))
+}
+
+/// Mute or unmute every playback stream of a process tree
+#[pyfunction]
+pub fn set_process_tree_mute(_py: Python, pid: u32, muted: bool) -> PyResult<usize> {
+    ProcessTreeController::set_mute(pid, muted)
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to set process tree mute: {}", e)))
+}
+
+/// Move every playback stream of a process tree to an output device
+#[pyfunction]
+pub fn set_process_tree_output_device(_py: Python, pid: u32, device: &str) -> PyResult<usize> {
+    ProcessTreeController::set_output_device(pid, device)
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to move process tree streams: {}", e)))
+}
+
+/// Get the streams of an app by Flatpak app ID or systemd unit
+#[pyfunction]
+pub fn get_app_streams_by_id(_py: Python, app_id: &str) -> PyResult<Vec<StreamInfo>> {
+    SandboxController::get_streams(app_id)
+        .map(|streams| streams.into_iter().map(StreamInfo::from).collect())
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to get app streams: {}", e)))
+}
+
+/// Get the average volume of an app's playback streams by Flatpak app ID or
+/// systemd unit
+#[pyfunction]
+pub fn get_app_volume_by_id(_py: Python, app_id: &str) -> PyResult<f64> {
+    SandboxController::get_volume(app_id)
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to get app volume: {}", e)))
+}
+
+/// Set the volume of every playback stream of an app by Flatpak app ID or
+/// systemd unit, returning how many streams were changed
+#[pyfunction]
+pub fn set_app_volume_by_id(_py: Python, app_id: &str, volume: f64) -> PyResult<usize> {
+    if !(0.0..=1.0).contains(&volume) {
+        return Err(PyValueError::new_err("Volume must be between 0.0 and 1.0"));
+    }
+    SandboxController::set_volume(app_id, volume)
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to set app volume: {}", e)))
+}
+
+/// Whether every playback stream of an app is muted, by Flatpak app ID or
+/// systemd unit
+#[pyfunction]
+pub fn is_app_muted_by_id(_py: Python, app_id: &str) -> PyResult<bool> {
+    SandboxController::is_muted(app_id)
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to get app mute: {}", e)))
+}
+
+/// Mute or unmute every playback stream of an app by Flatpak app ID or
+/// systemd unit
+#[pyfunction]
+pub fn set_app_mute_by_id(_py: Python, app_id: &str, muted: bool) -> PyResult<usize> {
+    SandboxController::set_mute(app_id, muted)
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to set app mute: {}", e)))
+}
+
+/// Move every playback stream of an app to an output device, by Flatpak app
+/// ID or systemd unit
+#[pyfunction]
+pub fn set_app_output_device_by_id(_py: Python, app_id: &str, device: &str) -> PyResult<usize> {
+    SandboxController::set_output_device(app_id, device)
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to move app streams: {}", e)))
+}
+
+/// Get the modules loaded into the sound server
+#[pyfunction]
+pub fn get_modules(_py: Python) -> PyResult<Vec<ModuleInfo>> {
+    ModuleController::get_modules()
+        .map(|modules| modules.into_iter().map(ModuleInfo::from).collect())
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to get modules: {}", e)))
+}
+
+/// Load a server module and return its index; it stays loaded until
+/// `unload_module` is called
+#[pyfunction]
+#[pyo3(signature = (name, argument=""))]
+pub fn load_module(_py: Python, name: &str, argument: &str) -> PyResult<u32> {
+    ModuleController::load_module(name, argument)
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to load module: {}", e)))
+}
+
+/// Unload a server module by index
+#[pyfunction]
+pub fn unload_module(_py: Python, index: u32) -> PyResult<()> {
+    ModuleController::unload_module(index)
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to unload module: {}", e)))
+}
+
+/// Get the entries of the stream-restore database
+#[pyfunction]
+pub fn get_restore_entries(_py: Python) -> PyResult<Vec<RestoreEntry>> {
+    StreamRestoreController::get_entries()
+        .map(|entries| entries.into_iter().map(RestoreEntry::from).collect())
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to get restore entries: {}", e)))
+}
+
+/// Add or replace a stream-restore entry; with `apply`, matching streams that
+/// are already playing are updated too
+#[pyfunction]
+#[pyo3(signature = (name, volume=None, mute=false, device=None, apply=true))]
+pub fn write_restore_entry(
+    _py: Python,
+    name: String,
+    volume: Option<f64>,
+    mute: bool,
+    device: Option<String>,
+    apply: bool,
+) -> PyResult<()> {
+    if volume.is_some_and(|v| !(0.0..=1.0).contains(&v)) {
+        return Err(PyValueError::new_err("Volume must be between 0.0 and 1.0"));
+    }
+    let entry = RustRestoreEntry {
+        name,
+        volume: volume.map(|v| v as f32),
+        mute,
+        device,
+    };
+    StreamRestoreController::write_entry(&entry, apply)
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to write restore entry: {}", e)))
+}
+
+/// Delete stream-restore entries by name
+#[pyfunction]
+pub fn delete_restore_entries(_py: Python, names: Vec<String>) -> PyResult<()> {
+    let names: Vec<&str> = names.iter().map(String::as_str).collect();
+    StreamRestoreController::delete_entries(&names)
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to delete restore entries: {}", e)))
+}
+
+/// Stream-restore key for an application's playback streams, e.g.
+/// "sink-input-by-application-name:Firefox"
+#[pyfunction]
+pub fn app_restore_key(application_name: &str) -> String {
+    RustRestoreEntry::application_key(application_name)
+}
+
+/// Keep the default output and input devices on the first available device
+/// of each list, switching as devices are plugged in and removed
+#[pyfunction]
+#[pyo3(signature = (outputs=None, inputs=None, move_streams=true))]
+pub fn watch_preferred_devices(
+    py: Python,
+    outputs: Option<Vec<String>>,
+    inputs: Option<Vec<String>>,
+    move_streams: bool,
+) -> PyResult<PreferredDevices> {
+    let options = PreferredDeviceOptions {
+        outputs: outputs.unwrap_or_default(),
+        inputs: inputs.unwrap_or_default(),
+        move_streams,
+    };
+
+    py.allow_threads(|| PreferredDevicesController::watch(&options))
+        .map(|watcher| PreferredDevices {
+            inner: Some(watcher),
+        })
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to watch preferred devices: {}", e)))
+}
+
+/// The routing rules file to use: `path`, or the default under the user's
+/// config directory
+fn routing_rules_path(path: Option<std::path::PathBuf>) -> PyResult<std::path::PathBuf> {
+    match path {
+        Some(path) => Ok(path),
+        None => crate::routing::default_rules_path().map_err(PyRuntimeError::new_err),
+    }
+}
+
+/// Get the saved per-app routing rules, in the order they are tried
+#[pyfunction]
+#[pyo3(signature = (path=None))]
+pub fn get_routing_rules(
+    _py: Python,
+    path: Option<std::path::PathBuf>,
+) -> PyResult<Vec<RoutingRule>> {
+    RoutingController::get_rules(&routing_rules_path(path)?)
+        .map(|rules| rules.into_iter().map(RoutingRule::from).collect())
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to get routing rules: {}", e)))
+}
+
+/// Save a rule sending streams matching `selector` to the output device
+/// `device`, replacing any rule for the same selector, and move matching
+/// streams now
+#[pyfunction]
+#[pyo3(signature = (selector, device, path=None))]
+pub fn set_routing_rule(
+    py: Python,
+    selector: String,
+    device: String,
+    path: Option<std::path::PathBuf>,
+) -> PyResult<()> {
+    let path = routing_rules_path(path)?;
+    let rule = RustRoutingRule { selector, device };
+    crate::routing::validate_rule(&rule).map_err(PyValueError::new_err)?;
+
+    py.allow_threads(|| RoutingController::set_rule(&path, &rule))
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to set routing rule: {}", e)))
+}
+
+/// Remove the rule for `selector`, returning whether there was one
+#[pyfunction]
+#[pyo3(signature = (selector, path=None))]
+pub fn remove_routing_rule(
+    _py: Python,
+    selector: &str,
+    path: Option<std::path::PathBuf>,
+) -> PyResult<bool> {
+    RoutingController::remove_rule(&routing_rules_path(path)?, selector)
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to remove routing rule: {}", e)))
+}
+
+/// Keep playback streams on the devices their routing rules name, moving
+/// them back when a disconnected device reappears
+#[pyfunction]
+#[pyo3(signature = (path=None))]
+pub fn watch_routing_rules(
+    py: Python,
+    path: Option<std::path::PathBuf>,
+) -> PyResult<RoutingWatcher> {
+    let path = routing_rules_path(path)?;
+    py.allow_threads(|| RoutingController::watch(&path))
+        .map(|watcher| RoutingWatcher {
+            inner: Some(watcher),
+        })
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to watch routing rules: {}", e)))
+}
+
 /// Get microphone volume level (0.0 to 1.0)
 #[pyfunction]
 pub fn get_microphone_volume(_py: Python) -> PyResult<f64> {
@@ -152,6 +579,636 @@ pub fn set_microphone_mute(_py: Python, muted: bool) -> PyResult<()> {
         .map_err(|e| PyRuntimeError::new_err(format!("Failed to set microphone mute: {}", e)))
 }
 
+/// Get list of sound cards with their profiles
+#[pyfunction]
+pub fn get_cards(_py: Python) -> PyResult<Vec<CardInfo>> {
+    CardController::get_cards()
+        .map(|cards| cards.into_iter().map(CardInfo::from).collect())
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to get cards: {}", e)))
+}
+
+/// Switch a card (by name or index) to the given profile
+#[pyfunction]
+pub fn set_card_profile(_py: Python, card: &str, profile: &str) -> PyResult<()> {
+    CardController::set_card_profile(card, profile)
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to set card profile: {}", e)))
+}
+
+/// Get list of output devices (sinks) with their ports
+#[pyfunction]
+pub fn get_output_devices(_py: Python) -> PyResult<Vec<DeviceInfo>> {
+    DeviceController::get_output_devices()
+        .map(|devices| devices.into_iter().map(DeviceInfo::from).collect())
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to get output devices: {}", e)))
+}
+
+/// Get list of input devices (sources) with their ports
+#[pyfunction]
+pub fn get_input_devices(_py: Python) -> PyResult<Vec<DeviceInfo>> {
+    DeviceController::get_input_devices()
+        .map(|devices| devices.into_iter().map(DeviceInfo::from).collect())
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to get input devices: {}", e)))
+}
+
+/// Activate a port (e.g. headphones or speakers) on a device
+#[pyfunction]
+pub fn set_device_port(_py: Python, device: &str, port: &str) -> PyResult<()> {
+    DeviceController::set_device_port(device, port)
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to set device port: {}", e)))
+}
+
+/// Set the default output device for new streams
+#[pyfunction]
+pub fn set_default_output_device(_py: Python, device: &str) -> PyResult<()> {
+    DeviceController::set_default_output_device(device)
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to set default output device: {}", e)))
+}
+
+/// Set the default input device for new streams
+#[pyfunction]
+pub fn set_default_input_device(_py: Python, device: &str) -> PyResult<()> {
+    DeviceController::set_default_input_device(device)
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to set default input device: {}", e)))
+}
+
+/// Suspend a device, closing it until resumed, or resume it with
+/// `suspend=False`
+#[pyfunction]
+#[pyo3(signature = (device, suspend=true))]
+pub fn suspend_device(_py: Python, device: &str, suspend: bool) -> PyResult<()> {
+    DeviceController::suspend_device(device, suspend).map_err(|e| {
+        let action = if suspend { "suspend" } else { "resume" };
+        PyRuntimeError::new_err(format!("Failed to {} device: {}", action, e))
+    })
+}
+
+/// Create a virtual output device (null sink) managed by the library
+#[pyfunction]
+#[pyo3(signature = (name, description=None))]
+pub fn create_null_sink(
+    _py: Python,
+    name: &str,
+    description: Option<&str>,
+) -> PyResult<VirtualDevice> {
+    VirtualDeviceController::create_null_sink(name, description.unwrap_or(name))
+        .map(VirtualDevice::from)
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to create null sink: {}", e)))
+}
+
+/// Create a virtual input device re-exposing `master` (e.g. a null sink monitor)
+#[pyfunction]
+#[pyo3(signature = (name, master, description=None))]
+pub fn create_remapped_source(
+    _py: Python,
+    name: &str,
+    master: &str,
+    description: Option<&str>,
+) -> PyResult<VirtualDevice> {
+    VirtualDeviceController::create_remapped_source(name, description.unwrap_or(name), master)
+        .map(VirtualDevice::from)
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to create remapped source: {}", e)))
+}
+
+/// Create a managed output device that plays on several devices at once
+#[pyfunction]
+#[pyo3(signature = (devices, name, description=None))]
+pub fn create_combined_output(
+    _py: Python,
+    devices: Vec<String>,
+    name: &str,
+    description: Option<&str>,
+) -> PyResult<VirtualDevice> {
+    let devices: Vec<&str> = devices.iter().map(String::as_str).collect();
+    VirtualDeviceController::create_combined_output(name, description.unwrap_or(name), &devices)
+        .map(VirtualDevice::from)
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to create combined output: {}", e)))
+}
+
+/// Route audio from a source to a sink through a managed loopback
+#[pyfunction]
+#[pyo3(signature = (source, sink, latency_ms=200))]
+pub fn create_loopback(
+    _py: Python,
+    source: &str,
+    sink: &str,
+    latency_ms: u32,
+) -> PyResult<Loopback> {
+    LoopbackController::create_loopback(source, sink, latency_ms)
+        .map(Loopback::from)
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to create loopback: {}", e)))
+}
+
+/// Create a managed echo-cancelled source/sink pair
+#[pyfunction]
+#[pyo3(signature = (
+    source_master=None,
+    sink_master=None,
+    method="webrtc",
+    aec_args=None,
+    noise_suppression=true,
+    source_name="echo_cancel_source",
+    sink_name="echo_cancel_sink",
+    make_default=false,
+))]
+#[allow(clippy::too_many_arguments)]
+pub fn create_echo_cancel(
+    _py: Python,
+    source_master: Option<String>,
+    sink_master: Option<String>,
+    method: &str,
+    aec_args: Option<String>,
+    noise_suppression: bool,
+    source_name: &str,
+    sink_name: &str,
+    make_default: bool,
+) -> PyResult<EchoCancel> {
+    let options = EchoCancelOptions {
+        source_master,
+        sink_master,
+        source_name: source_name.to_string(),
+        sink_name: sink_name.to_string(),
+        method: method.to_string(),
+        aec_args,
+        noise_suppression,
+        make_default,
+    };
+
+    EchoCancelController::create_echo_cancel(&options)
+        .map(EchoCancel::from)
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to create echo cancel: {}", e)))
+}
+
+/// Build a sample spec from Python arguments
+fn sample_spec(rate: u32, channels: u8, format: &str) -> PyResult<SampleSpec> {
+    Ok(SampleSpec {
+        format: format
+            .parse::<SampleFormat>()
+            .map_err(PyValueError::new_err)?,
+        rate,
+        channels,
+    })
+}
+
+/// Wrap a Python callable as a PCM callback. Exceptions raised by the
+/// callable are reported as unraisable since there is no caller to raise to.
+fn pcm_callback(callback: PyObject) -> PcmCallback {
+    Box::new(move |data| {
+        Python::with_gil(|py| {
+            if let Err(e) = callback.call1(py, (PyBytes::new_bound(py, data),)) {
+                e.write_unraisable_bound(py, None);
+            }
+        })
+    })
+}
+
+/// Build the output of a recording from exactly one of a path and a callback
+fn recording_output(
+    path: Option<std::path::PathBuf>,
+    callback: Option<PyObject>,
+) -> PyResult<RecordingOutput> {
+    match (path, callback) {
+        (Some(path), None) => Ok(RecordingOutput::Wav(path)),
+        (None, Some(callback)) => Ok(RecordingOutput::Callback(pcm_callback(callback))),
+        _ => Err(PyValueError::new_err(
+            "Exactly one of path and callback must be given",
+        )),
+    }
+}
+
+/// Start recording from a device to a WAV file or a callback receiving PCM bytes
+#[pyfunction]
+#[pyo3(signature = (
+    path=None,
+    callback=None,
+    device=None,
+    rate=48000,
+    channels=2,
+    format="s16le",
+    max_duration=None,
+))]
+#[allow(clippy::too_many_arguments)]
+pub fn start_recording(
+    _py: Python,
+    path: Option<std::path::PathBuf>,
+    callback: Option<PyObject>,
+    device: Option<String>,
+    rate: u32,
+    channels: u8,
+    format: &str,
+    max_duration: Option<f64>,
+) -> PyResult<Recording> {
+    let options = RecordingOptions {
+        device,
+        spec: sample_spec(rate, channels, format)?,
+        max_duration: max_duration.map(std::time::Duration::from_secs_f64),
+    };
+    let output = recording_output(path, callback)?;
+
+    RecordingController::start_recording(&options, output)
+        .map(Recording::from)
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to start recording: {}", e)))
+}
+
+/// Start recording only the audio played by the application with the given PID
+#[pyfunction]
+#[pyo3(signature = (
+    pid,
+    path=None,
+    callback=None,
+    rate=48000,
+    channels=2,
+    format="s16le",
+    max_duration=None,
+))]
+#[allow(clippy::too_many_arguments)]
+pub fn start_app_recording(
+    _py: Python,
+    pid: u32,
+    path: Option<std::path::PathBuf>,
+    callback: Option<PyObject>,
+    rate: u32,
+    channels: u8,
+    format: &str,
+    max_duration: Option<f64>,
+) -> PyResult<Recording> {
+    let options = RecordingOptions {
+        device: None,
+        spec: sample_spec(rate, channels, format)?,
+        max_duration: max_duration.map(std::time::Duration::from_secs_f64),
+    };
+    let output = recording_output(path, callback)?;
+
+    RecordingController::start_app_recording(pid, &options, output)
+        .map(Recording::from)
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to start app recording: {}", e)))
+}
+
+/// Start recording only the audio of the first playback stream matching a
+/// routing-rule selector, e.g. "application.name=Discord"
+#[pyfunction]
+#[pyo3(signature = (
+    selector,
+    path=None,
+    callback=None,
+    rate=48000,
+    channels=2,
+    format="s16le",
+    max_duration=None,
+))]
+#[allow(clippy::too_many_arguments)]
+pub fn start_app_recording_by_selector(
+    _py: Python,
+    selector: &str,
+    path: Option<std::path::PathBuf>,
+    callback: Option<PyObject>,
+    rate: u32,
+    channels: u8,
+    format: &str,
+    max_duration: Option<f64>,
+) -> PyResult<Recording> {
+    let options = RecordingOptions {
+        device: None,
+        spec: sample_spec(rate, channels, format)?,
+        max_duration: max_duration.map(std::time::Duration::from_secs_f64),
+    };
+    let output = recording_output(path, callback)?;
+
+    RecordingController::start_app_recording_by_selector(selector, &options, output)
+        .map(Recording::from)
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to start app recording: {}", e)))
+}
+
+/// Start an instant replay buffer keeping the last `seconds` of a device's
+/// or an application's audio in memory
+#[pyfunction]
+#[pyo3(signature = (seconds=30.0, device=None, pid=None, rate=48000, channels=2, format="s16le"))]
+pub fn start_replay_buffer(
+    _py: Python,
+    seconds: f64,
+    device: Option<String>,
+    pid: Option<u32>,
+    rate: u32,
+    channels: u8,
+    format: &str,
+) -> PyResult<ReplayBuffer> {
+    if !seconds.is_finite() || seconds <= 0.0 {
+        return Err(PyValueError::new_err("seconds must be greater than 0"));
+    }
+
+    let options = ReplayOptions {
+        device,
+        pid,
+        spec: sample_spec(rate, channels, format)?,
+        duration: std::time::Duration::from_secs_f64(seconds),
+    };
+
+    RecordingController::start_replay_buffer(&options)
+        .map(|buffer| ReplayBuffer {
+            inner: Some(buffer),
+        })
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to start replay buffer: {}", e)))
+}
+
+/// Build a playback source from exactly one of a WAV path and PCM bytes
+fn playback_source(
+    path: Option<std::path::PathBuf>,
+    data: Option<&[u8]>,
+    rate: u32,
+    channels: u8,
+    format: &str,
+) -> PyResult<PlaybackSource> {
+    match (path, data) {
+        (Some(path), None) => Ok(PlaybackSource::Wav(path)),
+        (None, Some(data)) => Ok(PlaybackSource::Pcm {
+            data: data.to_vec(),
+            spec: sample_spec(rate, channels, format)?,
+        }),
+        _ => Err(PyValueError::new_err(
+            "Exactly one of path and data must be given",
+        )),
+    }
+}
+
+/// Play a WAV file, or PCM bytes in the given format, on an output device.
+/// Returns once playback has started, or once it has finished if `wait` is true.
+#[pyfunction]
+#[pyo3(signature = (
+    path=None,
+    data=None,
+    device=None,
+    name="fa-control playback".to_string(),
+    role=None,
+    volume=None,
+    rate=48000,
+    channels=2,
+    format="s16le",
+    wait=false,
+))]
+#[allow(clippy::too_many_arguments)]
+pub fn play_audio(
+    py: Python,
+    path: Option<std::path::PathBuf>,
+    data: Option<&[u8]>,
+    device: Option<String>,
+    name: String,
+    role: Option<String>,
+    volume: Option<f64>,
+    rate: u32,
+    channels: u8,
+    format: &str,
+    wait: bool,
+) -> PyResult<Playback> {
+    if volume.is_some_and(|v| !(0.0..=1.0).contains(&v)) {
+        return Err(PyValueError::new_err("Volume must be between 0.0 and 1.0"));
+    }
+
+    let source = playback_source(path, data, rate, channels, format)?;
+    let options = PlaybackOptions {
+        device,
+        name,
+        role,
+        volume,
+    };
+
+    let mut playback = PlaybackController::play(source, &options)
+        .map(Playback::from)
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to play audio: {}", e)))?;
+    if wait {
+        playback.wait(py)?;
+    }
+    Ok(playback)
+}
+
+/// Get the channels of an output device (the default output device if None)
+#[pyfunction]
+#[pyo3(signature = (device=None))]
+pub fn get_device_channels(_py: Python, device: Option<&str>) -> PyResult<Vec<ChannelInfo>> {
+    SpeakerTestController::get_channels(device)
+        .map(|channels| channels.into_iter().map(ChannelInfo::from).collect())
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to get device channels: {}", e)))
+}
+
+/// Play a sine tone or pink noise on one channel (e.g. "front-left") or all
+/// channels of an output device
+#[pyfunction]
+#[pyo3(signature = (
+    device=None,
+    channel=None,
+    signal="sine",
+    frequency=440.0,
+    level=0.5,
+    duration=1.0,
+    wait=false,
+))]
+#[allow(clippy::too_many_arguments)]
+pub fn play_test_tone(
+    py: Python,
+    device: Option<String>,
+    channel: Option<String>,
+    signal: &str,
+    frequency: f64,
+    level: f64,
+    duration: f64,
+    wait: bool,
+) -> PyResult<Playback> {
+    let signal = match signal {
+        "sine" => TestSignal::Sine { frequency },
+        "pink" | "pink_noise" => TestSignal::PinkNoise,
+        _ => {
+            return Err(PyValueError::new_err(format!(
+                "Unknown signal '{}', expected 'sine' or 'pink'",
+                signal
+            )))
+        }
+    };
+    if !(0.0..=1.0).contains(&level) {
+        return Err(PyValueError::new_err("Level must be between 0.0 and 1.0"));
+    }
+    if !duration.is_finite() || duration <= 0.0 {
+        return Err(PyValueError::new_err("duration must be greater than 0"));
+    }
+
+    let options = TestToneOptions {
+        device,
+        signal,
+        channel,
+        level,
+        duration: std::time::Duration::from_secs_f64(duration),
+    };
+
+    let mut playback = SpeakerTestController::play_test_tone(&options)
+        .map(Playback::from)
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to play test tone: {}", e)))?;
+    if wait {
+        playback.wait(py)?;
+    }
+    Ok(playback)
+}
+
+/// Upload a WAV file, or PCM bytes in the given format, to the sample cache
+#[pyfunction]
+#[pyo3(signature = (name, path=None, data=None, rate=48000, channels=2, format="s16le"))]
+pub fn upload_sample(
+    _py: Python,
+    name: &str,
+    path: Option<std::path::PathBuf>,
+    data: Option<&[u8]>,
+    rate: u32,
+    channels: u8,
+    format: &str,
+) -> PyResult<()> {
+    let source = playback_source(path, data, rate, channels, format)?;
+
+    SampleCacheController::upload_sample(name, source)
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to upload sample: {}", e)))
+}
+
+/// Play a cached sample on an output device
+#[pyfunction]
+#[pyo3(signature = (name, device=None, volume=None))]
+pub fn play_sample(
+    _py: Python,
+    name: &str,
+    device: Option<&str>,
+    volume: Option<f64>,
+) -> PyResult<()> {
+    if volume.is_some_and(|v| !(0.0..=1.0).contains(&v)) {
+        return Err(PyValueError::new_err("Volume must be between 0.0 and 1.0"));
+    }
+
+    SampleCacheController::play_sample(name, device, volume)
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to play sample: {}", e)))
+}
+
+/// Get the samples in the sample cache
+#[pyfunction]
+pub fn get_samples(_py: Python) -> PyResult<Vec<CachedSample>> {
+    SampleCacheController::get_samples()
+        .map(|samples| samples.into_iter().map(CachedSample::from).collect())
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to get samples: {}", e)))
+}
+
+/// Remove a sample from the sample cache
+#[pyfunction]
+pub fn remove_sample(_py: Python, name: &str) -> PyResult<()> {
+    SampleCacheController::remove_sample(name)
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to remove sample: {}", e)))
+}
+
+/// Create a soundboard: a virtual microphone `<name>_mic` carrying the real
+/// microphone mixed with clips triggered by name
+#[pyfunction]
+#[pyo3(signature = (name="soundboard".to_string(), microphone=None, monitor_device=None, latency_ms=50))]
+pub fn create_soundboard(
+    _py: Python,
+    name: String,
+    microphone: Option<String>,
+    monitor_device: Option<String>,
+    latency_ms: u32,
+) -> PyResult<Soundboard> {
+    let options = SoundboardOptions {
+        name,
+        microphone,
+        monitor_device,
+        latency_ms,
+    };
+
+    SoundboardController::create_soundboard(&options)
+        .map(|soundboard| Soundboard {
+            source_name: soundboard.source_name().to_string(),
+            mix_sink_name: soundboard.mix_sink_name().to_string(),
+            inner: Some(soundboard),
+        })
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to create soundboard: {}", e)))
+}
+
+/// A built-in preset by name, or else a preset file
+fn eq_preset(preset: &str) -> PyResult<EqPreset> {
+    match EqPreset::builtin(preset) {
+        Some(preset) => Ok(preset),
+        None => EqPreset::load(std::path::Path::new(preset))
+            .map_err(|e| PyValueError::new_err(format!("Failed to load preset: {}", e))),
+    }
+}
+
+/// Create an equalizer: a virtual sink named `name` whose audio is filtered
+/// and played on `output` (the default output device if None). `preset` is a
+/// built-in preset name or the path of a preset file.
+#[pyfunction]
+#[pyo3(signature = (name="equalizer".to_string(), output=None, preset=None))]
+pub fn create_equalizer(
+    py: Python,
+    name: String,
+    output: Option<String>,
+    preset: Option<&str>,
+) -> PyResult<Equalizer> {
+    let mut options = EqualizerOptions {
+        name,
+        output,
+        ..Default::default()
+    };
+    if let Some(preset) = preset {
+        options.preset = eq_preset(preset)?;
+    }
+
+    py.allow_threads(|| EqualizerController::create_equalizer(&options))
+        .map(|equalizer| Equalizer {
+            sink_name: equalizer.sink_name().to_string(),
+            output_device: equalizer.output_device().to_string(),
+            inner: Some(equalizer),
+        })
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to create equalizer: {}", e)))
+}
+
+/// Names of the built-in equalizer presets
+#[pyfunction]
+pub fn get_equalizer_presets(_py: Python) -> Vec<String> {
+    EqPreset::builtin_names()
+        .iter()
+        .map(|name| name.to_string())
+        .collect()
+}
+
+/// Create a denoised copy of `microphone` (the default input device if None)
+/// as the virtual source `name`, optionally making it the default input
+#[pyfunction]
+#[pyo3(signature = (name="noise_suppressed".to_string(), microphone=None, strength=0.7, make_default=false))]
+pub fn create_noise_suppressor(
+    py: Python,
+    name: String,
+    microphone: Option<String>,
+    strength: f64,
+    make_default: bool,
+) -> PyResult<NoiseSuppressor> {
+    if !(0.0..=1.0).contains(&strength) {
+        return Err(PyValueError::new_err(
+            "Strength must be between 0.0 and 1.0",
+        ));
+    }
+    let options = NoiseSuppressionOptions {
+        name,
+        microphone,
+        strength,
+        make_default,
+    };
+
+    py.allow_threads(|| NoiseSuppressionController::create_noise_suppressor(&options))
+        .map(|suppressor| NoiseSuppressor {
+            source_name: suppressor.source_name().to_string(),
+            microphone: suppressor.microphone().to_string(),
+            inner: Some(suppressor),
+        })
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to create noise suppressor: {}", e)))
+}
+
+/// Get the sound server's name, version, defaults and default sample spec
+#[pyfunction]
+pub fn get_server_info(_py: Python) -> PyResult<ServerInfo> {
+    ServerController::get_server_info()
+        .map(ServerInfo::from)
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to get server info: {}", e)))
+}
+
 /// Get current platform
 #[pyfunction]
 pub fn get_platform(_py: Python) -> String {
@@ -173,6 +1230,7 @@ pub struct AppInfo {
     pub name: String,
     pub volume: f64,
     pub muted: bool,
+    process: Option<ProcessInfo>,
 }
 
 impl From<RustAppInfo> for AppInfo {
@@ -182,6 +1240,7 @@ impl From<RustAppInfo> for AppInfo {
             name: info.name,
             volume: info.volume,
             muted: info.muted,
+            process: info.process,
         }
     }
 }
@@ -195,6 +1254,7 @@ impl AppInfo {
             name,
             volume,
             muted,
+            process: None,
         }
     }
 
@@ -218,14 +1278,2038 @@ impl AppInfo {
         self.muted
     }
 
-    pub fn __repr__(&self) -> String {
-        format!(
-            "AppInfo(pid={}, name='{}', volume={:.2}, muted={})",
-            self.pid, self.name, self.volume, self.muted
-        )
+    /// Full path of the executable (Linux only)
+    #[getter]
+    pub fn exe(&self) -> Option<&str> {
+        self.process.as_ref()?.exe.as_deref()
     }
 
-    pub fn __str__(&self) -> String {
-        self.__repr__()
+    /// Executable name, e.g. "firefox" (Linux only)
+    #[getter]
+    pub fn binary(&self) -> Option<&str> {
+        self.process.as_ref()?.binary.as_deref()
+    }
+
+    /// Command line arguments, empty if unknown (Linux only)
+    #[getter]
+    pub fn cmdline(&self) -> Vec<String> {
+        self.process
+            .as_ref()
+            .map(|p| p.cmdline.clone())
+            .unwrap_or_default()
+    }
+
+    /// Name of the user owning the process (Linux only)
+    #[getter]
+    pub fn user(&self) -> Option<&str> {
+        self.process.as_ref()?.user.as_deref()
+    }
+
+    /// Parent process ID (Linux only)
+    #[getter]
+    pub fn ppid(&self) -> Option<u32> {
+        self.process.as_ref()?.ppid
+    }
+
+    /// Flatpak application ID, e.g. "org.telegram.desktop" (Linux only)
+    #[getter]
+    pub fn flatpak_id(&self) -> Option<&str> {
+        self.process.as_ref()?.flatpak_id.as_deref()
+    }
+
+    /// Systemd service or scope the process runs in (Linux only)
+    #[getter]
+    pub fn systemd_unit(&self) -> Option<&str> {
+        self.process.as_ref()?.systemd_unit.as_deref()
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "AppInfo(pid={}, name='{}', binary={:?}, volume={:.2}, muted={})",
+            self.pid,
+            self.name,
+            self.binary(),
+            self.volume,
+            self.muted
+        )
+    }
+
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+}
+
+/// Python representation of CardProfile
+#[pyclass]
+#[derive(Debug, Clone)]
+pub struct CardProfile {
+    pub name: String,
+    pub description: String,
+    pub priority: u32,
+    pub available: bool,
+}
+
+impl From<RustCardProfile> for CardProfile {
+    fn from(profile: RustCardProfile) -> Self {
+        CardProfile {
+            name: profile.name,
+            description: profile.description,
+            priority: profile.priority,
+            available: profile.available,
+        }
+    }
+}
+
+#[pymethods]
+impl CardProfile {
+    #[getter]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[getter]
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    #[getter]
+    pub fn priority(&self) -> u32 {
+        self.priority
+    }
+
+    #[getter]
+    pub fn available(&self) -> bool {
+        self.available
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "CardProfile(name='{}', description='{}', priority={}, available={})",
+            self.name, self.description, self.priority, self.available
+        )
+    }
+
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+}
+
+/// Python representation of CardInfo
+#[pyclass]
+#[derive(Debug, Clone)]
+pub struct CardInfo {
+    pub index: u32,
+    pub name: String,
+    pub description: String,
+    pub driver: String,
+    pub profiles: Vec<CardProfile>,
+    pub active_profile: Option<String>,
+}
+
+impl From<RustCardInfo> for CardInfo {
+    fn from(info: RustCardInfo) -> Self {
+        CardInfo {
+            index: info.index,
+            name: info.name,
+            description: info.description,
+            driver: info.driver,
+            profiles: info.profiles.into_iter().map(CardProfile::from).collect(),
+            active_profile: info.active_profile,
+        }
+    }
+}
+
+#[pymethods]
+impl CardInfo {
+    #[getter]
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    #[getter]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[getter]
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    #[getter]
+    pub fn driver(&self) -> &str {
+        &self.driver
+    }
+
+    #[getter]
+    pub fn profiles(&self) -> Vec<CardProfile> {
+        self.profiles.clone()
+    }
+
+    #[getter]
+    pub fn active_profile(&self) -> Option<&str> {
+        self.active_profile.as_deref()
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "CardInfo(index={}, name='{}', active_profile={:?}, profiles={})",
+            self.index,
+            self.name,
+            self.active_profile,
+            self.profiles.len()
+        )
+    }
+
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+}
+
+/// Python representation of PortInfo
+#[pyclass]
+#[derive(Debug, Clone)]
+pub struct PortInfo {
+    pub name: String,
+    pub description: String,
+    pub priority: u32,
+    pub available: Option<bool>,
+    pub active: bool,
+}
+
+impl From<RustPortInfo> for PortInfo {
+    fn from(port: RustPortInfo) -> Self {
+        PortInfo {
+            name: port.name,
+            description: port.description,
+            priority: port.priority,
+            available: port.available,
+            active: port.active,
+        }
+    }
+}
+
+#[pymethods]
+impl PortInfo {
+    #[getter]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[getter]
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    #[getter]
+    pub fn priority(&self) -> u32 {
+        self.priority
+    }
+
+    #[getter]
+    pub fn available(&self) -> Option<bool> {
+        self.available
+    }
+
+    #[getter]
+    pub fn active(&self) -> bool {
+        self.active
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "PortInfo(name='{}', description='{}', available={:?}, active={})",
+            self.name, self.description, self.available, self.active
+        )
+    }
+
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+}
+
+/// Python representation of DeviceInfo
+#[pyclass]
+#[derive(Debug, Clone)]
+pub struct DeviceInfo {
+    pub index: u32,
+    pub name: String,
+    pub description: String,
+    pub ports: Vec<PortInfo>,
+    pub active_port: Option<String>,
+    pub plugged: Option<bool>,
+    pub monitor_source: Option<String>,
+    pub channels: Vec<ChannelInfo>,
+    pub state: String,
+}
+
+impl From<RustDeviceInfo> for DeviceInfo {
+    fn from(info: RustDeviceInfo) -> Self {
+        DeviceInfo {
+            index: info.index,
+            name: info.name,
+            description: info.description,
+            ports: info.ports.into_iter().map(PortInfo::from).collect(),
+            active_port: info.active_port,
+            plugged: info.plugged,
+            monitor_source: info.monitor_source,
+            channels: info.channels.into_iter().map(ChannelInfo::from).collect(),
+            state: info.state.as_str().to_string(),
+        }
+    }
+}
+
+#[pymethods]
+impl DeviceInfo {
+    #[getter]
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    #[getter]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[getter]
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    #[getter]
+    pub fn ports(&self) -> Vec<PortInfo> {
+        self.ports.clone()
+    }
+
+    #[getter]
+    pub fn active_port(&self) -> Option<&str> {
+        self.active_port.as_deref()
+    }
+
+    #[getter]
+    pub fn plugged(&self) -> Option<bool> {
+        self.plugged
+    }
+
+    #[getter]
+    pub fn monitor_source(&self) -> Option<&str> {
+        self.monitor_source.as_deref()
+    }
+
+    #[getter]
+    pub fn channels(&self) -> Vec<ChannelInfo> {
+        self.channels.clone()
+    }
+
+    /// "running", "idle", "suspended" or "unknown"
+    #[getter]
+    pub fn state(&self) -> &str {
+        &self.state
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "DeviceInfo(index={}, name='{}', active_port={:?}, plugged={:?}, state='{}')",
+            self.index, self.name, self.active_port, self.plugged, self.state
+        )
+    }
+
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+}
+
+/// Python representation of ChannelInfo
+#[pyclass]
+#[derive(Debug, Clone)]
+pub struct ChannelInfo {
+    pub name: String,
+    pub label: String,
+}
+
+impl From<RustChannelInfo> for ChannelInfo {
+    fn from(channel: RustChannelInfo) -> Self {
+        ChannelInfo {
+            name: channel.name,
+            label: channel.label,
+        }
+    }
+}
+
+#[pymethods]
+impl ChannelInfo {
+    #[getter]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[getter]
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!("ChannelInfo(name='{}', label='{}')", self.name, self.label)
+    }
+
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+}
+
+/// Python handle to a virtual device; the device is removed on `destroy()`,
+/// when leaving a `with` block, or when the handle is garbage collected
+#[pyclass]
+pub struct VirtualDevice {
+    name: String,
+    module_index: u32,
+    inner: Option<RustVirtualDevice>,
+}
+
+impl From<RustVirtualDevice> for VirtualDevice {
+    fn from(device: RustVirtualDevice) -> Self {
+        VirtualDevice {
+            name: device.name().to_string(),
+            module_index: device.module_index(),
+            inner: Some(device),
+        }
+    }
+}
+
+#[pymethods]
+impl VirtualDevice {
+    #[getter]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[getter]
+    pub fn module_index(&self) -> u32 {
+        self.module_index
+    }
+
+    #[getter]
+    pub fn active(&self) -> bool {
+        self.inner.is_some()
+    }
+
+    pub fn destroy(&mut self) -> PyResult<()> {
+        match self.inner.take() {
+            Some(device) => device.destroy().map_err(|e| {
+                PyRuntimeError::new_err(format!("Failed to destroy virtual device: {}", e))
+            }),
+            None => Ok(()),
+        }
+    }
+
+    pub fn __enter__(slf: Py<Self>) -> Py<Self> {
+        slf
+    }
+
+    pub fn __exit__(
+        &mut self,
+        _exc_type: PyObject,
+        _exc_value: PyObject,
+        _traceback: PyObject,
+    ) -> PyResult<bool> {
+        self.destroy()?;
+        Ok(false)
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "VirtualDevice(name='{}', module_index={}, active={})",
+            self.name,
+            self.module_index,
+            self.inner.is_some()
+        )
+    }
+
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+}
+
+/// Python handle to a loopback; the loopback is removed on `destroy()`, when
+/// leaving a `with` block, or when the handle is garbage collected
+#[pyclass]
+pub struct Loopback {
+    source: String,
+    sink: String,
+    module_index: u32,
+    inner: Option<RustLoopback>,
+}
+
+impl From<RustLoopback> for Loopback {
+    fn from(loopback: RustLoopback) -> Self {
+        Loopback {
+            source: loopback.source().to_string(),
+            sink: loopback.sink().to_string(),
+            module_index: loopback.module_index(),
+            inner: Some(loopback),
+        }
+    }
+}
+
+impl Loopback {
+    fn inner(&self) -> PyResult<&RustLoopback> {
+        self.inner
+            .as_ref()
+            .ok_or_else(|| PyRuntimeError::new_err("Loopback has been destroyed"))
+    }
+}
+
+#[pymethods]
+impl Loopback {
+    #[getter]
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    #[getter]
+    pub fn sink(&self) -> &str {
+        &self.sink
+    }
+
+    #[getter]
+    pub fn module_index(&self) -> u32 {
+        self.module_index
+    }
+
+    #[getter]
+    pub fn active(&self) -> bool {
+        self.inner.is_some()
+    }
+
+    pub fn get_volume(&self) -> PyResult<f64> {
+        self.inner()?
+            .get_volume()
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to get loopback volume: {}", e)))
+    }
+
+    pub fn set_volume(&self, volume: f64) -> PyResult<()> {
+        if !(0.0..=1.0).contains(&volume) {
+            return Err(PyValueError::new_err("Volume must be between 0.0 and 1.0"));
+        }
+        self.inner()?
+            .set_volume(volume)
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to set loopback volume: {}", e)))
+    }
+
+    pub fn is_muted(&self) -> PyResult<bool> {
+        self.inner()?.is_muted().map_err(|e| {
+            PyRuntimeError::new_err(format!("Failed to get loopback mute state: {}", e))
+        })
+    }
+
+    pub fn set_mute(&self, muted: bool) -> PyResult<()> {
+        self.inner()?
+            .set_mute(muted)
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to set loopback mute: {}", e)))
+    }
+
+    pub fn destroy(&mut self) -> PyResult<()> {
+        match self.inner.take() {
+            Some(loopback) => loopback
+                .destroy()
+                .map_err(|e| PyRuntimeError::new_err(format!("Failed to destroy loopback: {}", e))),
+            None => Ok(()),
+        }
+    }
+
+    pub fn __enter__(slf: Py<Self>) -> Py<Self> {
+        slf
+    }
+
+    pub fn __exit__(
+        &mut self,
+        _exc_type: PyObject,
+        _exc_value: PyObject,
+        _traceback: PyObject,
+    ) -> PyResult<bool> {
+        self.destroy()?;
+        Ok(false)
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "Loopback(source='{}', sink='{}', module_index={}, active={})",
+            self.source,
+            self.sink,
+            self.module_index,
+            self.inner.is_some()
+        )
+    }
+
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+}
+
+/// Python handle to an echo-cancelled source/sink pair; both devices are
+/// removed on `destroy()`, when leaving a `with` block, or when the handle is
+/// garbage collected
+#[pyclass]
+pub struct EchoCancel {
+    source_name: String,
+    sink_name: String,
+    module_index: u32,
+    inner: Option<RustEchoCancel>,
+}
+
+impl From<RustEchoCancel> for EchoCancel {
+    fn from(echo_cancel: RustEchoCancel) -> Self {
+        EchoCancel {
+            source_name: echo_cancel.source_name().to_string(),
+            sink_name: echo_cancel.sink_name().to_string(),
+            module_index: echo_cancel.module_index(),
+            inner: Some(echo_cancel),
+        }
+    }
+}
+
+#[pymethods]
+impl EchoCancel {
+    #[getter]
+    pub fn source_name(&self) -> &str {
+        &self.source_name
+    }
+
+    #[getter]
+    pub fn sink_name(&self) -> &str {
+        &self.sink_name
+    }
+
+    #[getter]
+    pub fn module_index(&self) -> u32 {
+        self.module_index
+    }
+
+    #[getter]
+    pub fn active(&self) -> bool {
+        self.inner.is_some()
+    }
+
+    pub fn destroy(&mut self) -> PyResult<()> {
+        match self.inner.take() {
+            Some(echo_cancel) => echo_cancel.destroy().map_err(|e| {
+                PyRuntimeError::new_err(format!("Failed to destroy echo cancel: {}", e))
+            }),
+            None => Ok(()),
+        }
+    }
+
+    pub fn __enter__(slf: Py<Self>) -> Py<Self> {
+        slf
+    }
+
+    pub fn __exit__(
+        &mut self,
+        _exc_type: PyObject,
+        _exc_value: PyObject,
+        _traceback: PyObject,
+    ) -> PyResult<bool> {
+        self.destroy()?;
+        Ok(false)
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "EchoCancel(source_name='{}', sink_name='{}', module_index={}, active={})",
+            self.source_name,
+            self.sink_name,
+            self.module_index,
+            self.inner.is_some()
+        )
+    }
+
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+}
+
+/// Python handle to a running recording; the recording stops on `stop()`,
+/// when leaving a `with` block, or when the handle is garbage collected
+#[pyclass]
+pub struct Recording {
+    inner: Option<RustRecording>,
+}
+
+impl From<RustRecording> for Recording {
+    fn from(recording: RustRecording) -> Self {
+        Recording {
+            inner: Some(recording),
+        }
+    }
+}
+
+#[pymethods]
+impl Recording {
+    /// Whether the recording has stopped, e.g. because max_duration was reached
+    #[getter]
+    pub fn finished(&self) -> bool {
+        self.inner.as_ref().is_none_or(|r| r.is_finished())
+    }
+
+    /// Stop recording and finish the output
+    pub fn stop(&mut self, py: Python) -> PyResult<()> {
+        match self.inner.take() {
+            // The recording thread may need the GIL to run a callback
+            Some(recording) => py
+                .allow_threads(move || recording.stop())
+                .map_err(|e| PyRuntimeError::new_err(format!("Failed to record: {}", e))),
+            None => Ok(()),
+        }
+    }
+
+    /// Block until the recording reaches max_duration
+    pub fn wait(&mut self, py: Python) -> PyResult<()> {
+        match self.inner.take() {
+            Some(recording) => py
+                .allow_threads(move || recording.wait())
+                .map_err(|e| PyRuntimeError::new_err(format!("Failed to record: {}", e))),
+            None => Ok(()),
+        }
+    }
+
+    pub fn __enter__(slf: Py<Self>) -> Py<Self> {
+        slf
+    }
+
+    pub fn __exit__(
+        &mut self,
+        py: Python,
+        _exc_type: PyObject,
+        _exc_value: PyObject,
+        _traceback: PyObject,
+    ) -> PyResult<bool> {
+        self.stop(py)?;
+        Ok(false)
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!("Recording(finished={})", self.finished())
+    }
+
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+}
+
+impl Drop for Recording {
+    fn drop(&mut self) {
+        if let Some(recording) = self.inner.take() {
+            Python::with_gil(|py| py.allow_threads(move || drop(recording)));
+        }
+    }
+}
+
+/// Python handle to an instant replay buffer; capture stops on `stop()`,
+/// when leaving a `with` block, or when the handle is garbage collected
+#[pyclass]
+pub struct ReplayBuffer {
+    inner: Option<RustReplayBuffer>,
+}
+
+impl ReplayBuffer {
+    fn buffer(&self) -> PyResult<&RustReplayBuffer> {
+        self.inner
+            .as_ref()
+            .ok_or_else(|| PyRuntimeError::new_err("Replay buffer has been stopped"))
+    }
+}
+
+#[pymethods]
+impl ReplayBuffer {
+    /// Seconds of audio the buffer can hold
+    #[getter]
+    pub fn capacity_seconds(&self) -> f64 {
+        self.inner
+            .as_ref()
+            .map_or(0.0, |b| b.capacity().as_secs_f64())
+    }
+
+    /// Seconds of audio currently buffered
+    #[getter]
+    pub fn buffered_seconds(&self) -> f64 {
+        self.inner
+            .as_ref()
+            .map_or(0.0, |b| b.buffered().as_secs_f64())
+    }
+
+    #[getter]
+    pub fn running(&self) -> bool {
+        self.inner.as_ref().is_some_and(|b| b.is_running())
+    }
+
+    /// Write the last `seconds` of audio (everything buffered if None) to a
+    /// WAV file, returning the number of seconds written
+    #[pyo3(signature = (path, seconds=None))]
+    pub fn save(&self, path: std::path::PathBuf, seconds: Option<f64>) -> PyResult<f64> {
+        if seconds.is_some_and(|s| !s.is_finite() || s < 0.0) {
+            return Err(PyValueError::new_err("seconds must not be negative"));
+        }
+
+        self.buffer()?
+            .save(&path, seconds.map(std::time::Duration::from_secs_f64))
+            .map(|written| written.as_secs_f64())
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to save replay: {}", e)))
+    }
+
+    /// Drop everything buffered so far
+    pub fn clear(&self) -> PyResult<()> {
+        self.buffer()?
+            .clear()
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to clear replay buffer: {}", e)))
+    }
+
+    /// Stop capturing, discarding the buffer
+    pub fn stop(&mut self, py: Python) -> PyResult<()> {
+        match self.inner.take() {
+            Some(buffer) => py
+                .allow_threads(move || buffer.stop())
+                .map_err(|e| PyRuntimeError::new_err(format!("Failed to capture: {}", e))),
+            None => Ok(()),
+        }
+    }
+
+    pub fn __enter__(slf: Py<Self>) -> Py<Self> {
+        slf
+    }
+
+    pub fn __exit__(
+        &mut self,
+        py: Python,
+        _exc_type: PyObject,
+        _exc_value: PyObject,
+        _traceback: PyObject,
+    ) -> PyResult<bool> {
+        self.stop(py)?;
+        Ok(false)
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "ReplayBuffer(buffered_seconds={:.2}, capacity_seconds={:.2}, running={})",
+            self.buffered_seconds(),
+            self.capacity_seconds(),
+            self.running()
+        )
+    }
+
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+}
+
+/// Python handle to audio playing in the background; playback stops on
+/// `stop()`, when leaving a `with` block, or when the handle is garbage
+/// collected
+#[pyclass]
+pub struct Playback {
+    inner: Option<RustPlayback>,
+    sink_input_index: u32,
+}
+
+impl From<RustPlayback> for Playback {
+    fn from(playback: RustPlayback) -> Self {
+        Playback {
+            sink_input_index: playback.sink_input_index(),
+            inner: Some(playback),
+        }
+    }
+}
+
+#[pymethods]
+impl Playback {
+    /// Index of the playback stream on the server
+    #[getter]
+    pub fn sink_input_index(&self) -> u32 {
+        self.sink_input_index
+    }
+
+    /// Whether playback has completed or been stopped
+    #[getter]
+    pub fn finished(&self) -> bool {
+        self.inner.as_ref().is_none_or(|p| p.is_finished())
+    }
+
+    /// Block until everything has been played
+    pub fn wait(&mut self, py: Python) -> PyResult<()> {
+        match self.inner.take() {
+            Some(playback) => py
+                .allow_threads(move || playback.wait())
+                .map_err(|e| PyRuntimeError::new_err(format!("Failed to play audio: {}", e))),
+            None => Ok(()),
+        }
+    }
+
+    /// Stop playing immediately
+    pub fn stop(&mut self, py: Python) -> PyResult<()> {
+        match self.inner.take() {
+            Some(playback) => py
+                .allow_threads(move || playback.stop())
+                .map_err(|e| PyRuntimeError::new_err(format!("Failed to play audio: {}", e))),
+            None => Ok(()),
+        }
+    }
+
+    pub fn __enter__(slf: Py<Self>) -> Py<Self> {
+        slf
+    }
+
+    pub fn __exit__(
+        &mut self,
+        py: Python,
+        _exc_type: PyObject,
+        _exc_value: PyObject,
+        _traceback: PyObject,
+    ) -> PyResult<bool> {
+        self.stop(py)?;
+        Ok(false)
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "Playback(sink_input_index={}, finished={})",
+            self.sink_input_index,
+            self.finished()
+        )
+    }
+
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+}
+
+impl Drop for Playback {
+    fn drop(&mut self) {
+        if let Some(playback) = self.inner.take() {
+            Python::with_gil(|py| py.allow_threads(move || drop(playback)));
+        }
+    }
+}
+
+/// Python representation of CachedSample
+#[pyclass]
+#[derive(Debug, Clone)]
+pub struct CachedSample {
+    pub index: u32,
+    pub name: String,
+    pub duration: f64,
+    pub bytes: u32,
+    pub rate: u32,
+    pub channels: u8,
+    pub lazy: bool,
+    pub filename: Option<String>,
+}
+
+impl From<RustCachedSample> for CachedSample {
+    fn from(sample: RustCachedSample) -> Self {
+        CachedSample {
+            index: sample.index,
+            name: sample.name,
+            duration: sample.duration.as_secs_f64(),
+            bytes: sample.bytes,
+            rate: sample.rate,
+            channels: sample.channels,
+            lazy: sample.lazy,
+            filename: sample.filename,
+        }
+    }
+}
+
+#[pymethods]
+impl CachedSample {
+    #[getter]
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    #[getter]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Duration in seconds
+    #[getter]
+    pub fn duration(&self) -> f64 {
+        self.duration
+    }
+
+    #[getter]
+    pub fn bytes(&self) -> u32 {
+        self.bytes
+    }
+
+    #[getter]
+    pub fn rate(&self) -> u32 {
+        self.rate
+    }
+
+    #[getter]
+    pub fn channels(&self) -> u8 {
+        self.channels
+    }
+
+    #[getter]
+    pub fn lazy(&self) -> bool {
+        self.lazy
+    }
+
+    #[getter]
+    pub fn filename(&self) -> Option<&str> {
+        self.filename.as_deref()
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "CachedSample(name='{}', duration={:.2}, rate={}, channels={})",
+            self.name, self.duration, self.rate, self.channels
+        )
+    }
+
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+}
+
+/// Python handle to a soundboard; its devices are removed on `destroy()`,
+/// when leaving a `with` block, or when the handle is garbage collected
+#[pyclass]
+pub struct Soundboard {
+    source_name: String,
+    mix_sink_name: String,
+    inner: Option<RustSoundboard>,
+}
+
+impl Soundboard {
+    fn inner(&self) -> PyResult<&RustSoundboard> {
+        self.inner
+            .as_ref()
+            .ok_or_else(|| PyRuntimeError::new_err("Soundboard has been destroyed"))
+    }
+
+    fn inner_mut(&mut self) -> PyResult<&mut RustSoundboard> {
+        self.inner
+            .as_mut()
+            .ok_or_else(|| PyRuntimeError::new_err("Soundboard has been destroyed"))
+    }
+}
+
+#[pymethods]
+impl Soundboard {
+    /// Name of the virtual microphone to select in voice chat applications
+    #[getter]
+    pub fn source_name(&self) -> &str {
+        &self.source_name
+    }
+
+    /// Name of the sink mixing the microphone and clips
+    #[getter]
+    pub fn mix_sink_name(&self) -> &str {
+        &self.mix_sink_name
+    }
+
+    #[getter]
+    pub fn clips(&self) -> Vec<String> {
+        self.inner
+            .as_ref()
+            .map(|soundboard| soundboard.clip_names())
+            .unwrap_or_default()
+    }
+
+    #[getter]
+    pub fn active(&self) -> bool {
+        self.inner.is_some()
+    }
+
+    /// Load a clip from a WAV file or PCM bytes, replacing any clip with the
+    /// same name
+    #[pyo3(signature = (name, path=None, data=None, volume=1.0, rate=48000, channels=2, format="s16le"))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn add_clip(
+        &mut self,
+        name: &str,
+        path: Option<std::path::PathBuf>,
+        data: Option<&[u8]>,
+        volume: f64,
+        rate: u32,
+        channels: u8,
+        format: &str,
+    ) -> PyResult<()> {
+        if !(0.0..=1.0).contains(&volume) {
+            return Err(PyValueError::new_err("Volume must be between 0.0 and 1.0"));
+        }
+        let source = playback_source(path, data, rate, channels, format)?;
+
+        self.inner_mut()?
+            .add_clip(name, source, volume)
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to add clip: {}", e)))
+    }
+
+    pub fn remove_clip(&mut self, name: &str) -> PyResult<()> {
+        self.inner_mut()?
+            .remove_clip(name)
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to remove clip: {}", e)))
+    }
+
+    pub fn get_clip_volume(&self, name: &str) -> PyResult<f64> {
+        self.inner()?
+            .get_clip_volume(name)
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to get clip volume: {}", e)))
+    }
+
+    pub fn set_clip_volume(&mut self, name: &str, volume: f64) -> PyResult<()> {
+        if !(0.0..=1.0).contains(&volume) {
+            return Err(PyValueError::new_err("Volume must be between 0.0 and 1.0"));
+        }
+        self.inner_mut()?
+            .set_clip_volume(name, volume)
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to set clip volume: {}", e)))
+    }
+
+    /// Start playing a clip into the virtual microphone; clips can overlap
+    pub fn play_clip(&mut self, name: &str) -> PyResult<()> {
+        self.inner_mut()?
+            .play_clip(name)
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to play clip: {}", e)))
+    }
+
+    /// Stop every clip that is playing
+    pub fn stop_clips(&mut self, py: Python) -> PyResult<()> {
+        let soundboard = self.inner_mut()?;
+        py.allow_threads(|| soundboard.stop_clips())
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to stop clips: {}", e)))
+    }
+
+    pub fn get_microphone_volume(&self) -> PyResult<f64> {
+        self.inner()?
+            .get_microphone_volume()
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to get microphone volume: {}", e)))
+    }
+
+    pub fn set_microphone_volume(&self, volume: f64) -> PyResult<()> {
+        if !(0.0..=1.0).contains(&volume) {
+            return Err(PyValueError::new_err("Volume must be between 0.0 and 1.0"));
+        }
+        self.inner()?
+            .set_microphone_volume(volume)
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to set microphone volume: {}", e)))
+    }
+
+    pub fn is_microphone_muted(&self) -> PyResult<bool> {
+        self.inner()?.is_microphone_muted().map_err(|e| {
+            PyRuntimeError::new_err(format!("Failed to get microphone mute state: {}", e))
+        })
+    }
+
+    pub fn set_microphone_mute(&self, muted: bool) -> PyResult<()> {
+        self.inner()?
+            .set_microphone_mute(muted)
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to set microphone mute: {}", e)))
+    }
+
+    pub fn destroy(&mut self, py: Python) -> PyResult<()> {
+        match self.inner.take() {
+            Some(soundboard) => py.allow_threads(move || soundboard.destroy()).map_err(|e| {
+                PyRuntimeError::new_err(format!("Failed to destroy soundboard: {}", e))
+            }),
+            None => Ok(()),
+        }
+    }
+
+    pub fn __enter__(slf: Py<Self>) -> Py<Self> {
+        slf
+    }
+
+    pub fn __exit__(
+        &mut self,
+        py: Python,
+        _exc_type: PyObject,
+        _exc_value: PyObject,
+        _traceback: PyObject,
+    ) -> PyResult<bool> {
+        self.destroy(py)?;
+        Ok(false)
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "Soundboard(source_name='{}', clips={:?}, active={})",
+            self.source_name,
+            self.clips(),
+            self.active()
+        )
+    }
+
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+}
+
+impl Drop for Soundboard {
+    fn drop(&mut self) {
+        if let Some(soundboard) = self.inner.take() {
+            Python::with_gil(|py| py.allow_threads(move || drop(soundboard)));
+        }
+    }
+}
+
+/// Python representation of EqBand
+#[pyclass]
+#[derive(Debug, Clone)]
+pub struct EqBand {
+    inner: RustEqBand,
+}
+
+#[pymethods]
+impl EqBand {
+    /// `filter` is one of peaking, low_shelf, high_shelf, low_pass, high_pass
+    #[new]
+    #[pyo3(signature = (filter, frequency, gain_db=0.0, q=0.707))]
+    pub fn new(filter: &str, frequency: f64, gain_db: f64, q: f64) -> PyResult<Self> {
+        let filter: FilterType = filter.parse().map_err(PyValueError::new_err)?;
+        let inner = RustEqBand::new(filter, frequency, gain_db, q);
+        inner.validate().map_err(PyValueError::new_err)?;
+        Ok(EqBand { inner })
+    }
+
+    #[getter]
+    pub fn filter(&self) -> String {
+        self.inner.filter.to_string()
+    }
+
+    #[getter]
+    pub fn frequency(&self) -> f64 {
+        self.inner.frequency
+    }
+
+    #[getter]
+    pub fn gain_db(&self) -> f64 {
+        self.inner.gain_db
+    }
+
+    #[getter]
+    pub fn q(&self) -> f64 {
+        self.inner.q
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "EqBand(filter='{}', frequency={}, gain_db={}, q={})",
+            self.inner.filter, self.inner.frequency, self.inner.gain_db, self.inner.q
+        )
+    }
+
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+}
+
+/// Python handle to an equalizer; its sink is removed on `destroy()`, when
+/// leaving a `with` block, or when the handle is garbage collected
+#[pyclass]
+pub struct Equalizer {
+    sink_name: String,
+    output_device: String,
+    inner: Option<RustEqualizer>,
+}
+
+impl Equalizer {
+    fn inner(&self) -> PyResult<&RustEqualizer> {
+        self.inner
+            .as_ref()
+            .ok_or_else(|| PyRuntimeError::new_err("Equalizer has been destroyed"))
+    }
+
+    fn preset(&self) -> PyResult<EqPreset> {
+        self.inner()?
+            .preset()
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to get preset: {}", e)))
+    }
+}
+
+#[pymethods]
+impl Equalizer {
+    /// Name of the virtual sink to route applications to
+    #[getter]
+    pub fn sink_name(&self) -> &str {
+        &self.sink_name
+    }
+
+    /// Device the equalized audio is played on
+    #[getter]
+    pub fn output_device(&self) -> &str {
+        &self.output_device
+    }
+
+    #[getter]
+    pub fn active(&self) -> bool {
+        self.inner.is_some()
+    }
+
+    /// Name of the preset last loaded
+    #[getter]
+    pub fn preset_name(&self) -> PyResult<String> {
+        Ok(self.preset()?.name)
+    }
+
+    #[getter]
+    pub fn preamp_db(&self) -> PyResult<f64> {
+        Ok(self.preset()?.preamp_db)
+    }
+
+    #[getter]
+    pub fn bands(&self) -> PyResult<Vec<EqBand>> {
+        Ok(self
+            .preset()?
+            .bands
+            .into_iter()
+            .map(|inner| EqBand { inner })
+            .collect())
+    }
+
+    /// Replace all bands; takes effect immediately
+    pub fn set_bands(&self, bands: Vec<EqBand>) -> PyResult<()> {
+        self.inner()?
+            .set_bands(bands.into_iter().map(|band| band.inner).collect())
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to set bands: {}", e)))
+    }
+
+    pub fn set_band(&self, index: usize, band: EqBand) -> PyResult<()> {
+        self.inner()?
+            .set_band(index, band.inner)
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to set band: {}", e)))
+    }
+
+    pub fn set_preamp(&self, preamp_db: f64) -> PyResult<()> {
+        if !(-30.0..=30.0).contains(&preamp_db) {
+            return Err(PyValueError::new_err(
+                "Preamp must be between -30 and 30 dB",
+            ));
+        }
+        self.inner()?
+            .set_preamp(preamp_db)
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to set preamp: {}", e)))
+    }
+
+    /// Load a built-in preset by name, or a preset file
+    pub fn load_preset(&self, preset: &str) -> PyResult<()> {
+        let preset = eq_preset(preset)?;
+        self.inner()?
+            .apply_preset(&preset)
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to load preset: {}", e)))
+    }
+
+    /// Save the current settings to a preset file, named after the file if
+    /// `name` is None
+    #[pyo3(signature = (path, name=None))]
+    pub fn save_preset(&self, path: std::path::PathBuf, name: Option<String>) -> PyResult<()> {
+        let name = name.unwrap_or_else(|| {
+            path.file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+                .unwrap_or_default()
+        });
+        self.inner()?
+            .save_preset(&path, &name)
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to save preset: {}", e)))
+    }
+
+    pub fn destroy(&mut self, py: Python) -> PyResult<()> {
+        match self.inner.take() {
+            Some(equalizer) => py.allow_threads(move || equalizer.destroy()).map_err(|e| {
+                PyRuntimeError::new_err(format!("Failed to destroy equalizer: {}", e))
+            }),
+            None => Ok(()),
+        }
+    }
+
+    pub fn __enter__(slf: Py<Self>) -> Py<Self> {
+        slf
+    }
+
+    pub fn __exit__(
+        &mut self,
+        py: Python,
+        _exc_type: PyObject,
+        _exc_value: PyObject,
+        _traceback: PyObject,
+    ) -> PyResult<bool> {
+        self.destroy(py)?;
+        Ok(false)
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "Equalizer(sink_name='{}', output_device='{}', active={})",
+            self.sink_name,
+            self.output_device,
+            self.active()
+        )
+    }
+
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+}
+
+impl Drop for Equalizer {
+    fn drop(&mut self) {
+        if let Some(equalizer) = self.inner.take() {
+            Python::with_gil(|py| py.allow_threads(move || drop(equalizer)));
+        }
+    }
+}
+
+/// Python handle to a noise suppressor; its devices are removed on
+/// `destroy()`, when leaving a `with` block, or when the handle is garbage
+/// collected
+#[pyclass]
+pub struct NoiseSuppressor {
+    source_name: String,
+    microphone: String,
+    inner: Option<RustNoiseSuppressor>,
+}
+
+impl NoiseSuppressor {
+    fn inner(&self) -> PyResult<&RustNoiseSuppressor> {
+        self.inner
+            .as_ref()
+            .ok_or_else(|| PyRuntimeError::new_err("Noise suppressor has been destroyed"))
+    }
+}
+
+#[pymethods]
+impl NoiseSuppressor {
+    /// Name of the denoised source to record from
+    #[getter]
+    pub fn source_name(&self) -> &str {
+        &self.source_name
+    }
+
+    /// The real microphone being denoised
+    #[getter]
+    pub fn microphone(&self) -> &str {
+        &self.microphone
+    }
+
+    #[getter]
+    pub fn active(&self) -> bool {
+        self.inner.is_some()
+    }
+
+    #[getter]
+    pub fn enabled(&self) -> bool {
+        self.inner
+            .as_ref()
+            .is_some_and(|suppressor| suppressor.is_enabled())
+    }
+
+    #[getter]
+    pub fn strength(&self) -> PyResult<f64> {
+        Ok(self.inner()?.strength())
+    }
+
+    /// Turn suppression on or off; when off the microphone passes through
+    pub fn set_enabled(&self, enabled: bool) -> PyResult<()> {
+        self.inner()?.set_enabled(enabled);
+        Ok(())
+    }
+
+    pub fn set_strength(&self, strength: f64) -> PyResult<()> {
+        if !(0.0..=1.0).contains(&strength) {
+            return Err(PyValueError::new_err(
+                "Strength must be between 0.0 and 1.0",
+            ));
+        }
+        self.inner()?
+            .set_strength(strength)
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to set strength: {}", e)))
+    }
+
+    pub fn get_volume(&self) -> PyResult<f64> {
+        self.inner()?
+            .get_volume()
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to get volume: {}", e)))
+    }
+
+    pub fn set_volume(&self, volume: f64) -> PyResult<()> {
+        if !(0.0..=1.0).contains(&volume) {
+            return Err(PyValueError::new_err("Volume must be between 0.0 and 1.0"));
+        }
+        self.inner()?
+            .set_volume(volume)
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to set volume: {}", e)))
+    }
+
+    pub fn is_muted(&self) -> PyResult<bool> {
+        self.inner()?
+            .is_muted()
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to get mute state: {}", e)))
+    }
+
+    pub fn set_mute(&self, muted: bool) -> PyResult<()> {
+        self.inner()?
+            .set_mute(muted)
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to set mute: {}", e)))
+    }
+
+    pub fn destroy(&mut self, py: Python) -> PyResult<()> {
+        match self.inner.take() {
+            Some(suppressor) => py.allow_threads(move || suppressor.destroy()).map_err(|e| {
+                PyRuntimeError::new_err(format!("Failed to destroy noise suppressor: {}", e))
+            }),
+            None => Ok(()),
+        }
+    }
+
+    pub fn __enter__(slf: Py<Self>) -> Py<Self> {
+        slf
+    }
+
+    pub fn __exit__(
+        &mut self,
+        py: Python,
+        _exc_type: PyObject,
+        _exc_value: PyObject,
+        _traceback: PyObject,
+    ) -> PyResult<bool> {
+        self.destroy(py)?;
+        Ok(false)
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "NoiseSuppressor(source_name='{}', microphone='{}', enabled={}, active={})",
+            self.source_name,
+            self.microphone,
+            self.enabled(),
+            self.active()
+        )
+    }
+
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+}
+
+impl Drop for NoiseSuppressor {
+    fn drop(&mut self) {
+        if let Some(suppressor) = self.inner.take() {
+            Python::with_gil(|py| py.allow_threads(move || drop(suppressor)));
+        }
+    }
+}
+
+/// Python representation of ServerInfo
+#[pyclass]
+#[derive(Debug, Clone)]
+pub struct ServerInfo {
+    inner: RustServerInfo,
+}
+
+impl From<RustServerInfo> for ServerInfo {
+    fn from(inner: RustServerInfo) -> Self {
+        ServerInfo { inner }
+    }
+}
+
+#[pymethods]
+impl ServerInfo {
+    #[getter]
+    pub fn name(&self) -> &str {
+        &self.inner.name
+    }
+
+    #[getter]
+    pub fn version(&self) -> &str {
+        &self.inner.version
+    }
+
+    #[getter]
+    pub fn host(&self) -> &str {
+        &self.inner.host
+    }
+
+    #[getter]
+    pub fn user(&self) -> &str {
+        &self.inner.user
+    }
+
+    #[getter]
+    pub fn default_sink(&self) -> Option<&str> {
+        self.inner.default_sink.as_deref()
+    }
+
+    #[getter]
+    pub fn default_source(&self) -> Option<&str> {
+        self.inner.default_source.as_deref()
+    }
+
+    #[getter]
+    pub fn sample_format(&self) -> &str {
+        &self.inner.sample_format
+    }
+
+    #[getter]
+    pub fn sample_rate(&self) -> u32 {
+        self.inner.sample_rate
+    }
+
+    #[getter]
+    pub fn channels(&self) -> u8 {
+        self.inner.channels
+    }
+
+    #[getter]
+    pub fn channel_map(&self) -> Vec<ChannelInfo> {
+        self.inner
+            .channel_map
+            .iter()
+            .cloned()
+            .map(ChannelInfo::from)
+            .collect()
+    }
+
+    /// Whether the server is PipeWire's PulseAudio compatibility layer
+    #[getter]
+    pub fn is_pipewire(&self) -> bool {
+        self.inner.is_pipewire()
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "ServerInfo(name='{}', version='{}', default_sink={:?}, default_source={:?}, \
+             sample_spec='{} {}ch {}Hz')",
+            self.inner.name,
+            self.inner.version,
+            self.inner.default_sink,
+            self.inner.default_source,
+            self.inner.sample_format,
+            self.inner.channels,
+            self.inner.sample_rate
+        )
+    }
+
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+}
+
+/// Python representation of ClientInfo
+#[pyclass]
+#[derive(Debug, Clone)]
+pub struct ClientInfo {
+    inner: RustClientInfo,
+}
+
+impl From<RustClientInfo> for ClientInfo {
+    fn from(inner: RustClientInfo) -> Self {
+        ClientInfo { inner }
+    }
+}
+
+#[pymethods]
+impl ClientInfo {
+    #[getter]
+    pub fn index(&self) -> u32 {
+        self.inner.index
+    }
+
+    #[getter]
+    pub fn name(&self) -> &str {
+        &self.inner.name
+    }
+
+    #[getter]
+    pub fn driver(&self) -> &str {
+        &self.inner.driver
+    }
+
+    #[getter]
+    pub fn pid(&self) -> Option<u32> {
+        self.inner.pid
+    }
+
+    #[getter]
+    pub fn properties(&self) -> std::collections::BTreeMap<String, String> {
+        self.inner.properties.clone()
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "ClientInfo(index={}, name='{}', driver='{}', pid={:?})",
+            self.inner.index, self.inner.name, self.inner.driver, self.inner.pid
+        )
+    }
+
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+}
+
+/// Python representation of StreamInfo
+#[pyclass]
+#[derive(Debug, Clone)]
+pub struct StreamInfo {
+    inner: RustStreamInfo,
+}
+
+impl From<RustStreamInfo> for StreamInfo {
+    fn from(inner: RustStreamInfo) -> Self {
+        StreamInfo { inner }
+    }
+}
+
+#[pymethods]
+impl StreamInfo {
+    #[getter]
+    pub fn index(&self) -> u32 {
+        self.inner.index
+    }
+
+    /// "sink_input" for playback or "source_output" for recording
+    #[getter]
+    pub fn kind(&self) -> &str {
+        self.inner.kind.as_str()
+    }
+
+    #[getter]
+    pub fn name(&self) -> &str {
+        &self.inner.name
+    }
+
+    #[getter]
+    pub fn client(&self) -> Option<u32> {
+        self.inner.client
+    }
+
+    #[getter]
+    pub fn pid(&self) -> Option<u32> {
+        self.inner.pid
+    }
+
+    /// Index of the sink or source the stream is connected to
+    #[getter]
+    pub fn device(&self) -> u32 {
+        self.inner.device
+    }
+
+    #[getter]
+    pub fn properties(&self) -> std::collections::BTreeMap<String, String> {
+        self.inner.properties.clone()
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "StreamInfo(index={}, kind='{}', name='{}', client={:?}, pid={:?})",
+            self.inner.index,
+            self.inner.kind.as_str(),
+            self.inner.name,
+            self.inner.client,
+            self.inner.pid
+        )
+    }
+
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+}
+
+/// Python representation of ModuleInfo
+#[pyclass]
+#[derive(Debug, Clone)]
+pub struct ModuleInfo {
+    inner: RustModuleInfo,
+}
+
+impl From<RustModuleInfo> for ModuleInfo {
+    fn from(inner: RustModuleInfo) -> Self {
+        ModuleInfo { inner }
+    }
+}
+
+#[pymethods]
+impl ModuleInfo {
+    #[getter]
+    pub fn index(&self) -> u32 {
+        self.inner.index
+    }
+
+    #[getter]
+    pub fn name(&self) -> &str {
+        &self.inner.name
+    }
+
+    #[getter]
+    pub fn argument(&self) -> &str {
+        &self.inner.argument
+    }
+
+    /// How many clients or devices use the module, or None if unknown
+    #[getter]
+    pub fn usage(&self) -> Option<u32> {
+        self.inner.usage
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "ModuleInfo(index={}, name='{}', argument='{}', usage={:?})",
+            self.inner.index, self.inner.name, self.inner.argument, self.inner.usage
+        )
+    }
+
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+}
+
+/// Python representation of RestoreEntry
+#[pyclass]
+#[derive(Debug, Clone)]
+pub struct RestoreEntry {
+    inner: RustRestoreEntry,
+}
+
+impl From<RustRestoreEntry> for RestoreEntry {
+    fn from(inner: RustRestoreEntry) -> Self {
+        RestoreEntry { inner }
+    }
+}
+
+#[pymethods]
+impl RestoreEntry {
+    #[getter]
+    pub fn name(&self) -> &str {
+        &self.inner.name
+    }
+
+    /// Volume from 0.0 to 1.0, or None if the entry doesn't set one
+    #[getter]
+    pub fn volume(&self) -> Option<f64> {
+        self.inner.volume.map(|v| v as f64)
+    }
+
+    #[getter]
+    pub fn mute(&self) -> bool {
+        self.inner.mute
+    }
+
+    #[getter]
+    pub fn device(&self) -> Option<&str> {
+        self.inner.device.as_deref()
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "RestoreEntry(name='{}', volume={:?}, mute={}, device={:?})",
+            self.inner.name, self.inner.volume, self.inner.mute, self.inner.device
+        )
+    }
+
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+}
+
+/// Python handle to a preferred-device watcher; it stops on `stop()`, when
+/// leaving a `with` block, or when the handle is garbage collected
+#[pyclass]
+pub struct PreferredDevices {
+    inner: Option<RustPreferredDevices>,
+}
+
+impl PreferredDevices {
+    fn watcher(&self) -> PyResult<&RustPreferredDevices> {
+        self.inner
+            .as_ref()
+            .ok_or_else(|| PyRuntimeError::new_err("Preferred device watcher has been stopped"))
+    }
+}
+
+#[pymethods]
+impl PreferredDevices {
+    /// Output devices, highest priority first
+    #[getter]
+    pub fn outputs(&self) -> PyResult<Vec<String>> {
+        self.watcher()?
+            .outputs()
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to get outputs: {}", e)))
+    }
+
+    /// Input devices, highest priority first
+    #[getter]
+    pub fn inputs(&self) -> PyResult<Vec<String>> {
+        self.watcher()?
+            .inputs()
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to get inputs: {}", e)))
+    }
+
+    #[getter]
+    pub fn running(&self) -> bool {
+        self.inner.as_ref().is_some_and(|w| w.is_running())
+    }
+
+    /// Replace the output list and switch to its best device now
+    pub fn set_outputs(&self, py: Python, outputs: Vec<String>) -> PyResult<()> {
+        let watcher = self.watcher()?;
+        py.allow_threads(|| watcher.set_outputs(outputs))
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to set outputs: {}", e)))
+    }
+
+    /// Replace the input list and switch to its best device now
+    pub fn set_inputs(&self, py: Python, inputs: Vec<String>) -> PyResult<()> {
+        let watcher = self.watcher()?;
+        py.allow_threads(|| watcher.set_inputs(inputs))
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to set inputs: {}", e)))
+    }
+
+    /// Stop watching; the current defaults are left as they are
+    pub fn stop(&mut self, py: Python) -> PyResult<()> {
+        match self.inner.take() {
+            Some(watcher) => py
+                .allow_threads(move || watcher.stop())
+                .map_err(|e| PyRuntimeError::new_err(format!("Failed to watch devices: {}", e))),
+            None => Ok(()),
+        }
+    }
+
+    pub fn __enter__(slf: Py<Self>) -> Py<Self> {
+        slf
+    }
+
+    pub fn __exit__(
+        &mut self,
+        py: Python,
+        _exc_type: PyObject,
+        _exc_value: PyObject,
+        _traceback: PyObject,
+    ) -> PyResult<bool> {
+        self.stop(py)?;
+        Ok(false)
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "PreferredDevices(outputs={:?}, inputs={:?}, running={})",
+            self.outputs().unwrap_or_default(),
+            self.inputs().unwrap_or_default(),
+            self.running()
+        )
+    }
+
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+}
+
+impl Drop for PreferredDevices {
+    fn drop(&mut self) {
+        if let Some(watcher) = self.inner.take() {
+            Python::with_gil(|py| py.allow_threads(move || drop(watcher)));
+        }
+    }
+}
+
+/// Python representation of RoutingRule
+#[pyclass]
+#[derive(Debug, Clone)]
+pub struct RoutingRule {
+    inner: RustRoutingRule,
+}
+
+impl From<RustRoutingRule> for RoutingRule {
+    fn from(inner: RustRoutingRule) -> Self {
+        RoutingRule { inner }
+    }
+}
+
+#[pymethods]
+impl RoutingRule {
+    #[getter]
+    pub fn selector(&self) -> &str {
+        &self.inner.selector
+    }
+
+    #[getter]
+    pub fn device(&self) -> &str {
+        &self.inner.device
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "RoutingRule(selector='{}', device='{}')",
+            self.inner.selector, self.inner.device
+        )
+    }
+
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+}
+
+/// Python handle to a routing rule watcher; it stops on `stop()`, when
+/// leaving a `with` block, or when the handle is garbage collected
+#[pyclass]
+pub struct RoutingWatcher {
+    inner: Option<RustRoutingWatcher>,
+}
+
+#[pymethods]
+impl RoutingWatcher {
+    /// The rules file being applied
+    #[getter]
+    pub fn path(&self) -> Option<std::path::PathBuf> {
+        self.inner.as_ref().map(|w| w.path().to_path_buf())
+    }
+
+    #[getter]
+    pub fn running(&self) -> bool {
+        self.inner.as_ref().is_some_and(|w| w.is_running())
+    }
+
+    pub fn stop(&mut self, py: Python) -> PyResult<()> {
+        match self.inner.take() {
+            Some(watcher) => py
+                .allow_threads(move || watcher.stop())
+                .map_err(|e| PyRuntimeError::new_err(format!("Failed to watch routing: {}", e))),
+            None => Ok(()),
+        }
+    }
+
+    pub fn __enter__(slf: Py<Self>) -> Py<Self> {
+        slf
+    }
+
+    pub fn __exit__(
+        &mut self,
+        py: Python,
+        _exc_type: PyObject,
+        _exc_value: PyObject,
+        _traceback: PyObject,
+    ) -> PyResult<bool> {
+        self.stop(py)?;
+        Ok(false)
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "RoutingWatcher(path={:?}, running={})",
+            self.path(),
+            self.running()
+        )
+    }
+
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+}
+
+impl Drop for RoutingWatcher {
+    fn drop(&mut self) {
+        if let Some(watcher) = self.inner.take() {
+            Python::with_gil(|py| py.allow_threads(move || drop(watcher)));
+        }
     }
 }
@@ -0,0 +1,98 @@
+//! Fixed-capacity byte ring buffer holding the most recent audio.
+
+/// Keeps the last `capacity` bytes pushed into it. Memory is allocated once
+/// up front and never grows.
+pub(crate) struct RingBuffer {
+    data: Vec<u8>,
+    /// Index the next byte is written to
+    head: usize,
+    len: usize,
+}
+
+impl RingBuffer {
+    pub(crate) fn new(capacity: usize) -> Self {
+        RingBuffer {
+            data: vec![0; capacity],
+            head: 0,
+            len: 0,
+        }
+    }
+
+    pub(crate) fn capacity(&self) -> usize {
+        self.data.len()
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Append `bytes`, overwriting the oldest data once full
+    pub(crate) fn push(&mut self, mut bytes: &[u8]) {
+        let capacity = self.capacity();
+        if capacity == 0 {
+            return;
+        }
+        if bytes.len() > capacity {
+            bytes = &bytes[bytes.len() - capacity..];
+        }
+
+        let first = bytes.len().min(capacity - self.head);
+        self.data[self.head..self.head + first].copy_from_slice(&bytes[..first]);
+        self.data[..bytes.len() - first].copy_from_slice(&bytes[first..]);
+
+        self.head = (self.head + bytes.len()) % capacity;
+        self.len = (self.len + bytes.len()).min(capacity);
+    }
+
+    /// Copy of the most recent `count` bytes (or fewer if less is buffered),
+    /// oldest first
+    pub(crate) fn latest(&self, count: usize) -> Vec<u8> {
+        let count = count.min(self.len);
+        let capacity = self.capacity();
+        let start = (self.head + capacity - count) % capacity.max(1);
+
+        let mut out = Vec::with_capacity(count);
+        let first = count.min(capacity - start);
+        out.extend_from_slice(&self.data[start..start + first]);
+        out.extend_from_slice(&self.data[..count - first]);
+        out
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.head = 0;
+        self.len = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_and_wrap() {
+        let mut ring = RingBuffer::new(4);
+        ring.push(&[1, 2, 3]);
+        assert_eq!(ring.len(), 3);
+        assert_eq!(ring.latest(10), vec![1, 2, 3]);
+
+        ring.push(&[4, 5]);
+        assert_eq!(ring.len(), 4);
+        assert_eq!(ring.latest(4), vec![2, 3, 4, 5]);
+        assert_eq!(ring.latest(2), vec![4, 5]);
+
+        ring.push(&[6, 7, 8, 9, 10, 11]);
+        assert_eq!(ring.latest(4), vec![8, 9, 10, 11]);
+
+        ring.clear();
+        assert_eq!(ring.len(), 0);
+        assert!(ring.latest(4).is_empty());
+    }
+
+    #[test]
+    fn test_zero_capacity() {
+        let mut ring = RingBuffer::new(0);
+        ring.push(&[1, 2, 3]);
+        assert_eq!(ring.len(), 0);
+        assert!(ring.latest(3).is_empty());
+    }
+}
@@ -0,0 +1,298 @@
+//! Choosing devices for preferred-device lists and per-app routing rules.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::{DeviceInfo, RoutingRule};
+
+/// Stream properties a bare selector is compared with
+const BARE_SELECTOR_PROPERTIES: [&str; 2] = ["application.name", "application.process.binary"];
+
+/// Whether a device can be used: its active port, if it has jack detection,
+/// is plugged in
+pub(crate) fn is_available(device: &DeviceInfo) -> bool {
+    device.plugged != Some(false)
+}
+
+/// Whether `entry` of a preference list names `device`, by name or by
+/// description
+pub(crate) fn matches_device(entry: &str, device: &DeviceInfo) -> bool {
+    entry == device.name || entry == device.description
+}
+
+/// The first entry of `priority` that matches an available device
+pub(crate) fn choose_device<'a>(
+    priority: &[String],
+    devices: &'a [DeviceInfo],
+) -> Option<&'a DeviceInfo> {
+    priority.iter().find_map(|entry| {
+        devices
+            .iter()
+            .find(|device| matches_device(entry, device) && is_available(device))
+    })
+}
+
+/// Whether a stream with `properties` matches `selector`: either
+/// `key=value` on one property, or a bare name compared case-insensitively
+/// with the application name and binary
+pub(crate) fn selector_matches(selector: &str, properties: &BTreeMap<String, String>) -> bool {
+    match selector.split_once('=') {
+        Some((key, value)) => properties
+            .get(key.trim())
+            .is_some_and(|property| property == value.trim()),
+        None => BARE_SELECTOR_PROPERTIES.iter().any(|key| {
+            properties
+                .get(*key)
+                .is_some_and(|property| property.eq_ignore_ascii_case(selector.trim()))
+        }),
+    }
+}
+
+/// The first rule matching a stream's properties
+pub(crate) fn rule_for<'a>(
+    rules: &'a [RoutingRule],
+    properties: &BTreeMap<String, String>,
+) -> Option<&'a RoutingRule> {
+    rules
+        .iter()
+        .find(|rule| selector_matches(&rule.selector, properties))
+}
+
+pub(crate) fn validate_rule(rule: &RoutingRule) -> Result<(), String> {
+    if rule.selector.trim().is_empty() || rule.selector.contains('\n') {
+        return Err(format!("Invalid selector '{}'", rule.selector));
+    }
+    if rule.device.is_empty() || rule.device.contains(char::is_whitespace) {
+        return Err(format!(
+            "Invalid device '{}': rules take a device name, not a description",
+            rule.device
+        ));
+    }
+    Ok(())
+}
+
+/// `$XDG_CONFIG_HOME/fa-control/routing.rules`, falling back to
+/// `~/.config`
+pub(crate) fn default_rules_path() -> Result<PathBuf, String> {
+    let config = std::env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .ok_or_else(|| "Neither XDG_CONFIG_HOME nor HOME is set".to_string())?;
+    Ok(config.join("fa-control").join("routing.rules"))
+}
+
+/// Read the rules saved at `path`; a missing file has no rules
+pub(crate) fn load_rules(path: &Path) -> Result<Vec<RoutingRule>, String> {
+    match std::fs::read_to_string(path) {
+        Ok(text) => parse_rules(&text).map_err(|e| format!("{}: {}", path.display(), e)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(format!("Failed to read {}: {}", path.display(), e)),
+    }
+}
+
+/// Write the rules as a text file with one `rule = <device> <selector>` line
+/// per rule, creating its directory if needed. The file is replaced in one
+/// step, so a watcher never reads it half-written.
+pub(crate) fn save_rules(path: &Path, rules: &[RoutingRule]) -> Result<(), String> {
+    if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+    }
+
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| format!("{} is not a file path", path.display()))?;
+    let mut temp_name = std::ffi::OsString::from(".");
+    temp_name.push(file_name);
+    temp_name.push(format!(".{}.tmp", std::process::id()));
+    let temp_path = path.with_file_name(temp_name);
+
+    std::fs::write(&temp_path, rules_to_text(rules))
+        .and_then(|()| std::fs::rename(&temp_path, path))
+        .map_err(|e| {
+            let _ = std::fs::remove_file(&temp_path);
+            format!("Failed to write {}: {}", path.display(), e)
+        })
+}
+
+fn rules_to_text(rules: &[RoutingRule]) -> String {
+    let mut text = "# fa-control routing rules\n".to_string();
+    for rule in rules {
+        text.push_str(&format!("rule = {} {}\n", rule.device, rule.selector));
+    }
+    text
+}
+
+fn parse_rules(text: &str) -> Result<Vec<RoutingRule>, String> {
+    let mut rules = Vec::new();
+
+    for (number, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let error = |message: &str| format!("line {}: {}", number + 1, message);
+
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| error("expected 'key = value'"))?;
+        if key.trim() != "rule" {
+            return Err(error(&format!("unknown key '{}'", key.trim())));
+        }
+        let (device, selector) = value
+            .trim()
+            .split_once(char::is_whitespace)
+            .ok_or_else(|| error("expected 'rule = <device> <selector>'"))?;
+        let rule = RoutingRule {
+            selector: selector.trim().to_string(),
+            device: device.to_string(),
+        };
+        validate_rule(&rule).map_err(|e| error(&e))?;
+        rules.push(rule);
+    }
+
+    Ok(rules)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DeviceState;
+
+    fn device(index: u32, name: &str, description: &str, plugged: Option<bool>) -> DeviceInfo {
+        DeviceInfo {
+            index,
+            name: name.to_string(),
+            description: description.to_string(),
+            ports: Vec::new(),
+            active_port: None,
+            plugged,
+            monitor_source: None,
+            channels: Vec::new(),
+            state: DeviceState::Idle,
+        }
+    }
+
+    fn priority(entries: &[&str]) -> Vec<String> {
+        entries.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_choose_device_in_priority_order() {
+        let devices = [
+            device(1, "hdmi", "HDMI Monitor", None),
+            device(2, "dock", "Dock Speakers", None),
+            device(3, "usb_headset", "USB Headset", None),
+        ];
+
+        let chosen = choose_device(&priority(&["usb_headset", "dock"]), &devices);
+        assert_eq!(chosen.map(|d| d.index), Some(3));
+
+        // Removed devices are skipped, falling back to the next entry
+        let chosen = choose_device(&priority(&["usb_headset", "dock"]), &devices[..2]);
+        assert_eq!(chosen.map(|d| d.index), Some(2));
+
+        assert!(choose_device(&priority(&["usb_headset"]), &devices[..2]).is_none());
+        assert!(choose_device(&[], &devices).is_none());
+    }
+
+    #[test]
+    fn test_choose_device_by_description() {
+        let devices = [device(
+            1,
+            "alsa_output.pci-0000_00_1f.3",
+            "Dock Speakers",
+            None,
+        )];
+        let chosen = choose_device(&priority(&["Dock Speakers"]), &devices);
+        assert_eq!(chosen.map(|d| d.index), Some(1));
+    }
+
+    #[test]
+    fn test_choose_device_skips_unplugged() {
+        let devices = [
+            device(1, "headphones", "Headphones", Some(false)),
+            device(2, "speakers", "Speakers", Some(true)),
+        ];
+        let chosen = choose_device(&priority(&["headphones", "speakers"]), &devices);
+        assert_eq!(chosen.map(|d| d.index), Some(2));
+    }
+
+    fn properties(entries: &[(&str, &str)]) -> BTreeMap<String, String> {
+        entries
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    fn rule(device: &str, selector: &str) -> RoutingRule {
+        RoutingRule {
+            selector: selector.to_string(),
+            device: device.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_selector_matches() {
+        let spotify = properties(&[
+            ("application.name", "Spotify"),
+            ("application.process.binary", "spotify"),
+            ("media.role", "music"),
+        ]);
+        assert!(selector_matches("application.name=Spotify", &spotify));
+        assert!(selector_matches("media.role = music", &spotify));
+        assert!(selector_matches("SPOTIFY", &spotify));
+        assert!(!selector_matches("application.name=spotify", &spotify));
+        assert!(!selector_matches("Discord", &spotify));
+        assert!(!selector_matches("application.icon_name=spotify", &spotify));
+    }
+
+    #[test]
+    fn test_first_matching_rule_wins() {
+        let rules = [
+            rule("headset", "application.name=Discord"),
+            rule("speakers", "media.role=music"),
+            rule("hdmi", "Spotify"),
+        ];
+        let spotify = properties(&[("application.name", "Spotify"), ("media.role", "music")]);
+        assert_eq!(
+            rule_for(&rules, &spotify).map(|r| r.device.as_str()),
+            Some("speakers")
+        );
+        assert!(rule_for(&rules, &properties(&[("application.name", "mpv")])).is_none());
+    }
+
+    #[test]
+    fn test_rules_round_trip() {
+        let rules = vec![
+            rule("alsa_output.usb-headset", "application.name=Google Chrome"),
+            rule("speakers", "spotify"),
+        ];
+        let parsed = parse_rules(&rules_to_text(&rules)).unwrap();
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[0].selector, "application.name=Google Chrome");
+        assert_eq!(parsed[0].device, "alsa_output.usb-headset");
+        assert_eq!(parsed[1].selector, "spotify");
+
+        assert!(parse_rules("rule = speakers").is_err());
+        assert!(parse_rules("route = speakers spotify").is_err());
+    }
+
+    #[test]
+    fn test_save_rules_replaces_file() {
+        let dir = std::env::temp_dir().join(format!("fa-control-rules-{}", std::process::id()));
+        let path = dir.join("routing.rules");
+
+        save_rules(&path, &[rule("speakers", "spotify")]).unwrap();
+        save_rules(&path, &[rule("headset", "discord")]).unwrap();
+        let rules = load_rules(&path).unwrap();
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0].device, "headset");
+
+        // Only the rules file is left behind, not the temporary one
+        let files: Vec<_> = std::fs::read_dir(&dir).unwrap().collect();
+        assert_eq!(files.len(), 1);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
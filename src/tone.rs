@@ -0,0 +1,108 @@
+//! Test signal generation for speaker tests.
+
+use crate::TestSignal;
+
+/// Length of the fade applied at both ends to avoid clicks
+const FADE_SECONDS: f64 = 0.01;
+
+/// Generate `frames` interleaved 32 bit float frames of `signal` with peak
+/// amplitude `level`, on channel `channel` only or on every channel if `None`
+pub(crate) fn generate(
+    signal: TestSignal,
+    rate: u32,
+    channels: usize,
+    channel: Option<usize>,
+    level: f32,
+    frames: usize,
+) -> Vec<f32> {
+    let mut samples = vec![0.0; frames * channels];
+    let mut pink = PinkNoise::new();
+    let fade = ((FADE_SECONDS * rate as f64) as usize).clamp(1, frames.max(1));
+
+    for frame in 0..frames {
+        let value = match signal {
+            TestSignal::Sine { frequency } => {
+                let t = frame as f64 / rate as f64;
+                (2.0 * std::f64::consts::PI * frequency * t).sin() as f32
+            }
+            TestSignal::PinkNoise => pink.next(),
+        };
+        let envelope = (frame.min(frames - 1 - frame) as f32 / fade as f32).min(1.0);
+        let value = value * level * envelope;
+
+        let out = &mut samples[frame * channels..(frame + 1) * channels];
+        match channel {
+            Some(channel) => out[channel] = value,
+            None => out.fill(value),
+        }
+    }
+    samples
+}
+
+/// Pink noise from white noise through Paul Kellet's economy filter, scaled
+/// to stay within -1.0..=1.0
+struct PinkNoise {
+    state: u32,
+    b: [f32; 3],
+}
+
+impl PinkNoise {
+    fn new() -> Self {
+        PinkNoise {
+            state: 0x9E37_79B9,
+            b: [0.0; 3],
+        }
+    }
+
+    /// Uniform white noise in -1.0..1.0 (xorshift32)
+    fn white(&mut self) -> f32 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 17;
+        self.state ^= self.state << 5;
+        self.state as f32 / u32::MAX as f32 * 2.0 - 1.0
+    }
+
+    fn next(&mut self) -> f32 {
+        let white = self.white();
+        self.b[0] = 0.99765 * self.b[0] + white * 0.0990460;
+        self.b[1] = 0.96300 * self.b[1] + white * 0.2965164;
+        self.b[2] = 0.57000 * self.b[2] + white * 1.0526913;
+        let pink = self.b[0] + self.b[1] + self.b[2] + white * 0.1848;
+        (pink * 0.25).clamp(-1.0, 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sine_on_single_channel() {
+        let samples = generate(
+            TestSignal::Sine { frequency: 1000.0 },
+            48000,
+            2,
+            Some(1),
+            0.5,
+            4800,
+        );
+        assert_eq!(samples.len(), 9600);
+
+        let left = samples.iter().step_by(2);
+        let right: Vec<f32> = samples.iter().skip(1).step_by(2).copied().collect();
+        assert!(left.into_iter().all(|&s| s == 0.0));
+
+        let peak = right.iter().fold(0.0f32, |peak, s| peak.max(s.abs()));
+        assert!((peak - 0.5).abs() < 0.01);
+        // Faded in and out
+        assert_eq!(right[0], 0.0);
+        assert!(right[right.len() - 1].abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_pink_noise_level() {
+        let samples = generate(TestSignal::PinkNoise, 48000, 1, None, 0.25, 48000);
+        assert!(samples.iter().all(|s| s.abs() <= 0.25));
+        assert!(samples.iter().any(|s| s.abs() > 0.05));
+    }
+}
@@ -0,0 +1,245 @@
+//! Minimal WAV (RIFF) encoding and decoding of PCM audio.
+
+use std::fs::File;
+use std::io::{BufWriter, Seek, SeekFrom, Write};
+use std::path::Path;
+
+use crate::{SampleFormat, SampleSpec};
+
+const HEADER_SIZE: u32 = 44;
+
+/// Streams PCM frames into a WAV container. The RIFF and data chunk sizes are
+/// patched in by `finish`.
+pub(crate) struct WavWriter<W: Write + Seek> {
+    inner: W,
+    data_size: u32,
+}
+
+impl WavWriter<BufWriter<File>> {
+    pub(crate) fn create(path: &Path, spec: &SampleSpec) -> Result<Self, String> {
+        let file = File::create(path)
+            .map_err(|e| format!("Failed to create {}: {}", path.display(), e))?;
+        WavWriter::new(BufWriter::new(file), spec)
+    }
+}
+
+impl<W: Write + Seek> WavWriter<W> {
+    pub(crate) fn new(mut inner: W, spec: &SampleSpec) -> Result<Self, String> {
+        inner
+            .write_all(&header(spec, 0))
+            .map_err(|e| format!("Failed to write WAV header: {}", e))?;
+        Ok(WavWriter {
+            inner,
+            data_size: 0,
+        })
+    }
+
+    pub(crate) fn write(&mut self, data: &[u8]) -> Result<(), String> {
+        self.inner
+            .write_all(data)
+            .map_err(|e| format!("Failed to write WAV data: {}", e))?;
+        self.data_size = self.data_size.saturating_add(data.len() as u32);
+        Ok(())
+    }
+
+    pub(crate) fn finish(mut self) -> Result<W, String> {
+        let result = (|| {
+            self.inner.seek(SeekFrom::Start(4))?;
+            self.inner
+                .write_all(&(HEADER_SIZE - 8 + self.data_size).to_le_bytes())?;
+            self.inner.seek(SeekFrom::Start(40))?;
+            self.inner.write_all(&self.data_size.to_le_bytes())?;
+            self.inner.seek(SeekFrom::End(0))?;
+            self.inner.flush()
+        })();
+        result.map_err(|e| format!("Failed to finish WAV file: {}", e))?;
+        Ok(self.inner)
+    }
+}
+
+/// Read a WAV file into its sample spec and PCM data
+pub(crate) fn read(path: &Path) -> Result<(SampleSpec, Vec<u8>), String> {
+    let bytes =
+        std::fs::read(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    parse(&bytes).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+const FORMAT_PCM: u16 = 1;
+const FORMAT_FLOAT: u16 = 3;
+const FORMAT_EXTENSIBLE: u16 = 0xFFFE;
+
+/// Parse a RIFF/WAVE file holding 16 or 32 bit integer or 32 bit float PCM
+fn parse(bytes: &[u8]) -> Result<(SampleSpec, Vec<u8>), String> {
+    if bytes.len() < 12 || &bytes[0..4] != b"RIFF" || &bytes[8..12] != b"WAVE" {
+        return Err("Not a WAV file".to_string());
+    }
+
+    let mut spec = None;
+    let mut offset = 12;
+    while offset + 8 <= bytes.len() {
+        let id = &bytes[offset..offset + 4];
+        let size = u32::from_le_bytes(bytes[offset + 4..offset + 8].try_into().unwrap()) as usize;
+        let body = &bytes[offset + 8..bytes.len().min(offset + 8 + size)];
+
+        match id {
+            b"fmt " => spec = Some(parse_format(body)?),
+            b"data" => {
+                let spec = spec.ok_or_else(|| "WAV data before format chunk".to_string())?;
+                // Drop a trailing partial frame from truncated files
+                let len = body.len() - body.len() % spec.frame_size();
+                return Ok((spec, body[..len].to_vec()));
+            }
+            _ => {}
+        }
+        // Chunks are padded to an even size
+        offset += 8 + size + size % 2;
+    }
+
+    Err("WAV file has no data chunk".to_string())
+}
+
+fn parse_format(body: &[u8]) -> Result<SampleSpec, String> {
+    if body.len() < 16 {
+        return Err("Truncated WAV format chunk".to_string());
+    }
+    let u16_at = |at: usize| u16::from_le_bytes([body[at], body[at + 1]]);
+
+    let mut format_tag = u16_at(0);
+    if format_tag == FORMAT_EXTENSIBLE && body.len() >= 26 {
+        // The sub-format GUID starts with the actual format tag
+        format_tag = u16_at(24);
+    }
+    let channels = u16_at(2);
+    let rate = u32::from_le_bytes(body[4..8].try_into().unwrap());
+    let bits_per_sample = u16_at(14);
+
+    let format = match (format_tag, bits_per_sample) {
+        (FORMAT_PCM, 16) => SampleFormat::S16Le,
+        (FORMAT_PCM, 32) => SampleFormat::S32Le,
+        (FORMAT_FLOAT, 32) => SampleFormat::F32Le,
+        _ => {
+            return Err(format!(
+                "Unsupported WAV format {} with {} bits per sample",
+                format_tag, bits_per_sample
+            ))
+        }
+    };
+    if channels == 0 || channels > u8::MAX as u16 {
+        return Err(format!("Unsupported channel count {}", channels));
+    }
+
+    Ok(SampleSpec {
+        format,
+        rate,
+        channels: channels as u8,
+    })
+}
+
+/// Canonical 44 byte header for `data_size` bytes of PCM data
+fn header(spec: &SampleSpec, data_size: u32) -> Vec<u8> {
+    let format_tag = match spec.format {
+        SampleFormat::S16Le | SampleFormat::S32Le => FORMAT_PCM,
+        SampleFormat::F32Le => FORMAT_FLOAT,
+    };
+    let block_align = spec.frame_size() as u16;
+    let bits_per_sample = (spec.format.bytes_per_sample() * 8) as u16;
+
+    let mut header = Vec::with_capacity(HEADER_SIZE as usize);
+    header.extend_from_slice(b"RIFF");
+    header.extend_from_slice(&(HEADER_SIZE - 8 + data_size).to_le_bytes());
+    header.extend_from_slice(b"WAVE");
+    header.extend_from_slice(b"fmt ");
+    header.extend_from_slice(&16u32.to_le_bytes());
+    header.extend_from_slice(&format_tag.to_le_bytes());
+    header.extend_from_slice(&(spec.channels as u16).to_le_bytes());
+    header.extend_from_slice(&spec.rate.to_le_bytes());
+    header.extend_from_slice(&(spec.bytes_per_second() as u32).to_le_bytes());
+    header.extend_from_slice(&block_align.to_le_bytes());
+    header.extend_from_slice(&bits_per_sample.to_le_bytes());
+    header.extend_from_slice(b"data");
+    header.extend_from_slice(&data_size.to_le_bytes());
+    header
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_write_wav() {
+        let spec = SampleSpec {
+            format: SampleFormat::S16Le,
+            rate: 44100,
+            channels: 2,
+        };
+        let mut writer = WavWriter::new(Cursor::new(Vec::new()), &spec).unwrap();
+        writer.write(&[1, 0, 2, 0, 3, 0, 4, 0]).unwrap();
+        let bytes = writer.finish().unwrap().into_inner();
+
+        assert_eq!(bytes.len(), 44 + 8);
+        assert_eq!(&bytes[0..4], b"RIFF");
+        assert_eq!(u32::from_le_bytes(bytes[4..8].try_into().unwrap()), 36 + 8);
+        assert_eq!(u16::from_le_bytes(bytes[20..22].try_into().unwrap()), 1);
+        assert_eq!(u16::from_le_bytes(bytes[22..24].try_into().unwrap()), 2);
+        assert_eq!(u32::from_le_bytes(bytes[24..28].try_into().unwrap()), 44100);
+        assert_eq!(
+            u32::from_le_bytes(bytes[28..32].try_into().unwrap()),
+            176400
+        );
+        assert_eq!(u32::from_le_bytes(bytes[40..44].try_into().unwrap()), 8);
+        assert_eq!(&bytes[44..], &[1, 0, 2, 0, 3, 0, 4, 0]);
+    }
+
+    #[test]
+    fn test_float_format_tag() {
+        let spec = SampleSpec {
+            format: SampleFormat::F32Le,
+            rate: 48000,
+            channels: 1,
+        };
+        let bytes = header(&spec, 0);
+        assert_eq!(u16::from_le_bytes(bytes[20..22].try_into().unwrap()), 3);
+        assert_eq!(u16::from_le_bytes(bytes[34..36].try_into().unwrap()), 32);
+    }
+
+    #[test]
+    fn test_parse_round_trip() {
+        let spec = SampleSpec {
+            format: SampleFormat::S32Le,
+            rate: 22050,
+            channels: 1,
+        };
+        let mut writer = WavWriter::new(Cursor::new(Vec::new()), &spec).unwrap();
+        writer.write(&[1, 2, 3, 4, 5, 6, 7, 8]).unwrap();
+        let bytes = writer.finish().unwrap().into_inner();
+
+        let (parsed, data) = parse(&bytes).unwrap();
+        assert_eq!(parsed, spec);
+        assert_eq!(data, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn test_parse_skips_unknown_chunks() {
+        let spec = SampleSpec::default();
+        let mut bytes = header(&spec, 4);
+        // Insert an odd-sized LIST chunk between "fmt " and "data"
+        let list = [b"LIST".as_slice(), &3u32.to_le_bytes(), &[0, 0, 0, 0]].concat();
+        bytes.splice(36..36, list);
+        bytes.extend_from_slice(&[9, 0, 8, 0]);
+
+        let (parsed, data) = parse(&bytes).unwrap();
+        assert_eq!(parsed, spec);
+        assert_eq!(data, vec![9, 0, 8, 0]);
+    }
+
+    #[test]
+    fn test_parse_rejects_unsupported() {
+        assert!(parse(b"not a wav file").is_err());
+
+        let mut bytes = header(&SampleSpec::default(), 0);
+        // 8 bit PCM
+        bytes[34] = 8;
+        assert!(parse(&bytes).is_err());
+    }
+}